@@ -0,0 +1,93 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use goida_runtime::parser::prelude::Parser as ProgramParser;
+use goida_runtime::session::Session;
+
+use crate::{describe_runtime_error, format_parse_error};
+
+/// Parse and execution time of one run of the script.
+struct Sample {
+    parse: Duration,
+    execute: Duration,
+}
+
+/// Runs `file` once to warm up and then `iterations` more times, each in a fresh
+/// session, printing the time of every run and the median, p95 and minimum.
+pub(crate) fn run_bench(file: &str, iterations: usize) -> Result<(), String> {
+    if iterations == 0 {
+        return Err("Число замеров должно быть больше нуля".to_string());
+    }
+    let source = fs::read_to_string(file).map_err(|err| format!("{}: '{}'", err, file))?;
+
+    // Первый запуск платит за аллокатор и кэши ОС и в замеры не входит.
+    run_once(file, &source)?;
+    let mut samples = Vec::with_capacity(iterations);
+    for iteration in 1..=iterations {
+        let sample = run_once(file, &source)?;
+        println!(
+            "итерация {:>3}: разбор {:>9.3} мс, выполнение {:>9.3} мс",
+            iteration,
+            duration_ms(sample.parse),
+            duration_ms(sample.execute)
+        );
+        samples.push(sample);
+    }
+
+    let mut parse = samples
+        .iter()
+        .map(|sample| sample.parse)
+        .collect::<Vec<_>>();
+    let mut execute = samples
+        .iter()
+        .map(|sample| sample.execute)
+        .collect::<Vec<_>>();
+    parse.sort();
+    execute.sort();
+    println!();
+    println!(
+        "{:<12} {:>12} {:>12} {:>12}",
+        "", "медиана", "p95", "минимум"
+    );
+    print_summary("разбор", &parse);
+    print_summary("выполнение", &execute);
+    Ok(())
+}
+
+fn run_once(file: &str, source: &str) -> Result<Sample, String> {
+    let mut session = Session::new();
+    let started = Instant::now();
+    let module = ProgramParser::new(session.interner(), file, PathBuf::from(file))
+        .parse(source)
+        .map_err(|err| format_parse_error(&err))?;
+    let parse = started.elapsed();
+
+    let started = Instant::now();
+    session
+        .execute(module)
+        .map_err(|err| describe_runtime_error(err).0)?;
+    Ok(Sample {
+        parse,
+        execute: started.elapsed(),
+    })
+}
+
+fn print_summary(label: &str, sorted: &[Duration]) {
+    println!(
+        "{:<12} {:>9.3} мс {:>9.3} мс {:>9.3} мс",
+        label,
+        duration_ms(percentile(sorted, 0.50)),
+        duration_ms(percentile(sorted, 0.95)),
+        duration_ms(sorted[0])
+    );
+}
+
+fn percentile(sorted: &[Duration], percentile: f64) -> Duration {
+    let index = ((sorted.len() - 1) as f64 * percentile).ceil() as usize;
+    sorted[index]
+}
+
+fn duration_ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
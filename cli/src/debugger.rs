@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::sync::Arc;
+
+use goida_runtime::interpreter::debugger::{DebugCommand, DebugFrontend, DebugStop, Debugger};
+use goida_runtime::session::Session;
+
+const HELP: &str = "\
+Команды:
+  s, шаг               следующая инструкция, с заходом в функции
+  n, далее             следующая инструкция без захода в функции
+  c, продолжить        выполнять до точки остановки
+  b, точка [файл:]N    поставить точку остановки на строке N
+  d, удалить [файл:]N  убрать точку остановки
+  bl, точки            список точек остановки
+  p, переменные        показать переменные
+  q, выход             завершить программу
+  h, помощь            эта справка";
+
+/// Reads debugger commands from stdin; at end of input the program just runs on.
+struct TerminalFrontend {
+    sources: HashMap<String, Vec<String>>,
+}
+
+impl TerminalFrontend {
+    fn source_line(&mut self, file: &str, line: usize) -> String {
+        self.sources
+            .entry(file.to_string())
+            .or_insert_with(|| {
+                fs::read_to_string(file)
+                    .unwrap_or_default()
+                    .lines()
+                    .map(str::to_string)
+                    .collect()
+            })
+            .get(line.wrapping_sub(1))
+            .map(|text| text.trim().to_string())
+            .unwrap_or_default()
+    }
+}
+
+impl DebugFrontend for TerminalFrontend {
+    fn stopped(&mut self, stop: &DebugStop, debugger: &Debugger) -> DebugCommand {
+        println!(
+            "-> {}:{}  {}",
+            stop.file,
+            stop.line,
+            self.source_line(&stop.file, stop.line)
+        );
+        let stdin = io::stdin();
+        loop {
+            print!("(goida) ");
+            let _ = io::stdout().flush();
+            let mut input = String::new();
+            if stdin.lock().read_line(&mut input).unwrap_or(0) == 0 {
+                println!();
+                return DebugCommand::Continue;
+            }
+            let mut words = input.split_whitespace();
+            let command = words.next().unwrap_or("");
+            let argument = words.next();
+            match command {
+                "s" | "step" | "шаг" => return DebugCommand::Step,
+                "n" | "next" | "далее" => return DebugCommand::Next,
+                "c" | "continue" | "продолжить" => return DebugCommand::Continue,
+                "b" | "break" | "точка" | "d" | "delete" | "удалить" => {
+                    let Some((file, line)) = parse_location(argument, &stop.file) else {
+                        println!("Укажите строку: {command} [файл:]N");
+                        continue;
+                    };
+                    if matches!(command, "b" | "break" | "точка") {
+                        debugger.add_breakpoint(&file, line);
+                        println!("Точка остановки: {file}:{line}");
+                    } else if debugger.remove_breakpoint(&file, line) {
+                        println!("Точка остановки удалена: {file}:{line}");
+                    } else {
+                        println!("Нет точки остановки: {file}:{line}");
+                    }
+                }
+                "bl" | "breakpoints" | "точки" => {
+                    let breakpoints = debugger.breakpoints();
+                    if breakpoints.is_empty() {
+                        println!("Точек остановки нет");
+                    }
+                    for (file, line) in breakpoints {
+                        println!("  {file}:{line}");
+                    }
+                }
+                "p" | "vars" | "переменные" => {
+                    if stop.variables.is_empty() {
+                        println!("Переменных нет");
+                    }
+                    for (name, value) in &stop.variables {
+                        println!("  {name} = {value}");
+                    }
+                }
+                "q" | "quit" | "выход" => std::process::exit(0),
+                "" => {}
+                "h" | "help" | "помощь" => println!("{HELP}"),
+                _ => println!("Неизвестная команда: {command}. Введите h для справки"),
+            }
+        }
+    }
+}
+
+/// `N` in the current file or `файл:N`.
+fn parse_location(argument: Option<&str>, current_file: &str) -> Option<(String, usize)> {
+    let argument = argument?;
+    match argument.rsplit_once(':') {
+        // Отладчик сообщает полные пути, поэтому и точку ставим по полному.
+        Some((file, line)) => Some((
+            fs::canonicalize(file).map_or_else(
+                |_| file.to_string(),
+                |path| path.to_string_lossy().to_string(),
+            ),
+            line.parse().ok()?,
+        )),
+        None => Some((current_file.to_string(), argument.parse().ok()?)),
+    }
+}
+
+/// Attaches a terminal debugger that stops before the first statement.
+pub(crate) fn attach_terminal_debugger(session: &mut Session) {
+    println!("Отладка. Введите h для справки.");
+    session.attach_debugger(Arc::new(Debugger::new(Box::new(TerminalFrontend {
+        sources: HashMap::new(),
+    }))));
+}
@@ -0,0 +1,51 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use goida_runtime::parser::prelude::Parser as ProgramParser;
+use goida_runtime::session::Session;
+
+use crate::{collect_source_files, format_parse_error, DocFormatArg};
+
+/// Writes one documentation page per `.goida` module found under `path`.
+pub(crate) fn generate_docs(path: &str, out: &str, format: DocFormatArg) -> Result<(), String> {
+    let root = Path::new(path);
+    let mut files = Vec::new();
+    let base = if root.is_file() {
+        files.push(root.to_path_buf());
+        root.parent().unwrap_or(Path::new("")).to_path_buf()
+    } else {
+        collect_source_files(root, &mut files, &|name| name.ends_with(".goida"))
+            .map_err(|err| format!("{}: '{}'", err, path))?;
+        root.to_path_buf()
+    };
+    files.sort();
+
+    let out = Path::new(out);
+    let extension = match format {
+        DocFormatArg::Markdown => "md",
+        DocFormatArg::Html => "html",
+    };
+    for file in files {
+        let display = file.to_string_lossy().to_string();
+        let source = fs::read_to_string(&file).map_err(|err| format!("{}: '{}'", err, display))?;
+        let session = Session::new();
+        let parser = ProgramParser::new(session.interner(), &display, file.clone());
+        let mut docs = parser
+            .module_docs(&source)
+            .map_err(|err| format!("{display}: {}", format_parse_error(&err)))?;
+
+        let relative = file.strip_prefix(&base).unwrap_or(&file);
+        docs.name = relative.to_string_lossy().to_string();
+        let target: PathBuf = out.join(relative).with_extension(extension);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).map_err(|err| format!("{}: '{}'", err, parent.display()))?;
+        }
+        let page = match format {
+            DocFormatArg::Markdown => docs.to_markdown(),
+            DocFormatArg::Html => docs.to_html(),
+        };
+        fs::write(&target, page).map_err(|err| format!("{}: '{}'", err, target.display()))?;
+        println!("{}", target.display());
+    }
+    Ok(())
+}
@@ -1,18 +1,30 @@
 use ariadne::{Color, Label, Report, ReportKind};
-use clap::{Parser, Subcommand, ValueEnum};
-use std::{
-    fs,
-    io::{self, Write},
-    path::PathBuf,
-};
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use std::ffi::OsString;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use goida_runtime::interpreter::prelude::RuntimeError;
+use goida_runtime::interpreter::options::{Capability, InterpreterOptions};
+use goida_runtime::interpreter::prelude::{RuntimeError, Value};
+use goida_runtime::interpreter::profiler::ProfileEntry;
 use goida_runtime::parser::prelude::{FormatLanguage, ParseError, Parser as ProgramParser};
 use goida_runtime::session::Session;
 use goida_runtime::traits::prelude::CoreOperations;
 use goida_syntax::ast::prelude::{ErrorData, Span};
 
+mod bench;
+mod debugger;
+mod docs;
 mod package;
+mod repl;
+mod standalone;
+mod test_runner;
+
+/// Module names for source that does not come from a file.
+const STDIN_MODULE: &str = "<stdin>";
+const EVAL_MODULE: &str = "<eval>";
 
 #[derive(Parser)]
 #[command(
@@ -21,6 +33,13 @@ mod package;
     disable_help_subcommand = true
 )]
 struct Cli {
+    #[arg(
+        short = 'e',
+        long = "eval",
+        value_name = "КОД",
+        help = "Выполнить код из аргумента"
+    )]
+    eval: Option<String>,
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -29,6 +48,66 @@ struct Cli {
 enum Commands {
     #[command(about = "Запустить .goida файл")]
     Run {
+        #[arg(help = "Путь к исходному .goida файлу или - для чтения из stdin")]
+        file: String,
+        #[arg(long, help = "Вывести время выполнения по функциям")]
+        profile: bool,
+        #[arg(
+            long,
+            help = "Запретить работу с файлами, системой, сетью и процессами"
+        )]
+        sandbox: bool,
+        #[arg(
+            long,
+            value_name = "ДОСТУП",
+            requires = "sandbox",
+            value_parser = parse_capability,
+            help = "Разрешить в песочнице: файлы, система, сеть или процессы"
+        )]
+        allow: Vec<Capability>,
+        #[arg(
+            long,
+            value_name = "МС",
+            help = "Прервать скрипт, если он работает дольше"
+        )]
+        time_limit: Option<u64>,
+        #[arg(
+            long,
+            value_name = "ЧИСЛО",
+            help = "Прервать скрипт после этого числа операций"
+        )]
+        max_operations: Option<u64>,
+        #[arg(
+            long,
+            value_name = "МБ",
+            help = "Прервать скрипт, если его списки, словари и строки займут больше"
+        )]
+        memory_limit: Option<usize>,
+        #[arg(
+            long,
+            value_name = "ЧИСЛО",
+            help = "Зерно генератора Случай для повторяемых запусков"
+        )]
+        seed: Option<u64>,
+        #[arg(
+            long,
+            help = "Подставить значения констант и однократно присвоенных переменных в выражения перед запуском"
+        )]
+        optimize: bool,
+        #[arg(
+            long,
+            help = "Выполнять подключённые модули сразу, а не при первом обращении"
+        )]
+        eager_imports: bool,
+        #[arg(
+            trailing_var_arg = true,
+            allow_hyphen_values = true,
+            help = "Дополнительные аргументы скрипта"
+        )]
+        script_args: Vec<String>,
+    },
+    #[command(about = "Запустить .goida файл в пошаговом отладчике")]
+    Debug {
         #[arg(help = "Путь к исходному .goida файлу")]
         file: String,
         #[arg(
@@ -38,6 +117,37 @@ enum Commands {
         )]
         script_args: Vec<String>,
     },
+    #[command(about = "Проверить .goida файл и его импорты без запуска")]
+    Check {
+        #[arg(help = "Путь к исходному .goida файлу")]
+        file: String,
+    },
+    #[command(about = "Запустить тесты: файлы *_тест.goida и функции тест_*")]
+    Test {
+        #[arg(default_value = ".", help = "Файл или каталог с тестами")]
+        path: String,
+    },
+    #[command(about = "Замерить время разбора и выполнения .goida файла")]
+    Bench {
+        #[arg(help = "Путь к исходному .goida файлу")]
+        file: String,
+        #[arg(
+            short = 'n',
+            long,
+            default_value_t = 10,
+            help = "Число замеров после разогревочного запуска"
+        )]
+        iterations: usize,
+    },
+    #[command(about = "Сгенерировать документацию из комментариев ///")]
+    Doc {
+        #[arg(default_value = ".", help = "Каталог или файл с исходниками")]
+        path: String,
+        #[arg(long, default_value = "doc", help = "Каталог для документации")]
+        out: String,
+        #[arg(long, value_enum, default_value_t = DocFormatArg::Markdown)]
+        format: DocFormatArg,
+    },
     #[command(about = "Создать новый проект")]
     New {
         #[arg(help = "Имя каталога проекта и имя пакета")]
@@ -69,8 +179,20 @@ enum Commands {
     },
     #[command(about = "Install all dependencies from goida.toml and update goida.lock")]
     Sync,
-    #[command(about = "Synchronize dependencies and build the current package")]
-    Build,
+    #[command(
+        about = "Synchronize dependencies and build the current package, or build a script into an executable"
+    )]
+    Build {
+        #[arg(help = "Скрипт, который нужно собрать в исполняемый файл")]
+        file: Option<String>,
+        #[arg(
+            short,
+            long,
+            requires = "file",
+            help = "Путь к исполняемому файлу, по умолчанию имя скрипта"
+        )]
+        output: Option<String>,
+    },
     #[command(about = "Создать виртуальное окружение Гойда")]
     Venv {
         #[arg(default_value = ".goida", help = "Путь к каталогу окружения")]
@@ -78,6 +200,8 @@ enum Commands {
     },
     #[command(about = "Запустить интерактивный режим")]
     Repl,
+    #[command(about = "Запустить языковой сервер (LSP) через stdin/stdout")]
+    Lsp,
     #[command(about = "Format a .goida file")]
     Fmt {
         #[arg(help = "Path to a .goida file")]
@@ -87,6 +211,20 @@ enum Commands {
         #[arg(long, value_enum, default_value_t = FormatLanguageArg::English)]
         language: FormatLanguageArg,
     },
+    #[command(about = "Print the parsed AST of a .goida file")]
+    Ast {
+        #[arg(help = "Path to a .goida file")]
+        file: String,
+        #[arg(long, value_enum, default_value_t = AstFormatArg::Json)]
+        format: AstFormatArg,
+        #[arg(long, help = "Print node counts and arena memory instead of the tree")]
+        stats: bool,
+    },
+    #[command(about = "Print the lexer tokens of a .goida file")]
+    Tokens {
+        #[arg(help = "Path to a .goida file")]
+        file: String,
+    },
     #[command(about = "Show macro expansion AST preview")]
     ExpandMacros {
         #[arg(help = "Path to a .goida file")]
@@ -100,6 +238,18 @@ enum FormatLanguageArg {
     Russian,
 }
 
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum DocFormatArg {
+    Markdown,
+    Html,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum AstFormatArg {
+    Json,
+    CompactJson,
+}
+
 impl From<FormatLanguageArg> for FormatLanguage {
     fn from(value: FormatLanguageArg) -> Self {
         match value {
@@ -110,15 +260,110 @@ impl From<FormatLanguageArg> for FormatLanguage {
 }
 
 fn main() {
-    let cli = Cli::parse();
+    standalone::run_embedded_program();
+    let cli = Cli::parse_from(implicit_run_args());
     let mut session = Session::new();
+    if let Some(code) = &cli.eval {
+        if execute_code(&mut session, code, EVAL_MODULE).is_err() {
+            std::process::exit(1);
+        }
+        return;
+    }
     match &cli.command {
-        Some(Commands::Run { file, .. }) => {
+        Some(Commands::Run {
+            file,
+            profile,
+            sandbox,
+            allow,
+            time_limit,
+            max_operations,
+            memory_limit,
+            seed,
+            optimize,
+            eager_imports,
+            script_args,
+        }) => {
+            if *sandbox
+                || time_limit.is_some()
+                || max_operations.is_some()
+                || memory_limit.is_some()
+                || seed.is_some()
+                || *eager_imports
+            {
+                let mut options = if *sandbox {
+                    allow
+                        .iter()
+                        .fold(InterpreterOptions::sandboxed(), |options, capability| {
+                            options.allow(*capability)
+                        })
+                } else {
+                    InterpreterOptions::default()
+                };
+                if let Some(milliseconds) = time_limit {
+                    options = options.with_time_limit(Duration::from_millis(*milliseconds));
+                }
+                if let Some(operations) = max_operations {
+                    options = options.with_operation_limit(*operations);
+                }
+                if let Some(megabytes) = memory_limit {
+                    options = options.with_memory_limit(megabytes.saturating_mul(1024 * 1024));
+                }
+                if let Some(seed) = seed {
+                    options = options.with_seed(*seed);
+                }
+                if *eager_imports {
+                    options = options.with_eager_imports();
+                }
+                session = Session::with_options(options);
+            }
+            session.set_script_args(script_args.clone());
+            if *optimize {
+                session.enable_optimizations();
+            }
+            if *profile {
+                session.enable_profiling();
+            }
+            let result = run_file(&mut session, file);
+            let entries = session.profile();
+            if !entries.is_empty() {
+                print_profile(&entries);
+            }
+            if let Err((err, _)) = result {
+                println!("{}", err.lines().next().unwrap_or(&err));
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::Debug { file, script_args }) => {
+            session.set_script_args(script_args.clone());
+            debugger::attach_terminal_debugger(&mut session);
             if let Err((err, _)) = run_file(&mut session, file) {
                 println!("{}", err.lines().next().unwrap_or(&err));
                 std::process::exit(1);
             }
         }
+        Some(Commands::Check { file }) => {
+            if let Err(err) = check_file(&mut session, file) {
+                eprintln!("{err}");
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::Test { path }) => {
+            if !test_runner::run_tests(path) {
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::Bench { file, iterations }) => {
+            if let Err(err) = bench::run_bench(file, *iterations) {
+                eprintln!("{err}");
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::Doc { path, out, format }) => {
+            if let Err(err) = docs::generate_docs(path, out, *format) {
+                eprintln!("{err}");
+                std::process::exit(1);
+            }
+        }
         Some(Commands::New {
             name,
             description,
@@ -141,9 +386,15 @@ fn main() {
         )),
         Some(Commands::Remove { name }) => exit_on_package_error(package::remove_dependency(name)),
         Some(Commands::Sync) => exit_on_package_error(package::sync_dependencies()),
-        Some(Commands::Build) => exit_on_package_error(package::build_project()),
+        Some(Commands::Build { file, output }) => match file {
+            Some(file) => {
+                exit_on_package_error(standalone::build_executable(file, output.as_deref()))
+            }
+            None => exit_on_package_error(package::build_project()),
+        },
         Some(Commands::Venv { path }) => exit_on_package_error(package::create_venv(path)),
-        Some(Commands::Repl) => run_repl(&mut session),
+        Some(Commands::Repl) => repl::run_repl(std::mem::take(&mut session)),
+        Some(Commands::Lsp) => goida_lsp::run_stdio(),
         Some(Commands::Fmt {
             file,
             write,
@@ -154,6 +405,28 @@ fn main() {
                 std::process::exit(1);
             }
         }
+        Some(Commands::Ast {
+            file,
+            format,
+            stats,
+        }) => {
+            let result = if *stats {
+                print_ast_stats(&session, file)
+            } else {
+                dump_ast_file(&session, file, *format)
+            };
+            if let Err(err) = result {
+                eprintln!("{err}");
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::Tokens { file }) => match fs::read_to_string(file) {
+            Ok(source) => print!("{}", ProgramParser::dump_tokens(&source)),
+            Err(err) => {
+                eprintln!("{}: '{}'", err, file);
+                std::process::exit(1);
+            }
+        },
         Some(Commands::ExpandMacros { file }) => {
             if let Err(err) = expand_macros_file(&session, file) {
                 eprintln!("{err}");
@@ -166,6 +439,26 @@ fn main() {
     }
 }
 
+fn parse_capability(name: &str) -> Result<Capability, String> {
+    Capability::from_name(name).ok_or_else(|| {
+        format!("неизвестный доступ '{name}', ожидается файлы, система, сеть или процессы")
+    })
+}
+
+/// `goida файл.goida ...` means `goida run файл.goida ...`, as `#!/usr/bin/env goida` needs.
+fn implicit_run_args() -> Vec<OsString> {
+    let mut args = std::env::args_os().collect::<Vec<_>>();
+    if let Some(first) = args.get(1).and_then(|arg| arg.to_str()) {
+        let is_command = Cli::command()
+            .get_subcommands()
+            .any(|command| command.get_name() == first);
+        if !is_command && !first.starts_with('-') && Path::new(first).is_file() {
+            args.insert(1, OsString::from("run"));
+        }
+    }
+    args
+}
+
 fn exit_on_package_error(result: Result<(), String>) {
     if let Err(err) = result {
         eprintln!("{err}");
@@ -192,6 +485,36 @@ fn format_file(
     Ok(())
 }
 
+fn dump_ast_file(session: &Session, file: &str, format: AstFormatArg) -> Result<(), String> {
+    let source = fs::read_to_string(file).map_err(|err| format!("{}: '{}'", err, file))?;
+    let parser = ProgramParser::new(session.interner(), file, PathBuf::from(file));
+    let ast = parser
+        .ast_json(&source)
+        .map_err(|err| format_parse_error(&err))?;
+    let output = match format {
+        AstFormatArg::Json => serde_json::to_string_pretty(&ast),
+        AstFormatArg::CompactJson => serde_json::to_string(&ast),
+    }
+    .map_err(|err| err.to_string())?;
+    println!("{output}");
+    Ok(())
+}
+
+fn print_ast_stats(session: &Session, file: &str) -> Result<(), String> {
+    let source = fs::read_to_string(file).map_err(|err| format!("{}: '{}'", err, file))?;
+    let parser = ProgramParser::new(session.interner(), file, PathBuf::from(file));
+    let stats = parser
+        .ast_stats(&source)
+        .map_err(|err| format_parse_error(&err))?;
+    println!("выражения:              {}", stats.expressions);
+    println!("инструкции:             {}", stats.statements);
+    println!("типы:                   {}", stats.types);
+    println!("повторные типы:         {}", stats.reused_types);
+    println!("повторяющиеся позиции:  {}", stats.repeated_spans);
+    println!("память арены:           {} байт", stats.bytes);
+    Ok(())
+}
+
 fn expand_macros_file(session: &Session, file: &str) -> Result<(), String> {
     let source = fs::read_to_string(file).map_err(|err| format!("{}: '{}'", err, file))?;
     let parser = ProgramParser::new(session.interner(), file, PathBuf::from(file));
@@ -220,7 +543,64 @@ fn format_parse_error(err: &ParseError) -> String {
     format!("{kind}: {}", data.message)
 }
 
+fn check_file(session: &mut Session, file: &str) -> Result<(), String> {
+    let source = fs::read_to_string(file).map_err(|err| format!("{}: '{}'", err, file))?;
+    let parser = ProgramParser::new(session.interner(), file, PathBuf::from(file));
+    let module = parser.module.clone();
+    match parser.parse(&source) {
+        Ok(_) => {
+            println!("Ошибок не найдено: {file}");
+            Ok(())
+        }
+        Err(err) => {
+            let message = format_parse_error(&err);
+            session.register_diagnostic_module(module);
+            let (kind, data) = match &err {
+                ParseError::TypeError(e) => ("Ошибка типов", e),
+                ParseError::InvalidSyntax(e) => ("Ошибка синтаксиса", e),
+                ParseError::ImportError(e) => ("Ошибка импорта", e),
+            };
+            render_error(session, kind, data);
+            Err(message)
+        }
+    }
+}
+
+/// Prints the profile table to stderr so it does not mix with program output.
+fn print_profile(entries: &[ProfileEntry]) {
+    let width = entries
+        .iter()
+        .map(|entry| entry.name.chars().count())
+        .chain(["Функция".chars().count()])
+        .max()
+        .unwrap_or_default();
+    let millis = |duration: std::time::Duration| duration.as_secs_f64() * 1000.0;
+    eprintln!("\nПрофиль выполнения:");
+    eprintln!(
+        "{:<width$}  {:>8}  {:>14}  {:>12}  {:>12}",
+        "Функция", "Вызовы", "Собственное, мс", "Всего, мс", "Среднее, мс"
+    );
+    for entry in entries {
+        eprintln!(
+            "{:<width$}  {:>8}  {:>14.3}  {:>12.3}  {:>12.3}",
+            entry.name,
+            entry.calls,
+            millis(entry.own),
+            millis(entry.total),
+            millis(entry.total) / entry.calls as f64
+        );
+    }
+}
+
 fn run_file(session: &mut Session, filename: &str) -> Result<(), (String, ErrorData)> {
+    if filename == "-" {
+        let mut content = String::new();
+        std::io::stdin().read_to_string(&mut content).map_err(|e| {
+            let msg = format!("{}: stdin", e);
+            (msg.clone(), ErrorData::new(Span::default(), msg))
+        })?;
+        return execute_code(session, &content, STDIN_MODULE);
+    }
     let content = fs::read_to_string(filename).map_err(|e| {
         let msg = format!("{}: '{}'", e, filename);
         (msg.clone(), ErrorData::new(Span::default(), msg))
@@ -233,53 +613,50 @@ fn execute_code(
     code: &str,
     filename: &str,
 ) -> Result<(), (String, ErrorData)> {
-    let path = PathBuf::from(filename);
+    execute_source(session, code, filename, false).map(|_| ())
+}
+
+/// Runs source in the session; incremental runs keep globals between calls
+/// and return the value of a trailing expression.
+fn execute_source(
+    session: &mut Session,
+    code: &str,
+    filename: &str,
+    incremental: bool,
+) -> Result<Option<Value>, (String, ErrorData)> {
+    let parser = if incremental {
+        session.incremental_parser(filename)
+    } else {
+        ProgramParser::new(session.interner(), filename, PathBuf::from(filename))
+            .with_optimizations(session.optimizations_enabled())
+    };
+    execute_with_parser(session, parser, code, filename, incremental)
+}
 
-    let parser = ProgramParser::new(session.interner(), filename, path.clone());
+fn execute_with_parser(
+    session: &mut Session,
+    parser: ProgramParser,
+    code: &str,
+    filename: &str,
+    incremental: bool,
+) -> Result<Option<Value>, (String, ErrorData)> {
+    // Ввод без файла (stdin, --eval, REPL) нужен диагностике из памяти.
+    session.runtime().source_manager.load_source(filename, code);
     let _module = parser.module.clone();
 
     match parser.parse(code) {
         Ok(program) => {
-            let interpret_result = session.execute(program);
+            let interpret_result = if incremental {
+                session.execute_incremental(program)
+            } else {
+                session.execute(program).map(|()| None)
+            };
 
             interpret_result.map_err(|e| {
-                let (msg, error_data) = match e {
-                    RuntimeError::UndefinedVariable(err) => {
-                        (format!("Неопределенная переменная: {}", err.message), err)
-                    }
-                    RuntimeError::UndefinedFunction(err) => {
-                        (format!("Неопределенная функция: {}", err.message), err)
-                    }
-                    RuntimeError::UndefinedMethod(err) => {
-                        (format!("Неопределенный метод: {}", err.message), err)
-                    }
-                    RuntimeError::TypeMismatch(err) => {
-                        (format!("Несоответствие типов: {}", err.message), err)
-                    }
-                    RuntimeError::Panic(err) => (format!("Паника: {}", err.message), err),
-                    RuntimeError::Raised(err, class_name) => {
-                        (format!("{}: {}", class_name, err.message), err)
-                    }
-                    RuntimeError::DivisionByZero(err) => ("Деление на ноль".to_string(), err),
-                    RuntimeError::InvalidOperation(err) => {
-                        (format!("Недопустимая операция: {}", err.message), err)
-                    }
-                    RuntimeError::IOError(err) => {
-                        (format!("Ошибка чтения файла: {}", err.message), err)
-                    }
-                    RuntimeError::TypeError(err) => {
-                        (format!("Недопустимый тип данных: {}", err.message), err)
-                    }
-                    RuntimeError::Return(err, ..) => ("Неожиданный return".to_string(), err),
-                    RuntimeError::ImportError(err) => match err {
-                        ParseError::TypeError(e) => ("Ошибка типов".to_string(), e),
-                        ParseError::InvalidSyntax(e) => ("Ошибка синтаксиса".to_string(), e),
-                        ParseError::ImportError(e) => ("Ошибка импорта".to_string(), e),
-                    },
-                };
+                let (msg, error_data) = describe_runtime_error(e);
                 render_error(session, &msg, &error_data);
                 (msg, error_data)
-            })?;
+            })
         }
         Err(err) => {
             session.register_diagnostic_module(_module);
@@ -289,12 +666,67 @@ fn execute_code(
                 ParseError::ImportError(e) => ("Ошибка импорта", e),
             };
             render_error(session, msg, &data);
-            return Err((msg.to_string(), data));
+            Err((msg.to_string(), data))
+        }
+    }
+}
+
+/// Recursively collects files accepted by `accept`, skipping hidden and `target` directories.
+fn collect_source_files(
+    dir: &Path,
+    files: &mut Vec<PathBuf>,
+    accept: &dyn Fn(&str) -> bool,
+) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        if path.is_dir() {
+            if !name.starts_with('.') && name != "target" {
+                collect_source_files(&path, files, accept)?;
+            }
+        } else if accept(&name) {
+            files.push(path);
         }
     }
     Ok(())
 }
 
+/// Human-readable headline and location data for a runtime error.
+fn describe_runtime_error(error: RuntimeError) -> (String, ErrorData) {
+    match error {
+        RuntimeError::UndefinedVariable(err) => {
+            (format!("Неопределенная переменная: {}", err.message), err)
+        }
+        RuntimeError::UndefinedFunction(err) => {
+            (format!("Неопределенная функция: {}", err.message), err)
+        }
+        RuntimeError::UndefinedMethod(err) => {
+            (format!("Неопределенный метод: {}", err.message), err)
+        }
+        RuntimeError::TypeMismatch(err) => (format!("Несоответствие типов: {}", err.message), err),
+        RuntimeError::Panic(err) => (format!("Паника: {}", err.message), err),
+        RuntimeError::Raised(err, class_name) => (format!("{}: {}", class_name, err.message), err),
+        RuntimeError::DivisionByZero(err) => ("Деление на ноль".to_string(), err),
+        RuntimeError::InvalidOperation(err) => {
+            (format!("Недопустимая операция: {}", err.message), err)
+        }
+        RuntimeError::Overflow(err) => (format!("Переполнение: {}", err.message), err),
+        RuntimeError::IOError(err) => (format!("Ошибка чтения файла: {}", err.message), err),
+        RuntimeError::LimitExceeded(err) => (err.message.clone(), err),
+        RuntimeError::PermissionDenied(err) => (format!("Доступ запрещён: {}", err.message), err),
+        RuntimeError::TypeError(err) => (format!("Недопустимый тип данных: {}", err.message), err),
+        RuntimeError::Return(err, ..) => ("Неожиданный return".to_string(), err),
+        RuntimeError::ImportError(err) => match err {
+            ParseError::TypeError(e) => ("Ошибка типов".to_string(), e),
+            ParseError::InvalidSyntax(e) => ("Ошибка синтаксиса".to_string(), e),
+            ParseError::ImportError(e) => ("Ошибка импорта".to_string(), e),
+        },
+    }
+}
+
 fn render_error(session: &Session, msg: &str, error: &ErrorData) {
     let intp = session.runtime();
     let file_name = intp.get_file_path(&error.location.file_id);
@@ -329,24 +761,3 @@ fn render_error(session: &Session, msg: &str, error: &ErrorData) {
         .print(&intp.source_manager)
         .expect("Can't build report message");
 }
-
-fn run_repl(session: &mut Session) {
-    println!("Интерактивный режим Гойда. Введите 'выход' для завершения.");
-    loop {
-        print!("гойда> ");
-        io::stdout().flush().unwrap();
-        let mut input = String::new();
-        if io::stdin().read_line(&mut input).is_ok() {
-            let input = input.trim();
-            if input == "выход" || input == "exit" {
-                break;
-            }
-            if input.is_empty() {
-                continue;
-            }
-            if let Err(e) = execute_code(session, input, "repl") {
-                eprintln!("Ошибка: {}", e.0.lines().next().unwrap_or(&e.0));
-            }
-        }
-    }
-}
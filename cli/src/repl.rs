@@ -0,0 +1,215 @@
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::Validator;
+use rustyline::{Context, Editor, Helper};
+
+use std::fs;
+
+use goida_runtime::interpreter::prelude::Value;
+use goida_runtime::parser::prelude::Parser as ProgramParser;
+use goida_runtime::session::Session;
+
+use crate::execute_source;
+
+const PROMPT: &str = "гойда> ";
+const CONTINUATION_PROMPT: &str = "....> ";
+const HELP: &str = "\
+Команды интерактивного режима:
+  :load <файл>      выполнить файл в текущей сессии
+  :reload <модуль>  перечитать подключённый модуль по псевдониму или пути
+  :vars             показать переменные, их типы и значения
+  :funcs            показать функции и классы
+  :reset            сбросить состояние интерпретатора
+  :help             показать эту справку
+  выход             завершить работу";
+
+/// Состояние REPL: сессия интерпретатора и автодополнение по ней.
+struct ReplHelper {
+    session: Session,
+}
+
+impl Completer for ReplHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        let start = identifier_start(line, pos);
+        let receiver = line[..start]
+            .strip_suffix('.')
+            .map(|before| &before[receiver_start(before)..]);
+        let candidates = self
+            .session
+            .completions(receiver.filter(|name| !name.is_empty()), &line[start..pos]);
+        Ok((start, candidates))
+    }
+}
+
+impl Hinter for ReplHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ReplHelper {}
+
+impl Validator for ReplHelper {}
+
+impl Helper for ReplHelper {}
+
+/// Начало получателя перед точкой: имени или строкового литерала.
+fn receiver_start(before: &str) -> usize {
+    before
+        .strip_suffix('"')
+        .and_then(|body| body.rfind('"'))
+        .unwrap_or_else(|| identifier_start(before, before.len()))
+}
+
+fn identifier_start(line: &str, pos: usize) -> usize {
+    line[..pos]
+        .char_indices()
+        .rev()
+        .take_while(|(_, ch)| ch.is_alphanumeric() || *ch == '_')
+        .last()
+        .map_or(pos, |(idx, _)| idx)
+}
+
+fn print_value(session: &Session, value: &Value) {
+    match value {
+        Value::Empty => {}
+        Value::Text(text) => println!("{text:?}"),
+        value => println!("{}", session.runtime().format_value(value)),
+    }
+}
+
+fn run_command(session: &mut Session, input: &str) {
+    let (command, argument) = input
+        .split_once(char::is_whitespace)
+        .map_or((input, ""), |(command, argument)| {
+            (command, argument.trim())
+        });
+    match command {
+        ":load" | ":загрузить" => {
+            if argument.is_empty() {
+                eprintln!("Использование: :load <файл>");
+                return;
+            }
+            let code = match fs::read_to_string(argument) {
+                Ok(code) => code,
+                Err(err) => {
+                    eprintln!("Ошибка: {err}: '{argument}'");
+                    return;
+                }
+            };
+            match execute_source(session, &code, argument, true) {
+                Ok(Some(value)) => print_value(session, &value),
+                Ok(None) => {}
+                Err(e) => eprintln!("Ошибка: {}", e.0.lines().next().unwrap_or(&e.0)),
+            }
+        }
+        ":reload" | ":перезагрузить" => {
+            if argument.is_empty() {
+                eprintln!("Использование: :reload <модуль>");
+                return;
+            }
+            match session.reload_module(argument) {
+                Ok(()) => println!("Модуль {argument} перезагружен."),
+                Err(err) => eprintln!("Ошибка: {}", err.error_message()),
+            }
+        }
+        ":vars" | ":переменные" => {
+            for (name, value) in session.globals() {
+                if !matches!(value, Value::Function(_) | Value::Class(_)) {
+                    let runtime = session.runtime();
+                    println!(
+                        "{name}: {} = {}",
+                        runtime.type_name(&value),
+                        runtime.format_value(&value)
+                    );
+                }
+            }
+        }
+        ":funcs" | ":функции" => {
+            for (_, value) in session.globals() {
+                if matches!(value, Value::Function(_) | Value::Class(_)) {
+                    println!("{}", session.runtime().type_name(&value));
+                }
+            }
+        }
+        ":reset" | ":сброс" => {
+            *session = Session::new();
+            println!("Состояние сброшено.");
+        }
+        ":help" | ":помощь" => println!("{HELP}"),
+        _ => eprintln!("Неизвестная команда {command}. Введите :help для справки."),
+    }
+}
+
+pub(crate) fn run_repl(session: Session) {
+    println!("Интерактивный режим Гойда. Введите :help для справки или 'выход' для завершения.");
+    let mut editor = match Editor::<ReplHelper, DefaultHistory>::new() {
+        Ok(editor) => editor,
+        Err(err) => {
+            eprintln!("Ошибка терминала: {err}");
+            return;
+        }
+    };
+    editor.set_helper(Some(ReplHelper { session }));
+
+    let mut buffer = String::new();
+    let mut inputs = 0usize;
+    loop {
+        let prompt = if buffer.is_empty() {
+            PROMPT
+        } else {
+            CONTINUATION_PROMPT
+        };
+        let line = match editor.readline(prompt) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => {
+                buffer.clear();
+                continue;
+            }
+            Err(_) => break,
+        };
+
+        let input = line.trim();
+        if buffer.is_empty() {
+            if input == "выход" || input == "exit" {
+                break;
+            }
+            if input.is_empty() {
+                continue;
+            }
+            if input.starts_with(':') {
+                let _ = editor.add_history_entry(input);
+                run_command(&mut editor.helper_mut().unwrap().session, input);
+                continue;
+            }
+        }
+
+        // Пустая строка в режиме продолжения отправляет ввод как есть.
+        let force = !buffer.is_empty() && input.is_empty();
+        buffer.push_str(&line);
+        buffer.push('\n');
+        if !force && ProgramParser::is_incomplete(&buffer) {
+            continue;
+        }
+
+        let code = std::mem::take(&mut buffer);
+        let _ = editor.add_history_entry(code.trim_end());
+        // Каждый ввод — отдельный модуль, чтобы функции прошлых вводов сохраняли тела.
+        inputs += 1;
+        let name = format!("<repl:{inputs}>");
+        let session = &mut editor.helper_mut().unwrap().session;
+        match execute_source(session, code.trim_end(), &name, true) {
+            Ok(Some(value)) => print_value(session, &value),
+            Ok(None) => {}
+            Err(e) => eprintln!("Ошибка: {}", e.0.lines().next().unwrap_or(&e.0)),
+        }
+    }
+}
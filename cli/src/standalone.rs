@@ -0,0 +1,162 @@
+use std::fs;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use goida_runtime::parser::prelude::{Parser as ProgramParser, ProgramSources};
+use goida_runtime::session::Session;
+use serde_json::json;
+
+use crate::{execute_with_parser, format_parse_error};
+
+/// Marks a goida binary with an appended program: payload, its length, then this tag.
+const MAGIC: &[u8; 8] = b"GOIDAEXE";
+const TRAILER_LEN: u64 = 16;
+
+/// Copies the running interpreter to `output` with `file` and its imports appended.
+pub(crate) fn build_executable(file: &str, output: Option<&str>) -> Result<(), String> {
+    let source = fs::read_to_string(file).map_err(|err| format!("{}: '{}'", err, file))?;
+    let session = Session::new();
+    let sources = ProgramParser::new(session.interner(), file, PathBuf::from(file))
+        .collect_sources(&source)
+        .map_err(|err| format_parse_error(&err))?;
+
+    let payload = json!({
+        "main": file,
+        "code": source,
+        "files": sources
+            .files
+            .iter()
+            .map(|(path, code)| (path.to_string_lossy().to_string(), json!(code)))
+            .collect::<serde_json::Map<_, _>>(),
+        "imports": sources
+            .imports
+            .iter()
+            .map(|((from, import), path)| json!([from, import, path]))
+            .collect::<Vec<_>>(),
+    })
+    .to_string()
+    .into_bytes();
+
+    let output = match output {
+        Some(output) => PathBuf::from(output),
+        None => {
+            let stem = Path::new(file).file_stem().unwrap_or_default();
+            PathBuf::from(stem).with_extension(std::env::consts::EXE_EXTENSION)
+        }
+    };
+    let interpreter = std::env::current_exe()
+        .map_err(|err| format!("Не удалось найти исполняемый файл goida: {err}"))?;
+    let mut binary =
+        fs::read(&interpreter).map_err(|err| format!("{}: '{}'", err, interpreter.display()))?;
+    // Собранный файл тоже можно пересобрать: старая программа отбрасывается.
+    if let Some((start, _)) = find_payload(&binary) {
+        binary.truncate(start as usize);
+    }
+    binary.extend_from_slice(&payload);
+    binary.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+    binary.extend_from_slice(MAGIC);
+
+    fs::write(&output, binary).map_err(|err| format!("{}: '{}'", err, output.display()))?;
+    make_executable(&output)?;
+    println!("Создан исполняемый файл: {}", output.display());
+    Ok(())
+}
+
+/// Runs the program embedded into this binary, if there is one, and exits.
+pub(crate) fn run_embedded_program() {
+    let Some(program) = read_embedded_program() else {
+        return;
+    };
+    let mut session = Session::new();
+    session.set_script_args(std::env::args().skip(1).collect());
+    // Импортированные файлы нужны диагностике, хотя на диске их нет.
+    for (path, code) in &program.sources.files {
+        session
+            .runtime()
+            .source_manager
+            .load_source(&path.to_string_lossy(), code);
+    }
+    let parser = ProgramParser::new(
+        session.interner(),
+        &program.main,
+        PathBuf::from(&program.main),
+    )
+    .with_sources(program.sources);
+    let result = execute_with_parser(&mut session, parser, &program.code, &program.main, false);
+    std::process::exit(i32::from(result.is_err()));
+}
+
+struct EmbeddedProgram {
+    main: String,
+    code: String,
+    sources: ProgramSources,
+}
+
+fn read_embedded_program() -> Option<EmbeddedProgram> {
+    let mut file = fs::File::open(std::env::current_exe().ok()?).ok()?;
+    let length = file.seek(SeekFrom::End(0)).ok()?;
+    if length < TRAILER_LEN {
+        return None;
+    }
+    let mut trailer = [0u8; TRAILER_LEN as usize];
+    file.seek(SeekFrom::End(-(TRAILER_LEN as i64))).ok()?;
+    file.read_exact(&mut trailer).ok()?;
+    let (start, payload_len) = payload_range(&trailer, length)?;
+    let mut payload = vec![0u8; payload_len as usize];
+    file.seek(SeekFrom::Start(start)).ok()?;
+    file.read_exact(&mut payload).ok()?;
+
+    let payload: serde_json::Value = serde_json::from_slice(&payload).ok()?;
+    let files = payload["files"]
+        .as_object()?
+        .iter()
+        .filter_map(|(path, code)| Some((PathBuf::from(path), code.as_str()?.to_string())))
+        .collect();
+    let imports = payload["imports"]
+        .as_array()?
+        .iter()
+        .filter_map(|entry| {
+            let from = entry.get(0)?.as_str()?;
+            let import = entry.get(1)?.as_str()?;
+            let path = entry.get(2)?.as_str()?;
+            Some((
+                (PathBuf::from(from), import.to_string()),
+                PathBuf::from(path),
+            ))
+        })
+        .collect();
+    Some(EmbeddedProgram {
+        main: payload["main"].as_str()?.to_string(),
+        code: payload["code"].as_str()?.to_string(),
+        sources: ProgramSources { files, imports },
+    })
+}
+
+fn find_payload(binary: &[u8]) -> Option<(u64, u64)> {
+    let trailer = binary.get(binary.len().checked_sub(TRAILER_LEN as usize)?..)?;
+    payload_range(trailer, binary.len() as u64)
+}
+
+/// Start offset and length of the payload described by a trailer.
+fn payload_range(trailer: &[u8], file_len: u64) -> Option<(u64, u64)> {
+    if &trailer[8..] != MAGIC {
+        return None;
+    }
+    let payload_len = u64::from_le_bytes(trailer[..8].try_into().ok()?);
+    let start = file_len
+        .checked_sub(TRAILER_LEN)?
+        .checked_sub(payload_len)?;
+    Some((start, payload_len))
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> Result<(), String> {
+    use std::os::unix::fs::PermissionsExt;
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+        .map_err(|err| format!("{}: '{}'", err, path.display()))
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> Result<(), String> {
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use std::fs;
+use std::path::Path;
+
+use goida_runtime::parser::prelude::{ParseError, Parser as ProgramParser};
+use goida_runtime::session::Session;
+use goida_runtime::traits::prelude::CoreOperations;
+use goida_syntax::ast::prelude::ErrorData;
+
+use crate::{collect_source_files, describe_runtime_error, format_parse_error};
+
+const TEST_FILE_SUFFIXES: [&str; 2] = ["_тест.goida", "_test.goida"];
+const TEST_FUNCTION_PREFIXES: [&str; 2] = ["тест_", "test_"];
+
+#[derive(Default)]
+struct Summary {
+    passed: usize,
+    failed: usize,
+}
+
+impl Summary {
+    fn pass(&mut self, name: &str) {
+        self.passed += 1;
+        println!("ок      {name}");
+    }
+
+    fn fail(&mut self, name: &str, reason: &str) {
+        self.failed += 1;
+        println!("ПРОВАЛ  {name}");
+        println!("        {reason}");
+    }
+}
+
+/// Runs every test file under `path`; returns `false` if anything failed.
+pub(crate) fn run_tests(path: &str) -> bool {
+    let root = Path::new(path);
+    let mut files = Vec::new();
+    if root.is_file() {
+        files.push(root.to_path_buf());
+    } else if let Err(err) = collect_source_files(root, &mut files, &|name| {
+        TEST_FILE_SUFFIXES
+            .iter()
+            .any(|suffix| name.ends_with(suffix))
+    }) {
+        eprintln!("{}: '{}'", err, path);
+        return false;
+    }
+    files.sort();
+
+    if files.is_empty() {
+        println!("Тесты не найдены: {path}");
+        return true;
+    }
+
+    let mut summary = Summary::default();
+    for file in &files {
+        run_test_file(file, &mut summary);
+    }
+    println!(
+        "\nИтог: {} пройдено, {} провалено",
+        summary.passed, summary.failed
+    );
+    summary.failed == 0
+}
+
+/// Each file gets a fresh session so tests cannot leak state into each other.
+fn run_test_file(file: &Path, summary: &mut Summary) {
+    let display = file.to_string_lossy().to_string();
+    let source = match fs::read_to_string(file) {
+        Ok(source) => source,
+        Err(err) => return summary.fail(&display, &format!("{}: '{}'", err, display)),
+    };
+
+    let mut session = Session::new();
+    let parser = ProgramParser::new(session.interner(), &display, file.to_path_buf());
+    let module = match parser.parse(&source) {
+        Ok(module) => module,
+        Err(err) => {
+            let data = match &err {
+                ParseError::TypeError(e)
+                | ParseError::InvalidSyntax(e)
+                | ParseError::ImportError(e) => e.clone(),
+            };
+            let reason = format!(
+                "{}: {}",
+                source_location(&display, &source, &data),
+                format_parse_error(&err)
+            );
+            return summary.fail(&display, &reason);
+        }
+    };
+    let module_id = module.name;
+
+    if let Err(err) = session.execute(module) {
+        let (message, data) = describe_runtime_error(err);
+        let reason = format!("{}: {message}", runtime_location(&session, &data));
+        return summary.fail(&display, &reason);
+    }
+
+    let tests = session
+        .module_functions(module_id)
+        .into_iter()
+        .filter(|name| {
+            TEST_FUNCTION_PREFIXES
+                .iter()
+                .any(|prefix| name.starts_with(prefix))
+        })
+        .collect::<Vec<_>>();
+    if tests.is_empty() {
+        return summary.pass(&display);
+    }
+
+    for test in tests {
+        let name = format!("{display}::{test}");
+        match session.call_module_function(module_id, &test) {
+            Ok(_) => summary.pass(&name),
+            Err(err) => {
+                let (message, data) = describe_runtime_error(err);
+                let reason = format!("{}: {message}", runtime_location(&session, &data));
+                summary.fail(&name, &reason);
+            }
+        }
+    }
+}
+
+fn runtime_location(session: &Session, error: &ErrorData) -> String {
+    let runtime = session.runtime();
+    let file = runtime.get_file_path(&error.location.file_id);
+    let source = runtime.source_manager.get_file_content(file.as_str());
+    source_location(&file, &source, error)
+}
+
+/// `файл:строка:столбец`, with the column counted in characters.
+fn source_location(file: &str, source: &str, error: &ErrorData) -> String {
+    let offset = (error.location.start as usize).min(source.len());
+    let prefix = source.get(..offset).unwrap_or(source);
+    let line = prefix.matches('\n').count() + 1;
+    let line_start = prefix.rfind('\n').map_or(0, |index| index + 1);
+    let column = prefix[line_start..].chars().count() + 1;
+    format!("{file}:{line}:{column}")
+}
@@ -92,24 +92,22 @@ impl Compiler {
                 .insert(id, Arc::new(compiler.finish(Some(result))));
         }
         for function in &hir.functions {
-            bytecode.bodies.insert(
-                function.body,
-                Arc::new(Self::statement_chunk(module, hir, function.body)),
-            );
+            bytecode
+                .bodies
+                .insert(function.body, Self::body_chunk(module, hir, function.body));
         }
         for (_, node) in hir.arena.statements() {
-            if let HirStatementKind::FunctionDefinition(function) = &node.kind {
-                bytecode.bodies.insert(
-                    function.body,
-                    Arc::new(Self::statement_chunk(module, hir, function.body)),
-                );
+            if let HirStatementKind::FunctionDefinition { function, .. } = &node.kind {
+                bytecode
+                    .bodies
+                    .insert(function.body, Self::body_chunk(module, hir, function.body));
             }
         }
         for (_, node) in hir.arena.expressions() {
             if let HirExpressionKind::Lambda { body, .. } = node.kind {
                 bytecode
                     .bodies
-                    .insert(body, Arc::new(Self::statement_chunk(module, hir, body)));
+                    .insert(body, Self::body_chunk(module, hir, body));
             }
         }
         bytecode
@@ -123,7 +121,7 @@ impl Compiler {
         }
         for (_, statement) in hir.arena.statements() {
             match &statement.kind {
-                HirStatementKind::FunctionDefinition(function) => {
+                HirStatementKind::FunctionDefinition { function, .. } => {
                     Self::collect_parameter_defaults(&function.params, &mut ids);
                 }
                 HirStatementKind::ClassDefinition(class) => {
@@ -174,6 +172,12 @@ impl Compiler {
         compiler.finish(None)
     }
 
+    fn body_chunk(module: &dyn BytecodeSource, hir: &HirModule, body: StmtId) -> Arc<Chunk> {
+        let mut chunk = Self::statement_chunk(module, hir, body);
+        chunk.generator = chunk.yields();
+        Arc::new(chunk)
+    }
+
     fn statement_chunk(module: &dyn BytecodeSource, hir: &HirModule, statement: StmtId) -> Chunk {
         let node = hir.arena.statement(statement).expect("valid statement");
         if let HirStatementKind::Block(statements) = &node.kind {
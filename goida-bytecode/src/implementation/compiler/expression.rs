@@ -1,4 +1,4 @@
-use crate::RegisterArg;
+use crate::{InlineCache, RegisterArg};
 use goida_hir::HirCallArg;
 use goida_syntax::prelude::BinaryOperator;
 
@@ -67,12 +67,12 @@ impl<'a> ChunkCompiler<'a> {
                 dst
             }
             HirExpressionKind::FunctionCall { function, args } => {
-                let args = self.args(args);
-                let dst = self.register();
                 match self.hir.arena.expression(*function).map(|e| &e.kind) {
                     Some(HirExpressionKind::Identifier { name, binding, .. })
                         if !matches!(binding, Binding::LocalSlot(_) | Binding::UpvalueSlot(_)) =>
                     {
+                        let args = self.args(args);
+                        let dst = self.register();
                         self.release_args(&args);
                         self.chunk.emit(
                             Instruction::CallDirect {
@@ -82,9 +82,12 @@ impl<'a> ChunkCompiler<'a> {
                             },
                             span,
                         );
+                        dst
                     }
                     _ => {
                         let callable = self.expression(*function);
+                        let args = self.args(args);
+                        let dst = self.register();
                         self.release(callable);
                         self.release_args(&args);
                         self.chunk.emit(
@@ -95,9 +98,9 @@ impl<'a> ChunkCompiler<'a> {
                             },
                             span,
                         );
+                        dst
                     }
                 }
-                dst
             }
             HirExpressionKind::Index { object, index } => {
                 let object = self.expression(*object);
@@ -109,6 +112,26 @@ impl<'a> ChunkCompiler<'a> {
                 self.release(index);
                 dst
             }
+            HirExpressionKind::Slice { object, start, end } => {
+                let object = self.expression(*object);
+                let start = start.map(|start| self.expression(start));
+                let end = end.map(|end| self.expression(end));
+                let dst = self.register();
+                self.chunk.emit(
+                    Instruction::ReadSlice {
+                        dst,
+                        object,
+                        start,
+                        end,
+                    },
+                    span,
+                );
+                self.release(object);
+                for bound in [start, end].into_iter().flatten() {
+                    self.release(bound);
+                }
+                dst
+            }
             HirExpressionKind::PropertyAccess { object, property } => {
                 let receiver = self.hir.arena.expression(*object).map(|e| &e.kind);
                 let receiver_is_this = matches!(receiver, Some(HirExpressionKind::This));
@@ -125,6 +148,7 @@ impl<'a> ChunkCompiler<'a> {
                         property: *property,
                         receiver_is_this,
                         receiver_name,
+                        cache: InlineCache::default(),
                     },
                     span,
                 );
@@ -152,6 +176,7 @@ impl<'a> ChunkCompiler<'a> {
                         resolution: *resolution,
                         args,
                         receiver_is_this,
+                        cache: InlineCache::default(),
                     },
                     span,
                 );
@@ -171,7 +196,34 @@ impl<'a> ChunkCompiler<'a> {
                 );
                 dst
             }
-            HirExpressionKind::Lambda { params, body } => {
+            HirExpressionKind::SuperCall {
+                class,
+                receiver,
+                method,
+                args,
+            } => {
+                let receiver = self.expression(*receiver);
+                let args = self.args(args);
+                let dst = self.register();
+                self.release(receiver);
+                self.release_args(&args);
+                self.chunk.emit(
+                    Instruction::CallSuper {
+                        dst,
+                        class: *class,
+                        receiver,
+                        method: *method,
+                        args,
+                    },
+                    span,
+                );
+                dst
+            }
+            HirExpressionKind::Lambda {
+                params,
+                body,
+                captures,
+            } => {
                 let dst = self.register();
                 self.chunk.emit(
                     Instruction::MakeLambda {
@@ -183,7 +235,9 @@ impl<'a> ChunkCompiler<'a> {
                             body: *body,
                             span,
                             module: Some(self.module.name()),
+                            doc: None,
                         },
+                        captures: captures.clone(),
                     },
                     span,
                 );
@@ -207,7 +261,7 @@ impl<'a> ChunkCompiler<'a> {
         let left = self.expression(left_id);
         let dst = self.register();
         self.chunk
-            .emit(Instruction::ToBoolean { dst, source: left }, span);
+            .emit(Instruction::Move { dst, source: left }, span);
         self.release(left);
         match op {
             BinaryOperator::And => {
@@ -220,7 +274,7 @@ impl<'a> ChunkCompiler<'a> {
                 );
                 let right = self.expression(right_id);
                 self.chunk
-                    .emit(Instruction::ToBoolean { dst, source: right }, span);
+                    .emit(Instruction::Move { dst, source: right }, span);
                 self.release(right);
                 let end = self.chunk.code.len();
                 self.patch_jump_if_false(jump, end);
@@ -238,7 +292,7 @@ impl<'a> ChunkCompiler<'a> {
                 self.patch_jump_if_false(branch, right_start);
                 let right = self.expression(right_id);
                 self.chunk
-                    .emit(Instruction::ToBoolean { dst, source: right }, span);
+                    .emit(Instruction::Move { dst, source: right }, span);
                 self.release(right);
                 let end = self.chunk.code.len();
                 self.patch_jump(skip_right, end);
@@ -253,6 +307,7 @@ impl<'a> ChunkCompiler<'a> {
             .map(|arg| RegisterArg {
                 name: arg.name,
                 register: self.expression(arg.value),
+                spread: arg.spread,
             })
             .collect()
     }
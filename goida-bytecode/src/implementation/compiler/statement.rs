@@ -5,6 +5,9 @@ impl<'a> ChunkCompiler<'a> {
     fn statement(&mut self, id: StmtId) {
         let node = self.hir.arena.statement(id).expect("valid statement");
         let span = node.span;
+        if !matches!(node.kind, HirStatementKind::Block(_)) {
+            self.chunk.statements.push((self.chunk.code.len(), span));
+        }
         match &node.kind {
             HirStatementKind::Expression(expr) => {
                 let result = self.expression(*expr);
@@ -29,6 +32,28 @@ impl<'a> ChunkCompiler<'a> {
                 );
                 self.release(source);
             }
+            HirStatementKind::MultiAssign { targets, values } => {
+                let sources = if let [value] = values[..] {
+                    let source = self.expression(value);
+                    let dsts = targets.iter().map(|_| self.register()).collect::<Vec<_>>();
+                    self.chunk.emit(
+                        Instruction::Unpack {
+                            dsts: dsts.clone(),
+                            source,
+                        },
+                        span,
+                    );
+                    self.release(source);
+                    dsts
+                } else {
+                    values.iter().map(|value| self.expression(*value)).collect()
+                };
+                for (target, source) in targets.iter().zip(sources) {
+                    let target = self.assign_target(*target);
+                    self.store_target(target, source, span);
+                    self.release(source);
+                }
+            }
             HirStatementKind::CompoundAssign { target, op, value } => {
                 let target = self.assign_target(*target);
                 let left = self.read_target(&target, span);
@@ -117,15 +142,16 @@ impl<'a> ChunkCompiler<'a> {
             }
             HirStatementKind::ForEach {
                 variable,
+                binding,
                 iterable,
                 body,
-                ..
             } => {
                 let iterable = self.expression(*iterable);
                 let body = Arc::new(Compiler::statement_chunk(self.module, self.hir, *body));
                 self.chunk.emit(
                     Instruction::ForEach {
                         variable: *variable,
+                        binding: *binding,
                         iterable,
                         body,
                     },
@@ -133,10 +159,33 @@ impl<'a> ChunkCompiler<'a> {
                 );
                 self.release(iterable);
             }
+            HirStatementKind::With {
+                variable,
+                binding,
+                value,
+                body,
+            } => {
+                let value = self.expression(*value);
+                let body = Arc::new(Compiler::statement_chunk(self.module, self.hir, *body));
+                self.chunk.emit(
+                    Instruction::With {
+                        variable: *variable,
+                        binding: *binding,
+                        value,
+                        body,
+                    },
+                    span,
+                );
+                self.release(value);
+            }
             HirStatementKind::Thread { body } => {
                 let body = Arc::new(Compiler::statement_chunk(self.module, self.hir, *body));
                 self.chunk.emit(Instruction::Thread(body), span);
             }
+            HirStatementKind::Defer { body } => {
+                let body = Arc::new(Compiler::statement_chunk(self.module, self.hir, *body));
+                self.chunk.emit(Instruction::Defer(body), span);
+            }
             HirStatementKind::Try { body, handlers } => {
                 let body = Arc::new(Compiler::statement_chunk(self.module, self.hir, *body));
                 let handlers = handlers
@@ -179,9 +228,19 @@ impl<'a> ChunkCompiler<'a> {
                 let value = value.map(|value| self.expression(value));
                 self.chunk.emit(Instruction::Return(value), span);
             }
-            HirStatementKind::FunctionDefinition(function) => {
-                self.chunk
-                    .emit(Instruction::DefineFunction(function.clone()), span);
+            HirStatementKind::Yield(value) => {
+                let value = self.expression(*value);
+                self.chunk.emit(Instruction::Yield(value), span);
+                self.release(value);
+            }
+            HirStatementKind::FunctionDefinition { function, captures } => {
+                self.chunk.emit(
+                    Instruction::DefineFunction {
+                        function: function.clone(),
+                        captures: captures.clone(),
+                    },
+                    span,
+                );
             }
             HirStatementKind::NativeLibraryDefinition(definition) => {
                 self.chunk
@@ -326,6 +385,7 @@ impl<'a> ChunkCompiler<'a> {
                         property: *property,
                         receiver_is_this: *receiver_is_this,
                         receiver_name: None,
+                        cache: InlineCache::default(),
                     },
                     span,
                 );
@@ -425,7 +485,7 @@ impl<'a> ChunkCompiler<'a> {
                     HirStatementKind::Assign { binding, .. } => {
                         !matches!(binding, Binding::LocalSlot(_))
                     }
-                    HirStatementKind::FunctionDefinition(_)
+                    HirStatementKind::FunctionDefinition { .. }
                     | HirStatementKind::ClassDefinition(_)
                     | HirStatementKind::NativeLibraryDefinition(_) => true,
                     _ => false,
@@ -3,8 +3,10 @@ use crate::ast::prelude::{
     NativeLibraryDefinition, Span, StmtId, UnaryOperator,
 };
 use crate::hir::{Binding, MethodResolution};
+use std::any::Any;
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::fmt;
+use std::sync::{Arc, PoisonError, RwLock};
 use string_interner::DefaultSymbol as Symbol;
 
 pub type Register = u32;
@@ -13,7 +15,45 @@ pub type Register = u32;
 pub struct RegisterArg {
     pub name: Option<Symbol>,
     pub register: Register,
+    pub spread: bool,
 }
+/// Slot of a property read or method call where the runtime keeps what the last
+/// lookup resolved to, so a site that keeps seeing one class skips the lookup.
+/// A hit only takes a reference to the entry, so the lock is held for a
+/// reference count increment.
+#[derive(Default)]
+pub struct InlineCache(RwLock<Option<Arc<dyn Any + Send + Sync>>>);
+
+impl InlineCache {
+    /// The cached entry, if one of type `T` is stored.
+    pub fn get<T: Any + Send + Sync>(&self) -> Option<Arc<T>> {
+        let entry = self
+            .0
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        entry?.downcast().ok()
+    }
+
+    /// Replaces the cached entry.
+    pub fn store<T: Any + Send + Sync>(&self, entry: Arc<T>) {
+        *self.0.write().unwrap_or_else(PoisonError::into_inner) = Some(entry);
+    }
+}
+
+/// A copied instruction starts with an empty cache.
+impl Clone for InlineCache {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl fmt::Debug for InlineCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("InlineCache")
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct BytecodeHandler {
     pub error_type: Option<Symbol>,
@@ -43,10 +83,15 @@ pub enum Instruction {
         left: Register,
         right: Register,
     },
-    ToBoolean {
+    Move {
         dst: Register,
         source: Register,
     },
+    /// Spreads the items of a collection over `dsts`; the item count must match.
+    Unpack {
+        dsts: Vec<Register>,
+        source: Register,
+    },
     CallDirect {
         dst: Register,
         name: Symbol,
@@ -62,12 +107,19 @@ pub enum Instruction {
         object: Register,
         index: Register,
     },
+    ReadSlice {
+        dst: Register,
+        object: Register,
+        start: Option<Register>,
+        end: Option<Register>,
+    },
     ReadProperty {
         dst: Register,
         object: Register,
         property: Symbol,
         receiver_is_this: bool,
         receiver_name: Option<Symbol>,
+        cache: InlineCache,
     },
     CallMethod {
         dst: Register,
@@ -75,15 +127,25 @@ pub enum Instruction {
         resolution: MethodResolution,
         args: Vec<RegisterArg>,
         receiver_is_this: bool,
+        cache: InlineCache,
     },
     NewObject {
         dst: Register,
         class_name: Symbol,
         args: Vec<RegisterArg>,
     },
+    /// Calls a method (or the constructor when `method` is `None`) of `class` on `receiver`.
+    CallSuper {
+        dst: Register,
+        class: Symbol,
+        receiver: Register,
+        method: Option<Symbol>,
+        args: Vec<RegisterArg>,
+    },
     MakeLambda {
         dst: Register,
         function: FunctionDefinition,
+        captures: Vec<Symbol>,
     },
     InvalidThis {
         dst: Register,
@@ -113,10 +175,20 @@ pub enum Instruction {
     Scope(Arc<Chunk>),
     ForEach {
         variable: Symbol,
+        binding: Binding,
         iterable: Register,
         body: Arc<Chunk>,
     },
+    /// Runs the body with the resource bound to `variable`, then closes the resource.
+    With {
+        variable: Symbol,
+        binding: Binding,
+        value: Register,
+        body: Arc<Chunk>,
+    },
     Thread(Arc<Chunk>),
+    /// Registers a block that runs when the current frame finishes.
+    Defer(Arc<Chunk>),
     Try {
         body: Arc<Chunk>,
         handlers: Vec<BytecodeHandler>,
@@ -126,7 +198,12 @@ pub enum Instruction {
         message: Option<Register>,
     },
     Return(Option<Register>),
-    DefineFunction(FunctionDefinition),
+    /// Hands a value to the consumer of the running generator and waits for the next request.
+    Yield(Register),
+    DefineFunction {
+        function: FunctionDefinition,
+        captures: Vec<Symbol>,
+    },
     LoadNativeLibrary(NativeLibraryDefinition),
     DefineClass(ClassDefinition),
     Halt,
@@ -136,8 +213,12 @@ pub enum Instruction {
 pub struct Chunk {
     pub code: Vec<Instruction>,
     pub spans: Vec<Span>,
+    /// Addresses where statements begin, ascending, with the statement span.
+    pub statements: Vec<(usize, Span)>,
     pub register_count: u32,
     pub result: Option<Register>,
+    /// Set on function bodies that contain `выдать`: calling them creates a generator.
+    pub generator: bool,
 }
 
 impl Chunk {
@@ -147,6 +228,22 @@ impl Chunk {
         self.spans.push(span);
         address
     }
+
+    /// Whether `выдать` runs in this chunk or its nested blocks; thread bodies and
+    /// nested functions are separate frames and do not count.
+    pub(super) fn yields(&self) -> bool {
+        self.code.iter().any(|instruction| match instruction {
+            Instruction::Yield(_) => true,
+            Instruction::Scope(body)
+            | Instruction::Defer(body)
+            | Instruction::ForEach { body, .. }
+            | Instruction::With { body, .. } => body.yields(),
+            Instruction::Try { body, handlers } => {
+                body.yields() || handlers.iter().any(|handler| handler.body.yields())
+            }
+            _ => false,
+        })
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -79,6 +79,14 @@ pub fn walk_statement<V: Visitor + ?Sized>(visitor: &mut V, module: &dyn HirSour
     match &node.kind {
         StatementKind::Expression(expr) => visitor.visit_expression(module, *expr),
         StatementKind::Assign { value, .. } => visitor.visit_expression(module, *value),
+        StatementKind::MultiAssign { targets, values } => {
+            for value in values {
+                visitor.visit_expression(module, *value);
+            }
+            for target in targets {
+                visitor.visit_expression(module, *target);
+            }
+        }
         StatementKind::CompoundAssign { target, value, .. } => {
             visitor.visit_expression(module, *target);
             visitor.visit_expression(module, *value);
@@ -123,7 +131,13 @@ pub fn walk_statement<V: Visitor + ?Sized>(visitor: &mut V, module: &dyn HirSour
             visitor.visit_expression(module, *iterable);
             visitor.visit_statement(module, *body);
         }
-        StatementKind::Thread { body } => visitor.visit_statement(module, *body),
+        StatementKind::With { value, body, .. } => {
+            visitor.visit_expression(module, *value);
+            visitor.visit_statement(module, *body);
+        }
+        StatementKind::Thread { body } | StatementKind::Defer { body } => {
+            visitor.visit_statement(module, *body)
+        }
         StatementKind::Try { body, handlers } => {
             visitor.visit_statement(module, *body);
             for handler in handlers {
@@ -145,6 +159,7 @@ pub fn walk_statement<V: Visitor + ?Sized>(visitor: &mut V, module: &dyn HirSour
                 visitor.visit_expression(module, *value);
             }
         }
+        StatementKind::Yield(value) => visitor.visit_expression(module, *value),
         StatementKind::FunctionDefinition(function) => visitor.visit_function(module, function),
         StatementKind::ClassDefinition(class) => {
             for (_, _, field) in class.fields.values() {
@@ -183,6 +198,12 @@ pub fn walk_expression<V: Visitor + ?Sized>(visitor: &mut V, module: &dyn HirSou
             visitor.visit_expression(module, *object);
             visitor.visit_expression(module, *index);
         }
+        ExpressionKind::Slice { object, start, end } => {
+            visitor.visit_expression(module, *object);
+            for bound in [start, end].into_iter().flatten() {
+                visitor.visit_expression(module, *bound);
+            }
+        }
         ExpressionKind::PropertyAccess { object, .. } => visitor.visit_expression(module, *object),
         ExpressionKind::MethodCall { object, args, .. } => {
             visitor.visit_expression(module, *object);
@@ -195,6 +216,12 @@ pub fn walk_expression<V: Visitor + ?Sized>(visitor: &mut V, module: &dyn HirSou
                 visitor.visit_expression(module, arg.value);
             }
         }
+        ExpressionKind::SuperCall { receiver, args, .. } => {
+            visitor.visit_expression(module, *receiver);
+            for arg in args {
+                visitor.visit_expression(module, arg.value);
+            }
+        }
         ExpressionKind::Lambda { params, body } => {
             for param in params {
                 if let Some(default) = param.default_value {
@@ -211,9 +238,25 @@ pub struct Lowerer {
     resolutions: ResolutionTables,
     globals: HashMap<Symbol, u32>,
     scopes: Vec<HashMap<Symbol, u32>>,
-    function_depth: usize,
-    function_scope_starts: Vec<usize>,
-    next_local_slots: Vec<u32>,
+    functions: Vec<FunctionScope>,
+    next_function_id: usize,
+    /// Local slots read by nested functions; they must live in the environment.
+    captured_slots: HashSet<(usize, u32)>,
+    local_sites: Vec<(usize, u32, LocalSite)>,
+}
+
+/// Function whose body is being resolved.
+struct FunctionScope {
+    id: usize,
+    scope_start: usize,
+    next_local_slot: u32,
+    captures: Vec<Symbol>,
+}
+
+/// Place where a local slot binding was recorded.
+enum LocalSite {
+    Name(ExprId, Symbol),
+    Store(StmtId, Symbol),
 }
 
 #[derive(Default)]
@@ -222,6 +265,13 @@ struct ResolutionTables {
     stores: HashMap<StmtId, Binding>,
     modules: HashSet<ExprId>,
     methods: HashMap<ExprId, MethodResolution>,
+    captures: HashMap<FunctionSite, Vec<Symbol>>,
+}
+
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+enum FunctionSite {
+    Definition(StmtId),
+    Lambda(ExprId),
 }
 
 impl Lowerer {
@@ -237,11 +287,9 @@ impl Lowerer {
             globals.entry(name).or_insert(next);
         }
         for statement in module.body() {
-            if let Some(node) = module.arena().get_statement(*statement) {
-                if let StatementKind::Assign { name, .. } = node.kind {
-                    let next = globals.len() as u32;
-                    globals.entry(name).or_insert(next);
-                }
+            for name in module.arena().assigned_names(*statement) {
+                let next = globals.len() as u32;
+                globals.entry(name).or_insert(next);
             }
         }
 
@@ -249,9 +297,10 @@ impl Lowerer {
             resolutions: ResolutionTables::default(),
             globals,
             scopes: vec![HashMap::new()],
-            function_depth: 0,
-            function_scope_starts: Vec::new(),
-            next_local_slots: Vec::new(),
+            functions: Vec::new(),
+            next_function_id: 0,
+            captured_slots: HashSet::new(),
+            local_sites: Vec::new(),
         };
         for statement in module.body() {
             resolver.visit_statement(module, *statement);
@@ -259,6 +308,7 @@ impl Lowerer {
         for function in module.functions() {
             resolver.visit_function(module, &function);
         }
+        resolver.release_captured_slots();
         let mut global_names = resolver.globals.into_iter().collect::<Vec<_>>();
         global_names.sort_unstable_by_key(|(_, slot)| *slot);
         let mut hir = HirModule {
@@ -274,10 +324,14 @@ impl Lowerer {
         hir
     }
 
+    fn in_function(&self) -> bool {
+        !self.functions.is_empty()
+    }
+
     fn declare(&mut self, name: Symbol) -> u32 {
-        let next = self.next_local_slots.last_mut().map(|next| {
-            let slot = *next;
-            *next += 1;
+        let next = self.functions.last_mut().map(|function| {
+            let slot = function.next_local_slot;
+            function.next_local_slot += 1;
             slot
         });
         let scope = self.scopes.last_mut().expect("resolver always has a scope");
@@ -285,12 +339,31 @@ impl Lowerer {
         *scope.entry(name).or_insert(next)
     }
 
-    fn binding(&self, name: Symbol) -> Binding {
-        let start = self.function_scope_starts.last().copied().unwrap_or(0);
-        for scope in self.scopes[start..].iter().rev() {
-            if let Some(slot) = scope.get(&name) {
-                return Binding::LocalSlot(*slot);
-            }
+    /// Slot of `name` in the function being resolved, without looking outside it.
+    fn local_slot(&self, name: Symbol) -> Option<u32> {
+        let start = self
+            .functions
+            .last()
+            .map_or(0, |function| function.scope_start);
+        self.scopes[start..]
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(&name).copied())
+    }
+
+    /// Binding for an assignment target inside a function: assignment never
+    /// reaches into an enclosing function and declares a local instead.
+    fn store_binding(&mut self, name: Symbol) -> Binding {
+        let slot = self.local_slot(name).unwrap_or_else(|| self.declare(name));
+        Binding::LocalSlot(slot)
+    }
+
+    fn binding(&mut self, name: Symbol) -> Binding {
+        if let Some(slot) = self.local_slot(name) {
+            return Binding::LocalSlot(slot);
+        }
+        if let Some(upvalue) = self.capture(name) {
+            return Binding::UpvalueSlot(upvalue);
         }
         self.globals
             .get(&name)
@@ -298,6 +371,93 @@ impl Lowerer {
             .map(Binding::GlobalSlot)
             .unwrap_or(Binding::Dynamic(name))
     }
+
+    /// Captures `name` from an enclosing function into every function between
+    /// its owner and the current one; returns its index among the current captures.
+    fn capture(&mut self, name: Symbol) -> Option<u32> {
+        let start = self.functions.last()?.scope_start;
+        let (scope, slot) = (0..start)
+            .rev()
+            .find_map(|scope| Some((scope, *self.scopes[scope].get(&name)?)))?;
+        let owner = self
+            .functions
+            .iter()
+            .rposition(|function| function.scope_start <= scope)?;
+        self.captured_slots.insert((self.functions[owner].id, slot));
+        for function in &mut self.functions[owner + 1..] {
+            if !function.captures.contains(&name) {
+                function.captures.push(name);
+            }
+        }
+        let captures = &self.functions.last()?.captures;
+        captures
+            .iter()
+            .position(|capture| *capture == name)
+            .map(|index| index as u32)
+    }
+
+    fn record_local(&mut self, binding: Binding, site: LocalSite) {
+        if let (Binding::LocalSlot(slot), Some(function)) = (binding, self.functions.last()) {
+            self.local_sites.push((function.id, slot, site));
+        }
+    }
+
+    /// Resolves a function body and returns the names it captures.
+    fn resolve_function(
+        &mut self,
+        module: &dyn HirSource,
+        params: &[Parameter],
+        body: StmtId,
+    ) -> Vec<Symbol> {
+        for param in params {
+            if let Some(default) = param.default_value {
+                self.visit_expression(module, default);
+            }
+        }
+        self.enter_function(params);
+        self.visit_statement(module, body);
+        self.exit_function()
+    }
+
+    fn enter_function(&mut self, params: &[Parameter]) {
+        self.scopes.push(HashMap::new());
+        self.functions.push(FunctionScope {
+            id: self.next_function_id,
+            scope_start: self.scopes.len() - 1,
+            next_local_slot: 0,
+            captures: Vec::new(),
+        });
+        self.next_function_id += 1;
+        for param in params {
+            self.declare(param.name);
+        }
+    }
+
+    fn exit_function(&mut self) -> Vec<Symbol> {
+        self.scopes.pop();
+        self.functions
+            .pop()
+            .map(|function| function.captures)
+            .unwrap_or_default()
+    }
+
+    /// Locals read by nested functions are shared through the environment
+    /// instead of VM registers, so their uses fall back to name lookup.
+    fn release_captured_slots(&mut self) {
+        for (function, slot, site) in std::mem::take(&mut self.local_sites) {
+            if !self.captured_slots.contains(&(function, slot)) {
+                continue;
+            }
+            match site {
+                LocalSite::Name(id, name) => {
+                    self.resolutions.names.insert(id, Binding::Dynamic(name));
+                }
+                LocalSite::Store(id, name) => {
+                    self.resolutions.stores.insert(id, Binding::Dynamic(name));
+                }
+            }
+        }
+    }
 }
 
 struct Materializer<'a> {
@@ -341,11 +501,20 @@ impl<'a> Materializer<'a> {
             .unwrap_or(DataType::Any)
     }
 
+    fn captures(&self, site: FunctionSite) -> Vec<Symbol> {
+        self.resolutions
+            .captures
+            .get(&site)
+            .cloned()
+            .unwrap_or_default()
+    }
+
     fn args(args: &[CallArg]) -> Vec<HirCallArg> {
         args.iter()
             .map(|arg| HirCallArg {
                 name: arg.name,
                 value: arg.value,
+                spread: arg.spread,
             })
             .collect()
     }
@@ -379,6 +548,10 @@ impl Visitor for Materializer<'_> {
                 declared_type: type_hint.map(|id| self.data_type(id)),
                 value: *value,
             },
+            StatementKind::MultiAssign { targets, values } => HirStatementKind::MultiAssign {
+                targets: targets.clone(),
+                values: values.clone(),
+            },
             StatementKind::CompoundAssign { target, op, value } => {
                 HirStatementKind::CompoundAssign {
                     target: *target,
@@ -442,7 +615,23 @@ impl Visitor for Materializer<'_> {
                 iterable: *iterable,
                 body: *body,
             },
+            StatementKind::With {
+                variable,
+                value,
+                body,
+            } => HirStatementKind::With {
+                variable: *variable,
+                binding: self
+                    .resolutions
+                    .stores
+                    .get(&id)
+                    .copied()
+                    .unwrap_or(Binding::Dynamic(*variable)),
+                value: *value,
+                body: *body,
+            },
             StatementKind::Thread { body } => HirStatementKind::Thread { body: *body },
+            StatementKind::Defer { body } => HirStatementKind::Defer { body: *body },
             StatementKind::Try { body, handlers } => HirStatementKind::Try {
                 body: *body,
                 handlers: handlers.clone(),
@@ -456,9 +645,11 @@ impl Visitor for Materializer<'_> {
             },
             StatementKind::Block(statements) => HirStatementKind::Block(statements.clone()),
             StatementKind::Return(value) => HirStatementKind::Return(*value),
-            StatementKind::FunctionDefinition(function) => {
-                HirStatementKind::FunctionDefinition(function.clone())
-            }
+            StatementKind::Yield(value) => HirStatementKind::Yield(*value),
+            StatementKind::FunctionDefinition(function) => HirStatementKind::FunctionDefinition {
+                function: function.clone(),
+                captures: self.captures(FunctionSite::Definition(id)),
+            },
             StatementKind::NativeLibraryDefinition(definition) => {
                 HirStatementKind::NativeLibraryDefinition(definition.clone())
             }
@@ -522,6 +713,11 @@ impl Visitor for Materializer<'_> {
                 object: *object,
                 index: *index,
             },
+            ExpressionKind::Slice { object, start, end } => HirExpressionKind::Slice {
+                object: *object,
+                start: *start,
+                end: *end,
+            },
             ExpressionKind::PropertyAccess { object, property } => {
                 HirExpressionKind::PropertyAccess {
                     object: *object,
@@ -548,9 +744,21 @@ impl Visitor for Materializer<'_> {
                     args: Self::args(args),
                 }
             }
+            ExpressionKind::SuperCall {
+                class,
+                receiver,
+                method,
+                args,
+            } => HirExpressionKind::SuperCall {
+                class: *class,
+                receiver: *receiver,
+                method: *method,
+                args: Self::args(args),
+            },
             ExpressionKind::Lambda { params, body } => HirExpressionKind::Lambda {
                 params: params.clone(),
                 body: *body,
+                captures: self.captures(FunctionSite::Lambda(id)),
             },
             ExpressionKind::This => HirExpressionKind::This,
         };
@@ -575,16 +783,36 @@ impl Visitor for Lowerer {
         match &node.kind {
             StatementKind::Assign { name, value, .. } => {
                 self.visit_expression(module, *value);
-                let binding = if self.function_depth > 0 {
-                    match self.binding(*name) {
-                        Binding::LocalSlot(slot) => Binding::LocalSlot(slot),
-                        _ => Binding::LocalSlot(self.declare(*name)),
-                    }
+                let binding = if self.in_function() {
+                    self.store_binding(*name)
                 } else {
                     self.binding(*name)
                 };
+                self.record_local(binding, LocalSite::Store(id, *name));
                 self.resolutions.stores.insert(id, binding);
             }
+            StatementKind::MultiAssign { targets, values } => {
+                for value in values {
+                    self.visit_expression(module, *value);
+                }
+                for target in targets {
+                    let Some(ExpressionKind::Identifier(name)) = module
+                        .arena()
+                        .get_expression(*target)
+                        .map(|node| &node.kind)
+                    else {
+                        self.visit_expression(module, *target);
+                        continue;
+                    };
+                    let binding = if self.in_function() {
+                        self.store_binding(*name)
+                    } else {
+                        self.binding(*name)
+                    };
+                    self.record_local(binding, LocalSite::Name(*target, *name));
+                    self.resolutions.names.insert(*target, binding);
+                }
+            }
             StatementKind::For {
                 variable,
                 init,
@@ -594,11 +822,12 @@ impl Visitor for Lowerer {
             } => {
                 self.visit_expression(module, *init);
                 self.scopes.push(HashMap::new());
-                let binding = if self.function_depth > 0 {
+                let binding = if self.in_function() {
                     Binding::LocalSlot(self.declare(*variable))
                 } else {
                     Binding::Dynamic(*variable)
                 };
+                self.record_local(binding, LocalSite::Store(id, *variable));
                 self.resolutions.stores.insert(id, binding);
                 self.visit_expression(module, *condition);
                 self.visit_statement(module, *update);
@@ -612,11 +841,29 @@ impl Visitor for Lowerer {
             } => {
                 self.visit_expression(module, *iterable);
                 self.scopes.push(HashMap::new());
-                let binding = if self.function_depth > 0 {
+                let binding = if self.in_function() {
+                    Binding::LocalSlot(self.declare(*variable))
+                } else {
+                    Binding::Dynamic(*variable)
+                };
+                self.record_local(binding, LocalSite::Store(id, *variable));
+                self.resolutions.stores.insert(id, binding);
+                self.visit_statement(module, *body);
+                self.scopes.pop();
+            }
+            StatementKind::With {
+                variable,
+                value,
+                body,
+            } => {
+                self.visit_expression(module, *value);
+                self.scopes.push(HashMap::new());
+                let binding = if self.in_function() {
                     Binding::LocalSlot(self.declare(*variable))
                 } else {
                     Binding::Dynamic(*variable)
                 };
+                self.record_local(binding, LocalSite::Store(id, *variable));
                 self.resolutions.stores.insert(id, binding);
                 self.visit_statement(module, *body);
                 self.scopes.pop();
@@ -628,6 +875,26 @@ impl Visitor for Lowerer {
                 }
                 self.scopes.pop();
             }
+            StatementKind::FunctionDefinition(function) => {
+                // The name of a nested function is a local of the enclosing one,
+                // so siblings and the function itself can capture it.
+                if self.in_function() {
+                    self.declare(function.name);
+                }
+                let captures = self.resolve_function(module, &function.params, function.body);
+                self.resolutions
+                    .captures
+                    .insert(FunctionSite::Definition(id), captures);
+            }
+            StatementKind::ClassDefinition(class) => {
+                walk_statement(self, module, id);
+                let methods = class.methods.values().map(|(_, _, method)| method);
+                for method in methods.chain(&class.constructor) {
+                    if let crate::ast::program::MethodType::User(function) = method {
+                        self.visit_function(module, function);
+                    }
+                }
+            }
             _ => walk_statement(self, module, id),
         }
     }
@@ -638,7 +905,9 @@ impl Visitor for Lowerer {
         };
         match node.kind {
             ExpressionKind::Identifier(name) => {
-                self.resolutions.names.insert(id, self.binding(name));
+                let binding = self.binding(name);
+                self.record_local(binding, LocalSite::Name(id, name));
+                self.resolutions.names.insert(id, binding);
                 if module.is_module_name(name) {
                     self.resolutions.modules.insert(id);
                 }
@@ -650,46 +919,17 @@ impl Visitor for Lowerer {
                 walk_expression(self, module, id);
             }
             ExpressionKind::Lambda { ref params, body } => {
-                for param in params {
-                    if let Some(default) = param.default_value {
-                        self.visit_expression(module, default);
-                    }
-                }
-                self.function_depth += 1;
-                self.scopes.push(HashMap::new());
-                self.function_scope_starts.push(self.scopes.len() - 1);
-                self.next_local_slots.push(0);
-                for param in params {
-                    self.declare(param.name);
-                }
-                self.visit_statement(module, body);
-                self.next_local_slots.pop();
-                self.function_scope_starts.pop();
-                self.scopes.pop();
-                self.function_depth -= 1;
+                let captures = self.resolve_function(module, params, body);
+                self.resolutions
+                    .captures
+                    .insert(FunctionSite::Lambda(id), captures);
             }
             _ => walk_expression(self, module, id),
         }
     }
 
     fn visit_function(&mut self, module: &dyn HirSource, function: &FunctionDefinition) {
-        for param in &function.params {
-            if let Some(default) = param.default_value {
-                self.visit_expression(module, default);
-            }
-        }
-        self.function_depth += 1;
-        self.scopes.push(HashMap::new());
-        self.function_scope_starts.push(self.scopes.len() - 1);
-        self.next_local_slots.push(0);
-        for param in &function.params {
-            self.declare(param.name);
-        }
-        self.visit_statement(module, function.body);
-        self.next_local_slots.pop();
-        self.function_scope_starts.pop();
-        self.scopes.pop();
-        self.function_depth -= 1;
+        self.resolve_function(module, &function.params, function.body);
     }
 }
 
@@ -64,6 +64,7 @@ pub struct HirExpression {
 pub struct HirCallArg {
     pub name: Option<Symbol>,
     pub value: ExprId,
+    pub spread: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -91,6 +92,11 @@ pub enum HirExpressionKind {
         object: ExprId,
         index: ExprId,
     },
+    Slice {
+        object: ExprId,
+        start: Option<ExprId>,
+        end: Option<ExprId>,
+    },
     PropertyAccess {
         object: ExprId,
         property: Symbol,
@@ -104,9 +110,18 @@ pub enum HirExpressionKind {
         class_name: Symbol,
         args: Vec<HirCallArg>,
     },
+    /// Call of a base class method or constructor (`method` is `None`) on `receiver`.
+    SuperCall {
+        class: Symbol,
+        receiver: ExprId,
+        method: Option<Symbol>,
+        args: Vec<HirCallArg>,
+    },
     Lambda {
         params: Vec<crate::ast::prelude::Parameter>,
         body: StmtId,
+        /// Variables of enclosing functions the lambda reads.
+        captures: Vec<Symbol>,
     },
     This,
 }
@@ -128,6 +143,10 @@ pub enum HirStatementKind {
         declared_type: Option<DataType>,
         value: ExprId,
     },
+    MultiAssign {
+        targets: Vec<ExprId>,
+        values: Vec<ExprId>,
+    },
     CompoundAssign {
         target: ExprId,
         op: BinaryOperator,
@@ -161,9 +180,18 @@ pub enum HirStatementKind {
         iterable: ExprId,
         body: StmtId,
     },
+    With {
+        variable: Symbol,
+        binding: Binding,
+        value: ExprId,
+        body: StmtId,
+    },
     Thread {
         body: StmtId,
     },
+    Defer {
+        body: StmtId,
+    },
     Try {
         body: StmtId,
         handlers: Vec<TryHandler>,
@@ -174,7 +202,12 @@ pub enum HirStatementKind {
     },
     Block(Vec<StmtId>),
     Return(Option<ExprId>),
-    FunctionDefinition(FunctionDefinition),
+    Yield(ExprId),
+    FunctionDefinition {
+        function: FunctionDefinition,
+        /// Variables of enclosing functions a nested function reads.
+        captures: Vec<Symbol>,
+    },
     NativeLibraryDefinition(NativeLibraryDefinition),
     ClassDefinition(ClassDefinition),
     PropertyAssign {
@@ -203,6 +236,14 @@ pub fn walk_hir_statement<V: HirVisitor + ?Sized>(visitor: &mut V, module: &HirM
         HirStatementKind::Expression(value) | HirStatementKind::Assign { value, .. } => {
             visitor.visit_expression(module, *value);
         }
+        HirStatementKind::MultiAssign { targets, values } => {
+            for value in values {
+                visitor.visit_expression(module, *value);
+            }
+            for target in targets {
+                visitor.visit_expression(module, *target);
+            }
+        }
         HirStatementKind::CompoundAssign { target, value, .. } => {
             visitor.visit_expression(module, *target);
             visitor.visit_expression(module, *value);
@@ -247,7 +288,13 @@ pub fn walk_hir_statement<V: HirVisitor + ?Sized>(visitor: &mut V, module: &HirM
             visitor.visit_expression(module, *iterable);
             visitor.visit_statement(module, *body);
         }
-        HirStatementKind::Thread { body } => visitor.visit_statement(module, *body),
+        HirStatementKind::With { value, body, .. } => {
+            visitor.visit_expression(module, *value);
+            visitor.visit_statement(module, *body);
+        }
+        HirStatementKind::Thread { body } | HirStatementKind::Defer { body } => {
+            visitor.visit_statement(module, *body)
+        }
         HirStatementKind::Try { body, handlers } => {
             visitor.visit_statement(module, *body);
             for handler in handlers {
@@ -269,7 +316,8 @@ pub fn walk_hir_statement<V: HirVisitor + ?Sized>(visitor: &mut V, module: &HirM
                 visitor.visit_expression(module, *value);
             }
         }
-        HirStatementKind::FunctionDefinition(function) => {
+        HirStatementKind::Yield(value) => visitor.visit_expression(module, *value),
+        HirStatementKind::FunctionDefinition { function, .. } => {
             for param in &function.params {
                 if let Some(default) = param.default_value {
                     visitor.visit_expression(module, default);
@@ -318,6 +366,12 @@ pub fn walk_hir_expression<V: HirVisitor + ?Sized>(
             visitor.visit_expression(module, *object);
             visitor.visit_expression(module, *index);
         }
+        HirExpressionKind::Slice { object, start, end } => {
+            visitor.visit_expression(module, *object);
+            for bound in [start, end].into_iter().flatten() {
+                visitor.visit_expression(module, *bound);
+            }
+        }
         HirExpressionKind::PropertyAccess { object, .. } => {
             visitor.visit_expression(module, *object)
         }
@@ -332,7 +386,13 @@ pub fn walk_hir_expression<V: HirVisitor + ?Sized>(
                 visitor.visit_expression(module, arg.value);
             }
         }
-        HirExpressionKind::Lambda { params, body } => {
+        HirExpressionKind::SuperCall { receiver, args, .. } => {
+            visitor.visit_expression(module, *receiver);
+            for arg in args {
+                visitor.visit_expression(module, arg.value);
+            }
+        }
+        HirExpressionKind::Lambda { params, body, .. } => {
             for param in params {
                 if let Some(default) = param.default_value {
                     visitor.visit_expression(module, default);
@@ -184,6 +184,19 @@ impl TypeChecker {
                 match object {
                     DataType::List(item) | DataType::Array(item) => *item,
                     DataType::Dict { value, .. } => *value,
+                    text @ DataType::Primitive(PrimitiveType::Text) => text,
+                    _ => DataType::Any,
+                }
+            }
+            HirExpressionKind::Slice { object, start, end } => {
+                let object = self.infer_expression(module, *object);
+                for bound in [start, end].into_iter().flatten() {
+                    self.infer_expression(module, *bound);
+                }
+                match object {
+                    sliceable @ (DataType::List(_)
+                    | DataType::Array(_)
+                    | DataType::Primitive(PrimitiveType::Text)) => sliceable,
                     _ => DataType::Any,
                 }
             }
@@ -193,6 +206,13 @@ impl TypeChecker {
                 }
                 DataType::Any
             }
+            HirExpressionKind::SuperCall { receiver, args, .. } => {
+                self.infer_expression(module, *receiver);
+                for arg in args {
+                    self.infer_expression(module, arg.value);
+                }
+                DataType::Any
+            }
             HirExpressionKind::Lambda { .. } => DataType::Any,
             HirExpressionKind::PropertyAccess { object, .. }
             | HirExpressionKind::MethodCall { object, .. } => {
@@ -214,6 +234,13 @@ impl TypeChecker {
         args: &[HirCallArg],
         span: Span,
     ) {
+        // Число элементов `...значение` известно только во время выполнения.
+        if args.iter().any(|arg| arg.spread) {
+            for arg in args {
+                self.infer_expression(module, arg.value);
+            }
+            return;
+        }
         let mut bound = vec![None; signature.params.len()];
         let mut positional = 0;
         for arg in args {
@@ -319,6 +346,31 @@ impl HirVisitor for TypeChecker {
                     self.declare(*name, DataType::Any);
                 }
             }
+            HirStatementKind::MultiAssign { targets, values } => {
+                let mut actual = values
+                    .iter()
+                    .map(|value| self.infer_expression(module, *value))
+                    .collect::<Vec<_>>();
+                // Распакованные элементы одного значения заранее неизвестны.
+                if actual.len() != targets.len() {
+                    actual = vec![DataType::Any; targets.len()];
+                }
+                for (target, actual) in targets.iter().zip(actual) {
+                    let name = match module.arena.expression(*target).map(|node| &node.kind) {
+                        Some(HirExpressionKind::Identifier { name, .. }) => *name,
+                        _ => {
+                            self.infer_expression(module, *target);
+                            continue;
+                        }
+                    };
+                    match self.lookup(name) {
+                        Some(expected) => {
+                            self.check_compatible(node.span, "присваивания", &expected, &actual)
+                        }
+                        None => self.declare(name, DataType::Any),
+                    }
+                }
+            }
             HirStatementKind::Return(value) => {
                 let actual = value
                     .map(|value| self.infer_expression(module, value))
@@ -327,6 +379,9 @@ impl HirVisitor for TypeChecker {
                     self.check_compatible(node.span, "возвращаемого значения", &expected, &actual);
                 }
             }
+            HirStatementKind::Yield(value) => {
+                self.infer_expression(module, *value);
+            }
             HirStatementKind::Block(statements) => {
                 self.scopes.push(HashMap::new());
                 for statement in statements {
@@ -334,7 +389,9 @@ impl HirVisitor for TypeChecker {
                 }
                 self.scopes.pop();
             }
-            HirStatementKind::FunctionDefinition(function) => self.check_function(module, function),
+            HirStatementKind::FunctionDefinition { function, .. } => {
+                self.check_function(module, function)
+            }
             _ => crate::walk_hir_statement(self, module, id),
         }
     }
@@ -378,13 +435,48 @@ fn infer_binary(op: BinaryOperator, left: &DataType, right: &DataType) -> DataTy
         | BinaryOperator::Le
         | BinaryOperator::Gt
         | BinaryOperator::Ge
-        | BinaryOperator::And
-        | BinaryOperator::Or => DataType::Primitive(PrimitiveType::Boolean),
+        | BinaryOperator::In
+        | BinaryOperator::NotIn
+        | BinaryOperator::Is => DataType::Primitive(PrimitiveType::Boolean),
+        // `и` и `или` возвращают один из операндов.
+        BinaryOperator::And | BinaryOperator::Or if left == right => left.clone(),
+        BinaryOperator::And | BinaryOperator::Or => DataType::Any,
+        // Частное двух чисел бывает и числом, и дробью.
+        BinaryOperator::Div
+            if left == &DataType::Primitive(PrimitiveType::Number) && left == right =>
+        {
+            DataType::Any
+        }
         _ if left == right => left.clone(),
+        // Повторённая строка остаётся строкой.
+        BinaryOperator::Mul
+            if matches!(
+                (left, right),
+                (
+                    DataType::Primitive(PrimitiveType::Text),
+                    DataType::Primitive(PrimitiveType::Number)
+                ) | (
+                    DataType::Primitive(PrimitiveType::Number),
+                    DataType::Primitive(PrimitiveType::Text)
+                )
+            ) =>
+        {
+            DataType::Primitive(PrimitiveType::Text)
+        }
+        // Число с дробью даёт дробь.
+        _ if is_numeric(left) && is_numeric(right) => DataType::Primitive(PrimitiveType::Float),
         _ => DataType::Any,
     }
 }
 
+fn is_numeric(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Primitive(PrimitiveType::Number | PrimitiveType::Float)
+    )
+}
+
+/// An integer is accepted wherever a float is expected.
 fn types_compatible(expected: &DataType, actual: &DataType) -> bool {
     matches!(expected, DataType::Any)
         || matches!(actual, DataType::Any)
@@ -394,6 +486,10 @@ fn types_compatible(expected: &DataType, actual: &DataType) -> bool {
             (DataType::List(_), DataType::List(_))
                 | (DataType::Array(_), DataType::Array(_))
                 | (DataType::Dict { .. }, DataType::Dict { .. })
+                | (
+                    DataType::Primitive(PrimitiveType::Float),
+                    DataType::Primitive(PrimitiveType::Number)
+                )
         )
 }
 
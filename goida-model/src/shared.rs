@@ -75,6 +75,13 @@ impl<T> WeakSharedMut<T> {
     pub fn upgrade(&self) -> Option<SharedMut<T>> {
         self.0.upgrade().map(SharedMut)
     }
+
+    /// Same as [`SharedMut::identity`] of the value; the address stays taken
+    /// while this reference exists, even after the value is dropped.
+    #[must_use]
+    pub fn identity(&self) -> usize {
+        self.0.as_ptr() as usize
+    }
 }
 
 impl<T> Clone for WeakSharedMut<T> {
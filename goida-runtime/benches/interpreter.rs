@@ -0,0 +1,72 @@
+//! Criterion benchmarks for the lexer, the parser and the interpreter.
+//!
+//! Run with `cargo bench -p goida-runtime`. The scripts are those of
+//! `benchmarks/suite`, so the numbers can be read next to its report.
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use goida_runtime::interpreter::prelude::Module;
+use goida_runtime::parser::prelude::Parser;
+use goida_runtime::session::Session;
+use std::path::PathBuf;
+
+const ARITHMETIC: &str = include_str!("../../benchmarks/suite/01_arithmetic.goida");
+const CALLS: &str = include_str!("../../benchmarks/suite/02_function_calls.goida");
+const COLLECTIONS: &str = include_str!("../../benchmarks/suite/03_collections.goida");
+const OBJECTS: &str = include_str!("../../benchmarks/suite/04_objects.goida");
+const SCRIPTS: [&str; 4] = [ARITHMETIC, CALLS, COLLECTIONS, OBJECTS];
+
+fn parse(session: &Session, source: &str) -> Module {
+    Parser::new(session.interner(), "bench", PathBuf::from("bench.goida"))
+        .parse(source)
+        .expect("benchmark script should parse")
+}
+
+fn lexing(c: &mut Criterion) {
+    let source = SCRIPTS.concat().repeat(50);
+    c.bench_function("lexing", |b| {
+        b.iter(|| Parser::count_tokens(black_box(&source)))
+    });
+}
+
+fn parsing(c: &mut Criterion) {
+    c.bench_function("parsing", |b| {
+        b.iter_batched(
+            Session::new,
+            |session| SCRIPTS.map(|source| parse(&session, black_box(source))),
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+/// Times the execution of `source` alone; each run gets a freshly parsed module.
+fn execution(c: &mut Criterion, name: &str, source: &str) {
+    c.bench_function(name, |b| {
+        b.iter_batched(
+            || {
+                let session = Session::new();
+                let module = parse(&session, source);
+                (session, module)
+            },
+            |(mut session, module)| {
+                session
+                    .execute(module)
+                    .expect("benchmark script should run")
+            },
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+fn runtime(c: &mut Criterion) {
+    execution(c, "arithmetic", ARITHMETIC);
+    execution(c, "function_calls", CALLS);
+    execution(c, "collections", COLLECTIONS);
+    execution(c, "objects", OBJECTS);
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(20);
+    targets = lexing, parsing, runtime
+}
+criterion_main!(benches);
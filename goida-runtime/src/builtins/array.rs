@@ -20,7 +20,7 @@ pub fn setup_array_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtim
             let items: Vec<Value> = args[1..].iter().map(|arg| arg.value.clone()).collect();
             let internal_array = Value::Array(Arc::new(items));
 
-            let data_sym = interp.interner.write(|i| i.get_or_intern("__data"));
+            let data_sym = interp.symbols.data;
             instance.write(|i| i.field_values.insert(data_sym, internal_array));
         }
         Ok(Value::Empty)
@@ -52,7 +52,7 @@ pub fn setup_array_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtim
                 .collect::<Vec<_>>()
                 .join(sep);
 
-            Ok(Value::Text(res))
+            Ok(Value::Text(res.into()))
         } else {
             bail_runtime!(
                 TypeError,
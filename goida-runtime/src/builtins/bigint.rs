@@ -0,0 +1,545 @@
+use crate::ast::prelude::{ErrorData, Span};
+use crate::builtins::registry::*;
+use crate::interpreter::prelude::{
+    CallArgListExt, CallArgValue, ClassInstance, Interpreter, RuntimeClassDefinition, RuntimeError,
+    SharedInterner, Value,
+};
+use crate::shared::SharedMut;
+use crate::traits::prelude::CoreOperations;
+use crate::{bail_runtime, define_constructor, define_method, runtime_error};
+use std::any::Any;
+use std::cmp::Ordering;
+use std::fmt;
+use string_interner::DefaultSymbol as Symbol;
+
+/// Hidden field holding the number itself.
+pub(crate) const VALUE_FIELD: &str = "__bigint";
+
+/// Each limb holds nine decimal digits, so printing needs no base conversion.
+const BASE: u64 = 1_000_000_000;
+
+/// Signed integer of any size: limbs in base 10^9, least significant first.
+/// Zero has no limbs and is never negative.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct BigInt {
+    negative: bool,
+    limbs: Vec<u32>,
+}
+
+impl BigInt {
+    fn from_i64(value: i64) -> Self {
+        let mut magnitude = value.unsigned_abs();
+        let mut limbs = Vec::new();
+        while magnitude > 0 {
+            limbs.push((magnitude % BASE) as u32);
+            magnitude /= BASE;
+        }
+        BigInt {
+            negative: value < 0,
+            limbs,
+        }
+    }
+
+    /// Decimal digits with an optional sign; `_` may separate groups as in number literals.
+    fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        let (negative, digits) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        let digits: Vec<u8> = digits.bytes().filter(|byte| *byte != b'_').collect();
+        if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        let limbs = digits
+            .rchunks(9)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .fold(0u32, |limb, digit| limb * 10 + u32::from(digit - b'0'))
+            })
+            .collect();
+        Some(BigInt { negative, limbs }.normalized())
+    }
+
+    fn normalized(mut self) -> Self {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+        if self.limbs.is_empty() {
+            self.negative = false;
+        }
+        self
+    }
+
+    fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    fn to_i64(&self) -> Option<i64> {
+        let magnitude = self.limbs.iter().rev().try_fold(0i128, |acc, limb| {
+            acc.checked_mul(BASE as i128)?
+                .checked_add(i128::from(*limb))
+        })?;
+        i64::try_from(if self.negative { -magnitude } else { magnitude }).ok()
+    }
+
+    fn negated(&self) -> Self {
+        BigInt {
+            negative: !self.negative,
+            limbs: self.limbs.clone(),
+        }
+        .normalized()
+    }
+
+    fn add(&self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt {
+                negative: self.negative,
+                limbs: add_abs(&self.limbs, &other.limbs),
+            };
+        }
+        match cmp_abs(&self.limbs, &other.limbs) {
+            Ordering::Less => BigInt {
+                negative: other.negative,
+                limbs: sub_abs(&other.limbs, &self.limbs),
+            },
+            _ => BigInt {
+                negative: self.negative,
+                limbs: sub_abs(&self.limbs, &other.limbs),
+            },
+        }
+        .normalized()
+    }
+
+    fn sub(&self, other: &BigInt) -> BigInt {
+        self.add(&other.negated())
+    }
+
+    fn mul(&self, other: &BigInt) -> BigInt {
+        BigInt {
+            negative: self.negative != other.negative,
+            limbs: mul_abs(&self.limbs, &other.limbs),
+        }
+        .normalized()
+    }
+
+    /// Truncating division like `/` and `%` on numbers: the remainder takes the sign of `self`.
+    /// The caller rejects a zero divisor.
+    fn div_rem(&self, other: &BigInt) -> (BigInt, BigInt) {
+        let (quotient, remainder) = div_rem_abs(&self.limbs, &other.limbs);
+        let quotient = BigInt {
+            negative: self.negative != other.negative,
+            limbs: quotient,
+        };
+        let remainder = BigInt {
+            negative: self.negative,
+            limbs: remainder,
+        };
+        (quotient.normalized(), remainder.normalized())
+    }
+
+    fn pow(&self, mut exponent: u64) -> BigInt {
+        let mut result = BigInt::from_i64(1);
+        let mut base = self.clone();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.mul(&base);
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.mul(&base);
+            }
+        }
+        result
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_abs(&self.limbs, &other.limbs),
+            (true, true) => cmp_abs(&other.limbs, &self.limbs),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((top, rest)) = self.limbs.split_last() else {
+            return write!(f, "0");
+        };
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", top)?;
+        for limb in rest.iter().rev() {
+            write!(f, "{:09}", limb)?;
+        }
+        Ok(())
+    }
+}
+
+fn cmp_abs(left: &[u32], right: &[u32]) -> Ordering {
+    left.len()
+        .cmp(&right.len())
+        .then_with(|| left.iter().rev().cmp(right.iter().rev()))
+}
+
+fn add_abs(left: &[u32], right: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(left.len().max(right.len()) + 1);
+    let mut carry = 0u64;
+    for i in 0..left.len().max(right.len()) {
+        let sum = carry
+            + left.get(i).map_or(0, |limb| u64::from(*limb))
+            + right.get(i).map_or(0, |limb| u64::from(*limb));
+        result.push((sum % BASE) as u32);
+        carry = sum / BASE;
+    }
+    if carry > 0 {
+        result.push(carry as u32);
+    }
+    result
+}
+
+/// `left - right` for `left >= right`.
+fn sub_abs(left: &[u32], right: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(left.len());
+    let mut borrow = 0i64;
+    for (i, limb) in left.iter().enumerate() {
+        let mut difference =
+            i64::from(*limb) - borrow - right.get(i).map_or(0, |limb| i64::from(*limb));
+        borrow = 0;
+        if difference < 0 {
+            difference += BASE as i64;
+            borrow = 1;
+        }
+        result.push(difference as u32);
+    }
+    result
+}
+
+fn mul_abs(left: &[u32], right: &[u32]) -> Vec<u32> {
+    if left.is_empty() || right.is_empty() {
+        return Vec::new();
+    }
+    let mut result = vec![0u64; left.len() + right.len()];
+    for (i, a) in left.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, b) in right.iter().enumerate() {
+            let current = result[i + j] + u64::from(*a) * u64::from(*b) + carry;
+            result[i + j] = current % BASE;
+            carry = current / BASE;
+        }
+        result[i + right.len()] += carry;
+    }
+    let mut limbs: Vec<u32> = result.into_iter().map(|limb| limb as u32).collect();
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+    limbs
+}
+
+fn mul_small(limbs: &[u32], factor: u64) -> Vec<u32> {
+    let mut result = Vec::with_capacity(limbs.len() + 1);
+    let mut carry = 0u64;
+    for limb in limbs {
+        let current = u64::from(*limb) * factor + carry;
+        result.push((current % BASE) as u32);
+        carry = current / BASE;
+    }
+    if carry > 0 {
+        result.push(carry as u32);
+    }
+    while result.last() == Some(&0) {
+        result.pop();
+    }
+    result
+}
+
+/// Schoolbook long division, one limb of the quotient at a time.
+fn div_rem_abs(dividend: &[u32], divisor: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let mut quotient = vec![0u32; dividend.len()];
+    let mut remainder: Vec<u32> = Vec::new();
+    for (i, limb) in dividend.iter().enumerate().rev() {
+        remainder.insert(0, *limb);
+        while remainder.last() == Some(&0) {
+            remainder.pop();
+        }
+        // Наибольшая цифра частного, при которой произведение не превышает остаток.
+        let (mut low, mut high) = (0u64, BASE - 1);
+        while low < high {
+            let middle = (low + high).div_ceil(2);
+            if cmp_abs(&mul_small(divisor, middle), &remainder) == Ordering::Greater {
+                high = middle - 1;
+            } else {
+                low = middle;
+            }
+        }
+        if low > 0 {
+            remainder = sub_abs(&remainder, &mul_small(divisor, low));
+            while remainder.last() == Some(&0) {
+                remainder.pop();
+            }
+        }
+        quotient[i] = low as u32;
+    }
+    (quotient, remainder)
+}
+
+fn value_resource(
+    interpreter: &Interpreter,
+    instance: &SharedMut<ClassInstance>,
+) -> Option<BigInt> {
+    let field = interpreter.symbols.bigint;
+    match instance.read(|instance| instance.field_values.get(&field).cloned()) {
+        Some(Value::NativeResource(resource)) => {
+            resource.read(|boxed| boxed.downcast_ref::<BigInt>().cloned())
+        }
+        _ => None,
+    }
+}
+
+/// Another `БольшоеЧисло`, a number or a decimal string.
+fn operand(interpreter: &Interpreter, value: &Value, span: Span) -> Result<BigInt, RuntimeError> {
+    match value {
+        Value::Number(number) => Ok(BigInt::from_i64(*number)),
+        Value::Text(text) => BigInt::parse(text).ok_or_else(|| {
+            runtime_error!(
+                InvalidOperation,
+                span,
+                "Не удалось преобразовать '{}' в большое число",
+                text
+            )
+        }),
+        Value::Object(instance) => value_resource(interpreter, instance).ok_or_else(|| {
+            runtime_error!(
+                TypeError,
+                span,
+                "Ожидалось большое число, число или строка, получено {}",
+                interpreter.type_name(value)
+            )
+        }),
+        other => bail_runtime!(
+            TypeError,
+            span,
+            "Ожидалось большое число, число или строка, получено {}",
+            interpreter.type_name(other)
+        ),
+    }
+}
+
+fn receiver(
+    interpreter: &Interpreter,
+    args: &[CallArgValue],
+    span: Span,
+) -> Result<BigInt, RuntimeError> {
+    let Some(Value::Object(instance)) = CallArgListExt::first_value(args) else {
+        return bail_runtime!(TypeError, span, "Ожидался объект БольшоеЧисло");
+    };
+    match value_resource(interpreter, instance) {
+        Some(value) => Ok(value),
+        None => bail_runtime!(InvalidOperation, span, "БольшоеЧисло не создано"),
+    }
+}
+
+/// The receiver and the single argument of `число.операция(другое)`.
+fn operands(
+    interpreter: &Interpreter,
+    args: &[CallArgValue],
+    span: Span,
+    usage: &str,
+) -> Result<(BigInt, BigInt), RuntimeError> {
+    let Some(other) = CallArgListExt::get_value(args, 1) else {
+        return bail_runtime!(TypeError, span, "Использование: {}", usage);
+    };
+    Ok((
+        receiver(interpreter, args, span)?,
+        operand(interpreter, other, span)?,
+    ))
+}
+
+fn store_value(interpreter: &Interpreter, instance: &SharedMut<ClassInstance>, value: BigInt) {
+    let resource =
+        Value::NativeResource(SharedMut::new(Box::new(value) as Box<dyn Any + Send + Sync>));
+    let field = interpreter.symbols.bigint;
+    instance.write(|instance| instance.field_values.insert(field, resource));
+}
+
+fn new_bigint(interpreter: &Interpreter, value: BigInt, span: Span) -> Result<Value, RuntimeError> {
+    let class_symbol = interpreter.intern_string(class::BIG_INTEGER.names.canonical);
+    let Some(class_ref) = interpreter.std_classes.get(&class_symbol).cloned() else {
+        return bail_runtime!(InvalidOperation, span, "Класс БольшоеЧисло не найден");
+    };
+    let instance = SharedMut::new(ClassInstance::new(class_symbol, class_ref));
+    store_value(interpreter, &instance, value);
+    Ok(Value::Object(instance))
+}
+
+fn nonzero_divisor(divisor: &BigInt, span: Span) -> Result<(), RuntimeError> {
+    if divisor.is_zero() {
+        return bail_runtime!(DivisionByZero, span, "Деление на 0 запрещено");
+    }
+    Ok(())
+}
+
+pub fn setup_bigint_class(
+    interner: &SharedInterner,
+) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner.write(|i| i.get_or_intern(class::BIG_INTEGER.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    // --- новый БольшоеЧисло(значение?), значение — число, строка цифр или БольшоеЧисло ---
+    define_constructor!(class_def, (interpreter, args, span) {
+        let Some(Value::Object(instance)) = CallArgListExt::first_value(&args) else {
+            return bail_runtime!(TypeError, span, "Ожидался объект БольшоеЧисло");
+        };
+        let value = match CallArgListExt::get_value(&args, 1) {
+            None | Some(Value::Empty) => BigInt::from_i64(0),
+            Some(source) => operand(interpreter, source, span)?,
+        };
+        store_value(interpreter, instance, value);
+        Ok(Value::Empty)
+    });
+
+    // --- ч.сложить(другое) -> БольшоеЧисло ---
+    define_method!(class_def, interner, method::BIG_ADD.canonical => (interpreter, args, span) {
+        let (left, right) = operands(interpreter, &args, span, "ч.сложить(другое)")?;
+        new_bigint(interpreter, left.add(&right), span)
+    });
+
+    // --- ч.вычесть(другое) -> БольшоеЧисло ---
+    define_method!(class_def, interner, method::SUBTRACT.canonical => (interpreter, args, span) {
+        let (left, right) = operands(interpreter, &args, span, "ч.вычесть(другое)")?;
+        new_bigint(interpreter, left.sub(&right), span)
+    });
+
+    // --- ч.умножить(другое) -> БольшоеЧисло ---
+    define_method!(class_def, interner, method::MULTIPLY.canonical => (interpreter, args, span) {
+        let (left, right) = operands(interpreter, &args, span, "ч.умножить(другое)")?;
+        new_bigint(interpreter, left.mul(&right), span)
+    });
+
+    // --- ч.разделить(другое) -> БольшоеЧисло, целая часть как у `/` для чисел ---
+    define_method!(class_def, interner, method::DIVIDE.canonical => (interpreter, args, span) {
+        let (left, right) = operands(interpreter, &args, span, "ч.разделить(другое)")?;
+        nonzero_divisor(&right, span)?;
+        new_bigint(interpreter, left.div_rem(&right).0, span)
+    });
+
+    // --- ч.остаток(другое) -> БольшоеЧисло, знак как у `%` для чисел ---
+    define_method!(class_def, interner, method::REMAINDER.canonical => (interpreter, args, span) {
+        let (left, right) = operands(interpreter, &args, span, "ч.остаток(другое)")?;
+        nonzero_divisor(&right, span)?;
+        new_bigint(interpreter, left.div_rem(&right).1, span)
+    });
+
+    // --- ч.степень(показатель) -> БольшоеЧисло, показатель — неотрицательное число ---
+    define_method!(class_def, interner, method::POWER.canonical => (interpreter, args, span) {
+        let exponent = match CallArgListExt::get_value(&args, 1) {
+            Some(Value::Number(exponent)) if *exponent >= 0 => *exponent as u64,
+            Some(Value::Number(_)) => {
+                return bail_runtime!(InvalidOperation, span, "Показатель степени не может быть отрицательным")
+            }
+            _ => return bail_runtime!(TypeError, span, "Использование: ч.степень(показатель)"),
+        };
+        let base = receiver(interpreter, &args, span)?;
+        new_bigint(interpreter, base.pow(exponent), span)
+    });
+
+    // --- ч.сравнить(другое) -> Number: -1, 0 или 1 ---
+    define_method!(class_def, interner, method::COMPARE.canonical => (interpreter, args, span) {
+        let (left, right) = operands(interpreter, &args, span, "ч.сравнить(другое)")?;
+        Ok(Value::Number(match left.cmp(&right) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        }))
+    });
+
+    // --- ч.строка() -> String, десятичная запись ---
+    define_method!(class_def, interner, method::STRINGIFY.canonical => (interpreter, args, span) {
+        Ok(Value::Text(receiver(interpreter, &args, span)?.to_string().into()))
+    });
+
+    // --- ч.число() -> Number, ошибка, если значение не помещается ---
+    define_method!(class_def, interner, method::TO_NUMBER.canonical => (interpreter, args, span) {
+        let value = receiver(interpreter, &args, span)?;
+        match value.to_i64() {
+            Some(number) => Ok(Value::Number(number)),
+            None => bail_runtime!(Overflow, span, "Число {} не помещается в обычное число", value),
+        }
+    });
+
+    (name, SharedMut::new(class_def))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(text: &str) -> BigInt {
+        BigInt::parse(text).unwrap()
+    }
+
+    #[test]
+    fn parses_and_prints_across_limbs() {
+        assert_eq!(big("-000123_456789012345").to_string(), "-123456789012345");
+        assert_eq!(big("-0").to_string(), "0");
+        assert_eq!(big("1000000000").limbs, vec![0, 1]);
+        assert!(BigInt::parse("12a").is_none());
+        assert!(BigInt::parse("-").is_none());
+    }
+
+    #[test]
+    fn arithmetic_matches_i128() {
+        let samples = [
+            0i64,
+            1,
+            -1,
+            7,
+            -13,
+            999_999_999,
+            1_000_000_000,
+            i64::MAX,
+            i64::MIN,
+        ];
+        for a in samples {
+            for b in samples {
+                let (x, y) = (BigInt::from_i64(a), BigInt::from_i64(b));
+                let (a, b) = (i128::from(a), i128::from(b));
+                assert_eq!(x.add(&y).to_string(), (a + b).to_string());
+                assert_eq!(x.sub(&y).to_string(), (a - b).to_string());
+                assert_eq!(x.mul(&y).to_string(), (a * b).to_string());
+                assert_eq!(x.cmp(&y), a.cmp(&b));
+                if b != 0 {
+                    let (quotient, remainder) = x.div_rem(&y);
+                    assert_eq!(quotient.to_string(), (a / b).to_string());
+                    assert_eq!(remainder.to_string(), (a % b).to_string());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn large_powers_and_division() {
+        let value = BigInt::from_i64(2).pow(200);
+        assert_eq!(
+            value.to_string(),
+            "1606938044258990275541962092341162602522202993782792835301376"
+        );
+        let (quotient, remainder) = value.div_rem(&BigInt::from_i64(2).pow(100));
+        assert_eq!(quotient, BigInt::from_i64(2).pow(100));
+        assert!(remainder.is_zero());
+        assert_eq!(value.to_i64(), None);
+        assert_eq!(BigInt::from_i64(i64::MIN).to_i64(), Some(i64::MIN));
+    }
+}
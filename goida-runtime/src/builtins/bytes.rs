@@ -0,0 +1,198 @@
+use crate::ast::prelude::{ErrorData, Span};
+use crate::builtins::iterator::values_from_iterable;
+use crate::builtins::registry::*;
+use crate::interpreter::prelude::{
+    CallArgListExt, CallArgValue, Interpreter, RuntimeClassDefinition, RuntimeError,
+    RuntimeIterator, SharedInterner, Value,
+};
+use crate::shared::SharedMut;
+use crate::{bail_runtime, define_builtin, define_method, runtime_error};
+use std::sync::Arc;
+use string_interner::DefaultSymbol as Symbol;
+
+/// Text encodings understood by `байты(текст, кодировка)` and `в_текст`.
+#[derive(Clone, Copy)]
+enum Encoding {
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+    Latin1,
+    Ascii,
+}
+
+impl Encoding {
+    fn from_arg(value: Option<&Value>, span: Span) -> Result<Self, RuntimeError> {
+        let name = match value {
+            None | Some(Value::Empty) => return Ok(Encoding::Utf8),
+            Some(Value::Text(name)) => name.to_lowercase().replace('_', "-"),
+            Some(_) => return bail_runtime!(TypeError, span, "Кодировка должна быть строкой"),
+        };
+        match name.as_str() {
+            "utf-8" | "utf8" | "утф-8" => Ok(Encoding::Utf8),
+            "utf-16" | "utf-16le" => Ok(Encoding::Utf16Le),
+            "utf-16be" => Ok(Encoding::Utf16Be),
+            "latin-1" | "latin1" | "iso-8859-1" => Ok(Encoding::Latin1),
+            "ascii" => Ok(Encoding::Ascii),
+            _ => bail_runtime!(InvalidOperation, span, "Неизвестная кодировка '{}'", name),
+        }
+    }
+
+    fn encode(self, text: &str, span: Span) -> Result<Vec<u8>, RuntimeError> {
+        let limit = match self {
+            Encoding::Utf8 => return Ok(text.as_bytes().to_vec()),
+            Encoding::Utf16Le => {
+                return Ok(text.encode_utf16().flat_map(u16::to_le_bytes).collect())
+            }
+            Encoding::Utf16Be => {
+                return Ok(text.encode_utf16().flat_map(u16::to_be_bytes).collect())
+            }
+            Encoding::Latin1 => 0xFF,
+            Encoding::Ascii => 0x7F,
+        };
+        text.chars()
+            .map(|ch| match u8::try_from(u32::from(ch)) {
+                Ok(byte) if u32::from(byte) <= limit => Ok(byte),
+                _ => bail_runtime!(
+                    InvalidOperation,
+                    span,
+                    "Символ '{}' не представим в этой кодировке",
+                    ch
+                ),
+            })
+            .collect()
+    }
+
+    fn decode(self, bytes: &[u8], span: Span) -> Result<String, RuntimeError> {
+        let invalid = || {
+            runtime_error!(
+                InvalidOperation,
+                span,
+                "Байты не являются текстом в этой кодировке"
+            )
+        };
+        match self {
+            Encoding::Utf8 => String::from_utf8(bytes.to_vec()).map_err(|_| invalid()),
+            Encoding::Utf16Le | Encoding::Utf16Be => {
+                if !bytes.len().is_multiple_of(2) {
+                    return Err(invalid());
+                }
+                let units = bytes.chunks_exact(2).map(|pair| match self {
+                    Encoding::Utf16Be => u16::from_be_bytes([pair[0], pair[1]]),
+                    _ => u16::from_le_bytes([pair[0], pair[1]]),
+                });
+                char::decode_utf16(units)
+                    .collect::<Result<String, _>>()
+                    .map_err(|_| invalid())
+            }
+            Encoding::Latin1 => Ok(bytes.iter().map(|byte| char::from(*byte)).collect()),
+            Encoding::Ascii if bytes.is_ascii() => {
+                Ok(bytes.iter().map(|byte| char::from(*byte)).collect())
+            }
+            Encoding::Ascii => Err(invalid()),
+        }
+    }
+}
+
+/// Builds bytes from a text (with an optional encoding) or from numbers 0..=255.
+pub(crate) fn bytes_from_value(
+    value: &Value,
+    encoding: Option<&Value>,
+    span: Span,
+) -> Result<Vec<u8>, RuntimeError> {
+    match value {
+        Value::Bytes(bytes) => Ok(bytes.to_vec()),
+        Value::Text(text) => Encoding::from_arg(encoding, span)?.encode(text, span),
+        Value::List(_) | Value::Array(_) => values_from_iterable(value, span)?
+            .iter()
+            .map(|item| match item {
+                Value::Number(number) => u8::try_from(*number).map_err(|_| {
+                    runtime_error!(
+                        InvalidOperation,
+                        span,
+                        "Байт {} вне диапазона 0..255",
+                        number
+                    )
+                }),
+                _ => bail_runtime!(TypeError, span, "Байты собираются из чисел"),
+            })
+            .collect(),
+        _ => bail_runtime!(
+            TypeError,
+            span,
+            "Байты создаются из строки или списка чисел"
+        ),
+    }
+}
+
+fn receiver(args: &[CallArgValue], span: Span) -> Result<Arc<Vec<u8>>, RuntimeError> {
+    match CallArgListExt::first_value(args) {
+        Some(Value::Bytes(bytes)) => Ok(bytes.clone()),
+        _ => bail_runtime!(TypeError, span, "Ожидались байты"),
+    }
+}
+
+pub fn setup_bytes_func(interpreter: &mut Interpreter, interner: &SharedInterner) {
+    // --- байты(), байты(текст, кодировка = "utf-8"), байты(список чисел) ---
+    define_builtin!(interpreter, interner, function::BYTES.canonical => (_, arguments, span) {
+        match CallArgListExt::first_value(&arguments) {
+            None => Ok(Value::Bytes(Arc::new(Vec::new()))),
+            Some(value) => Ok(Value::Bytes(Arc::new(bytes_from_value(
+                value,
+                CallArgListExt::get_value(&arguments, 1),
+                span,
+            )?))),
+        }
+    });
+}
+
+pub fn setup_bytes_class(interner: &SharedInterner) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner.write(|i| i.get_or_intern(class::BYTES.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    // --- .длина() -> Number ---
+    define_method!(class_def, interner, method::LEN.canonical => (_, args, span) {
+        Ok(Value::Number(receiver(&args, span)?.len() as i64))
+    });
+
+    // --- .получить(индекс) -> Number, как байты[индекс] ---
+    define_method!(class_def, interner, method::GET.canonical => (_, args, span) {
+        let bytes = receiver(&args, span)?;
+        let Some(index) = CallArgListExt::get_value(&args, 1) else {
+            return bail_runtime!(TypeError, span, "Использование: байты.получить(индекс)");
+        };
+        let index = index.resolve_index(bytes.len(), span)?;
+        Ok(Value::Number(i64::from(bytes[index])))
+    });
+
+    // --- .срез(от, до = длина) -> Bytes, отрицательные границы считаются с конца ---
+    define_method!(class_def, interner, method::SLICE.canonical => (_, args, span) {
+        let bytes = receiver(&args, span)?;
+        let range = Value::slice_range(
+            bytes.len(),
+            CallArgListExt::get_value(&args, 1),
+            CallArgListExt::get_value(&args, 2),
+            span,
+        )?;
+        Ok(Value::Bytes(Arc::new(bytes[range].to_vec())))
+    });
+
+    // --- .в_текст(кодировка = "utf-8") -> Text ---
+    define_method!(class_def, interner, method::DECODE.canonical => (_, args, span) {
+        let bytes = receiver(&args, span)?;
+        let encoding = Encoding::from_arg(CallArgListExt::get_value(&args, 1), span)?;
+        Ok(Value::Text(encoding.decode(&bytes, span)?.into()))
+    });
+
+    // --- .список() -> List чисел ---
+    define_method!(class_def, interner, method::TO_LIST.canonical => (_, args, span) {
+        let values = values_from_iterable(&Value::Bytes(receiver(&args, span)?), span)?;
+        Ok(Value::List(SharedMut::new(values.to_vec())))
+    });
+
+    define_method!(class_def, interner, method::ITERATOR.canonical => (_, args, span) {
+        let values = values_from_iterable(&Value::Bytes(receiver(&args, span)?), span)?;
+        Ok(Value::Iterator(RuntimeIterator::new(values)))
+    });
+
+    (name, SharedMut::new(class_def))
+}
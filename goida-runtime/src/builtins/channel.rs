@@ -0,0 +1,110 @@
+use crate::ast::prelude::{ErrorData, Span};
+use crate::builtins::registry::*;
+use crate::interpreter::prelude::{
+    CallArgListExt, CallArgValue, Interpreter, RuntimeClassDefinition, RuntimeError,
+    SharedInterner, Value,
+};
+use crate::shared::SharedMut;
+use crate::{bail_runtime, define_constructor, define_method, runtime_error};
+use std::any::Any;
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::sync::{Mutex, PoisonError};
+use string_interner::DefaultSymbol as Symbol;
+
+/// Hidden field holding the channel ends.
+pub(crate) const CHANNEL_FIELD: &str = "__channel";
+
+/// Both ends of a `Канал`; the sender is dropped by `закрыть()`.
+struct Channel {
+    sender: Mutex<Option<Sender<Value>>>,
+    receiver: Mutex<Receiver<Value>>,
+}
+
+fn with_channel<R>(
+    interpreter: &Interpreter,
+    args: &[CallArgValue],
+    span: Span,
+    f: impl FnOnce(&Channel) -> Result<R, RuntimeError>,
+) -> Result<R, RuntimeError> {
+    let Some(Value::Object(instance)) = CallArgListExt::first_value(args) else {
+        return bail_runtime!(TypeError, span, "Ожидался объект Канал");
+    };
+    let field = interpreter.symbols.channel;
+    let resource = instance.read(|instance| instance.field_values.get(&field).cloned());
+    let Some(Value::NativeResource(resource)) = resource else {
+        return bail_runtime!(InvalidOperation, span, "Канал не создан");
+    };
+    // Блокировка ресурса только читающая, поэтому ожидание в получить()
+    // не мешает другим потокам отправлять.
+    resource.read(|boxed| match boxed.downcast_ref::<Channel>() {
+        Some(channel) => f(channel),
+        None => bail_runtime!(TypeError, span, "Внутренний ресурс канала повреждён"),
+    })
+}
+
+pub fn setup_channel_class(
+    interner: &SharedInterner,
+) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner.write(|i| i.get_or_intern(class::CHANNEL.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    define_constructor!(class_def, (interpreter, args, span) {
+        let Some(Value::Object(instance)) = CallArgListExt::first_value(&args) else {
+            return bail_runtime!(TypeError, span, "Ожидался объект Канал");
+        };
+        let (sender, receiver) = mpsc::channel();
+        let channel = Channel {
+            sender: Mutex::new(Some(sender)),
+            receiver: Mutex::new(receiver),
+        };
+        let resource = Value::NativeResource(SharedMut::new(
+            Box::new(channel) as Box<dyn Any + Send + Sync>
+        ));
+        let field = interpreter.symbols.channel;
+        instance.write(|instance| instance.field_values.insert(field, resource));
+        Ok(Value::Empty)
+    });
+
+    // --- канал.отправить(значение) ---
+    define_method!(class_def, interner, method::SEND.canonical => (interpreter, args, span) {
+        let Some(value) = CallArgListExt::get_value(&args, 1).cloned() else {
+            return bail_runtime!(TypeError, span, "Использование: канал.отправить(значение)");
+        };
+        with_channel(interpreter, &args, span, |channel| {
+            let sender = channel.sender.lock().unwrap_or_else(PoisonError::into_inner);
+            match sender.as_ref().map(|sender| sender.send(value)) {
+                Some(Ok(())) => Ok(Value::Empty),
+                _ => bail_runtime!(InvalidOperation, span, "Канал закрыт"),
+            }
+        })
+    });
+
+    // --- канал.получить() -> значение, ждёт его; пустота после закрытия ---
+    define_method!(class_def, interner, method::GET.canonical => (interpreter, args, span) {
+        with_channel(interpreter, &args, span, |channel| {
+            let receiver = channel.receiver.lock().unwrap_or_else(PoisonError::into_inner);
+            Ok(receiver.recv().unwrap_or(Value::Empty))
+        })
+    });
+
+    // --- канал.попробовать_получить() -> значение или пустота, не ждёт ---
+    define_method!(class_def, interner, method::TRY_RECEIVE.canonical => (interpreter, args, span) {
+        with_channel(interpreter, &args, span, |channel| {
+            let receiver = channel.receiver.lock().unwrap_or_else(PoisonError::into_inner);
+            match receiver.try_recv() {
+                Ok(value) => Ok(value),
+                Err(TryRecvError::Empty | TryRecvError::Disconnected) => Ok(Value::Empty),
+            }
+        })
+    });
+
+    // --- канал.закрыть() — получатели дочитывают очередь и получают пустоту ---
+    define_method!(class_def, interner, method::CLOSE.canonical => (interpreter, args, span) {
+        with_channel(interpreter, &args, span, |channel| {
+            channel.sender.lock().unwrap_or_else(PoisonError::into_inner).take();
+            Ok(Value::Empty)
+        })
+    });
+
+    (name, SharedMut::new(class_def))
+}
@@ -1,54 +1,13 @@
 use crate::ast::prelude::ErrorData;
 use crate::builtins::registry::*;
 use crate::interpreter::prelude::{Interpreter, RuntimeError, SharedInterner, Value};
-use crate::traits::runtime::CoreOperations;
-use crate::{define_builtin, expect_args, runtime_error};
+use crate::{bail_runtime, define_builtin, expect_args, runtime_error};
 
 pub fn setup_type_func(interpreter: &mut Interpreter, interner: &SharedInterner) {
     define_builtin!(interpreter, interner, function::TYPE.canonical => (interpreter, arguments, span) {
         expect_args!(arguments, 1, span, "тип");
 
-        let val = &arguments[0].value;
-        match val {
-            Value::Number(_) => Ok(Value::Text("число".into())),
-            Value::Float(_) => Ok(Value::Text("дробь".into())),
-            Value::Pointer(_) => Ok(Value::Text("указатель".into())),
-            Value::Text(_) => Ok(Value::Text("строка".into())),
-            Value::Boolean(_) => Ok(Value::Text("логический".into())),
-            Value::Object(obj) => {
-                let name_sym = obj.read(|i| i.class_name);
-                let name = interpreter.resolve_symbol(name_sym)
-                    .ok_or_else(|| runtime_error!(InvalidOperation, span, "Тип не найден"))?;
-                Ok(Value::Text(format!("объект \"{}\"", name)))
-            }
-            Value::Class(cls) => {
-                let name_sym = cls.read(|i| i.name);
-                let name = interpreter.resolve_symbol(name_sym)
-                    .ok_or_else(|| runtime_error!(InvalidOperation, span, "Тип не найден"))?;
-                Ok(Value::Text(format!("класс \"{}\"", name)))
-            }
-            Value::Function(obj) => {
-                let name = interpreter.resolve_symbol(obj.name)
-                    .ok_or_else(|| runtime_error!(InvalidOperation, span, "Тип не найден"))?;
-                Ok(Value::Text(format!("функция \"{}\"", name)))
-            }
-            Value::Builtin(_) => Ok(Value::Text("встроенная функция".into())),
-            Value::Module(sym) => {
-                let name = interpreter.resolve_symbol(*sym)
-                    .ok_or_else(|| runtime_error!(InvalidOperation, span, "Модуль не найден"))?;
-                Ok(Value::Text(format!("модуль \"{}\"", name)))
-            }
-            Value::List(_) => Ok(Value::Text("список".into())),
-            Value::Array(_) => Ok(Value::Text("массив".into())),
-            Value::Dict(_) => Ok(Value::Text("словарь".into())),
-            Value::Iterator(_) => Ok(Value::Text("итератор".into())),
-            Value::Thread(_) => Ok(Value::Text("Поток".into())),
-            Value::Mutex(_) => Ok(Value::Text("Мьютекс".into())),
-            Value::RwLock(_) => Ok(Value::Text("БлокировкаЧтенияЗаписи".into())),
-            Value::NativeResource(_) => Ok(Value::Text("ресурс".into())),
-            Value::NativeGlobal(_) => Ok(Value::Text("нативная переменная".into())),
-            Value::Empty => Ok(Value::Text("пустота".into())),
-        }
+        Ok(Value::Text(interpreter.type_name(&arguments[0].value).into()))
     });
 }
 
@@ -56,25 +15,53 @@ pub fn setup_is_instance_func(interpreter: &mut Interpreter, interner: &SharedIn
     define_builtin!(interpreter, interner, function::IS.canonical => (interpreter, arguments, span) {
         expect_args!(arguments, 2, span, "является");
 
-        let target = &arguments[0].value;
-        let schema = &arguments[1].value;
+        Ok(Value::Boolean(
+            interpreter.is_instance_of(&arguments[0].value, &arguments[1].value),
+        ))
+    });
+}
 
-        match (target, schema) {
-            (Value::Object(obj), Value::Class(cls)) => {
-                let obj_class_sym = obj.read(|i| i.class_name);
-                let target_class_sym = cls.read(|c| c.name);
-                Ok(Value::Boolean(obj_class_sym == target_class_sym))
-            }
-            (val, Value::Class(cls)) => {
-                let target_class_sym = cls.read(|c| c.name);
-                if let Some(actual_class_sym) = interpreter.get_class_for_value(val) {
-                    let obj_class_sym = actual_class_sym.read(|d| d.name);
-                    Ok(Value::Boolean(target_class_sym == obj_class_sym))
-                } else {
-                    Ok(Value::Boolean(false))
-                }
+pub fn setup_assert_func(interpreter: &mut Interpreter, interner: &SharedInterner) {
+    define_builtin!(interpreter, interner, function::ASSERT.canonical => (interpreter, arguments, span) {
+        if arguments.is_empty() || arguments.len() > 2 {
+            return bail_runtime!(
+                InvalidOperation,
+                span,
+                "проверить ожидает 1 или 2 аргумента, получено {}",
+                arguments.len()
+            );
+        }
+
+        match &arguments[0].value {
+            Value::Boolean(true) => Ok(Value::Empty),
+            Value::Boolean(false) => {
+                let message = arguments
+                    .get(1)
+                    .map(|argument| interpreter.format_value(&argument.value))
+                    .unwrap_or_else(|| "Проверка не пройдена".into());
+                Err(RuntimeError::Raised(
+                    ErrorData::new(span, message),
+                    errors::ASSERTION_ERROR.into(),
+                ))
             }
-            _ => Ok(Value::Boolean(false)),
+            other => bail_runtime!(
+                TypeError,
+                span,
+                "проверить ожидает логическое условие, получено {}",
+                interpreter.type_name(other)
+            ),
         }
     });
 }
+
+/// The interpreter runs `выполнить` and `подключить_динамически` itself when they are
+/// called by name; stored in a variable they lose the calling module.
+pub fn setup_dynamic_funcs(interpreter: &mut Interpreter, interner: &SharedInterner) {
+    define_builtin!(interpreter, interner, function::EVAL.canonical => (_interpreter, _arguments, span) {
+        bail_runtime!(InvalidOperation, span, "выполнить можно вызвать только по имени")
+    });
+
+    define_builtin!(interpreter, interner, function::IMPORT_DYNAMIC.canonical => (_interpreter, _arguments, span) {
+        bail_runtime!(InvalidOperation, span, "подключить_динамически можно вызвать только по имени")
+    });
+}
@@ -0,0 +1,167 @@
+use crate::ast::prelude::{ErrorData, Span};
+use crate::builtins::registry::*;
+use crate::interpreter::options::Capability;
+use crate::interpreter::prelude::{
+    CallArgListExt, FastHashMap, RuntimeClassDefinition, RuntimeError, SharedInterner, Value,
+};
+use crate::shared::SharedMut;
+use crate::{bail_runtime, define_method, runtime_error};
+use std::fs;
+use std::path::Path;
+use string_interner::DefaultSymbol as Symbol;
+
+/// Config file formats; the format of a file follows its extension.
+#[derive(Clone, Copy)]
+enum Format {
+    Toml,
+    Yaml,
+    Json,
+}
+
+impl Format {
+    fn from_name(name: &str, span: Span) -> Result<Self, RuntimeError> {
+        match name.to_lowercase().as_str() {
+            "toml" | "томл" => Ok(Format::Toml),
+            "yaml" | "yml" | "ямл" => Ok(Format::Yaml),
+            "json" | "джсон" => Ok(Format::Json),
+            _ => bail_runtime!(
+                InvalidOperation,
+                span,
+                "Неизвестный формат конфига '{}'",
+                name
+            ),
+        }
+    }
+
+    /// Explicit format argument, otherwise the extension of `path`, otherwise TOML.
+    fn resolve(
+        value: Option<&Value>,
+        path: Option<&str>,
+        span: Span,
+    ) -> Result<Self, RuntimeError> {
+        match value {
+            Some(Value::Text(name)) => Self::from_name(name, span),
+            None | Some(Value::Empty) => match path
+                .and_then(|path| Path::new(path).extension())
+                .and_then(|extension| extension.to_str())
+            {
+                Some(extension) => Self::from_name(extension, span),
+                None => Ok(Format::Toml),
+            },
+            Some(_) => bail_runtime!(TypeError, span, "Формат конфига должен быть строкой"),
+        }
+    }
+
+    fn parse(self, text: &str, span: Span) -> Result<Value, RuntimeError> {
+        let parsed = match self {
+            Format::Toml => text
+                .parse::<toml::Table>()
+                .map(|table| from_toml(toml::Value::Table(table)))
+                .map_err(|error| error.to_string()),
+            Format::Yaml => serde_yaml::from_str(text).map_err(|error| error.to_string()),
+            Format::Json => serde_json::from_str(text).map_err(|error| error.to_string()),
+        };
+        parsed.map_err(|error| {
+            runtime_error!(InvalidOperation, span, "Ошибка разбора конфига: {}", error)
+        })
+    }
+
+    fn stringify(self, value: &Value, span: Span) -> Result<String, RuntimeError> {
+        let text = match self {
+            Format::Toml => toml::to_string(value).map_err(|error| error.to_string()),
+            Format::Yaml => serde_yaml::to_string(value).map_err(|error| error.to_string()),
+            Format::Json => serde_json::to_string_pretty(value)
+                .map(|text| text + "\n")
+                .map_err(|error| error.to_string()),
+        };
+        text.map_err(|error| {
+            runtime_error!(
+                InvalidOperation,
+                span,
+                "Ошибка сериализации конфига: {}",
+                error
+            )
+        })
+    }
+}
+
+/// Like deserializing into `Value`, but TOML dates become texts instead of private maps.
+fn from_toml(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(text) => Value::Text(text.into()),
+        toml::Value::Integer(number) => Value::Number(number),
+        toml::Value::Float(number) => Value::Float(number),
+        toml::Value::Boolean(flag) => Value::Boolean(flag),
+        toml::Value::Datetime(datetime) => Value::Text(datetime.to_string().into()),
+        toml::Value::Array(items) => {
+            Value::List(SharedMut::new(items.into_iter().map(from_toml).collect()))
+        }
+        toml::Value::Table(table) => Value::Dict(SharedMut::new(
+            table
+                .into_iter()
+                .map(|(key, value)| (key.into(), from_toml(value)))
+                .collect::<FastHashMap<_, _>>(),
+        )),
+    }
+}
+
+pub fn setup_config_class(
+    interner: &SharedInterner,
+) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner.write(|i| i.get_or_intern(class::CONFIG.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    // --- Конфиг.загрузить(путь, формат = по расширению) -> Dict ---
+    define_method!(class_def, interner, @static method::LOAD.canonical => (interpreter, args, span) {
+        interpreter.require(Capability::File, span)?;
+        let Some(Value::Text(path)) = CallArgListExt::first_value(&args) else {
+            return bail_runtime!(TypeError, span, "Использование: Конфиг.загрузить(путь, формат)");
+        };
+        let format = Format::resolve(CallArgListExt::get_value(&args, 1), Some(path), span)?;
+        let text = fs::read_to_string(&**path)
+            .map_err(|error| runtime_error!(IOError, span, "{}: {}", path, error))?;
+        format.parse(&text, span)
+    });
+
+    // --- Конфиг.сохранить(путь, значение, формат = по расширению) ---
+    define_method!(class_def, interner, @static method::SAVE.canonical => (interpreter, args, span) {
+        interpreter.require(Capability::File, span)?;
+        let (Some(Value::Text(path)), Some(value)) = (
+            CallArgListExt::first_value(&args),
+            CallArgListExt::get_value(&args, 1),
+        ) else {
+            return bail_runtime!(
+                TypeError,
+                span,
+                "Использование: Конфиг.сохранить(путь, значение, формат)"
+            );
+        };
+        let format = Format::resolve(CallArgListExt::get_value(&args, 2), Some(path), span)?;
+        let text = format.stringify(value, span)?;
+        if let Some(parent) = Path::new(&**path).parent() {
+            fs::create_dir_all(parent)
+                .map_err(|error| runtime_error!(IOError, span, "{}: {}", path, error))?;
+        }
+        fs::write(&**path, text).map_err(|error| runtime_error!(IOError, span, "{}: {}", path, error))?;
+        Ok(Value::Empty)
+    });
+
+    // --- Конфиг.разобрать(текст, формат = "toml") ---
+    define_method!(class_def, interner, @static method::PARSE.canonical => (_, args, span) {
+        let Some(Value::Text(text)) = CallArgListExt::first_value(&args) else {
+            return bail_runtime!(TypeError, span, "Использование: Конфиг.разобрать(текст, формат)");
+        };
+        Format::resolve(CallArgListExt::get_value(&args, 1), None, span)?.parse(text, span)
+    });
+
+    // --- Конфиг.строка(значение, формат = "toml") -> Text ---
+    define_method!(class_def, interner, @static method::STRINGIFY.canonical => (_, args, span) {
+        let Some(value) = CallArgListExt::first_value(&args) else {
+            return bail_runtime!(TypeError, span, "Использование: Конфиг.строка(значение, формат)");
+        };
+        let format = Format::resolve(CallArgListExt::get_value(&args, 1), None, span)?;
+        Ok(Value::Text(format.stringify(value, span)?.into()))
+    });
+
+    (name, SharedMut::new(class_def))
+}
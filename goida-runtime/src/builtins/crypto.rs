@@ -0,0 +1,109 @@
+use crate::ast::prelude::{ErrorData, Span};
+use crate::builtins::bytes::bytes_from_value;
+use crate::builtins::registry::*;
+use crate::interpreter::prelude::{
+    CallArgListExt, CallArgValue, RuntimeClassDefinition, RuntimeError, SharedInterner, Value,
+};
+use crate::shared::SharedMut;
+use crate::{bail_runtime, define_method, runtime_error};
+use hmac::{Hmac, Mac};
+use md5::Md5;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use string_interner::DefaultSymbol as Symbol;
+
+/// Hash functions behind the digest methods and `хмак`.
+#[derive(Clone, Copy)]
+enum Algorithm {
+    Sha256,
+    Sha1,
+    Md5,
+}
+
+impl Algorithm {
+    fn from_arg(value: Option<&Value>, span: Span) -> Result<Self, RuntimeError> {
+        let name = match value {
+            None | Some(Value::Empty) => return Ok(Algorithm::Sha256),
+            Some(Value::Text(name)) => name.to_lowercase().replace('-', ""),
+            Some(_) => return bail_runtime!(TypeError, span, "Алгоритм должен быть строкой"),
+        };
+        match name.as_str() {
+            "sha256" => Ok(Algorithm::Sha256),
+            "sha1" => Ok(Algorithm::Sha1),
+            "md5" => Ok(Algorithm::Md5),
+            _ => bail_runtime!(InvalidOperation, span, "Неизвестный алгоритм '{}'", name),
+        }
+    }
+
+    fn digest(self, data: &[u8]) -> Vec<u8> {
+        match self {
+            Algorithm::Sha256 => Sha256::digest(data).to_vec(),
+            Algorithm::Sha1 => Sha1::digest(data).to_vec(),
+            Algorithm::Md5 => Md5::digest(data).to_vec(),
+        }
+    }
+
+    fn hmac(self, key: &[u8], data: &[u8]) -> Vec<u8> {
+        fn sign<M: Mac + hmac::digest::KeyInit>(key: &[u8], data: &[u8]) -> Vec<u8> {
+            // HMAC принимает ключ любой длины.
+            let mut mac =
+                <M as hmac::digest::KeyInit>::new_from_slice(key).expect("HMAC key of any length");
+            mac.update(data);
+            mac.finalize().into_bytes().to_vec()
+        }
+        match self {
+            Algorithm::Sha256 => sign::<Hmac<Sha256>>(key, data),
+            Algorithm::Sha1 => sign::<Hmac<Sha1>>(key, data),
+            Algorithm::Md5 => sign::<Hmac<Md5>>(key, data),
+        }
+    }
+}
+
+/// Text is hashed as UTF-8, bytes as they are.
+fn data_arg(
+    args: &[CallArgValue],
+    index: usize,
+    usage: &str,
+    span: Span,
+) -> Result<Vec<u8>, RuntimeError> {
+    match CallArgListExt::get_value(args, index) {
+        Some(value @ (Value::Text(_) | Value::Bytes(_))) => bytes_from_value(value, None, span),
+        _ => bail_runtime!(TypeError, span, "Использование: Крипто.{}", usage),
+    }
+}
+
+pub fn setup_crypto_class(
+    interner: &SharedInterner,
+) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner.write(|i| i.get_or_intern(class::CRYPTO.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    // --- Крипто.sha256(данные) -> hex-строка ---
+    define_method!(class_def, interner, @static method::SHA256.canonical => (_, args, span) {
+        let data = data_arg(&args, 0, "sha256(данные)", span)?;
+        Ok(Value::Text(hex::encode(Algorithm::Sha256.digest(&data)).into()))
+    });
+
+    // --- Крипто.sha1(данные) -> hex-строка ---
+    define_method!(class_def, interner, @static method::SHA1.canonical => (_, args, span) {
+        let data = data_arg(&args, 0, "sha1(данные)", span)?;
+        Ok(Value::Text(hex::encode(Algorithm::Sha1.digest(&data)).into()))
+    });
+
+    // --- Крипто.md5(данные) -> hex-строка ---
+    define_method!(class_def, interner, @static method::MD5.canonical => (_, args, span) {
+        let data = data_arg(&args, 0, "md5(данные)", span)?;
+        Ok(Value::Text(hex::encode(Algorithm::Md5.digest(&data)).into()))
+    });
+
+    // --- Крипто.хмак(ключ, данные, алгоритм = "sha256") -> hex-строка ---
+    define_method!(class_def, interner, @static method::HMAC.canonical => (_, args, span) {
+        let usage = "хмак(ключ, данные, алгоритм)";
+        let key = data_arg(&args, 0, usage, span)?;
+        let data = data_arg(&args, 1, usage, span)?;
+        let algorithm = Algorithm::from_arg(CallArgListExt::get_value(&args, 2), span)?;
+        Ok(Value::Text(hex::encode(algorithm.hmac(&key, &data)).into()))
+    });
+
+    (name, SharedMut::new(class_def))
+}
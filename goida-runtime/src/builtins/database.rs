@@ -0,0 +1,274 @@
+use crate::ast::prelude::{ErrorData, Span};
+use crate::builtins::iterator::call_callable;
+use crate::builtins::registry::*;
+use crate::interpreter::options::Capability;
+use crate::interpreter::prelude::{
+    CallArgListExt, CallArgValue, ClassInstance, FastHashMap, Interpreter, RuntimeClassDefinition,
+    RuntimeError, SharedInterner, Value,
+};
+use crate::shared::SharedMut;
+use crate::traits::prelude::CoreOperations;
+use crate::{bail_runtime, define_method, runtime_error};
+use rusqlite::limits::Limit;
+use rusqlite::types::{Value as SqlValue, ValueRef};
+use rusqlite::Connection;
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+use string_interner::DefaultSymbol as Symbol;
+
+/// Hidden field holding the connection; `пустота` once closed.
+pub(crate) const CONNECTION_FIELD: &str = "__connection";
+
+fn sql_error(error: rusqlite::Error, span: Span) -> RuntimeError {
+    runtime_error!(IOError, span, "Ошибка базы данных: {}", error)
+}
+
+fn to_sql(value: &Value, span: Span) -> Result<SqlValue, RuntimeError> {
+    Ok(match value {
+        Value::Empty => SqlValue::Null,
+        Value::Number(number) => SqlValue::Integer(*number),
+        Value::Boolean(flag) => SqlValue::Integer(i64::from(*flag)),
+        Value::Float(number) => SqlValue::Real(*number),
+        Value::Text(text) => SqlValue::Text(text.to_string()),
+        Value::Bytes(bytes) => SqlValue::Blob(bytes.to_vec()),
+        _ => return bail_runtime!(TypeError, span, "Значение нельзя передать в SQL"),
+    })
+}
+
+fn from_sql(value: ValueRef<'_>) -> Value {
+    match value {
+        ValueRef::Null => Value::Empty,
+        ValueRef::Integer(number) => Value::Number(number),
+        ValueRef::Real(number) => Value::Float(number),
+        ValueRef::Text(text) => Value::Text(String::from_utf8_lossy(text).into()),
+        ValueRef::Blob(bytes) => Value::Bytes(Arc::new(bytes.to_vec())),
+    }
+}
+
+/// Positional parameters come as a list, named ones (`:имя`) as a dict.
+enum Params {
+    None,
+    Positional(Vec<SqlValue>),
+    Named(Vec<(String, SqlValue)>),
+}
+
+impl Params {
+    fn from_arg(value: Option<&Value>, span: Span) -> Result<Self, RuntimeError> {
+        match value {
+            None | Some(Value::Empty) => Ok(Params::None),
+            Some(Value::List(items)) => items.read(|items| {
+                items
+                    .iter()
+                    .map(|item| to_sql(item, span))
+                    .collect::<Result<_, _>>()
+                    .map(Params::Positional)
+            }),
+            Some(Value::Array(items)) => items
+                .iter()
+                .map(|item| to_sql(item, span))
+                .collect::<Result<_, _>>()
+                .map(Params::Positional),
+            Some(Value::Dict(entries)) => entries.read(|entries| {
+                entries
+                    .iter()
+                    .map(|(name, item)| {
+                        let name = name.to_string();
+                        let name = if name.starts_with([':', '@', '$']) {
+                            name
+                        } else {
+                            format!(":{}", name)
+                        };
+                        to_sql(item, span).map(|item| (name, item))
+                    })
+                    .collect::<Result<_, _>>()
+                    .map(Params::Named)
+            }),
+            Some(_) => bail_runtime!(
+                TypeError,
+                span,
+                "Параметры должны быть списком или словарём"
+            ),
+        }
+    }
+
+    fn bind(&self, statement: &mut rusqlite::Statement<'_>) -> rusqlite::Result<()> {
+        match self {
+            Params::None => Ok(()),
+            Params::Positional(values) => {
+                for (index, value) in values.iter().enumerate() {
+                    statement.raw_bind_parameter(index + 1, value)?;
+                }
+                Ok(())
+            }
+            Params::Named(values) => {
+                for (name, value) in values {
+                    match statement.parameter_index(name)? {
+                        Some(index) => statement.raw_bind_parameter(index, value)?,
+                        None => return Err(rusqlite::Error::InvalidParameterName(name.clone())),
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+fn sql_arg<'a>(args: &'a [CallArgValue], usage: &str, span: Span) -> Result<&'a str, RuntimeError> {
+    match CallArgListExt::get_value(args, 1) {
+        Some(Value::Text(sql)) => Ok(sql),
+        _ => bail_runtime!(
+            TypeError,
+            span,
+            "Использование: база.{}(sql, параметры)",
+            usage
+        ),
+    }
+}
+
+/// Runs `f` with the connection of the receiver, failing if it was closed.
+fn with_connection<R>(
+    interpreter: &Interpreter,
+    args: &[CallArgValue],
+    span: Span,
+    f: impl FnOnce(&Connection) -> Result<R, RuntimeError>,
+) -> Result<R, RuntimeError> {
+    let Some(Value::Object(instance)) = CallArgListExt::first_value(args) else {
+        return bail_runtime!(TypeError, span, "Ожидался объект БазаДанных");
+    };
+    let field = interpreter.symbols.connection;
+    let resource = instance.read(|instance| instance.field_values.get(&field).cloned());
+    match resource {
+        Some(Value::NativeResource(resource)) => {
+            resource.read(|boxed| match boxed.downcast_ref::<Mutex<Connection>>() {
+                Some(connection) => {
+                    let connection = connection.lock().map_err(|_| {
+                        runtime_error!(InvalidOperation, span, "Соединение с базой повреждено")
+                    })?;
+                    f(&connection)
+                }
+                None => bail_runtime!(TypeError, span, "Внутренний ресурс БазаДанных повреждён"),
+            })
+        }
+        _ => bail_runtime!(InvalidOperation, span, "База данных закрыта"),
+    }
+}
+
+pub fn setup_database_class(
+    interner: &SharedInterner,
+) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner.write(|i| i.get_or_intern(class::DATABASE.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    // --- БазаДанных.открыть(путь = ":memory:") -> БазаДанных ---
+    define_method!(class_def, interner, @static method::OPEN.canonical => (interpreter, args, span) {
+        let connection = match CallArgListExt::first_value(&args) {
+            None => Connection::open_in_memory(),
+            Some(Value::Text(path)) if &**path == ":memory:" => Connection::open_in_memory(),
+            Some(Value::Text(path)) => {
+                interpreter.require(Capability::File, span)?;
+                Connection::open(&**path)
+            }
+            Some(_) => return bail_runtime!(TypeError, span, "Использование: БазаДанных.открыть(путь)"),
+        }
+        .map_err(|error| sql_error(error, span))?;
+        // ATTACH и VACUUM INTO открывают и пишут произвольные файлы в обход песочницы.
+        if !interpreter.options.is_allowed(Capability::File) {
+            connection
+                .set_limit(Limit::SQLITE_LIMIT_ATTACHED, 0)
+                .map_err(|error| sql_error(error, span))?;
+        }
+
+        let class_symbol = interpreter.intern_string(class::DATABASE.names.canonical);
+        let Some(class_ref) = interpreter.std_classes.get(&class_symbol).cloned() else {
+            return bail_runtime!(InvalidOperation, span, "Класс БазаДанных не найден");
+        };
+        let instance = SharedMut::new(ClassInstance::new(class_symbol, class_ref));
+        let resource = Value::NativeResource(SharedMut::new(
+            Box::new(Mutex::new(connection)) as Box<dyn Any + Send + Sync>
+        ));
+        instance.write(|instance| {
+            instance
+                .field_values
+                .insert(interpreter.symbols.connection, resource)
+        });
+        Ok(Value::Object(instance))
+    });
+
+    // --- база.выполнить(sql, параметры = пустота) -> число изменённых строк ---
+    define_method!(class_def, interner, method::RUN.canonical => (interpreter, args, span) {
+        let sql = sql_arg(&args, "выполнить", span)?;
+        let params = Params::from_arg(CallArgListExt::get_value(&args, 2), span)?;
+        with_connection(interpreter, &args, span, |connection| {
+            // Без параметров можно выполнить несколько операторов через `;`.
+            if let Params::None = params {
+                connection.execute_batch(sql).map_err(|error| sql_error(error, span))?;
+                return Ok(Value::Number(connection.changes() as i64));
+            }
+            let mut statement = connection.prepare(sql).map_err(|error| sql_error(error, span))?;
+            params.bind(&mut statement).map_err(|error| sql_error(error, span))?;
+            let changed = statement.raw_execute().map_err(|error| sql_error(error, span))?;
+            Ok(Value::Number(changed as i64))
+        })
+    });
+
+    // --- база.запрос(sql, параметры = пустота) -> список словарей ---
+    define_method!(class_def, interner, method::QUERY.canonical => (interpreter, args, span) {
+        let sql = sql_arg(&args, "запрос", span)?;
+        let params = Params::from_arg(CallArgListExt::get_value(&args, 2), span)?;
+        with_connection(interpreter, &args, span, |connection| {
+            let mut statement = connection.prepare(sql).map_err(|error| sql_error(error, span))?;
+            params.bind(&mut statement).map_err(|error| sql_error(error, span))?;
+            let columns: Vec<String> = statement
+                .column_names()
+                .into_iter()
+                .map(String::from)
+                .collect();
+            let mut rows = statement.raw_query();
+            let mut result = Vec::new();
+            while let Some(row) = rows.next().map_err(|error| sql_error(error, span))? {
+                let mut entry =
+                    FastHashMap::with_capacity_and_hasher(columns.len(), Default::default());
+                for (index, column) in columns.iter().enumerate() {
+                    let value = row.get_ref(index).map_err(|error| sql_error(error, span))?;
+                    entry.insert(column.as_str().into(), from_sql(value));
+                }
+                result.push(Value::Dict(SharedMut::new(entry)));
+            }
+            Ok(Value::List(SharedMut::new(result)))
+        })
+    });
+
+    // --- база.транзакция(функция): подтверждается при успехе, откатывается при ошибке ---
+    define_method!(class_def, interner, method::TRANSACTION.canonical => (interpreter, args, span) {
+        let Some(callable) = CallArgListExt::get_value(&args, 1).cloned() else {
+            return bail_runtime!(TypeError, span, "Использование: база.транзакция(функция)");
+        };
+        let database = CallArgListExt::first_value(&args).cloned().unwrap_or(Value::Empty);
+        with_connection(interpreter, &args, span, |connection| {
+            connection.execute_batch("BEGIN").map_err(|error| sql_error(error, span))
+        })?;
+        // Соединение не держится заблокированным, пока функция работает с базой.
+        let result = call_callable(interpreter, callable, vec![database], span);
+        let finish = if result.is_ok() { "COMMIT" } else { "ROLLBACK" };
+        let finished = with_connection(interpreter, &args, span, |connection| {
+            connection.execute_batch(finish).map_err(|error| sql_error(error, span))
+        });
+        // Ошибка функции важнее ошибки отката.
+        let value = result?;
+        finished?;
+        Ok(value)
+    });
+
+    // --- база.закрыть() ---
+    define_method!(class_def, interner, method::CLOSE.canonical => (interpreter, args, span) {
+        let Some(Value::Object(instance)) = CallArgListExt::first_value(&args) else {
+            return bail_runtime!(TypeError, span, "Ожидался объект БазаДанных");
+        };
+        let field = interpreter.symbols.connection;
+        // Соединение закрывается, когда ресурс освобождается.
+        instance.write(|instance| instance.field_values.insert(field, Value::Empty));
+        Ok(Value::Empty)
+    });
+
+    (name, SharedMut::new(class_def))
+}
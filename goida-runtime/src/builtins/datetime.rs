@@ -2,11 +2,14 @@ use crate::ast::prelude::{ErrorData, Span, Visibility};
 use crate::builtins::registry::*;
 use crate::interpreter::prelude::RuntimeClassDefinition;
 use crate::interpreter::prelude::{
-    BuiltinFn, CallArgListExt, CallArgValue, RuntimeError, SharedInterner, Value,
+    BuiltinFn, CallArgListExt, CallArgValue, ClassInstance, Interpreter, RuntimeError,
+    SharedInterner, Value,
 };
 use crate::shared::SharedMut;
+use crate::traits::prelude::CoreOperations;
 use crate::{bail_runtime, define_constructor, define_method, runtime_error};
-use chrono::{DateTime, Datelike, Local, LocalResult, TimeZone, Timelike};
+use chrono::{DateTime, Datelike, FixedOffset, Local, LocalResult, TimeZone, Timelike, Utc};
+use chrono_tz::Tz;
 use std::sync::Arc;
 use string_interner::DefaultSymbol as Symbol;
 
@@ -17,6 +20,137 @@ fn local_datetime(ms: i64, span: Span) -> Result<DateTime<Local>, RuntimeError>
     }
 }
 
+/// Time zone a `ДатаВремя` is shown in: the system one unless set by `в_поясе`.
+#[derive(Clone, Copy)]
+enum Zone {
+    Local,
+    Named(Tz),
+}
+
+impl Zone {
+    fn from_name(name: &str, span: Span) -> Result<Self, RuntimeError> {
+        match name.parse::<Tz>() {
+            Ok(zone) => Ok(Zone::Named(zone)),
+            Err(_) => bail_runtime!(
+                InvalidOperation,
+                span,
+                "Неизвестный часовой пояс '{}'",
+                name
+            ),
+        }
+    }
+
+    fn at(self, ms: i64, span: Span) -> Result<DateTime<FixedOffset>, RuntimeError> {
+        match self {
+            Zone::Local => Ok(local_datetime(ms, span)?.fixed_offset()),
+            Zone::Named(zone) => match zone.timestamp_millis_opt(ms) {
+                LocalResult::Single(datetime) => Ok(datetime.fixed_offset()),
+                _ => bail_runtime!(InvalidOperation, span, "Date/time value is out of range"),
+            },
+        }
+    }
+
+    /// Formats with the zone itself, so `%Z` prints an abbreviation like `MSK`.
+    fn format(self, ms: i64, pattern: &str, span: Span) -> Result<String, RuntimeError> {
+        match self {
+            Zone::Local => Ok(local_datetime(ms, span)?.format(pattern).to_string()),
+            Zone::Named(zone) => match zone.timestamp_millis_opt(ms) {
+                LocalResult::Single(datetime) => Ok(datetime.format(pattern).to_string()),
+                _ => bail_runtime!(InvalidOperation, span, "Date/time value is out of range"),
+            },
+        }
+    }
+}
+
+/// Reads one calendar component, e.g. the year.
+type Component = fn(&DateTime<FixedOffset>) -> i64;
+
+/// Hidden field holding milliseconds since the Unix epoch.
+pub(crate) const MILLIS_FIELD: &str = "_мс";
+/// Hidden field holding the IANA zone name; absent for local time.
+pub(crate) const ZONE_FIELD: &str = "_пояс";
+
+/// Builds a `ДатаВремя` object for a timestamp in milliseconds.
+pub(crate) fn datetime_value(
+    interpreter: &Interpreter,
+    ms: i64,
+    span: Span,
+) -> Result<Value, RuntimeError> {
+    zoned_datetime_value(interpreter, ms, Zone::Local, span)
+}
+
+fn zoned_datetime_value(
+    interpreter: &Interpreter,
+    ms: i64,
+    zone: Zone,
+    span: Span,
+) -> Result<Value, RuntimeError> {
+    zone.at(ms, span)?;
+    let class_symbol = interpreter.intern_string(class::DATETIME.names.canonical);
+    let Some(class_ref) = interpreter.std_classes.get(&class_symbol).cloned() else {
+        return bail_runtime!(InvalidOperation, span, "Класс ДатаВремя не найден");
+    };
+    let instance = SharedMut::new(ClassInstance::new(class_symbol, class_ref));
+    instance.write(|instance| {
+        instance
+            .field_values
+            .insert(interpreter.symbols.millis, Value::Number(ms));
+        if let Zone::Named(zone) = zone {
+            instance
+                .field_values
+                .insert(interpreter.symbols.zone, Value::Text(zone.name().into()));
+        }
+    });
+    Ok(Value::Object(instance))
+}
+
+/// Builds a `Длительность` object for a span of milliseconds.
+pub(crate) fn duration_value(
+    interpreter: &Interpreter,
+    ms: i64,
+    span: Span,
+) -> Result<Value, RuntimeError> {
+    let class_symbol = interpreter.intern_string(class::DURATION.names.canonical);
+    let Some(class_ref) = interpreter.std_classes.get(&class_symbol).cloned() else {
+        return bail_runtime!(InvalidOperation, span, "Класс Длительность не найден");
+    };
+    let instance = SharedMut::new(ClassInstance::new(class_symbol, class_ref));
+    instance.write(|instance| {
+        instance
+            .field_values
+            .insert(interpreter.symbols.millis, Value::Number(ms))
+    });
+    Ok(Value::Object(instance))
+}
+
+/// Std classes that keep their state as milliseconds and compare by it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Temporal {
+    DateTime,
+    Duration,
+}
+
+/// Milliseconds behind a `ДатаВремя` or `Длительность` object.
+pub(crate) fn temporal_millis(interpreter: &Interpreter, value: &Value) -> Option<(Temporal, i64)> {
+    let Value::Object(instance) = value else {
+        return None;
+    };
+    let datetime = interpreter.intern_string(class::DATETIME.names.canonical);
+    let duration = interpreter.intern_string(class::DURATION.names.canonical);
+    let field = interpreter.symbols.millis;
+    instance.read(|instance| {
+        let kind = if instance.class_name == datetime {
+            Temporal::DateTime
+        } else if instance.class_name == duration {
+            Temporal::Duration
+        } else {
+            return None;
+        };
+        let ms = instance.field_values.get(&field)?.as_i64()?;
+        Some((kind, ms))
+    })
+}
+
 fn shift_millis(
     current_ms: i64,
     amount: i64,
@@ -41,7 +175,8 @@ pub fn setup_datetime_class(
     let name_sym = interner_ref.write(|i| i.get_or_intern(class::DATETIME.names.canonical));
     let mut class_def = RuntimeClassDefinition::new(name_sym, Span::default());
 
-    let ms_sym = interner_ref.write(|i| i.get_or_intern("_мс"));
+    let ms_sym = interner_ref.write(|i| i.get_or_intern(MILLIS_FIELD));
+    let zone_sym = interner_ref.write(|i| i.get_or_intern(ZONE_FIELD));
 
     define_constructor!(class_def, (_, args, span) {
         let instance = match CallArgListExt::first_value(&args) {
@@ -93,32 +228,34 @@ pub fn setup_datetime_class(
         }
     };
 
+    // --- Вспомогательная функция: часовой пояс self ---
+    let get_zone = move |args: &Vec<CallArgValue>, span: Span| -> Result<Zone, RuntimeError> {
+        let name = match CallArgListExt::first_value(args) {
+            Some(Value::Object(inst)) => inst.read(|i| i.field_values.get(&zone_sym).cloned()),
+            _ => None,
+        };
+        match name {
+            Some(Value::Text(name)) => Zone::from_name(&name, span),
+            _ => Ok(Zone::Local),
+        }
+    };
+
     // --- Методы получения компонентов (год, месяц, день, час, минута, секунда) ---
-    let components = [
-        method::YEAR.canonical,
-        method::MONTH.canonical,
-        method::DAY.canonical,
-        method::HOUR.canonical,
-        method::MINUTE.canonical,
-        method::SECOND.canonical,
+    let components: [(&str, Component); 6] = [
+        (method::YEAR.canonical, |dt| i64::from(dt.year())),
+        (method::MONTH.canonical, |dt| i64::from(dt.month())),
+        (method::DAY.canonical, |dt| i64::from(dt.day())),
+        (method::HOUR.canonical, |dt| i64::from(dt.hour())),
+        (method::MINUTE.canonical, |dt| i64::from(dt.minute())),
+        (method::SECOND.canonical, |dt| i64::from(dt.second())),
     ];
 
-    for name in components {
+    for (name, component) in components {
         let aliases = BUILTINS.method_names(name);
-        let method_name = name.to_string();
         let method = BuiltinFn(Arc::new(move |_, args, span| {
             let ms = get_ms(&args)?;
-            let dt = local_datetime(ms, span)?;
-            let val = match method_name.as_str() {
-                "год" => dt.year() as i64,
-                "месяц" => dt.month() as i64,
-                "день" => dt.day() as i64,
-                "час" => dt.hour() as i64,
-                "минута" => dt.minute() as i64,
-                "секунда" => dt.second() as i64,
-                _ => 0,
-            };
-            Ok(Value::Number(val))
+            let dt = get_zone(&args, span)?.at(ms, span)?;
+            Ok(Value::Number(component(&dt)))
         }));
         for alias in aliases {
             class_def.add_method(
@@ -182,34 +319,113 @@ pub fn setup_datetime_class(
         }
     }
 
+    // --- Метод: .разница(другая) -> Длительность, положительная если self позже ---
+    define_method!(class_def, interner_ref, method::DIFFERENCE.canonical => (interpreter, args, span) {
+        let ms = get_ms(&args)?;
+        let Some((Temporal::DateTime, other)) = CallArgListExt::get_value(&args, 1)
+            .and_then(|other| temporal_millis(interpreter, other))
+        else {
+            return bail_runtime!(TypeError, span, "Использование: дата.разница(другая_дата)");
+        };
+        let difference = ms.checked_sub(other).ok_or_else(|| {
+            runtime_error!(InvalidOperation, span, "Date/time arithmetic overflow")
+        })?;
+        duration_value(interpreter, difference, span)
+    });
+
     // --- Метод: .сейчас() (стандартный вывод) ---
     define_method!(class_def, interner_ref, method::NOW.canonical => (_, args, _) {
         let now = Local::now();
 
         let pattern = match CallArgListExt::get_value(&args, 1) {
-            Some(Value::Text(t)) => t.as_str(),
+            Some(Value::Text(t)) => &**t,
             _ => "%d.%m.%Y %H:%M:%S",
         };
 
         let formatted = now.format(pattern).to_string();
-        Ok(Value::Text(formatted))
+        Ok(Value::Text(formatted.into()))
     });
 
     // --- Метод: .формат(шаблон) ---
     define_method!(class_def, interner_ref, method::FORMAT.canonical => (_, args, span) {
         let ms = get_ms(&args)?;
-        let dt = local_datetime(ms, span)?;
         let pattern = CallArgListExt::get_value(&args, 1)
             .and_then(|v| v.as_str())
-            .map(|s| s.as_str())
             .unwrap_or("%d.%m.%Y %H:%M:%S");
 
-        Ok(Value::Text(dt.format(pattern).to_string()))
+        Ok(Value::Text(get_zone(&args, span)?.format(ms, pattern, span)?.into()))
+    });
+
+    // --- ДатаВремя.сейчас_утс() -> ДатаВремя в поясе UTC ---
+    define_method!(class_def, interner_ref, @static method::NOW_UTC.canonical => (interpreter, _, span) {
+        zoned_datetime_value(interpreter, Utc::now().timestamp_millis(), Zone::Named(Tz::UTC), span)
+    });
+
+    // --- Метод: .в_поясе("Europe/Moscow") -> новая ДатаВремя того же момента ---
+    define_method!(class_def, interner_ref, method::IN_ZONE.canonical => (interpreter, args, span) {
+        let ms = get_ms(&args)?;
+        let zone = match CallArgListExt::get_value(&args, 1) {
+            Some(Value::Text(name)) => Zone::from_name(name, span)?,
+            Some(Value::Empty) => Zone::Local,
+            _ => return bail_runtime!(TypeError, span, "Использование: дата.в_поясе(\"Europe/Moscow\")"),
+        };
+        zoned_datetime_value(interpreter, ms, zone, span)
+    });
+
+    // --- Метод: .пояс() -> имя пояса или пустота для местного времени ---
+    define_method!(class_def, interner_ref, method::ZONE.canonical => (_, args, span) {
+        Ok(match get_zone(&args, span)? {
+            Zone::Local => Value::Empty,
+            Zone::Named(zone) => Value::Text(zone.name().into()),
+        })
     });
 
     (name_sym, SharedMut::new(class_def))
 }
 
+pub fn setup_duration_class(
+    interner: &SharedInterner,
+) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner.write(|i| i.get_or_intern(class::DURATION.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+    let ms_sym = interner.write(|i| i.get_or_intern(MILLIS_FIELD));
+
+    // --- Длительность(мс = 0) ---
+    define_constructor!(class_def, (_, args, span) {
+        let Some(Value::Object(instance)) = CallArgListExt::first_value(&args) else {
+            return bail_runtime!(TypeError, span, "Ошибка инициализации self");
+        };
+        let ms = match CallArgListExt::get_value(&args, 1) {
+            None => 0,
+            Some(Value::Number(ms)) => *ms,
+            Some(_) => return bail_runtime!(TypeError, span, "Длительность задаётся в миллисекундах"),
+        };
+        instance.write(|i| i.field_values.insert(ms_sym, Value::Number(ms)));
+        Ok(Value::Empty)
+    });
+
+    // --- .дни(), .часы(), .минуты(), .секунды(), .миллисекунды(): целое число единиц ---
+    let units = [
+        (method::DAYS.canonical, 86_400_000),
+        (method::HOURS.canonical, 3_600_000),
+        (method::MINUTES.canonical, 60_000),
+        (method::SECONDS.canonical, 1_000),
+        (method::MILLISECONDS.canonical, 1),
+    ];
+    for (method_name, unit_ms) in units {
+        define_method!(class_def, interner, method_name => (interpreter, args, span) {
+            match CallArgListExt::first_value(&args)
+                .and_then(|duration| temporal_millis(interpreter, duration))
+            {
+                Some((Temporal::Duration, ms)) => Ok(Value::Number(ms / unit_ms)),
+                _ => bail_runtime!(InvalidOperation, span, "Метод должен вызываться у объекта"),
+            }
+        });
+    }
+
+    (name, SharedMut::new(class_def))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
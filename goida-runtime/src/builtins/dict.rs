@@ -3,11 +3,11 @@ use crate::builtins::iterator::values_from_iterable;
 use crate::builtins::registry::*;
 use crate::interpreter::prelude::RuntimeClassDefinition;
 use crate::interpreter::prelude::{
-    CallArgListExt, Interpreter, RuntimeError, RuntimeIterator, SharedInterner, Value,
+    CallArgListExt, DictKey, FastHashMap, Interpreter, RuntimeError, RuntimeIterator,
+    SharedInterner, Value,
 };
 use crate::shared::SharedMut;
 use crate::{bail_runtime, define_builtin, define_constructor, define_method, runtime_error};
-use std::collections::HashMap;
 use string_interner::DefaultSymbol as Symbol;
 
 pub fn setup_dict_class(interner: &SharedInterner) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
@@ -17,9 +17,9 @@ pub fn setup_dict_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtime
 
     define_constructor!(class_def, (interp, args, span) {
         if let Some(Value::Object(instance)) = CallArgListExt::first_value(&args) {
-            let internal_dict = Value::Dict(SharedMut::new(HashMap::new()));
+            let internal_dict = Value::Dict(SharedMut::new(FastHashMap::default()));
 
-            let data_sym = interp.interner.write(|i| i.get_or_intern("__data"));
+            let data_sym = interp.symbols.data;
             instance.write(|i| i.field_values.insert(data_sym, internal_dict));
 
             Ok(Value::Empty)
@@ -32,32 +32,34 @@ pub fn setup_dict_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtime
         }
     });
 
-    // 1. set(key: Text, value: Any) -> Empty
+    // 1. set(key: Number | Text | Boolean, value: Any) -> Empty
     define_method!(class_def, interner, method::SET.canonical => (_, args, span) {
-        if let (Some(Value::Dict(dict)), Some(Value::Text(key)), Some(val)) = (
+        if let (Some(Value::Dict(dict)), Some(key), Some(val)) = (
             CallArgListExt::first_value(&args),
             CallArgListExt::get_value(&args, 1),
             CallArgListExt::get_value(&args, 2),
         ) {
-            dict.write(|i| i.insert(key.clone(), val.clone()));
+            let key = DictKey::from_value(key, span)?;
+            dict.write(|i| i.insert(key, val.clone()));
             Ok(Value::Empty)
         } else {
             bail_runtime!(
                 TypeError,
                 span,
-                "Использование: dict.set(string, value)"
+                "Использование: dict.set(key, value)"
             )
         }
     });
 
-    // 2. get(key: Text, default?: Any) -> Any
+    // 2. get(key: Number | Text | Boolean, default?: Any) -> Any
     define_method!(class_def, interner, method::GET.canonical => (_, args, span) {
-        if let (Some(Value::Dict(dict)), Some(Value::Text(key))) = (
+        if let (Some(Value::Dict(dict)), Some(key)) = (
             CallArgListExt::first_value(&args),
             CallArgListExt::get_value(&args, 1),
         ) {
+            let key = DictKey::from_value(key, span)?;
             let result = dict.read(|d| {
-                d.get(key)
+                d.get(&key)
                     .cloned()
                     .unwrap_or_else(|| {
                         CallArgListExt::get_value(&args, 2)
@@ -71,32 +73,33 @@ pub fn setup_dict_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtime
             bail_runtime!(
                 TypeError,
                 span,
-                "Использование: dict.get(string, default?)"
+                "Использование: dict.get(key, default?)"
             )
         }
     });
 
-    // 3. has(key: Text) -> Boolean
+    // 3. has(key: Number | Text | Boolean) -> Boolean
     define_method!(class_def, interner, method::HAS.canonical => (_, args, span) {
-        if let (Some(Value::Dict(dict)), Some(Value::Text(key))) = (
+        if let (Some(Value::Dict(dict)), Some(key)) = (
             CallArgListExt::first_value(&args),
             CallArgListExt::get_value(&args, 1),
         ) {
-            Ok(Value::Boolean(dict.read(|i| i.contains_key(key))))
+            let key = DictKey::from_value(key, span)?;
+            Ok(Value::Boolean(dict.read(|i| i.contains_key(&key))))
         } else {
             bail_runtime!(
                 TypeError,
                 span,
-                "Использование: dict.has(string)"
+                "Использование: dict.has(key)"
             )
         }
     });
 
-    // 4. keys() -> List<Text>
+    // 4. keys() -> List<Number | Text | Boolean>
     define_method!(class_def, interner, method::KEYS.canonical => (_, args, span) {
         if let Some(Value::Dict(dict)) = CallArgListExt::first_value(&args) {
             let keys: Vec<Value> =
-                dict.read(|i| i.keys().map(|k| Value::Text(k.clone())).collect());
+                dict.read(|i| i.keys().map(DictKey::to_value).collect());
             Ok(Value::List(SharedMut::new(keys)))
         } else {
             bail_runtime!(
@@ -121,18 +124,79 @@ pub fn setup_dict_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtime
         }
     });
 
-    // 5. remove(key: Text) -> Any
+    // entries() -> List<[key, Any]>, в том же порядке ключей, что и `для ... из словарь`
+    define_method!(class_def, interner, method::ENTRIES.canonical => (_, args, span) {
+        if let Some(Value::Dict(dict)) = CallArgListExt::first_value(&args) {
+            let entries: Vec<Value> = dict.read(|i| {
+                let mut entries: Vec<_> = i.iter().collect();
+                entries.sort_by_key(|(key, _)| *key);
+                entries
+                    .into_iter()
+                    .map(|(key, value)| {
+                        Value::List(SharedMut::new(vec![key.to_value(), value.clone()]))
+                    })
+                    .collect()
+            });
+            Ok(Value::List(SharedMut::new(entries)))
+        } else {
+            bail_runtime!(
+                TypeError,
+                span,
+                "Ожидался словарь"
+            )
+        }
+    });
+
+    // merge(other: Dict) -> Dict, значения из other перекрывают исходные
+    define_method!(class_def, interner, method::MERGE.canonical => (_, args, span) {
+        if let (Some(Value::Dict(dict)), Some(Value::Dict(other))) = (
+            CallArgListExt::first_value(&args),
+            CallArgListExt::get_value(&args, 1),
+        ) {
+            let mut merged = dict.read(|i| i.clone());
+            merged.extend(other.read(|i| i.clone()));
+            Ok(Value::Dict(SharedMut::new(merged)))
+        } else {
+            bail_runtime!(
+                TypeError,
+                span,
+                "Использование: dict.merge(dict)"
+            )
+        }
+    });
+
+    // update(other: Dict) -> Empty
+    define_method!(class_def, interner, method::UPDATE.canonical => (_, args, span) {
+        if let (Some(Value::Dict(dict)), Some(Value::Dict(other))) = (
+            CallArgListExt::first_value(&args),
+            CallArgListExt::get_value(&args, 1),
+        ) {
+            // Копируем заранее: `д.обновить(д)` не должен брать блокировку дважды.
+            let other = other.read(|i| i.clone());
+            dict.write(|i| i.extend(other));
+            Ok(Value::Empty)
+        } else {
+            bail_runtime!(
+                TypeError,
+                span,
+                "Использование: dict.update(dict)"
+            )
+        }
+    });
+
+    // 5. remove(key: Number | Text | Boolean) -> Any
     define_method!(class_def, interner, method::REMOVE.canonical => (_, args, span) {
-        if let (Some(Value::Dict(dict)), Some(Value::Text(key))) = (
+        if let (Some(Value::Dict(dict)), Some(key)) = (
             CallArgListExt::first_value(&args),
             CallArgListExt::get_value(&args, 1),
         ) {
-            Ok(dict.write(|i| i.remove(key)).unwrap_or(Value::Empty))
+            let key = DictKey::from_value(key, span)?;
+            Ok(dict.write(|i| i.remove(&key)).unwrap_or(Value::Empty))
         } else {
             bail_runtime!(
                 TypeError,
                 span,
-                "Использование: dict.remove(string)"
+                "Использование: dict.remove(key)"
             )
         }
     });
@@ -161,7 +225,7 @@ pub fn setup_dict_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtime
 }
 
 pub fn setup_dict_func(interpreter: &mut Interpreter, interner: &SharedInterner) {
-    define_builtin!(interpreter, interner, function::DICT.canonical => (interpreter, arguments, span) {
+    define_builtin!(interpreter, interner, function::DICT.canonical => (_, arguments, span) {
         if arguments.len() % 2 != 0 {
             return bail_runtime!(
                 InvalidOperation,
@@ -170,12 +234,9 @@ pub fn setup_dict_func(interpreter: &mut Interpreter, interner: &SharedInterner)
             );
         }
 
-        let mut dict = HashMap::new();
+        let mut dict = FastHashMap::default();
         for i in (0..arguments.len()).step_by(2) {
-            let key = match &arguments[i].value {
-                Value::Text(s) => s.clone(),
-                v => interpreter.format_value(v),
-            };
+            let key = DictKey::from_value(&arguments[i].value, span)?;
             let value = arguments[i + 1].value.clone();
             dict.insert(key, value);
         }
@@ -0,0 +1,247 @@
+use crate::ast::prelude::{ErrorData, Span};
+use crate::builtins::registry::*;
+use crate::interpreter::options::Capability;
+use crate::interpreter::prelude::{
+    CallArgListExt, CallArgValue, RuntimeClassDefinition, RuntimeError, SharedInterner, Value,
+};
+use crate::shared::SharedMut;
+use crate::{bail_runtime, define_method, runtime_error};
+use std::fs;
+use std::path::{Path, PathBuf};
+use string_interner::DefaultSymbol as Symbol;
+
+fn path_arg(
+    args: &[CallArgValue],
+    index: usize,
+    usage: &str,
+    span: Span,
+) -> Result<PathBuf, RuntimeError> {
+    match CallArgListExt::get_value(args, index) {
+        Some(Value::Text(path)) => Ok(PathBuf::from(&**path)),
+        _ => bail_runtime!(TypeError, span, "Использование: Папка.{}", usage),
+    }
+}
+
+fn io_error(path: &Path, error: std::io::Error, span: Span) -> RuntimeError {
+    runtime_error!(IOError, span, "{}: {}", path.display(), error)
+}
+
+fn text_list(mut items: Vec<String>) -> Value {
+    items.sort();
+    Value::List(SharedMut::new(
+        items
+            .into_iter()
+            .map(|text| Value::Text(text.into()))
+            .collect(),
+    ))
+}
+
+/// Files under `dir`, as paths relative to `root`.
+fn walk(root: &Path, dir: &Path, output: &mut Vec<String>, span: Span) -> Result<(), RuntimeError> {
+    for entry in fs::read_dir(dir).map_err(|error| io_error(dir, error, span))? {
+        let entry = entry.map_err(|error| io_error(dir, error, span))?;
+        let path = entry.path();
+        // Ссылки на папки не раскрываются: ссылка на родителя зациклила бы обход.
+        let is_dir = entry
+            .file_type()
+            .map_err(|error| io_error(&path, error, span))?
+            .is_dir();
+        if is_dir {
+            walk(root, &path, output, span)?;
+        } else if let Ok(relative) = path.strip_prefix(root) {
+            // Разделитель «/» на всех системах, чтобы вывод не зависел от ОС.
+            let parts = relative
+                .components()
+                .map(|part| part.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>();
+            output.push(parts.join("/"));
+        }
+    }
+    Ok(())
+}
+
+/// `path` with symlinks and `..` resolved, also when its last parts do not exist yet.
+fn resolve_path(path: &Path) -> std::io::Result<PathBuf> {
+    let absolute = std::path::absolute(path)?;
+    let mut existing = absolute.as_path();
+    let mut missing = Vec::new();
+    while !existing.exists() {
+        let Some(parent) = existing.parent() else {
+            break;
+        };
+        missing.extend(existing.file_name());
+        existing = parent;
+    }
+    let mut resolved = existing.canonicalize()?;
+    for part in missing.into_iter().rev() {
+        resolved.push(part);
+    }
+    Ok(resolved)
+}
+
+/// Copies the folder `from` into `to`, refusing a destination inside the source,
+/// which would copy the copy again on every level.
+fn copy_tree(from: &Path, to: &Path, span: Span) -> Result<(), RuntimeError> {
+    let source = from
+        .canonicalize()
+        .map_err(|error| io_error(from, error, span))?;
+    let target = resolve_path(to).map_err(|error| io_error(to, error, span))?;
+    if target.starts_with(&source) {
+        return bail_runtime!(
+            IOError,
+            span,
+            "{}: нельзя копировать папку внутрь неё самой",
+            to.display()
+        );
+    }
+    copy_dir(from, to, span)
+}
+
+fn copy_dir(from: &Path, to: &Path, span: Span) -> Result<(), RuntimeError> {
+    fs::create_dir_all(to).map_err(|error| io_error(to, error, span))?;
+    for entry in fs::read_dir(from).map_err(|error| io_error(from, error, span))? {
+        let entry = entry.map_err(|error| io_error(from, error, span))?;
+        let path = entry.path();
+        let target = to.join(entry.file_name());
+        let is_dir = entry
+            .file_type()
+            .map_err(|error| io_error(&path, error, span))?
+            .is_dir();
+        if is_dir {
+            copy_dir(&path, &target, span)?;
+        } else {
+            fs::copy(&path, &target).map_err(|error| io_error(&path, error, span))?;
+        }
+    }
+    Ok(())
+}
+
+pub fn setup_directory_class(
+    interner: &SharedInterner,
+) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner.write(|i| i.get_or_intern(class::DIRECTORY.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    // --- Папка.список(путь) -> List имён файлов и папок ---
+    define_method!(class_def, interner, @static method::TO_LIST.canonical => (interpreter, args, span) {
+        interpreter.require(Capability::File, span)?;
+        let path = path_arg(&args, 0, "список(путь)", span)?;
+        let mut names = Vec::new();
+        for entry in fs::read_dir(&path).map_err(|error| io_error(&path, error, span))? {
+            let entry = entry.map_err(|error| io_error(&path, error, span))?;
+            names.push(entry.file_name().to_string_lossy().to_string());
+        }
+        Ok(text_list(names))
+    });
+
+    // --- Папка.обойти(путь) -> List путей всех файлов относительно папки ---
+    define_method!(class_def, interner, @static method::WALK.canonical => (interpreter, args, span) {
+        interpreter.require(Capability::File, span)?;
+        let path = path_arg(&args, 0, "обойти(путь)", span)?;
+        let mut files = Vec::new();
+        walk(&path, &path, &mut files, span)?;
+        Ok(text_list(files))
+    });
+
+    // --- Папка.создать(путь), вместе с недостающими родителями ---
+    define_method!(class_def, interner, @static method::CREATE.canonical => (interpreter, args, span) {
+        interpreter.require(Capability::File, span)?;
+        let path = path_arg(&args, 0, "создать(путь)", span)?;
+        fs::create_dir_all(&path).map_err(|error| io_error(&path, error, span))?;
+        Ok(Value::Empty)
+    });
+
+    // --- Папка.удалить(путь, рекурсивно = ложь) ---
+    define_method!(class_def, interner, @static method::REMOVE.canonical => (interpreter, args, span) {
+        interpreter.require(Capability::File, span)?;
+        let path = path_arg(&args, 0, "удалить(путь, рекурсивно)", span)?;
+        let recursive = CallArgListExt::get_value(&args, 1).is_some_and(Value::is_truthy);
+        if recursive {
+            fs::remove_dir_all(&path)
+        } else {
+            fs::remove_dir(&path)
+        }
+        .map_err(|error| io_error(&path, error, span))?;
+        Ok(Value::Empty)
+    });
+
+    // --- Папка.существует(путь) -> Bool, ложь для обычных файлов ---
+    define_method!(class_def, interner, @static method::EXISTS.canonical => (interpreter, args, span) {
+        interpreter.require(Capability::File, span)?;
+        let path = path_arg(&args, 0, "существует(путь)", span)?;
+        Ok(Value::Boolean(path.is_dir()))
+    });
+
+    // --- Папка.копировать(откуда, куда), со всем содержимым ---
+    define_method!(class_def, interner, @static method::COPY.canonical => (interpreter, args, span) {
+        interpreter.require(Capability::File, span)?;
+        let from = path_arg(&args, 0, "копировать(откуда, куда)", span)?;
+        let to = path_arg(&args, 1, "копировать(откуда, куда)", span)?;
+        if !from.is_dir() {
+            return bail_runtime!(IOError, span, "{}: папка не найдена", from.display());
+        }
+        copy_tree(&from, &to, span)?;
+        Ok(Value::Empty)
+    });
+
+    // --- Папка.переместить(откуда, куда) ---
+    define_method!(class_def, interner, @static method::MOVE.canonical => (interpreter, args, span) {
+        interpreter.require(Capability::File, span)?;
+        let from = path_arg(&args, 0, "переместить(откуда, куда)", span)?;
+        let to = path_arg(&args, 1, "переместить(откуда, куда)", span)?;
+        if fs::rename(&from, &to).is_err() {
+            // Между дисками переименование невозможно: копируем и удаляем.
+            copy_tree(&from, &to, span)?;
+            fs::remove_dir_all(&from).map_err(|error| io_error(&from, error, span))?;
+        }
+        Ok(Value::Empty)
+    });
+
+    (name, SharedMut::new(class_def))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{copy_tree, walk};
+    use crate::ast::prelude::Span;
+    use std::fs;
+
+    #[test]
+    fn copying_a_folder_into_itself_fails_before_creating_anything() {
+        let root = std::env::temp_dir().join(format!("goida-copy-into-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("данные")).unwrap();
+        fs::write(root.join("данные/файл.txt"), "1").unwrap();
+
+        let into_child = root.join("данные/копия/глубже");
+        assert!(copy_tree(&root.join("данные"), &into_child, Span::default()).is_err());
+        assert!(!root.join("данные/копия").exists());
+        let same = root.join("данные/../данные");
+        assert!(copy_tree(&root.join("данные"), &same, Span::default()).is_err());
+
+        copy_tree(
+            &root.join("данные"),
+            &root.join("данные-копия"),
+            Span::default(),
+        )
+        .expect("sibling destination should be allowed");
+        assert!(root.join("данные-копия/файл.txt").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn walk_does_not_descend_into_symlinked_folders() {
+        let root = std::env::temp_dir().join(format!("goida-walk-link-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("папка")).unwrap();
+        fs::write(root.join("папка/файл.txt"), "1").unwrap();
+        std::os::unix::fs::symlink("..", root.join("папка/петля")).unwrap();
+
+        let mut files = Vec::new();
+        walk(&root, &root, &mut files, Span::default()).unwrap();
+        files.sort();
+        assert_eq!(files, ["папка/петля", "папка/файл.txt"]);
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
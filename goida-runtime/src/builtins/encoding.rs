@@ -0,0 +1,103 @@
+use crate::ast::prelude::{ErrorData, Span};
+use crate::builtins::bytes::bytes_from_value;
+use crate::builtins::registry::*;
+use crate::interpreter::prelude::{
+    CallArgListExt, CallArgValue, RuntimeClassDefinition, RuntimeError, SharedInterner, Value,
+};
+use crate::shared::SharedMut;
+use crate::{bail_runtime, define_method, runtime_error};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use std::sync::Arc;
+use string_interner::DefaultSymbol as Symbol;
+
+/// Everything except the unreserved characters of RFC 3986 gets escaped.
+const URL_COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+pub(crate) fn url_encode(text: &str) -> String {
+    utf8_percent_encode(text, URL_COMPONENT).to_string()
+}
+
+pub(crate) fn url_decode(text: &str, span: Span) -> Result<String, RuntimeError> {
+    percent_decode_str(text)
+        .decode_utf8()
+        .map(|text| text.into_owned())
+        .map_err(|_| runtime_error!(InvalidOperation, span, "URL-строка не является UTF-8"))
+}
+
+fn data_arg(args: &[CallArgValue], usage: &str, span: Span) -> Result<Vec<u8>, RuntimeError> {
+    match CallArgListExt::first_value(args) {
+        Some(value @ (Value::Text(_) | Value::Bytes(_))) => bytes_from_value(value, None, span),
+        _ => bail_runtime!(
+            TypeError,
+            span,
+            "Использование: Кодирование.{}(данные)",
+            usage
+        ),
+    }
+}
+
+fn text_arg<'a>(
+    args: &'a [CallArgValue],
+    usage: &str,
+    span: Span,
+) -> Result<&'a str, RuntimeError> {
+    match CallArgListExt::first_value(args) {
+        Some(Value::Text(text)) => Ok(text),
+        _ => bail_runtime!(
+            TypeError,
+            span,
+            "Использование: Кодирование.{}(текст)",
+            usage
+        ),
+    }
+}
+
+pub fn setup_encoding_class(
+    interner: &SharedInterner,
+) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner.write(|i| i.get_or_intern(class::ENCODING.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    // --- Кодирование.в_base64(данные) -> Text ---
+    define_method!(class_def, interner, @static method::TO_BASE64.canonical => (_, args, span) {
+        Ok(Value::Text(BASE64.encode(data_arg(&args, "в_base64", span)?).into()))
+    });
+
+    // --- Кодирование.из_base64(текст) -> Bytes ---
+    define_method!(class_def, interner, @static method::FROM_BASE64.canonical => (_, args, span) {
+        let bytes = BASE64
+            .decode(text_arg(&args, "из_base64", span)?.trim())
+            .map_err(|error| runtime_error!(InvalidOperation, span, "Неверный base64: {}", error))?;
+        Ok(Value::Bytes(Arc::new(bytes)))
+    });
+
+    // --- Кодирование.в_hex(данные) -> Text ---
+    define_method!(class_def, interner, @static method::TO_HEX.canonical => (_, args, span) {
+        Ok(Value::Text(hex::encode(data_arg(&args, "в_hex", span)?).into()))
+    });
+
+    // --- Кодирование.из_hex(текст) -> Bytes ---
+    define_method!(class_def, interner, @static method::FROM_HEX.canonical => (_, args, span) {
+        let bytes = hex::decode(text_arg(&args, "из_hex", span)?.trim())
+            .map_err(|error| runtime_error!(InvalidOperation, span, "Неверный hex: {}", error))?;
+        Ok(Value::Bytes(Arc::new(bytes)))
+    });
+
+    // --- Кодирование.в_url(текст) -> Text, как encodeURIComponent ---
+    define_method!(class_def, interner, @static method::URL_ENCODE.canonical => (_, args, span) {
+        Ok(Value::Text(url_encode(text_arg(&args, "в_url", span)?).into()))
+    });
+
+    // --- Кодирование.из_url(текст) -> Text ---
+    define_method!(class_def, interner, @static method::URL_DECODE.canonical => (_, args, span) {
+        Ok(Value::Text(url_decode(text_arg(&args, "из_url", span)?, span)?.into()))
+    });
+
+    (name, SharedMut::new(class_def))
+}
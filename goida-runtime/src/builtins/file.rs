@@ -1,13 +1,23 @@
 use crate::ast::prelude::{ErrorData, Span};
+use crate::builtins::bytes::bytes_from_value;
+use crate::builtins::datetime::datetime_value;
+use crate::builtins::iterator::call_callable;
+use crate::builtins::promise::define_async_variant;
 use crate::builtins::registry::*;
+use crate::interpreter::options::Capability;
 use crate::interpreter::prelude::RuntimeClassDefinition;
 use crate::interpreter::prelude::{
-    CallArgListExt, CallArgValue, RuntimeError, SharedInterner, Value,
+    CallArgListExt, CallArgValue, ClassInstance, Interpreter, RuntimeError, SharedInterner, Value,
 };
 use crate::shared::SharedMut;
+use crate::traits::prelude::CoreOperations;
 use crate::{bail_runtime, define_constructor, define_method, runtime_error};
+use std::any::Any;
 use std::fs;
+use std::io::{BufRead, BufReader, Write};
 use std::path::Path;
+use std::sync::Arc;
+use std::time::UNIX_EPOCH;
 use string_interner::DefaultSymbol as Symbol;
 
 pub fn setup_file_class(
@@ -18,6 +28,7 @@ pub fn setup_file_class(
     let mut class_def = RuntimeClassDefinition::new(name, Span::default());
 
     define_constructor!(class_def, (interp, args, span) {
+        interp.require(Capability::File, span)?;
         if let (Some(Value::Object(instance)), Some(Value::Text(path))) = (
             CallArgListExt::first_value(&args),
             CallArgListExt::get_value(&args, 1),
@@ -39,7 +50,7 @@ pub fn setup_file_class(
             return instance.read(|i| {
                 for val in i.field_values.values() {
                     if let Value::Text(p) = val {
-                        return Ok(p.clone());
+                        return Ok(p.to_string());
                     }
                 }
                 bail_runtime!(InvalidOperation, Span::default(), "Путь не найден")
@@ -59,7 +70,15 @@ pub fn setup_file_class(
         let path = get_path(&args)?;
         let content = fs::read_to_string(path)
             .map_err(|e| runtime_error!(IOError, span, "{}", e.to_string()))?;
-        Ok(Value::Text(content))
+        Ok(Value::Text(content.into()))
+    });
+
+    // --- .читать_байты() -> Bytes ---
+    define_method!(class_def, interner_ref, method::READ_BYTES.canonical => (_, args, span) {
+        let path = get_path(&args)?;
+        let content = fs::read(path)
+            .map_err(|e| runtime_error!(IOError, span, "{}", e.to_string()))?;
+        Ok(Value::Bytes(Arc::new(content)))
     });
 
     // --- .записать(текст) ---
@@ -81,6 +100,24 @@ pub fn setup_file_class(
         Ok(Value::Empty)
     });
 
+    // --- .писать_байты(байты или список чисел) ---
+    define_method!(class_def, interner_ref, method::WRITE_BYTES.canonical => (_, args, span) {
+        let path = get_path(&args)?;
+        let Some(value) = CallArgListExt::get_value(&args, 1) else {
+            return bail_runtime!(TypeError, span, "Использование: файл.писать_байты(байты)");
+        };
+        let content = bytes_from_value(value, None, span)?;
+
+        if let Some(parent) = Path::new(&path).parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| runtime_error!(IOError, span, "{}", e.to_string()))?;
+        }
+
+        fs::write(path, content)
+            .map_err(|e| runtime_error!(IOError, span, "{}", e.to_string()))?;
+        Ok(Value::Empty)
+    });
+
     // --- .дописать(текст) ---
     define_method!(class_def, interner_ref, method::APPEND.canonical => (interpreter, args, span) {
         let path = get_path(&args)?;
@@ -101,7 +138,6 @@ pub fn setup_file_class(
             .open(path)
             .map_err(|e| runtime_error!(IOError, span, "{}", e.to_string()))?;
 
-        use std::io::Write;
         file.write_all(text.as_bytes())
             .map_err(|e| runtime_error!(IOError, span, "{}", e.to_string()))?;
 
@@ -116,5 +152,263 @@ pub fn setup_file_class(
         Ok(Value::Empty)
     });
 
+    // --- .размер() -> Number в байтах ---
+    define_method!(class_def, interner_ref, method::SIZE.canonical => (_, args, span) {
+        let path = get_path(&args)?;
+        let metadata = fs::metadata(path)
+            .map_err(|e| runtime_error!(IOError, span, "{}", e.to_string()))?;
+        Ok(Value::Number(metadata.len() as i64))
+    });
+
+    // --- .изменён() -> ДатаВремя последнего изменения ---
+    define_method!(class_def, interner_ref, method::MODIFIED.canonical => (interpreter, args, span) {
+        let path = get_path(&args)?;
+        let modified = fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .map_err(|e| runtime_error!(IOError, span, "{}", e.to_string()))?;
+        let ms = match modified.duration_since(UNIX_EPOCH) {
+            Ok(since) => since.as_millis() as i64,
+            Err(before) => -(before.duration().as_millis() as i64),
+        };
+        datetime_value(interpreter, ms, span)
+    });
+
+    // --- .является_папкой() -> Bool, ложь если пути нет ---
+    define_method!(class_def, interner_ref, method::IS_DIR.canonical => (_, args, _) {
+        let path = get_path(&args).unwrap_or_default();
+        Ok(Value::Boolean(Path::new(&path).is_dir()))
+    });
+
+    // --- .права() -> Text вида "rwxr-xr-x" ---
+    define_method!(class_def, interner_ref, method::PERMISSIONS.canonical => (_, args, span) {
+        let path = get_path(&args)?;
+        let metadata = fs::metadata(path)
+            .map_err(|e| runtime_error!(IOError, span, "{}", e.to_string()))?;
+        Ok(Value::Text(permissions_text(&metadata).into()))
+    });
+
+    // --- Файл.открыть(путь, режим = "чтение") -> ОткрытыйФайл ---
+    define_method!(class_def, interner_ref, @static method::OPEN.canonical => (interpreter, args, span) {
+        interpreter.require(Capability::File, span)?;
+        let Some(Value::Text(path)) = CallArgListExt::first_value(&args) else {
+            return bail_runtime!(TypeError, span, "Использование: Файл.открыть(путь, режим)");
+        };
+        let handle = open_file(path, CallArgListExt::get_value(&args, 1), span)?;
+        open_file_object(interpreter, handle, span)
+    });
+
+    // --- .прочитать_асинхронно(), .записать_асинхронно(текст) -> Обещание ---
+    define_async_variant(
+        &mut class_def,
+        interner_ref,
+        method::READ.canonical,
+        method::READ_ASYNC.canonical,
+    );
+    define_async_variant(
+        &mut class_def,
+        interner_ref,
+        method::WRITE.canonical,
+        method::WRITE_ASYNC.canonical,
+    );
+
+    (name, SharedMut::new(class_def))
+}
+
+#[cfg(unix)]
+fn permissions_text(metadata: &fs::Metadata) -> String {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mode = metadata.permissions().mode();
+    (0..9)
+        .map(|bit| {
+            if mode & (0o400 >> bit) == 0 {
+                '-'
+            } else {
+                ['r', 'w', 'x'][bit % 3]
+            }
+        })
+        .collect()
+}
+
+/// Without unix modes only the read-only flag is known.
+#[cfg(not(unix))]
+fn permissions_text(metadata: &fs::Metadata) -> String {
+    let access = if metadata.permissions().readonly() {
+        "r--"
+    } else {
+        "rw-"
+    };
+    access.repeat(3)
+}
+
+/// Hidden field of `ОткрытыйФайл` holding the handle; `пустота` once closed.
+pub(crate) const HANDLE_FIELD: &str = "__file";
+
+/// File opened by `Файл.открыть`: buffered for reading, unbuffered for writing
+/// so nothing is lost if the script never calls `закрыть()`.
+enum FileHandle {
+    Reader(BufReader<fs::File>),
+    Writer(fs::File),
+}
+
+fn io_error(error: std::io::Error, span: Span) -> RuntimeError {
+    runtime_error!(IOError, span, "{}", error.to_string())
+}
+
+fn open_file(path: &str, mode: Option<&Value>, span: Span) -> Result<FileHandle, RuntimeError> {
+    let mode = match mode {
+        None | Some(Value::Empty) => "чтение",
+        Some(Value::Text(mode)) => mode,
+        Some(_) => return bail_runtime!(TypeError, span, "Режим файла должен быть строкой"),
+    };
+    let mut options = fs::OpenOptions::new();
+    match mode {
+        "чтение" | "r" => options.read(true),
+        "запись" | "w" => options.write(true).create(true).truncate(true),
+        "дописать" | "a" => options.append(true).create(true),
+        _ => {
+            return bail_runtime!(
+                InvalidOperation,
+                span,
+                "Неизвестный режим '{}': ожидалось чтение, запись или дописать",
+                mode
+            )
+        }
+    };
+    let is_read = matches!(mode, "чтение" | "r");
+    if !is_read {
+        if let Some(parent) = Path::new(path).parent() {
+            fs::create_dir_all(parent).map_err(|e| io_error(e, span))?;
+        }
+    }
+    let file = options.open(path).map_err(|e| io_error(e, span))?;
+    Ok(if is_read {
+        FileHandle::Reader(BufReader::new(file))
+    } else {
+        FileHandle::Writer(file)
+    })
+}
+
+fn open_file_object(
+    interpreter: &Interpreter,
+    handle: FileHandle,
+    span: Span,
+) -> Result<Value, RuntimeError> {
+    let class_symbol = interpreter.intern_string(class::OPEN_FILE.names.canonical);
+    let Some(class_ref) = interpreter.std_classes.get(&class_symbol).cloned() else {
+        return bail_runtime!(InvalidOperation, span, "Класс ОткрытыйФайл не найден");
+    };
+    let instance = SharedMut::new(ClassInstance::new(class_symbol, class_ref));
+    let resource = Value::NativeResource(SharedMut::new(
+        Box::new(handle) as Box<dyn Any + Send + Sync>
+    ));
+    instance.write(|instance| {
+        instance
+            .field_values
+            .insert(interpreter.symbols.file, resource)
+    });
+    Ok(Value::Object(instance))
+}
+
+/// Runs `f` with the handle of the receiver, failing if the file was closed.
+fn with_handle<R>(
+    interpreter: &Interpreter,
+    args: &[CallArgValue],
+    span: Span,
+    f: impl FnOnce(&mut FileHandle) -> Result<R, RuntimeError>,
+) -> Result<R, RuntimeError> {
+    let Some(Value::Object(instance)) = CallArgListExt::first_value(args) else {
+        return bail_runtime!(TypeError, span, "Ожидался объект ОткрытыйФайл");
+    };
+    let field = interpreter.symbols.file;
+    let resource = instance.read(|instance| instance.field_values.get(&field).cloned());
+    match resource {
+        Some(Value::NativeResource(resource)) => {
+            resource.write(|boxed| match boxed.downcast_mut::<FileHandle>() {
+                Some(handle) => f(handle),
+                None => bail_runtime!(TypeError, span, "Внутренний ресурс файла повреждён"),
+            })
+        }
+        _ => bail_runtime!(InvalidOperation, span, "Файл закрыт"),
+    }
+}
+
+/// Next line without its line ending, or `None` at the end of the file.
+fn next_line(handle: &mut FileHandle, span: Span) -> Result<Option<String>, RuntimeError> {
+    let FileHandle::Reader(reader) = handle else {
+        return bail_runtime!(InvalidOperation, span, "Файл открыт не для чтения");
+    };
+    let mut line = String::new();
+    if reader.read_line(&mut line).map_err(|e| io_error(e, span))? == 0 {
+        return Ok(None);
+    }
+    if line.ends_with('\n') {
+        line.pop();
+        if line.ends_with('\r') {
+            line.pop();
+        }
+    }
+    Ok(Some(line))
+}
+
+pub fn setup_open_file_class(
+    interner: &SharedInterner,
+) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner.write(|i| i.get_or_intern(class::OPEN_FILE.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    // --- .читать_строку() -> Text или пустота в конце файла ---
+    define_method!(class_def, interner, method::READ_LINE.canonical => (interpreter, args, span) {
+        let line = with_handle(interpreter, &args, span, |handle| next_line(handle, span))?;
+        Ok(line.map_or(Value::Empty, |line| Value::Text(line.into())))
+    });
+
+    // --- .читать_строки() -> List оставшихся строк ---
+    define_method!(class_def, interner, method::READ_LINES.canonical => (interpreter, args, span) {
+        with_handle(interpreter, &args, span, |handle| {
+            let mut lines = Vec::new();
+            while let Some(line) = next_line(handle, span)? {
+                lines.push(Value::Text(line.into()));
+            }
+            Ok(Value::List(SharedMut::new(lines)))
+        })
+    });
+
+    // --- .построчно(обработчик) -> число строк, файл не читается целиком ---
+    define_method!(class_def, interner, method::EACH_LINE.canonical => (interpreter, args, span) {
+        let Some(handler) = CallArgListExt::get_value(&args, 1).cloned() else {
+            return bail_runtime!(TypeError, span, "Использование: файл.построчно(обработчик)");
+        };
+        let mut count = 0;
+        // Ресурс блокируется только на чтение строки, чтобы обработчик мог обращаться к файлу.
+        while let Some(line) = with_handle(interpreter, &args, span, |handle| next_line(handle, span))? {
+            call_callable(interpreter, handler.clone(), vec![Value::Text(line.into())], span)?;
+            count += 1;
+        }
+        Ok(Value::Number(count))
+    });
+
+    // --- .записать(текст) ---
+    define_method!(class_def, interner, method::WRITE.canonical => (interpreter, args, span) {
+        let text = CallArgListExt::get_value(&args, 1)
+            .map(|value| interpreter.format_value(value))
+            .unwrap_or_default();
+        with_handle(interpreter, &args, span, |handle| match handle {
+            FileHandle::Writer(file) => file.write_all(text.as_bytes()).map_err(|e| io_error(e, span)),
+            FileHandle::Reader(_) => bail_runtime!(InvalidOperation, span, "Файл открыт только для чтения"),
+        })?;
+        Ok(Value::Empty)
+    });
+
+    // --- .закрыть() ---
+    define_method!(class_def, interner, method::CLOSE.canonical => (interpreter, args, span) {
+        let Some(Value::Object(instance)) = CallArgListExt::first_value(&args) else {
+            return bail_runtime!(TypeError, span, "Ожидался объект ОткрытыйФайл");
+        };
+        let field = interpreter.symbols.file;
+        instance.write(|instance| instance.field_values.insert(field, Value::Empty));
+        Ok(Value::Empty)
+    });
+
     (name, SharedMut::new(class_def))
 }
@@ -0,0 +1,177 @@
+use crate::ast::prelude::{ErrorData, Span};
+use crate::builtins::promise::define_async_variant;
+use crate::builtins::registry::*;
+use crate::interpreter::options::Capability;
+use crate::interpreter::prelude::{
+    CallArgListExt, CallArgValue, ClassInstance, FastHashMap, Interpreter, RuntimeClassDefinition,
+    RuntimeError, SharedInterner, Value,
+};
+use crate::shared::SharedMut;
+use crate::traits::prelude::CoreOperations;
+use crate::{bail_runtime, define_method, runtime_error};
+use std::time::Duration;
+use string_interner::DefaultSymbol as Symbol;
+use ureq::http::Response;
+use ureq::{Agent, Body};
+
+/// Requests that hang longer than this fail instead of blocking the script forever.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub(crate) const STATUS_FIELD: &str = "статус";
+pub(crate) const HEADERS_FIELD: &str = "заголовки";
+pub(crate) const BODY_FIELD: &str = "тело";
+
+fn agent() -> Agent {
+    Agent::config_builder()
+        .http_status_as_error(false)
+        .timeout_global(Some(REQUEST_TIMEOUT))
+        .build()
+        .into()
+}
+
+fn url_arg(args: &[CallArgValue], method: &str, span: Span) -> Result<String, RuntimeError> {
+    match CallArgListExt::first_value(args) {
+        Some(Value::Text(url)) => Ok(url.to_string()),
+        _ => bail_runtime!(TypeError, span, "ХТТП.{} ожидает адрес строкой", method),
+    }
+}
+
+fn headers_arg(
+    args: &[CallArgValue],
+    index: usize,
+    span: Span,
+) -> Result<Vec<(String, String)>, RuntimeError> {
+    match CallArgListExt::get_value(args, index) {
+        None | Some(Value::Empty) => Ok(Vec::new()),
+        Some(Value::Dict(headers)) => Ok(headers.read(|headers| {
+            headers
+                .iter()
+                .map(|(name, value)| {
+                    let value = match value {
+                        Value::Text(value) => value.to_string(),
+                        other => other.to_string(),
+                    };
+                    (name.to_string(), value)
+                })
+                .collect()
+        })),
+        Some(_) => bail_runtime!(TypeError, span, "Заголовки должны быть словарём"),
+    }
+}
+
+fn request_error(url: &str, error: ureq::Error, span: Span) -> RuntimeError {
+    runtime_error!(IOError, span, "Ошибка запроса к '{}': {}", url, error)
+}
+
+/// Wraps a response into a `ХТТПОтвет` object with `статус`, `заголовки` and `тело`.
+fn build_response(
+    interpreter: &Interpreter,
+    url: &str,
+    mut response: Response<Body>,
+    span: Span,
+) -> Result<Value, RuntimeError> {
+    let class_symbol = interpreter.intern_string(class::HTTP_RESPONSE.names.canonical);
+    let Some(class_ref) = interpreter.std_classes.get(&class_symbol).cloned() else {
+        return bail_runtime!(InvalidOperation, span, "Класс ХТТПОтвет не найден");
+    };
+
+    let status = i64::from(response.status().as_u16());
+    let headers = response
+        .headers()
+        .iter()
+        .map(|(name, value)| {
+            (
+                name.as_str().into(),
+                Value::Text(String::from_utf8_lossy(value.as_bytes()).into()),
+            )
+        })
+        .collect::<FastHashMap<_, _>>();
+    let body = response
+        .body_mut()
+        .read_to_string()
+        .map_err(|error| request_error(url, error, span))?;
+
+    let instance = SharedMut::new(ClassInstance::new(class_symbol, class_ref));
+    instance.write(|instance| {
+        instance
+            .field_values
+            .insert(interpreter.symbols.status, Value::Number(status));
+        instance.field_values.insert(
+            interpreter.symbols.headers,
+            Value::Dict(SharedMut::new(headers)),
+        );
+        instance
+            .field_values
+            .insert(interpreter.symbols.body, Value::Text(body.into()));
+    });
+    Ok(Value::Object(instance))
+}
+
+pub fn setup_http_class(interner: &SharedInterner) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner.write(|i| i.get_or_intern(class::HTTP.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    // --- ХТТП.получить(адрес, заголовки = пустота) -> ХТТПОтвет ---
+    define_method!(class_def, interner, @static method::GET.canonical => (interpreter, args, span) {
+        interpreter.require(Capability::Network, span)?;
+        let url = url_arg(&args, "получить", span)?;
+        let mut request = agent().get(&url);
+        for (name, value) in headers_arg(&args, 1, span)? {
+            request = request.header(name, value);
+        }
+        let response = request.call().map_err(|error| request_error(&url, error, span))?;
+        build_response(interpreter, &url, response, span)
+    });
+
+    // --- ХТТП.отправить(адрес, тело, заголовки = пустота) -> ХТТПОтвет ---
+    define_method!(class_def, interner, @static method::SEND.canonical => (interpreter, args, span) {
+        interpreter.require(Capability::Network, span)?;
+        let url = url_arg(&args, "отправить", span)?;
+        let mut request = agent().post(&url);
+        // Словари и списки уходят как JSON, остальное — как текст.
+        let body = match CallArgListExt::get_value(&args, 1) {
+            None | Some(Value::Empty) => String::new(),
+            Some(Value::Text(text)) => text.to_string(),
+            Some(value @ (Value::Dict(_) | Value::List(_) | Value::Array(_))) => {
+                request = request.header("Content-Type", "application/json");
+                serde_json::to_string(value).map_err(|error| {
+                    runtime_error!(InvalidOperation, span, "Ошибка сериализации JSON: {}", error)
+                })?
+            }
+            Some(other) => other.to_string(),
+        };
+        for (name, value) in headers_arg(&args, 2, span)? {
+            request = request.header(name, value);
+        }
+        let response = request
+            .send(body)
+            .map_err(|error| request_error(&url, error, span))?;
+        build_response(interpreter, &url, response, span)
+    });
+
+    // --- ХТТП.получить_асинхронно(...), ХТТП.отправить_асинхронно(...) -> Обещание ---
+    define_async_variant(
+        &mut class_def,
+        interner,
+        method::GET.canonical,
+        method::GET_ASYNC.canonical,
+    );
+    define_async_variant(
+        &mut class_def,
+        interner,
+        method::SEND.canonical,
+        method::SEND_ASYNC.canonical,
+    );
+
+    (name, SharedMut::new(class_def))
+}
+
+pub fn setup_http_response_class(
+    interner: &SharedInterner,
+) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner.write(|i| i.get_or_intern(class::HTTP_RESPONSE.names.canonical));
+    (
+        name,
+        SharedMut::new(RuntimeClassDefinition::new(name, Span::default())),
+    )
+}
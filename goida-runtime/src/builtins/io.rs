@@ -83,7 +83,7 @@ pub fn setup_io_func(interpreter: &mut Interpreter, interner: &SharedInterner) {
 
         let mut input = String::new();
         if io::stdin().read_line(&mut input).is_ok() {
-            Ok(Value::Text(input.trim().to_string()))
+            Ok(Value::Text(input.trim().into()))
         } else {
             bail_runtime!(
                 IOError,
@@ -2,8 +2,8 @@ use crate::ast::prelude::{ErrorData, Span};
 use crate::builtins::registry::*;
 use crate::interpreter::prelude::RuntimeClassDefinition;
 use crate::interpreter::prelude::{
-    CallArgListExt, CallArgValue, Interpreter, IteratorStep, RuntimeError, RuntimeIterator,
-    SharedInterner, Value,
+    CallArgListExt, CallArgValue, DictKey, Interpreter, IteratorStep, RuntimeError,
+    RuntimeIterator, SharedInterner, Value,
 };
 use crate::shared::SharedMut;
 use crate::traits::prelude::InterpreterFunctions;
@@ -18,20 +18,29 @@ pub(crate) fn values_from_iterable(
     match value {
         Value::List(list) => Ok(Arc::new(list.read(|items| items.clone()))),
         Value::Array(items) => Ok(items.clone()),
+        Value::Bytes(bytes) => Ok(Arc::new(
+            bytes
+                .iter()
+                .map(|byte| Value::Number(i64::from(*byte)))
+                .collect(),
+        )),
         Value::Text(text) => Ok(Arc::new(
-            text.chars().map(|ch| Value::Text(ch.to_string())).collect(),
+            text.chars()
+                .map(|ch| Value::Text(ch.to_string().into()))
+                .collect(),
         )),
         Value::Dict(dict) => Ok(Arc::new(dict.read(|items| {
             let mut keys: Vec<_> = items.keys().cloned().collect();
             keys.sort();
-            keys.into_iter().map(Value::Text).collect()
+            keys.iter().map(DictKey::to_value).collect()
         }))),
         Value::Iterator(iterator) => Ok(iterator.source.clone()),
+        Value::Generator(generator) => Ok(Arc::new(generator.collect()?)),
         _ => bail_runtime!(TypeError, span, "Значение нельзя преобразовать в итератор"),
     }
 }
 
-fn call_callable(
+pub(crate) fn call_callable(
     interp: &Interpreter,
     callable: Value,
     arguments: Vec<Value>,
@@ -46,7 +55,7 @@ fn call_callable(
         Value::Function(function) => {
             let module_id = function.module.unwrap_or(function.span.file_id);
             let mut local = interp.fork_for_thread();
-            local.call_function(function, args, module_id, span)
+            local.call_function(&function, args, module_id, span)
         }
         Value::Builtin(builtin) => builtin(interp, args, span),
         _ => bail_runtime!(TypeError, span, "Ожидалась функция"),
@@ -1,8 +1,32 @@
-use crate::ast::prelude::ErrorData;
+use crate::ast::prelude::{ErrorData, Span};
 use crate::builtins::registry::*;
-use crate::interpreter::prelude::{Interpreter, RuntimeError, SharedInterner, Value};
-use crate::traits::json::JsonParsable;
-use crate::{define_builtin, expect_args, runtime_error};
+use crate::interpreter::prelude::{
+    CallArgListExt, Interpreter, RuntimeClassDefinition, RuntimeError, SharedInterner, Value,
+};
+use crate::shared::SharedMut;
+use crate::{define_builtin, define_method, expect_args, runtime_error};
+use string_interner::DefaultSymbol as Symbol;
+
+fn parse_json(text: &str, span: Span) -> Result<Value, RuntimeError> {
+    serde_json::from_str(text)
+        .map_err(|error| runtime_error!(InvalidOperation, span, "Ошибка разбора JSON: {}", error))
+}
+
+fn stringify_json(value: &Value, pretty: bool, span: Span) -> Result<Value, RuntimeError> {
+    let text = if pretty {
+        serde_json::to_string_pretty(value)
+    } else {
+        serde_json::to_string(value)
+    };
+    text.map(|text| Value::Text(text.into())).map_err(|error| {
+        runtime_error!(
+            InvalidOperation,
+            span,
+            "Ошибка сериализации JSON: {}",
+            error
+        )
+    })
+}
 
 pub fn setup_json_funcs(interpreter: &mut Interpreter, interner: &SharedInterner) {
     define_builtin!(interpreter, interner, function::FROM_JSON.canonical => (_interpreter, arguments, span) {
@@ -12,24 +36,52 @@ pub fn setup_json_funcs(interpreter: &mut Interpreter, interner: &SharedInterner
             runtime_error!(TypeError, span, "Функция 'из_json' ожидает строку")
         })?;
 
-        let parsed = serde_json::from_str(json_text).map_err(|error| {
-            runtime_error!(InvalidOperation, span, "Ошибка разбора JSON: {}", error)
-        })?;
-
-        Ok(Value::from_json(parsed))
+        parse_json(json_text, span)
     });
 
     define_builtin!(interpreter, interner, function::TO_JSON.canonical => (_interpreter, arguments, span) {
             expect_args!(arguments, 1, span, "в_json");
 
-            let json_value = arguments[0].value.to_json().map_err(|error| {
-                runtime_error!(InvalidOperation, span, "Ошибка сериализации JSON: {}", error)
-            })?;
+            stringify_json(&arguments[0].value, false, span)
+    });
+}
+
+pub fn setup_json_class(
+    interner_ref: &SharedInterner,
+) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner_ref.write(|i| i.get_or_intern(class::JSON.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    // --- ДЖСОН.разобрать(текст) ---
+    define_method!(class_def, interner_ref, @static method::PARSE.canonical => (_, args, span) {
+        match CallArgListExt::first_value(&args) {
+            Some(Value::Text(text)) => parse_json(text, span),
+            _ => Err(runtime_error!(
+                TypeError,
+                span,
+                "ДЖСОН.разобрать ожидает строку"
+            )),
+        }
+    });
 
-            serde_json::to_string(&json_value)
-                .map(Value::Text)
-                .map_err(|error| {
-                    runtime_error!(InvalidOperation, span, "Ошибка сериализации JSON: {}", error)
-                })
+    // --- ДЖСОН.строка(значение, красиво = ложь) ---
+    define_method!(class_def, interner_ref, @static method::STRINGIFY.canonical => (_, args, span) {
+        let value = CallArgListExt::first_value(&args).ok_or_else(|| {
+            runtime_error!(TypeError, span, "ДЖСОН.строка ожидает значение")
+        })?;
+        let pretty = match CallArgListExt::get_value(&args, 1) {
+            None => false,
+            Some(Value::Boolean(pretty)) => *pretty,
+            Some(_) => {
+                return Err(runtime_error!(
+                    TypeError,
+                    span,
+                    "Второй аргумент ДЖСОН.строка должен быть логическим"
+                ))
+            }
+        };
+        stringify_json(value, pretty, span)
     });
+
+    (name, SharedMut::new(class_def))
 }
@@ -1,15 +1,51 @@
 use crate::ast::prelude::{ErrorData, Span};
-use crate::builtins::iterator::values_from_iterable;
+use crate::builtins::iterator::{call_callable, values_from_iterable};
 use crate::builtins::registry::*;
 use crate::interpreter::prelude::RuntimeClassDefinition;
 use crate::interpreter::prelude::{
     CallArgListExt, Interpreter, RuntimeError, RuntimeIterator, SharedInterner, Value,
 };
 use crate::shared::SharedMut;
-use crate::traits::prelude::CoreOperations;
+use crate::traits::prelude::ValueOperations;
 use crate::{bail_runtime, define_builtin, define_constructor, define_method, runtime_error};
+use std::cmp::Ordering;
 use string_interner::DefaultSymbol as Symbol;
 
+/// Stable sort that stops at the first error of `compare` (e.g. from a user function).
+fn try_sort<T>(
+    items: &mut [T],
+    mut compare: impl FnMut(&T, &T) -> Result<Ordering, RuntimeError>,
+) -> Result<(), RuntimeError> {
+    let mut error = None;
+    items.sort_by(|a, b| {
+        if error.is_some() {
+            return Ordering::Equal;
+        }
+        compare(a, b).unwrap_or_else(|e| {
+            error = Some(e);
+            Ordering::Equal
+        })
+    });
+    error.map_or(Ok(()), Err)
+}
+
+fn natural_order(
+    interpreter: &Interpreter,
+    left: &Value,
+    right: &Value,
+    span: Span,
+) -> Result<Ordering, RuntimeError> {
+    interpreter.natural_order(left, right).ok_or_else(|| {
+        runtime_error!(
+            TypeMismatch,
+            span,
+            "Нельзя сравнить {} и {}",
+            interpreter.format_value(left),
+            interpreter.format_value(right)
+        )
+    })
+}
+
 pub fn setup_list_class(interner: &SharedInterner) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
     let name = interner.write(|i| i.get_or_intern(class::LIST.names.canonical));
 
@@ -20,18 +56,20 @@ pub fn setup_list_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtime
             let items = args[1..].iter().map(|arg| arg.value.clone()).collect();
             let internal_list = Value::List(SharedMut::new(items));
 
-            let data_sym = interp.intern_string("__data");
+            let data_sym = interp.symbols.data;
             instance.write(|i| i.field_values.insert(data_sym, internal_list));
         }
         Ok(Value::Empty)
     });
 
     // append(value) - Добавить в конец
-    define_method!(class_def, interner, method::ADD.canonical => (_, args, span) {
+    define_method!(class_def, interner, method::ADD.canonical => (interpreter, args, span) {
         if let (Some(Value::List(list)), Some(val)) = (
             CallArgListExt::first_value(&args),
             CallArgListExt::get_value(&args, 1),
         ) {
+            // Память проверяется до записи: замер кучи читает и этот список.
+            interpreter.check_items(list.read(Vec::len) + 1, span)?;
             list.write(|i| i.push(val.clone()));
             Ok(Value::Empty)
         } else {
@@ -108,13 +146,15 @@ pub fn setup_list_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtime
             CallArgListExt::first_value(&args),
             CallArgListExt::get_value(&args, 1),
         ) {
-            let joined = list.read(|i| {
+            let parts = list.read(|i| {
                 i.iter()
                     .map(|v| interpreter.format_value(v))
                     .collect::<Vec<_>>()
-                    .join(sep)
             });
-            Ok(Value::Text(joined))
+            let separators = sep.len().saturating_mul(parts.len().saturating_sub(1));
+            let bytes = parts.iter().map(String::len).fold(separators, usize::saturating_add);
+            interpreter.check_allocation(bytes, span)?;
+            Ok(Value::Text(parts.join(sep).into()))
         } else {
             bail_runtime!(TypeError, span, "Использование: list.join(string)")
         }
@@ -135,6 +175,194 @@ pub fn setup_list_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtime
         }
     });
 
+    // срез(от?, до?) - Новый список; отрицательные границы считаются с конца
+    define_method!(class_def, interner, method::SLICE.canonical => (_, args, span) {
+        let Some(Value::List(list)) = CallArgListExt::first_value(&args) else {
+            return bail_runtime!(TypeError, span, "Ожидался список");
+        };
+        list.read(|items| {
+            let range = Value::slice_range(
+                items.len(),
+                CallArgListExt::get_value(&args, 1),
+                CallArgListExt::get_value(&args, 2),
+                span,
+            )?;
+            Ok(Value::List(SharedMut::new(items[range].to_vec())))
+        })
+    });
+
+    // перевернуть() - Обратить порядок на месте
+    define_method!(class_def, interner, method::REVERSE.canonical => (_, args, span) {
+        let Some(Value::List(list)) = CallArgListExt::first_value(&args) else {
+            return bail_runtime!(TypeError, span, "Ожидался список");
+        };
+        list.write(|items| items.reverse());
+        Ok(Value::Empty)
+    });
+
+    // индекс(значение) -> Number: первое вхождение или -1
+    define_method!(class_def, interner, method::INDEX_OF.canonical => (interpreter, args, span) {
+        let (Some(Value::List(list)), Some(value)) = (
+            CallArgListExt::first_value(&args),
+            CallArgListExt::get_value(&args, 1),
+        ) else {
+            return bail_runtime!(TypeError, span, "Использование: list.index_of(value)");
+        };
+        let position = list.read(|items| {
+            items
+                .iter()
+                .position(|item| interpreter.values_equal(item, value))
+        });
+        Ok(Value::Number(position.map_or(-1, |index| index as i64)))
+    });
+
+    // содержит(значение) -> Boolean
+    define_method!(class_def, interner, method::CONTAINS.canonical => (interpreter, args, span) {
+        let (Some(Value::List(list)), Some(value)) = (
+            CallArgListExt::first_value(&args),
+            CallArgListExt::get_value(&args, 1),
+        ) else {
+            return bail_runtime!(TypeError, span, "Использование: list.contains(value)");
+        };
+        let found = list.read(|items| items.iter().any(|item| interpreter.values_equal(item, value)));
+        Ok(Value::Boolean(found))
+    });
+
+    // вставить(индекс, значение) - Индекс может быть равен длине (вставка в конец)
+    define_method!(class_def, interner, method::INSERT.canonical => (interpreter, args, span) {
+        let (Some(Value::List(list)), Some(index), Some(value)) = (
+            CallArgListExt::first_value(&args),
+            CallArgListExt::get_value(&args, 1),
+            CallArgListExt::get_value(&args, 2),
+        ) else {
+            return bail_runtime!(TypeError, span, "Использование: list.insert(index, value)");
+        };
+        interpreter.check_items(list.read(Vec::len) + 1, span)?;
+        list.write(|items| {
+            let index = match index {
+                Value::Number(n) if *n as usize == items.len() => items.len(),
+                index => index.resolve_index(items.len(), span)?,
+            };
+            items.insert(index, value.clone());
+            Ok(Value::Empty)
+        })
+    });
+
+    // расширить(другой) - Добавить в конец все элементы списка, массива или другой коллекции
+    define_method!(class_def, interner, method::EXTEND.canonical => (interpreter, args, span) {
+        let (Some(Value::List(list)), Some(other)) = (
+            CallArgListExt::first_value(&args),
+            CallArgListExt::get_value(&args, 1),
+        ) else {
+            return bail_runtime!(TypeError, span, "Использование: list.extend(list)");
+        };
+        // Значения собираются до записи, так что список можно расширить самим собой.
+        let values = values_from_iterable(other, span)?;
+        interpreter.check_items(list.read(Vec::len) + values.len(), span)?;
+        list.write(|items| items.extend(values.iter().cloned()));
+        Ok(Value::Empty)
+    });
+
+    // карта(функция) - Новый список из функция(элемент)
+    define_method!(class_def, interner, method::MAP.canonical => (interpreter, args, span) {
+        let (Some(Value::List(list)), Some(callable)) = (
+            CallArgListExt::first_value(&args),
+            CallArgListExt::get_value(&args, 1),
+        ) else {
+            return bail_runtime!(TypeError, span, "Использование: list.map(function)");
+        };
+        let items = list.read(|items| items.clone());
+        let mapped = items
+            .into_iter()
+            .map(|item| call_callable(interpreter, callable.clone(), vec![item], span))
+            .collect::<Result<_, _>>()?;
+        Ok(Value::List(SharedMut::new(mapped)))
+    });
+
+    // фильтр(функция) - Новый список из элементов, для которых функция истинна
+    define_method!(class_def, interner, method::FILTER.canonical => (interpreter, args, span) {
+        let (Some(Value::List(list)), Some(callable)) = (
+            CallArgListExt::first_value(&args),
+            CallArgListExt::get_value(&args, 1),
+        ) else {
+            return bail_runtime!(TypeError, span, "Использование: list.filter(function)");
+        };
+        let items = list.read(|items| items.clone());
+        let mut kept = Vec::new();
+        for item in items {
+            if call_callable(interpreter, callable.clone(), vec![item.clone()], span)?.is_truthy() {
+                kept.push(item);
+            }
+        }
+        Ok(Value::List(SharedMut::new(kept)))
+    });
+
+    // свернуть(функция, начальное?) - Без начального значения берётся первый элемент
+    define_method!(class_def, interner, method::REDUCE.canonical => (interpreter, args, span) {
+        let (Some(Value::List(list)), Some(callable)) = (
+            CallArgListExt::first_value(&args),
+            CallArgListExt::get_value(&args, 1),
+        ) else {
+            return bail_runtime!(TypeError, span, "Использование: list.reduce(function, initial)");
+        };
+        let mut items = list.read(|items| items.clone()).into_iter();
+        let mut acc = match CallArgListExt::get_value(&args, 2) {
+            Some(initial) => initial.clone(),
+            None => match items.next() {
+                Some(first) => first,
+                None => {
+                    return bail_runtime!(InvalidOperation, span, "свернуть пустой список без начального значения")
+                }
+            },
+        };
+        for item in items {
+            acc = call_callable(interpreter, callable.clone(), vec![acc, item], span)?;
+        }
+        Ok(acc)
+    });
+
+    // сортировать(сравнение?) - Сортировка на месте; сравнение(а, б) возвращает число < 0, 0 или > 0
+    define_method!(class_def, interner, method::SORT.canonical => (interpreter, args, span) {
+        let Some(Value::List(list)) = CallArgListExt::first_value(&args) else {
+            return bail_runtime!(TypeError, span, "Ожидался список");
+        };
+        // Функция сравнения может читать список, поэтому он не блокируется на время сортировки.
+        let mut items = list.read(|items| items.clone());
+        match CallArgListExt::get_value(&args, 1) {
+            None | Some(Value::Empty) => {
+                try_sort(&mut items, |a, b| natural_order(interpreter, a, b, span))?
+            }
+            Some(compare) => try_sort(&mut items, |a, b| {
+                match call_callable(interpreter, compare.clone(), vec![a.clone(), b.clone()], span)? {
+                    Value::Number(n) => Ok(n.cmp(&0)),
+                    Value::Float(f) => Ok(f.partial_cmp(&0.0).unwrap_or(Ordering::Equal)),
+                    _ => bail_runtime!(TypeError, span, "Функция сравнения должна вернуть число"),
+                }
+            })?,
+        }
+        list.write(|list| *list = items);
+        Ok(Value::Empty)
+    });
+
+    // сортировать_по(ключ) - Сортировка на месте по значению ключ(элемент)
+    define_method!(class_def, interner, method::SORT_BY.canonical => (interpreter, args, span) {
+        let (Some(Value::List(list)), Some(key)) = (
+            CallArgListExt::first_value(&args),
+            CallArgListExt::get_value(&args, 1),
+        ) else {
+            return bail_runtime!(TypeError, span, "Использование: list.sort_by(function)");
+        };
+        let items = list.read(|items| items.clone());
+        // Ключ вычисляется один раз для каждого элемента.
+        let mut keyed = items
+            .into_iter()
+            .map(|item| Ok((call_callable(interpreter, key.clone(), vec![item.clone()], span)?, item)))
+            .collect::<Result<Vec<_>, RuntimeError>>()?;
+        try_sort(&mut keyed, |(a, _), (b, _)| natural_order(interpreter, a, b, span))?;
+        list.write(|list| *list = keyed.into_iter().map(|(_, item)| item).collect());
+        Ok(Value::Empty)
+    });
+
     define_method!(class_def, interner, method::ITERATOR.canonical => (_, args, span) {
         let Some(value) = CallArgListExt::first_value(&args) else {
             return bail_runtime!(TypeError, span, "Ожидался список");
@@ -1,19 +1,38 @@
 mod array;
+pub(crate) mod bigint;
 mod bool;
+mod bytes;
+pub(crate) mod channel;
 mod common;
-mod datetime;
+mod config;
+mod crypto;
+pub(crate) mod database;
+pub(crate) mod datetime;
 mod dict;
-mod file;
+mod directory;
+mod encoding;
+pub(crate) mod file;
 mod float;
+pub(crate) mod http;
 mod io;
 pub(crate) mod iterator;
 mod json;
 mod list;
 pub(crate) mod macros;
 mod number;
-mod regex;
+mod path;
+pub(crate) mod promise;
+pub(crate) mod queue;
+pub(crate) mod random;
+mod reflection;
+pub(crate) mod regex;
 pub mod registry;
+pub(crate) mod set;
+pub(crate) mod stopwatch;
+pub(crate) mod string_builder;
 mod system;
+pub(crate) mod tcp;
 mod terminal;
 mod text;
 mod thread;
+mod url;
@@ -1,5 +1,6 @@
 use crate::ast::prelude::ErrorData;
 use crate::builtins::registry::*;
+use crate::interpreter::operations::checked_number;
 use crate::interpreter::prelude::{Interpreter, RuntimeError, SharedInterner, Value};
 use crate::{bail_runtime, define_builtin, expect_args, runtime_error};
 
@@ -15,3 +16,38 @@ pub fn setup_number_func(interpreter: &mut Interpreter, interner: &SharedInterne
         Ok(Value::Number(n))
     });
 }
+
+pub fn setup_int_div_func(interpreter: &mut Interpreter, interner: &SharedInterner) {
+    // --- цел_дел(делимое, делитель): частное без дробной части, как у `%` ---
+    define_builtin!(interpreter, interner, function::INT_DIV.canonical => (interpreter, arguments, span) {
+        expect_args!(arguments, 2, span, "цел_дел");
+
+        let (left, right) = (&arguments[0].value, &arguments[1].value);
+        let as_float = |value: &Value| match value {
+            Value::Number(number) => Some(*number as f64),
+            Value::Float(number) => Some(*number),
+            _ => None,
+        };
+        match (left, right) {
+            (Value::Number(_), Value::Number(0)) => {
+                bail_runtime!(DivisionByZero, span, "Деление на 0 запрещено")
+            }
+            (Value::Number(a), Value::Number(b)) => {
+                checked_number(a.checked_div(*b), span, *a, "/", *b)
+            }
+            _ => match (as_float(left), as_float(right)) {
+                (Some(_), Some(0.0)) => {
+                    bail_runtime!(DivisionByZero, span, "Деление на 0 запрещено")
+                }
+                (Some(a), Some(b)) => Ok(Value::Float((a / b).trunc())),
+                _ => bail_runtime!(
+                    TypeError,
+                    span,
+                    "цел_дел применим только к числам, получено {} и {}",
+                    interpreter.type_name(left),
+                    interpreter.type_name(right)
+                ),
+            },
+        }
+    });
+}
@@ -0,0 +1,102 @@
+use crate::ast::prelude::{ErrorData, Span};
+use crate::builtins::registry::*;
+use crate::interpreter::prelude::{
+    CallArgListExt, CallArgValue, RuntimeClassDefinition, RuntimeError, SharedInterner, Value,
+};
+use crate::shared::SharedMut;
+use crate::{bail_runtime, define_method, runtime_error};
+use std::path::{Component, Path, PathBuf};
+use string_interner::DefaultSymbol as Symbol;
+
+fn path_arg(args: &[CallArgValue], usage: &str, span: Span) -> Result<PathBuf, RuntimeError> {
+    match CallArgListExt::first_value(args) {
+        Some(Value::Text(path)) => Ok(PathBuf::from(&**path)),
+        _ => bail_runtime!(TypeError, span, "Использование: Путь.{}", usage),
+    }
+}
+
+fn path_value(path: &Path) -> Value {
+    Value::Text(path.to_string_lossy().into())
+}
+
+/// Resolves `.` and `..` without touching the file system.
+fn normalize(path: &Path) -> PathBuf {
+    let mut output = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match output.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    output.pop();
+                }
+                // Выше корня подняться нельзя.
+                Some(Component::RootDir | Component::Prefix(_)) => {}
+                _ => output.push(".."),
+            },
+            other => output.push(other),
+        }
+    }
+    if output.as_os_str().is_empty() {
+        output.push(".");
+    }
+    output
+}
+
+pub fn setup_path_class(interner: &SharedInterner) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner.write(|i| i.get_or_intern(class::PATH.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    // --- Путь.соединить(часть, ...) -> Text с разделителем системы ---
+    define_method!(class_def, interner, @static method::PATH_JOIN.canonical => (_, args, span) {
+        let mut path = PathBuf::new();
+        for arg in &args {
+            match &arg.value {
+                Value::Text(part) => path.push(&**part),
+                _ => return bail_runtime!(TypeError, span, "Путь.соединить ожидает строки"),
+            }
+        }
+        Ok(path_value(&path))
+    });
+
+    // --- Путь.родитель(путь) -> Text, пустая строка у корня ---
+    define_method!(class_def, interner, @static method::PARENT.canonical => (_, args, span) {
+        let path = path_arg(&args, "родитель(путь)", span)?;
+        Ok(path_value(path.parent().unwrap_or(Path::new(""))))
+    });
+
+    // --- Путь.имя_файла(путь) -> Text ---
+    define_method!(class_def, interner, @static method::FILE_NAME.canonical => (_, args, span) {
+        let path = path_arg(&args, "имя_файла(путь)", span)?;
+        Ok(Value::Text(
+            path.file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default().into(),
+        ))
+    });
+
+    // --- Путь.расширение(путь) -> Text без точки, пустая строка если его нет ---
+    define_method!(class_def, interner, @static method::EXTENSION.canonical => (_, args, span) {
+        let path = path_arg(&args, "расширение(путь)", span)?;
+        Ok(Value::Text(
+            path.extension()
+                .map(|extension| extension.to_string_lossy().to_string())
+                .unwrap_or_default().into(),
+        ))
+    });
+
+    // --- Путь.абсолютный(путь) -> Text от текущей папки, файл может не существовать ---
+    define_method!(class_def, interner, @static method::ABSOLUTE.canonical => (_, args, span) {
+        let path = path_arg(&args, "абсолютный(путь)", span)?;
+        let absolute = std::path::absolute(&path)
+            .map_err(|error| runtime_error!(IOError, span, "{}: {}", path.display(), error))?;
+        Ok(path_value(&normalize(&absolute)))
+    });
+
+    // --- Путь.нормализовать(путь) -> Text без «.» и «..» ---
+    define_method!(class_def, interner, @static method::NORMALIZE.canonical => (_, args, span) {
+        let path = path_arg(&args, "нормализовать(путь)", span)?;
+        Ok(path_value(&normalize(&path)))
+    });
+
+    (name, SharedMut::new(class_def))
+}
@@ -0,0 +1,235 @@
+use crate::ast::prelude::{ErrorData, Span};
+use crate::builtins::registry::*;
+use crate::interpreter::prelude::{
+    BuiltinFn, CallArgListExt, CallArgValue, ClassInstance, Interpreter, RuntimeClassDefinition,
+    RuntimeError, RuntimeMethodType, SharedInterner, Value,
+};
+use crate::shared::SharedMut;
+use crate::traits::prelude::CoreOperations;
+use crate::{bail_runtime, define_builtin, define_method, runtime_error};
+use std::any::Any;
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Condvar, Mutex, OnceLock, PoisonError};
+use string_interner::DefaultSymbol as Symbol;
+
+/// Hidden field holding the shared promise state.
+pub(crate) const PROMISE_FIELD: &str = "__promise";
+/// Threads serving every `_асинхронно` call; further calls wait in the queue.
+const IO_WORKERS: usize = 16;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Result slot filled by an IO worker and read by `ждать`.
+#[derive(Default)]
+struct PromiseState {
+    result: Mutex<Option<Result<Value, RuntimeError>>>,
+    ready: Condvar,
+}
+
+impl PromiseState {
+    fn is_ready(&self) -> bool {
+        self.result
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_some()
+    }
+
+    fn wait(&self) -> Result<Value, RuntimeError> {
+        let mut result = self.result.lock().unwrap_or_else(PoisonError::into_inner);
+        while result.is_none() {
+            result = self
+                .ready
+                .wait(result)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        // Как и у потока, ошибка возвращается один раз, дальше — пустота.
+        match result.take() {
+            Some(Ok(value)) => {
+                *result = Some(Ok(value.clone()));
+                Ok(value)
+            }
+            Some(Err(error)) => {
+                *result = Some(Ok(Value::Empty));
+                Err(error)
+            }
+            None => unreachable!("результат обещания проверен выше"),
+        }
+    }
+}
+
+fn io_pool() -> &'static Mutex<Sender<Job>> {
+    static POOL: OnceLock<Mutex<Sender<Job>>> = OnceLock::new();
+    POOL.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        for index in 0..IO_WORKERS {
+            let receiver = receiver.clone();
+            std::thread::Builder::new()
+                .name(format!("goida-io-{}", index))
+                .spawn(move || loop {
+                    let job = receiver
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .recv();
+                    match job {
+                        Ok(job) => job(),
+                        Err(_) => break,
+                    }
+                })
+                .expect("не удалось запустить поток ввода-вывода");
+        }
+        Mutex::new(sender)
+    })
+}
+
+/// Runs `job` on the IO pool and returns an `Обещание` for its result.
+pub(crate) fn spawn_promise(
+    interpreter: &Interpreter,
+    span: Span,
+    job: impl FnOnce(&Interpreter) -> Result<Value, RuntimeError> + Send + 'static,
+) -> Result<Value, RuntimeError> {
+    let class_symbol = interpreter.intern_string(class::PROMISE.names.canonical);
+    let Some(class_ref) = interpreter.std_classes.get(&class_symbol).cloned() else {
+        return bail_runtime!(InvalidOperation, span, "Класс Обещание не найден");
+    };
+
+    let state = Arc::new(PromiseState::default());
+    let worker = interpreter.fork_for_thread();
+    let worker_state = state.clone();
+    let task: Job = Box::new(move || {
+        let result = job(&worker);
+        *worker_state
+            .result
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(result);
+        worker_state.ready.notify_all();
+    });
+    io_pool()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .send(task)
+        .map_err(|_| runtime_error!(InvalidOperation, span, "Пул ввода-вывода остановлен"))?;
+
+    let instance = SharedMut::new(ClassInstance::new(class_symbol, class_ref));
+    let resource =
+        Value::NativeResource(SharedMut::new(Box::new(state) as Box<dyn Any + Send + Sync>));
+    instance.write(|instance| {
+        instance
+            .field_values
+            .insert(interpreter.symbols.promise, resource)
+    });
+    Ok(Value::Object(instance))
+}
+
+/// Registers `async_name` as a copy of the native `sync_name` method that runs
+/// on the IO pool and returns `Обещание` instead of blocking the script.
+pub(crate) fn define_async_variant(
+    class_def: &mut RuntimeClassDefinition,
+    interner: &SharedInterner,
+    sync_name: &str,
+    async_name: &str,
+) {
+    let sync_alias = BUILTINS
+        .method_names(sync_name)
+        .first()
+        .copied()
+        .unwrap_or(sync_name);
+    let sync_symbol = interner.write(|i| i.get_or_intern(sync_alias));
+    let Some((visibility, is_static, RuntimeMethodType::Native(sync))) =
+        class_def.methods.get(&sync_symbol).cloned()
+    else {
+        panic!(
+            "метод '{}' должен быть определён до асинхронного варианта",
+            sync_name
+        );
+    };
+
+    let method = BuiltinFn(Arc::new(move |interpreter, arguments, span| {
+        let sync = sync.clone();
+        spawn_promise(interpreter, span, move |worker| {
+            (sync.0)(worker, arguments, span)
+        })
+    }));
+    for alias in BUILTINS.method_names(async_name) {
+        class_def.add_method(
+            interner.write(|i| i.get_or_intern(alias)),
+            visibility.clone(),
+            is_static,
+            method.clone(),
+        );
+    }
+}
+
+fn promise_state(interpreter: &Interpreter, value: &Value) -> Option<Arc<PromiseState>> {
+    let Value::Object(instance) = value else {
+        return None;
+    };
+    let field = interpreter.symbols.promise;
+    match instance.read(|instance| instance.field_values.get(&field).cloned()) {
+        Some(Value::NativeResource(resource)) => {
+            resource.read(|boxed| boxed.downcast_ref::<Arc<PromiseState>>().cloned())
+        }
+        _ => None,
+    }
+}
+
+/// Waits for promises and threads, including ones inside a list.
+fn await_value(
+    interpreter: &Interpreter,
+    value: &Value,
+    span: Span,
+) -> Result<Value, RuntimeError> {
+    if let Some(state) = promise_state(interpreter, value) {
+        return state.wait();
+    }
+    match value {
+        Value::Thread(thread) => interpreter.join_thread_handle(thread, span),
+        Value::List(items) => {
+            let items = items.read(|items| items.clone());
+            let results = items
+                .iter()
+                .map(|item| await_value(interpreter, item, span))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Value::List(SharedMut::new(results)))
+        }
+        other => Ok(other.clone()),
+    }
+}
+
+pub fn setup_await_func(interpreter: &mut Interpreter, interner: &SharedInterner) {
+    define_builtin!(interpreter, interner, function::AWAIT.canonical => (interp, args, span) {
+        let Some(value) = CallArgListExt::first_value(&args) else {
+            return bail_runtime!(TypeError, span, "Использование: ждать(обещание)");
+        };
+        await_value(interp, value, span)
+    });
+}
+
+fn with_promise(
+    interpreter: &Interpreter,
+    args: &[CallArgValue],
+    span: Span,
+) -> Result<Arc<PromiseState>, RuntimeError> {
+    CallArgListExt::first_value(args)
+        .and_then(|value| promise_state(interpreter, value))
+        .ok_or_else(|| runtime_error!(TypeError, span, "Ожидалось Обещание"))
+}
+
+pub fn setup_promise_class(
+    interner: &SharedInterner,
+) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner.write(|i| i.get_or_intern(class::PROMISE.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    // --- обещание.готово() -> Bool, не ждёт ---
+    define_method!(class_def, interner, method::READY.canonical => (interpreter, args, span) {
+        Ok(Value::Boolean(with_promise(interpreter, &args, span)?.is_ready()))
+    });
+
+    // --- обещание.подождать() -> результат ---
+    define_method!(class_def, interner, method::JOIN_THREAD.canonical => (interpreter, args, span) {
+        with_promise(interpreter, &args, span)?.wait()
+    });
+
+    (name, SharedMut::new(class_def))
+}
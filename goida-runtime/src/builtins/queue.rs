@@ -0,0 +1,168 @@
+use crate::ast::prelude::{ErrorData, Span};
+use crate::builtins::iterator::values_from_iterable;
+use crate::builtins::registry::*;
+use crate::interpreter::prelude::{
+    CallArgListExt, CallArgValue, Interpreter, RuntimeClassDefinition, RuntimeError,
+    SharedInterner, Value,
+};
+use crate::shared::SharedMut;
+use crate::{bail_runtime, define_constructor, define_method, runtime_error};
+use std::any::Any;
+use std::collections::VecDeque;
+use string_interner::DefaultSymbol as Symbol;
+
+/// Hidden field holding the elements of `Очередь` and `Стек`.
+pub(crate) const ITEMS_FIELD: &str = "__items";
+
+/// Stores the initial elements, taken from an optional collection argument.
+fn init_items(
+    interpreter: &Interpreter,
+    args: &[CallArgValue],
+    span: Span,
+    class_name: &str,
+) -> Result<(), RuntimeError> {
+    let Some(Value::Object(instance)) = CallArgListExt::first_value(args) else {
+        return bail_runtime!(TypeError, span, "Ожидался объект {}", class_name);
+    };
+    let items: VecDeque<Value> = match CallArgListExt::get_value(args, 1) {
+        None | Some(Value::Empty) => VecDeque::new(),
+        Some(source) => values_from_iterable(source, span)?
+            .iter()
+            .cloned()
+            .collect(),
+    };
+    let resource =
+        Value::NativeResource(SharedMut::new(Box::new(items) as Box<dyn Any + Send + Sync>));
+    let field = interpreter.symbols.items;
+    instance.write(|instance| instance.field_values.insert(field, resource));
+    Ok(())
+}
+
+fn with_items<R>(
+    interpreter: &Interpreter,
+    args: &[CallArgValue],
+    span: Span,
+    class_name: &str,
+    f: impl FnOnce(&mut VecDeque<Value>) -> R,
+) -> Result<R, RuntimeError> {
+    let Some(Value::Object(instance)) = CallArgListExt::first_value(args) else {
+        return bail_runtime!(TypeError, span, "Ожидался объект {}", class_name);
+    };
+    let field = interpreter.symbols.items;
+    let resource = instance.read(|instance| instance.field_values.get(&field).cloned());
+    let Some(Value::NativeResource(resource)) = resource else {
+        return bail_runtime!(
+            InvalidOperation,
+            span,
+            "Коллекция {} не создана",
+            class_name
+        );
+    };
+    resource.write(|boxed| match boxed.downcast_mut::<VecDeque<Value>>() {
+        Some(items) => Ok(f(items)),
+        None => bail_runtime!(
+            TypeError,
+            span,
+            "Внутренний ресурс коллекции {} повреждён",
+            class_name
+        ),
+    })
+}
+
+fn argument(args: &[CallArgValue], span: Span, usage: &str) -> Result<Value, RuntimeError> {
+    match CallArgListExt::get_value(args, 1) {
+        Some(value) => Ok(value.clone()),
+        None => bail_runtime!(TypeError, span, "Использование: {}", usage),
+    }
+}
+
+/// `длина()`, `пусто()` and `список()` are shared by both classes.
+fn define_common_methods(
+    class_def: &mut RuntimeClassDefinition,
+    interner: &SharedInterner,
+    class_name: &'static str,
+) {
+    define_method!(class_def, interner, method::LEN.canonical => (interpreter, args, span) {
+        with_items(interpreter, &args, span, class_name, |items| Value::Number(items.len() as i64))
+    });
+
+    define_method!(class_def, interner, method::IS_EMPTY.canonical => (interpreter, args, span) {
+        with_items(interpreter, &args, span, class_name, |items| Value::Boolean(items.is_empty()))
+    });
+
+    define_method!(class_def, interner, method::TO_LIST.canonical => (interpreter, args, span) {
+        with_items(interpreter, &args, span, class_name, |items| {
+            Value::List(SharedMut::new(items.iter().cloned().collect()))
+        })
+    });
+}
+
+pub fn setup_queue_class(interner: &SharedInterner) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    const NAME: &str = "Очередь";
+    let name = interner.write(|i| i.get_or_intern(class::QUEUE.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    // --- новый Очередь(коллекция?) ---
+    define_constructor!(class_def, (interpreter, args, span) {
+        init_items(interpreter, &args, span, NAME)?;
+        Ok(Value::Empty)
+    });
+
+    // --- очередь.в_конец(значение) ---
+    define_method!(class_def, interner, method::PUSH_BACK.canonical => (interpreter, args, span) {
+        let value = argument(&args, span, "очередь.в_конец(значение)")?;
+        with_items(interpreter, &args, span, NAME, |items| items.push_back(value))?;
+        Ok(Value::Empty)
+    });
+
+    // --- очередь.из_начала() -> Any, первый добавленный элемент ---
+    define_method!(class_def, interner, method::POP_FRONT.canonical => (interpreter, args, span) {
+        match with_items(interpreter, &args, span, NAME, VecDeque::pop_front)? {
+            Some(value) => Ok(value),
+            None => bail_runtime!(InvalidOperation, span, "Очередь пуста"),
+        }
+    });
+
+    define_common_methods(&mut class_def, interner, NAME);
+
+    (name, SharedMut::new(class_def))
+}
+
+pub fn setup_stack_class(interner: &SharedInterner) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    const NAME: &str = "Стек";
+    let name = interner.write(|i| i.get_or_intern(class::STACK.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    // --- новый Стек(коллекция?), последний элемент коллекции оказывается на вершине ---
+    define_constructor!(class_def, (interpreter, args, span) {
+        init_items(interpreter, &args, span, NAME)?;
+        Ok(Value::Empty)
+    });
+
+    // --- стек.положить(значение) ---
+    define_method!(class_def, interner, method::STACK_PUSH.canonical => (interpreter, args, span) {
+        let value = argument(&args, span, "стек.положить(значение)")?;
+        with_items(interpreter, &args, span, NAME, |items| items.push_back(value))?;
+        Ok(Value::Empty)
+    });
+
+    // --- стек.снять() -> Any, последний положенный элемент ---
+    define_method!(class_def, interner, method::STACK_POP.canonical => (interpreter, args, span) {
+        match with_items(interpreter, &args, span, NAME, VecDeque::pop_back)? {
+            Some(value) => Ok(value),
+            None => bail_runtime!(InvalidOperation, span, "Стек пуст"),
+        }
+    });
+
+    // --- стек.вершина() -> Any, не снимая элемент ---
+    define_method!(class_def, interner, method::PEEK.canonical => (interpreter, args, span) {
+        match with_items(interpreter, &args, span, NAME, |items| items.back().cloned())? {
+            Some(value) => Ok(value),
+            None => bail_runtime!(InvalidOperation, span, "Стек пуст"),
+        }
+    });
+
+    define_common_methods(&mut class_def, interner, NAME);
+
+    (name, SharedMut::new(class_def))
+}
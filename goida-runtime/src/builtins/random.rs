@@ -0,0 +1,98 @@
+use crate::ast::prelude::{ErrorData, Span};
+use crate::builtins::registry::*;
+use crate::interpreter::prelude::{
+    CallArgListExt, CallArgValue, Interpreter, RuntimeClassDefinition, RuntimeError,
+    SharedInterner, Value,
+};
+use crate::shared::SharedMut;
+use crate::{bail_runtime, define_method, runtime_error};
+use rand::rngs::StdRng;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::{Rng, SeedableRng};
+use std::sync::{Arc, Mutex, PoisonError};
+use string_interner::DefaultSymbol as Symbol;
+
+/// Generator behind `Случай`, shared by the threads of one interpreter.
+pub(crate) type SharedRandom = Arc<Mutex<StdRng>>;
+
+pub(crate) fn new_random(seed: Option<u64>) -> SharedRandom {
+    Arc::new(Mutex::new(match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_os_rng(),
+    }))
+}
+
+impl Interpreter {
+    fn with_random<T>(&self, f: impl FnOnce(&mut StdRng) -> T) -> T {
+        f(&mut self.random.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+}
+
+fn number_arg(args: &[CallArgValue], index: usize, span: Span) -> Result<i64, RuntimeError> {
+    match CallArgListExt::get_value(args, index) {
+        Some(Value::Number(number)) => Ok(*number),
+        _ => bail_runtime!(TypeError, span, "Аргумент {} должен быть числом", index + 1),
+    }
+}
+
+pub fn setup_random_class(
+    interner_ref: &SharedInterner,
+) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner_ref.write(|i| i.get_or_intern(class::RANDOM.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    // --- Случай.число(от, до) -> Number, обе границы включены ---
+    define_method!(class_def, interner_ref, @static method::RANDOM_INT.canonical => (interpreter, args, span) {
+        let low = number_arg(&args, 0, span)?;
+        let high = number_arg(&args, 1, span)?;
+        if low > high {
+            return bail_runtime!(
+                InvalidOperation,
+                span,
+                "Пустой диапазон: {} больше {}",
+                low,
+                high
+            );
+        }
+        Ok(Value::Number(interpreter.with_random(|rng| rng.random_range(low..=high))))
+    });
+
+    // --- Случай.дробь() -> Float от 0 до 1 ---
+    define_method!(class_def, interner_ref, @static method::RANDOM_FLOAT.canonical => (interpreter, _, _) {
+        Ok(Value::Float(interpreter.with_random(|rng| rng.random::<f64>())))
+    });
+
+    // --- Случай.перемешать(список) ---
+    define_method!(class_def, interner_ref, @static method::SHUFFLE.canonical => (interpreter, args, span) {
+        match CallArgListExt::first_value(&args) {
+            Some(Value::List(items)) => {
+                items.write(|items| interpreter.with_random(|rng| items.shuffle(rng)));
+                Ok(Value::Empty)
+            }
+            _ => bail_runtime!(TypeError, span, "Случай.перемешать ожидает список"),
+        }
+    });
+
+    // --- Случай.выбрать(список) -> элемент ---
+    define_method!(class_def, interner_ref, @static method::CHOICE.canonical => (interpreter, args, span) {
+        let choose = |items: &[Value]| {
+            interpreter
+                .with_random(|rng| items.choose(rng).cloned())
+                .ok_or_else(|| runtime_error!(InvalidOperation, span, "Нельзя выбрать из пустого списка"))
+        };
+        match CallArgListExt::first_value(&args) {
+            Some(Value::List(items)) => items.read(|items| choose(items)),
+            Some(Value::Array(items)) => choose(items),
+            _ => bail_runtime!(TypeError, span, "Случай.выбрать ожидает список или массив"),
+        }
+    });
+
+    // --- Случай.зерно(число) — повторяемая последовательность ---
+    define_method!(class_def, interner_ref, @static method::SEED.canonical => (interpreter, args, span) {
+        let seed = number_arg(&args, 0, span)?;
+        interpreter.with_random(|rng| *rng = StdRng::seed_from_u64(seed as u64));
+        Ok(Value::Empty)
+    });
+
+    (name, SharedMut::new(class_def))
+}
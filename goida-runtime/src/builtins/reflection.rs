@@ -0,0 +1,210 @@
+use crate::ast::prelude::{ErrorData, Span, Visibility};
+use crate::builtins::registry::*;
+use crate::interpreter::prelude::{
+    ClassInstance, Interpreter, RuntimeError, SharedInterner, Value,
+};
+use crate::shared::SharedMut;
+use crate::traits::runtime::CoreOperations;
+use crate::vm::Vm;
+use crate::{bail_runtime, define_builtin, expect_args, runtime_error};
+use string_interner::DefaultSymbol as Symbol;
+
+pub fn setup_reflection_funcs(interpreter: &mut Interpreter, interner: &SharedInterner) {
+    // --- поля(объект) -> список имён полей, доступных через точку ---
+    define_builtin!(interpreter, interner, function::FIELDS.canonical => (interpreter, arguments, span) {
+        expect_args!(arguments, 1, span, "поля");
+
+        let instance = expect_object(interpreter, &arguments[0].value, "поля", span)?;
+        let external = interpreter.method_depth == 0;
+        let fields = instance.read(|instance| {
+            instance
+                .fields
+                .keys()
+                .chain(instance.field_values.keys())
+                .filter(|name| instance.is_field_accessible(name, external))
+                .copied()
+                .collect::<Vec<_>>()
+        });
+        Ok(names_list(interpreter, fields))
+    });
+
+    // --- методы(значение) -> список имён методов его класса ---
+    define_builtin!(interpreter, interner, function::METHODS.canonical => (interpreter, arguments, span) {
+        expect_args!(arguments, 1, span, "методы");
+
+        let target = &arguments[0].value;
+        let Some(class) = interpreter.get_class_for_value(target) else {
+            return bail_runtime!(
+                TypeError,
+                span,
+                "методы ожидает объект или класс, получено {}",
+                interpreter.type_name(target)
+            );
+        };
+        let external = interpreter.method_depth == 0;
+        let wants_static = matches!(target, Value::Class(_));
+        let methods = class.read(|class| {
+            class
+                .methods
+                .iter()
+                .filter(|(_, (visibility, is_static, _))| {
+                    *is_static == wants_static
+                        && !(external && matches!(visibility, Visibility::Private))
+                })
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>()
+        });
+        Ok(names_list(interpreter, methods))
+    });
+
+    // --- есть_атрибут(объект, имя) -> есть ли доступное поле или метод ---
+    define_builtin!(interpreter, interner, function::HAS_ATTRIBUTE.canonical => (interpreter, arguments, span) {
+        expect_args!(arguments, 2, span, "есть_атрибут");
+
+        let name = attribute_name(interpreter, &arguments[1].value, "есть_атрибут", span)?;
+        let external = interpreter.method_depth == 0;
+        let found = match &arguments[0].value {
+            Value::Object(instance) => instance.read(|instance| {
+                instance.is_field_accessible(&name, external)
+                    || instance.class_ref.read(|class| {
+                        class.methods.get(&name).is_some_and(|(visibility, _, _)| {
+                            !(external && matches!(visibility, Visibility::Private))
+                        })
+                    })
+            }),
+            _ => false,
+        };
+        Ok(Value::Boolean(found))
+    });
+
+    // --- получить_атрибут(объект, имя, по_умолчанию?) -> значение поля ---
+    define_builtin!(interpreter, interner, function::GET_ATTRIBUTE.canonical => (interpreter, arguments, span) {
+        if arguments.len() != 2 && arguments.len() != 3 {
+            return bail_runtime!(
+                InvalidOperation,
+                span,
+                "получить_атрибут ожидает 2 или 3 аргумента, получено {}",
+                arguments.len()
+            );
+        }
+
+        let instance = expect_object(interpreter, &arguments[0].value, "получить_атрибут", span)?;
+        let name = attribute_name(interpreter, &arguments[1].value, "получить_атрибут", span)?;
+        match read_field(interpreter, &instance, name)? {
+            Some(value) => Ok(value),
+            None => match arguments.get(2) {
+                Some(default) => Ok(default.value.clone()),
+                None => bail_runtime!(
+                    UndefinedVariable,
+                    span,
+                    "Поле '{}' не найдено или недоступно",
+                    interpreter.resolve_symbol(name).unwrap_or_default()
+                ),
+            },
+        }
+    });
+
+    // --- установить_атрибут(объект, имя, значение) ---
+    define_builtin!(interpreter, interner, function::SET_ATTRIBUTE.canonical => (interpreter, arguments, span) {
+        expect_args!(arguments, 3, span, "установить_атрибут");
+
+        let instance = expect_object(interpreter, &arguments[0].value, "установить_атрибут", span)?;
+        let name = attribute_name(interpreter, &arguments[1].value, "установить_атрибут", span)?;
+        let value = arguments[2].value.clone();
+        interpreter.adopt_value(&value);
+        let external = interpreter.method_depth == 0;
+        instance.write(|instance| {
+            if !instance.is_field_accessible(&name, external) {
+                return bail_runtime!(
+                    InvalidOperation,
+                    span,
+                    "Поле '{}' не найдено или недоступно",
+                    interpreter.resolve_symbol(name).unwrap_or_default()
+                );
+            }
+            instance.set_field_value(name, value);
+            Ok(Value::Empty)
+        })
+    });
+}
+
+fn expect_object(
+    interpreter: &Interpreter,
+    value: &Value,
+    function: &str,
+    span: Span,
+) -> Result<SharedMut<ClassInstance>, RuntimeError> {
+    match value {
+        Value::Object(instance) => Ok(instance.clone()),
+        other => bail_runtime!(
+            TypeError,
+            span,
+            "{} ожидает объект, получено {}",
+            function,
+            interpreter.type_name(other)
+        ),
+    }
+}
+
+fn attribute_name(
+    interpreter: &Interpreter,
+    value: &Value,
+    function: &str,
+    span: Span,
+) -> Result<Symbol, RuntimeError> {
+    match value {
+        Value::Text(name) => Ok(interpreter.intern_string(name)),
+        other => bail_runtime!(
+            TypeError,
+            span,
+            "{} ожидает имя атрибута строкой, получено {}",
+            function,
+            interpreter.type_name(other)
+        ),
+    }
+}
+
+/// Reads a field the way `объект.поле` does, computing a pending default once.
+fn read_field(
+    interpreter: &Interpreter,
+    instance: &SharedMut<ClassInstance>,
+    name: Symbol,
+) -> Result<Option<Value>, RuntimeError> {
+    let external = interpreter.method_depth == 0;
+    let field = instance.read(|instance| {
+        if !instance.is_field_accessible(&name, external) {
+            return None;
+        }
+        match instance.field_values.get(&name) {
+            Some(value) => Some(Ok(value.clone())),
+            None => instance.get_field(&name).cloned().map(Err),
+        }
+    });
+    match field {
+        Some(Ok(value)) => Ok(Some(value)),
+        Some(Err(Some(expr))) => {
+            let module =
+                instance.read(|instance| instance.class_ref.read(|class| class.span.file_id));
+            let value = Vm::evaluate_compiled(&mut interpreter.fork_for_thread(), module, expr)?;
+            instance.write(|instance| instance.set_field_value(name, value.clone()));
+            Ok(Some(value))
+        }
+        Some(Err(None)) => Ok(Some(Value::Empty)),
+        None => Ok(None),
+    }
+}
+
+fn names_list(interpreter: &Interpreter, names: Vec<Symbol>) -> Value {
+    let mut names = names
+        .into_iter()
+        .filter_map(|name| interpreter.resolve_symbol(name))
+        .collect::<Vec<_>>();
+    names.sort();
+    names.dedup();
+    Value::List(SharedMut::new(
+        names
+            .into_iter()
+            .map(|text| Value::Text(text.into()))
+            .collect(),
+    ))
+}
@@ -13,6 +13,11 @@ use regex::Regex;
 use std::any::Any;
 use string_interner::DefaultSymbol as Symbol;
 
+/// Hidden field holding the source pattern.
+pub(crate) const PATTERN_FIELD: &str = "__pattern";
+/// Hidden field holding the compiled expression.
+pub(crate) const REGEX_FIELD: &str = "__regex";
+
 fn compile_regex(pattern: &str, span: Span) -> Result<Regex, RuntimeError> {
     Regex::new(pattern).map_err(|err| {
         runtime_error!(
@@ -46,13 +51,13 @@ fn build_regex_object(
     let compiled = compile_regex(&pattern, span)?;
     let instance = ClassInstance::new(class_symbol, class_ref);
     let instance_ref = SharedMut::new(instance);
-    let pattern_sym = interp.intern_string("__pattern");
-    let regex_sym = interp.intern_string("__regex");
+    let pattern_sym = interp.symbols.pattern;
+    let regex_sym = interp.symbols.regex;
 
     instance_ref.write(|instance| {
         instance
             .field_values
-            .insert(pattern_sym, Value::Text(pattern));
+            .insert(pattern_sym, Value::Text(pattern.into()));
         instance
             .field_values
             .insert(regex_sym, make_regex_resource(compiled));
@@ -70,12 +75,12 @@ fn get_regex_parts(
         return bail_runtime!(TypeError, span, "Ожидался объект РегулярноеВыражение");
     };
 
-    let pattern_sym = interp.intern_string("__pattern");
-    let regex_sym = interp.intern_string("__regex");
+    let pattern_sym = interp.symbols.pattern;
+    let regex_sym = interp.symbols.regex;
 
     instance_ref.read(|instance| {
         let pattern = match instance.field_values.get(&pattern_sym) {
-            Some(Value::Text(pattern)) => pattern.clone(),
+            Some(Value::Text(pattern)) => pattern.to_string(),
             _ => {
                 return bail_runtime!(
                     InvalidOperation,
@@ -117,13 +122,29 @@ fn capture_values(captures: regex::Captures<'_>) -> Value {
         .iter()
         .map(|capture| {
             capture
-                .map(|item| Value::Text(item.as_str().to_string()))
+                .map(|item| Value::Text(item.as_str().into()))
                 .unwrap_or(Value::Empty)
         })
         .collect();
     Value::List(SharedMut::new(values))
 }
 
+/// Named groups of one match; groups that did not take part are `пустота`.
+fn named_capture_values(regex: &Regex, captures: regex::Captures<'_>) -> Value {
+    let values = regex
+        .capture_names()
+        .flatten()
+        .map(|name| {
+            let value = captures
+                .name(name)
+                .map(|item| Value::Text(item.as_str().into()))
+                .unwrap_or(Value::Empty);
+            (name.into(), value)
+        })
+        .collect();
+    Value::Dict(SharedMut::new(values))
+}
+
 pub fn setup_regex_class(interner: &SharedInterner) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
     let name = interner.write(|i| i.get_or_intern(class::REGEX.names.canonical));
     let mut class_def = RuntimeClassDefinition::new(name, Span::default());
@@ -137,8 +158,8 @@ pub fn setup_regex_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtim
         };
 
         let compiled = compile_regex(pattern, span)?;
-        let pattern_sym = interp.intern_string("__pattern");
-        let regex_sym = interp.intern_string("__regex");
+        let pattern_sym = interp.symbols.pattern;
+        let regex_sym = interp.symbols.regex;
 
         instance.write(|i| {
             i.field_values
@@ -152,7 +173,7 @@ pub fn setup_regex_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtim
 
     define_method!(class_def, interner, method::PATTERN.canonical => (interp, args, span) {
         let (pattern, _) = get_regex_parts(interp, &args, span)?;
-        Ok(Value::Text(pattern))
+        Ok(Value::Text(pattern.into()))
     });
 
     define_method!(class_def, interner, method::MATCHES.canonical => (interp, args, span) {
@@ -169,7 +190,7 @@ pub fn setup_regex_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtim
         if let Some(Value::Text(text)) = CallArgListExt::get_value(&args, 1) {
             Ok(regex
                 .find(text)
-                .map(|item| Value::Text(item.as_str().to_string()))
+                .map(|item| Value::Text(item.as_str().into()))
                 .unwrap_or(Value::Empty))
         } else {
             bail_runtime!(TypeError, span, "Использование: regex.найти(text)")
@@ -181,7 +202,7 @@ pub fn setup_regex_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtim
         if let Some(Value::Text(text)) = CallArgListExt::get_value(&args, 1) {
             let matches = regex
                 .find_iter(text)
-                .map(|item| Value::Text(item.as_str().to_string()))
+                .map(|item| Value::Text(item.as_str().into()))
                 .collect();
             Ok(Value::List(SharedMut::new(matches)))
         } else {
@@ -214,13 +235,25 @@ pub fn setup_regex_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtim
         }
     });
 
+    define_method!(class_def, interner, method::NAMED_GROUPS.canonical => (interp, args, span) {
+        let (_, regex) = get_regex_parts(interp, &args, span)?;
+        if let Some(Value::Text(text)) = CallArgListExt::get_value(&args, 1) {
+            Ok(regex
+                .captures(text)
+                .map(|captures| named_capture_values(&regex, captures))
+                .unwrap_or(Value::Empty))
+        } else {
+            bail_runtime!(TypeError, span, "Использование: regex.именованные_группы(text)")
+        }
+    });
+
     define_method!(class_def, interner, method::REPLACE.canonical => (interp, args, span) {
         let (_, regex) = get_regex_parts(interp, &args, span)?;
         if let (Some(Value::Text(text)), Some(Value::Text(replacement))) = (
             CallArgListExt::get_value(&args, 1),
             CallArgListExt::get_value(&args, 2),
         ) {
-            Ok(Value::Text(regex.replace(text, replacement.as_str()).to_string()))
+            Ok(Value::Text(regex.replace(text, &**replacement).into()))
         } else {
             bail_runtime!(TypeError, span, "Использование: regex.заменить(text, replacement)")
         }
@@ -232,7 +265,7 @@ pub fn setup_regex_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtim
             CallArgListExt::get_value(&args, 1),
             CallArgListExt::get_value(&args, 2),
         ) {
-            Ok(Value::Text(regex.replace_all(text, replacement.as_str()).to_string()))
+            Ok(Value::Text(regex.replace_all(text, &**replacement).into()))
         } else {
             bail_runtime!(TypeError, span, "Использование: regex.заменить_все(text, replacement)")
         }
@@ -243,7 +276,7 @@ pub fn setup_regex_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtim
         if let Some(Value::Text(text)) = CallArgListExt::get_value(&args, 1) {
             let parts = regex
                 .split(text)
-                .map(|part| Value::Text(part.to_string()))
+                .map(|part| Value::Text(part.into()))
                 .collect();
             Ok(Value::List(SharedMut::new(parts)))
         } else {
@@ -258,7 +291,7 @@ pub fn setup_regex_func(interpreter: &mut Interpreter, interner: &SharedInterner
     define_builtin!(interpreter, interner, function::REGEX.canonical => (interp, arguments, span) {
         expect_args!(arguments, 1, span, "выражение");
         if let Value::Text(pattern) = &arguments[0].value {
-            build_regex_object(interp, pattern.clone(), span)
+            build_regex_object(interp, pattern.to_string(), span)
         } else {
             bail_runtime!(TypeError, span, "Функция регулярное_выражение ожидает строку")
         }
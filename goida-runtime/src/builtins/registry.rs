@@ -135,6 +135,13 @@ macro_rules! declare_builtin_registry {
             )*
         }
 
+        pub(crate) mod errors {
+            $(
+                #[allow(dead_code)]
+                pub(crate) const $error: &str = $error_name;
+            )*
+        }
+
         const FUNCTIONS: &[BuiltinNames] = &[$(function::$function),*];
         const CLASSES: &[BuiltinClass] = &[$(class::$class),*];
         const METHODS: &[BuiltinNames] = &[$(method::$method),*];
@@ -273,10 +280,15 @@ impl BuiltinRegistry {
                     else {
                         continue;
                     };
-                    let entry = self
-                        .methods
-                        .iter()
-                        .find(|entry| entry.names.contains(&name.as_str()));
+                    // Один псевдоним может принадлежать нескольким методам (`объединить`
+                    // у строк и словарей), поэтому берём запись, установленную в этом классе.
+                    let entry =
+                        self.methods.iter().find(|entry| {
+                            entry.names.contains(&name.as_str())
+                                && interner.read(|i| i.get(entry.canonical)).is_some_and(
+                                    |canonical| definition.methods.contains_key(&canonical),
+                                )
+                        });
                     let canonical =
                         entry.map_or_else(|| name.clone(), |entry| entry.canonical.to_string());
                     let aliases = entry.map_or_else(
@@ -4,37 +4,85 @@ declare_builtin_registry! {
         INPUT => ("input", ["ввод", "input"], super::io::setup_io_func);
         TYPE => ("type", ["тип", "type"], super::common::setup_type_func);
         IS => ("is", ["является", "is"], super::common::setup_is_instance_func);
+        ASSERT => ("assert", ["проверить", "assert"], super::common::setup_assert_func);
         NUMBER => ("number", ["число", "number"], super::number::setup_number_func);
+        INT_DIV => ("int_div", ["цел_дел", "int_div"], super::number::setup_int_div_func);
         STRING => ("string", ["строка", "string"], super::text::setup_text_func);
         BOOLEAN => ("bool", ["логический", "bool"], super::bool::setup_bool_func);
         FLOAT => ("float", ["дробь", "float"], super::float::setup_float_func);
         LIST => ("list", ["список", "list"], super::list::setup_list_func);
         ARRAY => ("array", ["массив", "array"], super::array::setup_array_func);
+        BYTES => ("bytes", ["байты", "bytes"], super::bytes::setup_bytes_func);
         DICT => ("dict", ["словарь", "dict"], super::dict::setup_dict_func);
         ITERATOR => ("iterator", ["итератор", "iterator"], super::iterator::setup_iterator_func);
         FROM_JSON => ("from_json", ["из_json", "from_json"], super::json::setup_json_funcs);
         TO_JSON => ("to_json", ["в_json", "to_json"], super::json::setup_json_funcs);
         STRING_FROM_POINTER => ("string_from_pointer", ["строка_из_указателя", "string_from_pointer"], super::text::setup_text_func);
         REGEX => ("regex", ["регулярное_выражение", "regex"], super::regex::setup_regex_func);
+        PARALLEL_MAP => ("parallel_map", ["параллельно_карта", "parallel_map"], super::thread::setup_parallel_func);
+        AWAIT => ("await", ["ждать", "await"], super::promise::setup_await_func);
+        EVAL => ("eval", ["выполнить", "eval"], super::common::setup_dynamic_funcs);
+        IMPORT_DYNAMIC => ("import_dynamic", ["подключить_динамически", "import_dynamic"], super::common::setup_dynamic_funcs);
+        FIELDS => ("fields", ["поля", "fields"], super::reflection::setup_reflection_funcs);
+        METHODS => ("methods", ["методы", "methods"], super::reflection::setup_reflection_funcs);
+        HAS_ATTRIBUTE => ("has_attribute", ["есть_атрибут", "has_attribute"], super::reflection::setup_reflection_funcs);
+        GET_ATTRIBUTE => ("get_attribute", ["получить_атрибут", "get_attribute"], super::reflection::setup_reflection_funcs);
+        SET_ATTRIBUTE => ("set_attribute", ["установить_атрибут", "set_attribute"], super::reflection::setup_reflection_funcs);
     }
     classes {
-        STRING => ("String", ["Строка", "String"], Text, super::text::setup_text_class);
+        STRING => ("String", ["Строка", "Текст", "String"], Text, super::text::setup_text_class);
+        STRING_BUILDER => ("StringBuilder", ["СтроительСтроки", "StringBuilder"], Object, super::string_builder::setup_string_builder_class);
         LIST => ("List", ["Список", "List"], List, super::list::setup_list_class);
         ARRAY => ("Array", ["Массив", "Array"], Array, super::array::setup_array_class);
+        BYTES => ("Bytes", ["Байты", "Bytes"], Object, super::bytes::setup_bytes_class);
         DICT => ("Dict", ["Словарь", "Dict"], Dict, super::dict::setup_dict_class);
         ITERATOR => ("Iterator", ["Итератор", "Iterator"], Object, super::iterator::setup_iterator_class);
         FILE => ("File", ["Файл", "File"], Object, super::file::setup_file_class);
+        OPEN_FILE => ("OpenFile", ["ОткрытыйФайл", "OpenFile"], Object, super::file::setup_open_file_class);
+        DIRECTORY => ("Directory", ["Папка", "Directory"], Object, super::directory::setup_directory_class);
+        PATH => ("Path", ["Путь", "Path"], Object, super::path::setup_path_class);
         SYSTEM => ("System", ["Система", "System"], Object, super::system::setup_system_class);
         TERMINAL => ("Terminal", ["Терминал", "Terminal"], Object, super::terminal::setup_terminal_class);
         DATETIME => ("DateTime", ["ДатаВремя", "DateTime"], Object, super::datetime::setup_datetime_class);
-        REGEX => ("Regex", ["РегулярноеВыражение", "Regex"], Object, super::regex::setup_regex_class);
+        DURATION => ("Duration", ["Длительность", "Duration"], Object, super::datetime::setup_duration_class);
+        REGEX => ("Regex", ["РегулярноеВыражение", "Регулярка", "Regex"], Object, super::regex::setup_regex_class);
+        JSON => ("JSON", ["ДЖСОН", "JSON"], Object, super::json::setup_json_class);
+        CONFIG => ("Config", ["Конфиг", "Config"], Object, super::config::setup_config_class);
+        DATABASE => ("Database", ["БазаДанных", "Database"], Object, super::database::setup_database_class);
+        CRYPTO => ("Crypto", ["Крипто", "Crypto"], Object, super::crypto::setup_crypto_class);
+        ENCODING => ("Encoding", ["Кодирование", "Encoding"], Object, super::encoding::setup_encoding_class);
+        RANDOM => ("Random", ["Случай", "Random"], Object, super::random::setup_random_class);
+        STOPWATCH => ("Stopwatch", ["Секундомер", "Stopwatch"], Object, super::stopwatch::setup_stopwatch_class);
+        HTTP => ("HTTP", ["ХТТП", "HTTP"], Object, super::http::setup_http_class);
+        HTTP_RESPONSE => ("HttpResponse", ["ХТТПОтвет", "HttpResponse"], Object, super::http::setup_http_response_class);
+        URL => ("URL", ["УРЛ", "URL"], Object, super::url::setup_url_class);
+        TCP => ("TCP", ["ТСП", "TCP"], Object, super::tcp::setup_tcp_class);
+        TCP_CONNECTION => ("TcpConnection", ["ТСПСоединение", "TcpConnection"], Object, super::tcp::setup_tcp_connection_class);
+        TCP_LISTENER => ("TcpListener", ["ТСПСервер", "TcpListener"], Object, super::tcp::setup_tcp_listener_class);
         THREAD => ("Thread", ["Поток", "Thread"], Object, super::thread::setup_thread_class);
-        MUTEX => ("Mutex", ["Мьютекс", "Mutex"], Object, super::thread::setup_mutex_class);
+        MUTEX => ("Mutex", ["Мьютекс", "Замок", "Mutex", "Lock"], Object, super::thread::setup_mutex_class);
         RWLOCK => ("RwLock", ["БлокировкаЧтенияЗаписи", "RwLock"], Object, super::thread::setup_rwlock_class);
+        SET => ("Set", ["Множество", "Set"], Object, super::set::setup_set_class);
+        QUEUE => ("Queue", ["Очередь", "Queue"], Object, super::queue::setup_queue_class);
+        STACK => ("Stack", ["Стек", "Stack"], Object, super::queue::setup_stack_class);
+        BIG_INTEGER => ("BigInteger", ["БольшоеЧисло", "BigInteger"], Object, super::bigint::setup_bigint_class);
+        CHANNEL => ("Channel", ["Канал", "Channel"], Object, super::channel::setup_channel_class);
+        PROMISE => ("Promise", ["Обещание", "Promise"], Object, super::promise::setup_promise_class);
     }
     methods {
         LEN => ("length", ["длина", "length"]);
+        BYTE_LEN => ("byte_length", ["байт_длина", "byte_length"]);
+        CHARS => ("chars", ["символы", "chars"]);
+        CHAR_CODE => ("char_code", ["код_символа", "char_code"]);
+        FROM_CHAR_CODE => ("from_char_code", ["символ_из_кода", "from_char_code"]);
+        CAPITALIZE => ("capitalize", ["с_заглавной", "capitalize"]);
         JOIN => ("join", ["объединить", "join"]);
+        SORT => ("sort", ["сортировать", "sort"]);
+        SORT_BY => ("sort_by", ["сортировать_по", "sort_by"]);
+        REVERSE => ("reverse", ["перевернуть", "reverse"]);
+        INDEX_OF => ("index_of", ["индекс", "index_of"]);
+        INSERT => ("insert", ["вставить", "insert"]);
+        EXTEND => ("extend", ["расширить", "extend"]);
         GET => ("get", ["получить", "get"]);
         ITERATOR => ("iterator", ["итератор", "iterator"]);
         ADD => ("push", ["добавить", "push"]);
@@ -44,11 +92,31 @@ declare_builtin_registry! {
         HAS => ("has", ["имеет", "has"]);
         KEYS => ("keys", ["ключи", "keys"]);
         VALUES => ("values", ["значения", "values"]);
-        MAP => ("map", ["преобразовать", "map"]);
-        FILTER => ("filter", ["отфильтровать", "filter"]);
+        ENTRIES => ("entries", ["пары", "entries"]);
+        MERGE => ("merge", ["объединить", "merge"]);
+        UPDATE => ("update", ["обновить", "update"]);
+        UNION => ("union", ["объединение", "union"]);
+        INTERSECTION => ("intersection", ["пересечение", "intersection"]);
+        SET_DIFFERENCE => ("set_difference", ["разность", "set_difference"]);
+        PUSH_BACK => ("push_back", ["в_конец", "push_back"]);
+        POP_FRONT => ("pop_front", ["из_начала", "pop_front"]);
+        STACK_PUSH => ("stack_push", ["положить", "stack_push"]);
+        STACK_POP => ("stack_pop", ["снять", "stack_pop"]);
+        PEEK => ("peek", ["вершина", "peek"]);
+        IS_EMPTY => ("is_empty", ["пусто", "is_empty"]);
+        BIG_ADD => ("add", ["сложить", "add"]);
+        SUBTRACT => ("subtract", ["вычесть", "subtract"]);
+        MULTIPLY => ("multiply", ["умножить", "multiply"]);
+        DIVIDE => ("divide", ["разделить", "divide"]);
+        REMAINDER => ("remainder", ["остаток", "remainder"]);
+        POWER => ("power", ["степень", "power"]);
+        COMPARE => ("compare", ["сравнить", "compare"]);
+        TO_NUMBER => ("to_number", ["число", "to_number"]);
+        MAP => ("map", ["преобразовать", "карта", "map"]);
+        FILTER => ("filter", ["отфильтровать", "фильтр", "filter"]);
         REDUCE => ("reduce", ["свернуть", "reduce"]);
         TO_LIST => ("список", ["список", "list"]);
-        SPLIT => ("split", ["разделить", "split"]);
+        SPLIT => ("split", ["разделить", "разбить", "split"]);
         UPPER => ("upper", ["верхний", "upper"]);
         LOWER => ("lower", ["нижний", "lower"]);
         CONTAINS => ("contains", ["содержит", "contains"]);
@@ -57,12 +125,81 @@ declare_builtin_registry! {
         TRIM => ("trim", ["обрезать", "trim"]);
         STARTS_WITH => ("starts_with", ["начинается_с", "starts_with"]);
         ENDS_WITH => ("ends_with", ["заканчивается_на", "ends_with"]);
+        REPEAT => ("repeat", ["повторить", "repeat"]);
+        PAD_LEFT => ("pad_left", ["дополнить_слева", "pad_left"]);
+        PAD_RIGHT => ("pad_right", ["дополнить_справа", "pad_right"]);
         PATTERN => ("pattern", ["шаблон", "pattern"]);
         MATCHES => ("matches", ["совпадает", "matches"]);
         FIND => ("find", ["найти", "find"]);
         FIND_ALL => ("find_all", ["найти_все", "find_all"]);
         GROUPS => ("groups", ["группы", "groups"]);
         GROUPS_ALL => ("groups_all", ["группы_все", "groups_all"]);
+        NAMED_GROUPS => ("named_groups", ["именованные_группы", "named_groups"]);
+        PARSE => ("parse", ["разобрать", "parse"]);
+        STRINGIFY => ("stringify", ["строка", "stringify"]);
+        RANDOM_INT => ("int", ["число", "int"]);
+        RANDOM_FLOAT => ("float", ["дробь", "float"]);
+        SHUFFLE => ("shuffle", ["перемешать", "shuffle"]);
+        CHOICE => ("choice", ["выбрать", "choice"]);
+        SEED => ("seed", ["зерно", "seed"]);
+        SEND => ("send", ["отправить", "send"]);
+        CONNECT => ("connect", ["подключиться", "connect"]);
+        LISTEN => ("listen", ["слушать", "listen"]);
+        ACCEPT => ("accept", ["принять_клиента", "accept"]);
+        RECEIVE => ("receive", ["принять", "receive"]);
+        CLOSE => ("close", ["закрыть", "close"]);
+        PORT => ("port", ["порт", "port"]);
+        RUN => ("run", ["выполнить", "run"]);
+        RUN_LINES => ("run_lines", ["выполнить_построчно", "run_lines"]);
+        START => ("start", ["старт", "start"]);
+        STOP => ("stop", ["стоп", "stop"]);
+        ELAPSED_MS => ("elapsed_ms", ["прошло_мс", "elapsed_ms"]);
+        TRY_RECEIVE => ("try_receive", ["попробовать_получить", "try_receive"]);
+        READY => ("ready", ["готово", "ready"]);
+        READ_ASYNC => ("read_async", ["прочитать_асинхронно", "read_async"]);
+        WRITE_ASYNC => ("write_async", ["записать_асинхронно", "write_async"]);
+        GET_ASYNC => ("get_async", ["получить_асинхронно", "get_async"]);
+        SEND_ASYNC => ("send_async", ["отправить_асинхронно", "send_async"]);
+        CONNECT_ASYNC => ("connect_async", ["подключиться_асинхронно", "connect_async"]);
+        ACCEPT_ASYNC => ("accept_async", ["принять_клиента_асинхронно", "accept_async"]);
+        RECEIVE_ASYNC => ("receive_async", ["принять_асинхронно", "receive_async"]);
+        SLICE => ("slice", ["срез", "slice"]);
+        DECODE => ("decode", ["в_текст", "decode"]);
+        READ_BYTES => ("read_bytes", ["читать_байты", "read_bytes"]);
+        WRITE_BYTES => ("write_bytes", ["писать_байты", "write_bytes"]);
+        OPEN => ("open", ["открыть", "open"]);
+        READ_LINE => ("read_line", ["читать_строку", "read_line"]);
+        READ_LINES => ("read_lines", ["читать_строки", "read_lines"]);
+        EACH_LINE => ("each_line", ["построчно", "each_line"]);
+        WALK => ("walk", ["обойти", "walk"]);
+        COPY => ("copy", ["копировать", "copy"]);
+        MOVE => ("move", ["переместить", "move"]);
+        PATH_JOIN => ("path_join", ["соединить", "path_join"]);
+        PARENT => ("parent", ["родитель", "parent"]);
+        FILE_NAME => ("file_name", ["имя_файла", "file_name"]);
+        EXTENSION => ("extension", ["расширение", "extension"]);
+        ABSOLUTE => ("absolute", ["абсолютный", "absolute"]);
+        NORMALIZE => ("normalize", ["нормализовать", "normalize"]);
+        SIZE => ("size", ["размер", "size"]);
+        MODIFIED => ("modified", ["изменён", "изменен", "modified"]);
+        IS_DIR => ("is_dir", ["является_папкой", "is_dir"]);
+        PERMISSIONS => ("permissions", ["права", "permissions"]);
+        LOAD => ("load", ["загрузить", "load"]);
+        SAVE => ("save", ["сохранить", "save"]);
+        QUERY => ("query", ["запрос", "query"]);
+        TRANSACTION => ("transaction", ["транзакция", "transaction"]);
+        SHA256 => ("sha256", ["sha256"]);
+        SHA1 => ("sha1", ["sha1"]);
+        MD5 => ("md5", ["md5"]);
+        HMAC => ("hmac", ["хмак", "hmac"]);
+        TO_BASE64 => ("to_base64", ["в_base64", "to_base64"]);
+        FROM_BASE64 => ("from_base64", ["из_base64", "from_base64"]);
+        TO_HEX => ("to_hex", ["в_hex", "to_hex"]);
+        FROM_HEX => ("from_hex", ["из_hex", "from_hex"]);
+        URL_ENCODE => ("url_encode", ["в_url", "url_encode"]);
+        URL_DECODE => ("url_decode", ["из_url", "url_decode"]);
+        BUILD => ("build", ["собрать", "build"]);
+        ENCODE_QUERY => ("encode_query", ["закодировать_запрос", "encode_query"]);
         EXISTS => ("exists", ["существует", "exists"]);
         READ => ("read", ["прочитать", "read"]);
         WRITE => ("write", ["записать", "write"]);
@@ -71,16 +208,27 @@ declare_builtin_registry! {
         PANIC => ("panic", ["паника", "panic"]);
         PLATFORM => ("platform", ["платформа", "platform"]);
         ARGS => ("args", ["аргументы", "args"]);
+        RELOAD => ("reload", ["перезагрузить", "reload"]);
         TIME => ("time", ["время", "time"]);
         SLEEP => ("sleep", ["сон", "sleep"]);
         BEEP => ("beep", ["сигнал", "beep"]);
         ENV => ("environment", ["окружение", "environment"]);
         CLEAR => ("clear", ["очистить", "clear"]);
+        CLEAR_SCREEN => ("clear_screen", ["очистить_экран", "clear_screen"]);
+        CLEAR_LINE => ("clear_line", ["очистить_строку", "clear_line"]);
+        MOVE_CURSOR => ("move_cursor", ["переместить_курсор", "move_cursor"]);
         TITLE => ("title", ["заголовок", "title"]);
         HIDE_CURSOR => ("hide_cursor", ["скрыть_курсор", "hide_cursor"]);
         SHOW_CURSOR => ("show_cursor", ["показать_курсор", "show_cursor"]);
         POSITION => ("position", ["позиция", "position"]);
         PAUSE => ("pause", ["пауза", "pause"]);
+        KEY => ("key", ["клавиша", "key"]);
+        PROGRESS => ("progress", ["прогресс", "progress"]);
+        TABLE => ("table", ["таблица", "table"]);
+        COLOR => ("color", ["цвет", "color"]);
+        BACKGROUND => ("background", ["фон", "background"]);
+        STYLE => ("style", ["стиль", "style"]);
+        COLORS_ENABLED => ("colors_enabled", ["цвета_включены", "colors_enabled"]);
         NOW => ("now", ["сейчас", "now"]);
         FORMAT => ("format", ["формат", "format"]);
         YEAR => ("year", ["год", "year"]);
@@ -89,22 +237,33 @@ declare_builtin_registry! {
         HOUR => ("hour", ["час", "hour"]);
         MINUTE => ("minute", ["минута", "minute"]);
         SECOND => ("second", ["секунда", "second"]);
-        ADD_SECONDS => ("add_seconds", ["добавить_секунд", "add_seconds"]);
-        SUB_SECONDS => ("sub_seconds", ["вычесть_секунд", "sub_seconds"]);
-        ADD_MINUTES => ("add_minutes", ["добавить_минут", "add_minutes"]);
-        SUB_MINUTES => ("sub_minutes", ["вычесть_минут", "sub_minutes"]);
-        ADD_HOURS => ("add_hours", ["добавить_часов", "add_hours"]);
-        SUB_HOURS => ("sub_hours", ["вычесть_часов", "sub_hours"]);
-        ADD_DAYS => ("add_days", ["добавить_дней", "add_days"]);
-        SUB_DAYS => ("sub_days", ["вычесть_дней", "sub_days"]);
+        ADD_SECONDS => ("add_seconds", ["добавить_секунд", "добавить_секунды", "add_seconds"]);
+        SUB_SECONDS => ("sub_seconds", ["вычесть_секунд", "вычесть_секунды", "sub_seconds"]);
+        ADD_MINUTES => ("add_minutes", ["добавить_минут", "добавить_минуты", "add_minutes"]);
+        SUB_MINUTES => ("sub_minutes", ["вычесть_минут", "вычесть_минуты", "sub_minutes"]);
+        ADD_HOURS => ("add_hours", ["добавить_часов", "добавить_часы", "add_hours"]);
+        SUB_HOURS => ("sub_hours", ["вычесть_часов", "вычесть_часы", "sub_hours"]);
+        ADD_DAYS => ("add_days", ["добавить_дней", "добавить_дни", "add_days"]);
+        SUB_DAYS => ("sub_days", ["вычесть_дней", "вычесть_дни", "sub_days"]);
         ADD_MONTHS => ("add_months", ["добавить_месяцев", "add_months"]);
         SUB_MONTHS => ("sub_months", ["вычесть_месяцев", "sub_months"]);
         ADD_YEARS => ("add_years", ["добавить_лет", "add_years"]);
         SUB_YEARS => ("sub_years", ["вычесть_лет", "sub_years"]);
+        NOW_UTC => ("now_utc", ["сейчас_утс", "now_utc"]);
+        IN_ZONE => ("in_zone", ["в_поясе", "in_zone"]);
+        ZONE => ("zone", ["пояс", "zone"]);
+        DIFFERENCE => ("difference", ["разница", "difference"]);
+        DAYS => ("days", ["дни", "days"]);
+        HOURS => ("hours", ["часы", "hours"]);
+        MINUTES => ("minutes", ["минуты", "minutes"]);
+        SECONDS => ("seconds", ["секунды", "seconds"]);
+        MILLISECONDS => ("milliseconds", ["миллисекунды", "milliseconds"]);
         CREATE => ("create", ["создать", "create"]);
-        JOIN_THREAD => ("wait", ["ждать", "wait"]);
-        LOCK => ("lock", ["блокировать", "lock"]);
-        UNLOCK => ("unlock", ["разблокировать", "unlock"]);
+        JOIN_THREAD => ("wait", ["ждать", "подождать", "wait"]);
+        SPAWN => ("spawn", ["запустить", "spawn"]);
+        LOCK => ("lock", ["блокировать", "захватить", "lock"]);
+        UNLOCK => ("unlock", ["разблокировать", "освободить", "unlock"]);
+        WITH_LOCK => ("with_lock", ["с_замком", "with_lock"]);
         WRITE_LOCK => ("write_lock", ["писать_блокировать", "write_lock"]);
         WRITE_UNLOCK => ("write_unlock", ["писать_разблокировать", "write_unlock"]);
         READ_LOCK => ("read_lock", ["читать_блокировать", "read_lock"]);
@@ -137,9 +296,12 @@ declare_builtin_registry! {
         TYPE_ERROR => ("ОшибкаТипа", Some("Ошибка"));
         DIVISION_BY_ZERO_ERROR => ("ОшибкаДеленияНаНоль", Some("Ошибка"));
         OPERATION_ERROR => ("ОшибкаОперации", Some("Ошибка"));
+        OVERFLOW_ERROR => ("ОшибкаПереполнения", Some("ОшибкаОперации"));
         IO_ERROR => ("ОшибкаВводаВывода", Some("Ошибка"));
         IMPORT_ERROR => ("ОшибкаИмпорта", Some("Ошибка"));
         PANIC => ("Паника", Some("Ошибка"));
+        ASSERTION_ERROR => ("ОшибкаПроверки", Some("Ошибка"));
+        PERMISSION_ERROR => ("ОшибкаДоступа", Some("Ошибка"));
     }
 }
 
@@ -0,0 +1,215 @@
+use crate::ast::prelude::{ErrorData, Span};
+use crate::builtins::iterator::values_from_iterable;
+use crate::builtins::registry::*;
+use crate::interpreter::prelude::{
+    CallArgListExt, CallArgValue, ClassInstance, DictKey, Interpreter, RuntimeClassDefinition,
+    RuntimeError, RuntimeIterator, SharedInterner, Value,
+};
+use crate::shared::SharedMut;
+use crate::traits::prelude::CoreOperations;
+use crate::{bail_runtime, define_constructor, define_method, runtime_error};
+use std::any::Any;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use string_interner::DefaultSymbol as Symbol;
+
+/// Hidden field holding the set elements.
+pub(crate) const SET_FIELD: &str = "__set";
+
+/// Elements are kept sorted, so `список()` and iteration do not depend on hashing.
+type Items = BTreeSet<DictKey>;
+
+fn set_resource(
+    interpreter: &Interpreter,
+    instance: &SharedMut<ClassInstance>,
+) -> Option<SharedMut<Box<dyn Any + Send + Sync>>> {
+    let field = interpreter.symbols.set;
+    match instance.read(|instance| instance.field_values.get(&field).cloned()) {
+        Some(Value::NativeResource(resource)) => Some(resource),
+        _ => None,
+    }
+}
+
+/// Whether `instance` is a `Множество` holding `item`; `None` for any other object.
+pub(crate) fn set_contains(
+    interpreter: &Interpreter,
+    instance: &SharedMut<ClassInstance>,
+    item: &Value,
+) -> Option<bool> {
+    let key = DictKey::from_value(item, Span::default()).ok();
+    set_resource(interpreter, instance)?.read(|boxed| {
+        let items = boxed.downcast_ref::<Items>()?;
+        Some(key.is_some_and(|key| items.contains(&key)))
+    })
+}
+
+fn with_set<R>(
+    interpreter: &Interpreter,
+    args: &[CallArgValue],
+    span: Span,
+    f: impl FnOnce(&mut Items) -> R,
+) -> Result<R, RuntimeError> {
+    let Some(Value::Object(instance)) = CallArgListExt::first_value(args) else {
+        return bail_runtime!(TypeError, span, "Ожидался объект Множество");
+    };
+    let Some(resource) = set_resource(interpreter, instance) else {
+        return bail_runtime!(InvalidOperation, span, "Множество не создано");
+    };
+    resource.write(|boxed| match boxed.downcast_mut::<Items>() {
+        Some(items) => Ok(f(items)),
+        None => bail_runtime!(TypeError, span, "Внутренний ресурс множества повреждён"),
+    })
+}
+
+/// Elements of another `Множество` or of any iterable value.
+fn items_of(interpreter: &Interpreter, value: &Value, span: Span) -> Result<Items, RuntimeError> {
+    if let Value::Object(instance) = value {
+        if let Some(resource) = set_resource(interpreter, instance) {
+            return resource.read(|boxed| match boxed.downcast_ref::<Items>() {
+                Some(items) => Ok(items.clone()),
+                None => bail_runtime!(TypeError, span, "Внутренний ресурс множества повреждён"),
+            });
+        }
+    }
+    values_from_iterable(value, span)?
+        .iter()
+        .map(|value| element(value, span))
+        .collect()
+}
+
+fn element(value: &Value, span: Span) -> Result<DictKey, RuntimeError> {
+    DictKey::from_value(value, span).map_err(|_| {
+        runtime_error!(
+            TypeError,
+            span,
+            "Элементом множества может быть только число, строка или логическое значение"
+        )
+    })
+}
+
+fn store_items(interpreter: &Interpreter, instance: &SharedMut<ClassInstance>, items: Items) {
+    let resource =
+        Value::NativeResource(SharedMut::new(Box::new(items) as Box<dyn Any + Send + Sync>));
+    let field = interpreter.symbols.set;
+    instance.write(|instance| instance.field_values.insert(field, resource));
+}
+
+fn new_set(interpreter: &Interpreter, items: Items, span: Span) -> Result<Value, RuntimeError> {
+    let class_symbol = interpreter.intern_string(class::SET.names.canonical);
+    let Some(class_ref) = interpreter.std_classes.get(&class_symbol).cloned() else {
+        return bail_runtime!(InvalidOperation, span, "Класс Множество не найден");
+    };
+    let instance = SharedMut::new(ClassInstance::new(class_symbol, class_ref));
+    store_items(interpreter, &instance, items);
+    Ok(Value::Object(instance))
+}
+
+/// `множество.операция(другое)`: elements of the receiver and the argument combined by `op`.
+fn combine(
+    interpreter: &Interpreter,
+    args: &[CallArgValue],
+    span: Span,
+    usage: &str,
+    op: fn(&Items, &Items) -> Items,
+) -> Result<Value, RuntimeError> {
+    let Some(other) = CallArgListExt::get_value(args, 1) else {
+        return bail_runtime!(TypeError, span, "Использование: {}", usage);
+    };
+    // Элементы аргумента копируются заранее: `м.объединение(м)` не должен брать блокировку дважды.
+    let other = items_of(interpreter, other, span)?;
+    let items = with_set(interpreter, args, span, |items| op(items, &other))?;
+    new_set(interpreter, items, span)
+}
+
+pub fn setup_set_class(interner: &SharedInterner) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner.write(|i| i.get_or_intern(class::SET.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    // --- новый Множество(коллекция?) ---
+    define_constructor!(class_def, (interpreter, args, span) {
+        let Some(Value::Object(instance)) = CallArgListExt::first_value(&args) else {
+            return bail_runtime!(TypeError, span, "Ожидался объект Множество");
+        };
+        let items = match CallArgListExt::get_value(&args, 1) {
+            None | Some(Value::Empty) => Items::new(),
+            Some(source) => items_of(interpreter, source, span)?,
+        };
+        store_items(interpreter, instance, items);
+        Ok(Value::Empty)
+    });
+
+    // --- множество.добавить(значение) ---
+    define_method!(class_def, interner, method::ADD.canonical => (interpreter, args, span) {
+        let Some(value) = CallArgListExt::get_value(&args, 1) else {
+            return bail_runtime!(TypeError, span, "Использование: множество.добавить(значение)");
+        };
+        let value = element(value, span)?;
+        with_set(interpreter, &args, span, |items| items.insert(value))?;
+        Ok(Value::Empty)
+    });
+
+    // --- множество.удалить(значение) -> Boolean, был ли элемент ---
+    define_method!(class_def, interner, method::REMOVE.canonical => (interpreter, args, span) {
+        let Some(value) = CallArgListExt::get_value(&args, 1) else {
+            return bail_runtime!(TypeError, span, "Использование: множество.удалить(значение)");
+        };
+        let value = element(value, span)?;
+        Ok(Value::Boolean(with_set(interpreter, &args, span, |items| items.remove(&value))?))
+    });
+
+    // --- множество.содержит(значение) -> Boolean ---
+    define_method!(class_def, interner, method::CONTAINS.canonical => (interpreter, args, span) {
+        let Some(value) = CallArgListExt::get_value(&args, 1) else {
+            return bail_runtime!(TypeError, span, "Использование: множество.содержит(значение)");
+        };
+        // Список или объект не может быть элементом, значит и не содержится.
+        let Ok(value) = DictKey::from_value(value, span) else {
+            return Ok(Value::Boolean(false));
+        };
+        Ok(Value::Boolean(with_set(interpreter, &args, span, |items| items.contains(&value))?))
+    });
+
+    // --- множество.длина() -> Number ---
+    define_method!(class_def, interner, method::LEN.canonical => (interpreter, args, span) {
+        Ok(Value::Number(with_set(interpreter, &args, span, |items| items.len() as i64)?))
+    });
+
+    // --- множество.объединение(другое) -> Множество ---
+    define_method!(class_def, interner, method::UNION.canonical => (interpreter, args, span) {
+        combine(interpreter, &args, span, "множество.объединение(другое)", |a, b| {
+            a.union(b).cloned().collect()
+        })
+    });
+
+    // --- множество.пересечение(другое) -> Множество ---
+    define_method!(class_def, interner, method::INTERSECTION.canonical => (interpreter, args, span) {
+        combine(interpreter, &args, span, "множество.пересечение(другое)", |a, b| {
+            a.intersection(b).cloned().collect()
+        })
+    });
+
+    // --- множество.разность(другое) -> Множество, элементы, которых нет в другом ---
+    define_method!(class_def, interner, method::SET_DIFFERENCE.canonical => (interpreter, args, span) {
+        combine(interpreter, &args, span, "множество.разность(другое)", |a, b| {
+            a.difference(b).cloned().collect()
+        })
+    });
+
+    // --- множество.список() -> List, элементы по порядку ---
+    define_method!(class_def, interner, method::TO_LIST.canonical => (interpreter, args, span) {
+        let values = with_set(interpreter, &args, span, |items| {
+            items.iter().map(DictKey::to_value).collect()
+        })?;
+        Ok(Value::List(SharedMut::new(values)))
+    });
+
+    // --- множество.итератор() -> Iterator ---
+    define_method!(class_def, interner, method::ITERATOR.canonical => (interpreter, args, span) {
+        let values: Vec<Value> = with_set(interpreter, &args, span, |items| {
+            items.iter().map(DictKey::to_value).collect()
+        })?;
+        Ok(Value::Iterator(RuntimeIterator::new(Arc::new(values))))
+    });
+
+    (name, SharedMut::new(class_def))
+}
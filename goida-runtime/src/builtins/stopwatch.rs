@@ -0,0 +1,108 @@
+use crate::ast::prelude::{ErrorData, Span};
+use crate::builtins::registry::*;
+use crate::interpreter::prelude::{
+    CallArgListExt, CallArgValue, Interpreter, RuntimeClassDefinition, RuntimeError,
+    SharedInterner, Value,
+};
+use crate::shared::SharedMut;
+use crate::{bail_runtime, define_constructor, define_method, runtime_error};
+use std::sync::OnceLock;
+use std::time::Instant;
+use string_interner::DefaultSymbol as Symbol;
+
+/// Hidden field holding the nanoseconds of finished segments.
+pub(crate) const ELAPSED_FIELD: &str = "__накоплено";
+/// Hidden field holding the start of the running segment.
+pub(crate) const STARTED_FIELD: &str = "__начало";
+
+/// Nanoseconds since the first use of a stopwatch in this process.
+fn now_ns() -> i64 {
+    static EPOCH: OnceLock<Instant> = OnceLock::new();
+    EPOCH.get_or_init(Instant::now).elapsed().as_nanos() as i64
+}
+
+/// Accumulated nanoseconds and the start of the running segment, if any.
+struct Stopwatch {
+    elapsed: i64,
+    started: Option<i64>,
+}
+
+impl Stopwatch {
+    fn total(&self) -> i64 {
+        self.elapsed + self.started.map_or(0, |started| now_ns() - started)
+    }
+}
+
+fn update_stopwatch<R>(
+    interpreter: &Interpreter,
+    args: &[CallArgValue],
+    span: Span,
+    f: impl FnOnce(&mut Stopwatch) -> R,
+) -> Result<R, RuntimeError> {
+    let Some(Value::Object(instance)) = CallArgListExt::first_value(args) else {
+        return bail_runtime!(TypeError, span, "Ожидался объект Секундомер");
+    };
+    let elapsed_sym = interpreter.symbols.elapsed;
+    let started_sym = interpreter.symbols.started;
+    Ok(instance.write(|instance| {
+        let mut stopwatch = Stopwatch {
+            elapsed: match instance.field_values.get(&elapsed_sym) {
+                Some(Value::Number(elapsed)) => *elapsed,
+                _ => 0,
+            },
+            started: match instance.field_values.get(&started_sym) {
+                Some(Value::Number(started)) => Some(*started),
+                _ => None,
+            },
+        };
+        let result = f(&mut stopwatch);
+        instance
+            .field_values
+            .insert(elapsed_sym, Value::Number(stopwatch.elapsed));
+        instance.field_values.insert(
+            started_sym,
+            stopwatch.started.map_or(Value::Empty, Value::Number),
+        );
+        result
+    }))
+}
+
+fn as_ms(nanos: i64) -> Value {
+    Value::Float(nanos as f64 / 1_000_000.0)
+}
+
+pub fn setup_stopwatch_class(
+    interner_ref: &SharedInterner,
+) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner_ref.write(|i| i.get_or_intern(class::STOPWATCH.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    define_constructor!(class_def, (interpreter, args, span) {
+        update_stopwatch(interpreter, &args, span, |_| ())?;
+        Ok(Value::Empty)
+    });
+
+    // --- секундомер.старт() — запускает или продолжает отсчёт ---
+    define_method!(class_def, interner_ref, method::START.canonical => (interpreter, args, span) {
+        update_stopwatch(interpreter, &args, span, |stopwatch| {
+            stopwatch.started.get_or_insert_with(now_ns);
+        })?;
+        Ok(Value::Empty)
+    });
+
+    // --- секундомер.стоп() -> Float, прошедшие миллисекунды ---
+    define_method!(class_def, interner_ref, method::STOP.canonical => (interpreter, args, span) {
+        update_stopwatch(interpreter, &args, span, |stopwatch| {
+            stopwatch.elapsed = stopwatch.total();
+            stopwatch.started = None;
+            as_ms(stopwatch.elapsed)
+        })
+    });
+
+    // --- секундомер.прошло_мс() -> Float, в том числе во время отсчёта ---
+    define_method!(class_def, interner_ref, method::ELAPSED_MS.canonical => (interpreter, args, span) {
+        update_stopwatch(interpreter, &args, span, |stopwatch| as_ms(stopwatch.total()))
+    });
+
+    (name, SharedMut::new(class_def))
+}
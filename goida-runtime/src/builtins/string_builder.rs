@@ -0,0 +1,101 @@
+use crate::ast::prelude::{ErrorData, Span};
+use crate::builtins::registry::*;
+use crate::interpreter::prelude::{
+    CallArgListExt, CallArgValue, Interpreter, RuntimeClassDefinition, RuntimeError,
+    SharedInterner, Value,
+};
+use crate::shared::SharedMut;
+use crate::{bail_runtime, define_constructor, define_method, runtime_error};
+use std::any::Any;
+use string_interner::DefaultSymbol as Symbol;
+
+/// Hidden field holding the text collected by `СтроительСтроки`.
+pub(crate) const TEXT_FIELD: &str = "__text";
+
+fn with_text<R>(
+    interpreter: &Interpreter,
+    args: &[CallArgValue],
+    span: Span,
+    f: impl FnOnce(&mut String) -> R,
+) -> Result<R, RuntimeError> {
+    let Some(Value::Object(instance)) = CallArgListExt::first_value(args) else {
+        return bail_runtime!(TypeError, span, "Ожидался объект СтроительСтроки");
+    };
+    let field = interpreter.symbols.text;
+    let resource = instance.read(|instance| instance.field_values.get(&field).cloned());
+    let Some(Value::NativeResource(resource)) = resource else {
+        return bail_runtime!(InvalidOperation, span, "СтроительСтроки не создан");
+    };
+    resource.write(|boxed| match boxed.downcast_mut::<String>() {
+        Some(text) => Ok(f(text)),
+        None => bail_runtime!(
+            TypeError,
+            span,
+            "Внутренний ресурс СтроительСтроки повреждён"
+        ),
+    })
+}
+
+/// Text of a value as `печать` would show it.
+fn piece(interpreter: &Interpreter, value: &Value) -> String {
+    match value {
+        Value::Text(text) => text.to_string(),
+        value => interpreter.format_value(value),
+    }
+}
+
+pub fn setup_string_builder_class(
+    interner: &SharedInterner,
+) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner.write(|i| i.get_or_intern(class::STRING_BUILDER.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    // --- новый СтроительСтроки(начало?) ---
+    define_constructor!(class_def, (interpreter, args, span) {
+        let Some(Value::Object(instance)) = CallArgListExt::first_value(&args) else {
+            return bail_runtime!(TypeError, span, "Ожидался объект СтроительСтроки");
+        };
+        let text = match CallArgListExt::get_value(&args, 1) {
+            None | Some(Value::Empty) => String::new(),
+            Some(value) => piece(interpreter, value),
+        };
+        let resource =
+            Value::NativeResource(SharedMut::new(Box::new(text) as Box<dyn Any + Send + Sync>));
+        let field = interpreter.symbols.text;
+        instance.write(|instance| instance.field_values.insert(field, resource));
+        Ok(Value::Empty)
+    });
+
+    // --- строитель.добавить(значение, ...) -> СтроительСтроки, дописывает значения в конец ---
+    define_method!(class_def, interner, method::ADD.canonical => (interpreter, args, span) {
+        let pieces = args
+            .iter()
+            .skip(1)
+            .map(|arg| piece(interpreter, &arg.value))
+            .collect::<Vec<_>>();
+        with_text(interpreter, &args, span, |text| {
+            for piece in &pieces {
+                text.push_str(piece);
+            }
+        })?;
+        Ok(args[0].value.clone())
+    });
+
+    // --- строитель.собрать() -> Строка ---
+    define_method!(class_def, interner, method::BUILD.canonical => (interpreter, args, span) {
+        with_text(interpreter, &args, span, |text| Value::Text(text.as_str().into()))
+    });
+
+    // --- строитель.длина() -> Число, в символах ---
+    define_method!(class_def, interner, method::LEN.canonical => (interpreter, args, span) {
+        with_text(interpreter, &args, span, |text| Value::Number(text.chars().count() as i64))
+    });
+
+    // --- строитель.очистить() ---
+    define_method!(class_def, interner, method::CLEAR.canonical => (interpreter, args, span) {
+        with_text(interpreter, &args, span, String::clear)?;
+        Ok(Value::Empty)
+    });
+
+    (name, SharedMut::new(class_def))
+}
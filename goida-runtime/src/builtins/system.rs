@@ -1,11 +1,15 @@
-use crate::ast::prelude::{ErrorData, Span};
+use crate::ast::prelude::{ErrorData, Span, Visibility};
+use crate::builtins::iterator::call_callable;
 use crate::builtins::registry::*;
-use crate::interpreter::prelude::RuntimeClassDefinition;
-use crate::interpreter::prelude::{CallArgListExt, RuntimeError, SharedInterner, Value};
+use crate::interpreter::options::Capability;
+use crate::interpreter::prelude::{
+    CallArgListExt, CallArgValue, FastHashMap, RuntimeError, SharedInterner, Value,
+};
+use crate::interpreter::prelude::{Interpreter, RuntimeClassDefinition, RuntimeMethodType};
 use crate::shared::SharedMut;
 use crate::{bail_runtime, define_method, runtime_error};
-use std::io::Write;
-use std::sync::Arc;
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Command, ExitStatus, Stdio};
 use string_interner::DefaultSymbol as Symbol;
 
 pub fn setup_system_class(
@@ -15,7 +19,8 @@ pub fn setup_system_class(
     let mut class_def = RuntimeClassDefinition::new(name, Span::default());
 
     // --- Система.выход(код) ---
-    define_method!(class_def, interner_ref, @static method::EXIT.canonical => (_, args, _) {
+    define_method!(class_def, interner_ref, @static method::EXIT.canonical => (interpreter, args, span) {
+        interpreter.require(Capability::System, span)?;
         let code = match CallArgListExt::first_value(&args) {
             Some(Value::Number(n)) => *n as i32,
             _ => 0,
@@ -39,20 +44,31 @@ pub fn setup_system_class(
     // --- Система.платформа() -> Text ---
     define_method!(class_def, interner_ref, @static method::PLATFORM.canonical => (_, _, _) {
         let os = std::env::consts::OS; // "windows", "linux", "macos"
-        Ok(Value::Text(os.to_string()))
+        Ok(Value::Text(os.into()))
     });
 
     // --- Система.аргументы() -> List ---
-    define_method!(class_def, interner_ref, @static method::ARGS.canonical => (_, _, _) {
-        let args_os: Vec<Value> = std::env::args()
-            .skip_while(|arg| arg != "--")
-            .skip(1)
-            .map(Value::Text)
+    define_method!(class_def, interner_ref, @static method::ARGS.canonical => (interpreter, _, _) {
+        let args: Vec<Value> = interpreter
+            .script_args
+            .iter()
+            .cloned()
+            .map(|text| Value::Text(text.into()))
             .collect();
 
-        Ok(Value::Array(Arc::new(args_os)))
+        Ok(Value::List(SharedMut::new(args)))
     });
 
+    // --- Система.перезагрузить(модуль) -> модуль, заменяет модуль в интерпретаторе ---
+    for alias in BUILTINS.method_names(method::RELOAD.canonical) {
+        class_def.add_method(
+            interner_ref.write(|i| i.get_or_intern(alias)),
+            Visibility::Public,
+            true,
+            RuntimeMethodType::Runtime(Interpreter::reload_method),
+        );
+    }
+
     // --- Система.время() -> Number (мс) ---
     define_method!(class_def, interner_ref, @static method::TIME.canonical => (_, _, _) {
         let now = std::time::SystemTime::now()
@@ -64,28 +80,12 @@ pub fn setup_system_class(
 
     // --- Система.сон(миллисекунды) ---
     define_method!(class_def, interner_ref, @static method::SLEEP.canonical => (_, args, span) {
-        let ms = match CallArgListExt::first_value(&args) {
-            Some(Value::Number(n)) => *n,
-            _ => {
-                return bail_runtime!(
-                    TypeError,
-                    span,
-                    "Функция 'сон' ожидает число (миллисекунды)"
-                )
-            }
-        };
-
-        if ms < 0 {
-            return bail_runtime!(
-                InvalidOperation,
-                span,
-                "Функция 'сон' ожидает число (миллисекунды)"
-            );
-        }
-
-        std::thread::sleep(std::time::Duration::from_millis(ms as u64));
+        sleep_ms(&args, "сон", span)
+    });
 
-        Ok(Value::Empty)
+    // --- Система.пауза(миллисекунды) ---
+    define_method!(class_def, interner_ref, @static method::PAUSE.canonical => (_, args, span) {
+        sleep_ms(&args, "пауза", span)
     });
 
     // --- Система.сигнал() ---
@@ -97,11 +97,12 @@ pub fn setup_system_class(
 
     // --- Система.окружение("SOME") ---
     define_method!(class_def, interner_ref, @static method::ENV.canonical => (interpreter, args, span) {
+        interpreter.require(Capability::System, span)?;
         let arg = CallArgListExt::first_value(&args)
             .map(|v| interpreter.format_value(v))
             .unwrap_or_else(|| "Неизвестная ошибка".into());
         match std::env::var(arg) {
-            Ok(v) => Ok(Value::Text(v)),
+            Ok(v) => Ok(Value::Text(v.into())),
             Err(err) => {
                     bail_runtime!(
                     InvalidOperation,
@@ -112,5 +113,133 @@ pub fn setup_system_class(
         }
     });
 
+    // --- Система.выполнить(команда, аргументы) -> словарь(код, вывод, ошибки) ---
+    define_method!(class_def, interner_ref, @static method::RUN.canonical => (interpreter, args, span) {
+        interpreter.require(Capability::Subprocess, span)?;
+        let (program, arguments) = command_args(&args, "выполнить", span)?;
+        let output = Command::new(&program)
+            .args(&arguments)
+            .stdin(Stdio::null())
+            .output()
+            .map_err(|error| spawn_error(&program, error, span))?;
+
+        let result = FastHashMap::from_iter([
+            ("код".into(), Value::Number(exit_code(output.status))),
+            (
+                "вывод".into(),
+                Value::Text(String::from_utf8_lossy(&output.stdout).into()),
+            ),
+            (
+                "ошибки".into(),
+                Value::Text(String::from_utf8_lossy(&output.stderr).into()),
+            ),
+        ]);
+        Ok(Value::Dict(SharedMut::new(result)))
+    });
+
+    // --- Система.выполнить_построчно(команда, аргументы, обработчик) -> код ---
+    define_method!(class_def, interner_ref, @static method::RUN_LINES.canonical => (interpreter, args, span) {
+        interpreter.require(Capability::Subprocess, span)?;
+        let (program, arguments) = command_args(&args, "выполнить_построчно", span)?;
+        let Some(handler) = CallArgListExt::get_value(&args, 2).cloned() else {
+            return bail_runtime!(
+                TypeError,
+                span,
+                "Использование: Система.выполнить_построчно(команда, аргументы, обработчик)"
+            );
+        };
+        // Ошибки процесса не перехватываются и сразу идут в терминал.
+        let mut child = Command::new(&program)
+            .args(&arguments)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|error| spawn_error(&program, error, span))?;
+
+        let stdout = child.stdout.take().expect("stdout is piped");
+        for line in BufReader::new(stdout).lines() {
+            let handled = line
+                .map_err(|error| runtime_error!(IOError, span, "Не удалось прочитать вывод '{}': {}", program, error))
+                .and_then(|line| call_callable(interpreter, handler.clone(), vec![Value::Text(line.into())], span));
+            if let Err(error) = handled {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(error);
+            }
+        }
+        let status = child
+            .wait()
+            .map_err(|error| spawn_error(&program, error, span))?;
+        Ok(Value::Number(exit_code(status)))
+    });
+
     (name, SharedMut::new(class_def))
 }
+
+fn sleep_ms(args: &[CallArgValue], function: &str, span: Span) -> Result<Value, RuntimeError> {
+    let ms = match CallArgListExt::first_value(args) {
+        Some(Value::Number(n)) => *n,
+        _ => {
+            return bail_runtime!(
+                TypeError,
+                span,
+                "Функция '{}' ожидает число (миллисекунды)",
+                function
+            )
+        }
+    };
+
+    if ms < 0 {
+        return bail_runtime!(
+            InvalidOperation,
+            span,
+            "Функция '{}' ожидает число (миллисекунды)",
+            function
+        );
+    }
+
+    std::thread::sleep(std::time::Duration::from_millis(ms as u64));
+
+    Ok(Value::Empty)
+}
+
+fn command_args(
+    args: &[CallArgValue],
+    method: &str,
+    span: Span,
+) -> Result<(String, Vec<String>), RuntimeError> {
+    let Some(Value::Text(program)) = CallArgListExt::first_value(args) else {
+        return bail_runtime!(
+            TypeError,
+            span,
+            "Система.{} ожидает команду строкой",
+            method
+        );
+    };
+    let to_text = |value: &Value| match value {
+        Value::Text(text) => text.to_string(),
+        other => other.to_string(),
+    };
+    let arguments = match CallArgListExt::get_value(args, 1) {
+        None | Some(Value::Empty) => Vec::new(),
+        Some(Value::List(items)) => items.read(|items| items.iter().map(to_text).collect()),
+        Some(Value::Array(items)) => items.iter().map(to_text).collect(),
+        Some(_) => return bail_runtime!(TypeError, span, "Аргументы команды должны быть списком"),
+    };
+    Ok((program.to_string(), arguments))
+}
+
+fn spawn_error(program: &str, error: std::io::Error, span: Span) -> RuntimeError {
+    runtime_error!(
+        IOError,
+        span,
+        "Не удалось запустить '{}': {}",
+        program,
+        error
+    )
+}
+
+/// Exit code, or -1 when the process was killed by a signal.
+fn exit_code(status: ExitStatus) -> i64 {
+    status.code().map_or(-1, i64::from)
+}
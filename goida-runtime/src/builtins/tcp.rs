@@ -0,0 +1,230 @@
+use crate::ast::prelude::{ErrorData, Span};
+use crate::builtins::promise::define_async_variant;
+use crate::builtins::registry::*;
+use crate::interpreter::options::Capability;
+use crate::interpreter::prelude::{
+    CallArgListExt, CallArgValue, ClassInstance, Interpreter, RuntimeClassDefinition, RuntimeError,
+    SharedInterner, Value,
+};
+use crate::shared::SharedMut;
+use crate::traits::prelude::CoreOperations;
+use crate::{bail_runtime, define_method, runtime_error};
+use std::any::Any;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use string_interner::DefaultSymbol as Symbol;
+
+/// Hidden field holding the socket; `пустота` once closed.
+pub(crate) const SOCKET_FIELD: &str = "__socket";
+/// Bytes read by `принять()` without an explicit size.
+const DEFAULT_RECEIVE_SIZE: usize = 4096;
+
+fn io_error(action: &str, error: std::io::Error, span: Span) -> RuntimeError {
+    runtime_error!(IOError, span, "Не удалось {}: {}", action, error)
+}
+
+fn address_args(args: &[CallArgValue], method: &str, span: Span) -> Result<String, RuntimeError> {
+    match (
+        CallArgListExt::first_value(args),
+        CallArgListExt::get_value(args, 1),
+    ) {
+        (Some(Value::Text(host)), Some(Value::Number(port))) if (0..=65535).contains(port) => {
+            Ok(format!("{}:{}", host, port))
+        }
+        _ => bail_runtime!(TypeError, span, "Использование: ТСП.{}(хост, порт)", method),
+    }
+}
+
+fn socket_object<T: Any + Send + Sync>(
+    interpreter: &Interpreter,
+    class_name: &str,
+    socket: T,
+    span: Span,
+) -> Result<Value, RuntimeError> {
+    let class_symbol = interpreter.intern_string(class_name);
+    let Some(class_ref) = interpreter.std_classes.get(&class_symbol).cloned() else {
+        return bail_runtime!(InvalidOperation, span, "Класс {} не найден", class_name);
+    };
+    let instance = SharedMut::new(ClassInstance::new(class_symbol, class_ref));
+    let resource = Value::NativeResource(SharedMut::new(
+        Box::new(socket) as Box<dyn Any + Send + Sync>
+    ));
+    instance.write(|instance| {
+        instance
+            .field_values
+            .insert(interpreter.symbols.socket, resource)
+    });
+    Ok(Value::Object(instance))
+}
+
+/// Runs `f` with the socket of the receiver, failing if it was closed.
+fn with_socket<T: Any, R>(
+    interpreter: &Interpreter,
+    args: &[CallArgValue],
+    span: Span,
+    f: impl FnOnce(&T) -> Result<R, RuntimeError>,
+) -> Result<R, RuntimeError> {
+    let Some(Value::Object(instance)) = CallArgListExt::first_value(args) else {
+        return bail_runtime!(TypeError, span, "Ожидался объект ТСП");
+    };
+    let field = interpreter.symbols.socket;
+    let resource = instance.read(|instance| instance.field_values.get(&field).cloned());
+    match resource {
+        Some(Value::NativeResource(resource)) => {
+            resource.read(|boxed| match boxed.downcast_ref::<T>() {
+                Some(socket) => f(socket),
+                None => bail_runtime!(TypeError, span, "Внутренний ресурс ТСП повреждён"),
+            })
+        }
+        _ => bail_runtime!(InvalidOperation, span, "Соединение закрыто"),
+    }
+}
+
+fn close_socket(
+    interpreter: &Interpreter,
+    args: &[CallArgValue],
+    span: Span,
+) -> Result<Value, RuntimeError> {
+    let Some(Value::Object(instance)) = CallArgListExt::first_value(args) else {
+        return bail_runtime!(TypeError, span, "Ожидался объект ТСП");
+    };
+    let field = interpreter.symbols.socket;
+    // Сокет закрывается, когда ресурс освобождается.
+    instance.write(|instance| instance.field_values.insert(field, Value::Empty));
+    Ok(Value::Empty)
+}
+
+pub fn setup_tcp_class(interner: &SharedInterner) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner.write(|i| i.get_or_intern(class::TCP.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    // --- ТСП.подключиться(хост, порт) -> ТСПСоединение ---
+    define_method!(class_def, interner, @static method::CONNECT.canonical => (interpreter, args, span) {
+        interpreter.require(Capability::Network, span)?;
+        let address = address_args(&args, "подключиться", span)?;
+        let stream = TcpStream::connect(&address)
+            .map_err(|error| io_error(&format!("подключиться к {}", address), error, span))?;
+        socket_object(interpreter, class::TCP_CONNECTION.names.canonical, stream, span)
+    });
+
+    // --- ТСП.слушать(хост, порт) -> ТСПСервер ---
+    define_method!(class_def, interner, @static method::LISTEN.canonical => (interpreter, args, span) {
+        interpreter.require(Capability::Network, span)?;
+        let address = address_args(&args, "слушать", span)?;
+        let listener = TcpListener::bind(&address)
+            .map_err(|error| io_error(&format!("слушать {}", address), error, span))?;
+        socket_object(interpreter, class::TCP_LISTENER.names.canonical, listener, span)
+    });
+
+    // --- ТСП.подключиться_асинхронно(хост, порт) -> Обещание ---
+    define_async_variant(
+        &mut class_def,
+        interner,
+        method::CONNECT.canonical,
+        method::CONNECT_ASYNC.canonical,
+    );
+
+    (name, SharedMut::new(class_def))
+}
+
+pub fn setup_tcp_connection_class(
+    interner: &SharedInterner,
+) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner.write(|i| i.get_or_intern(class::TCP_CONNECTION.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    // --- соединение.отправить(текст) -> число отправленных байт ---
+    define_method!(class_def, interner, method::SEND.canonical => (interpreter, args, span) {
+        let Some(Value::Text(text)) = CallArgListExt::get_value(&args, 1) else {
+            return bail_runtime!(TypeError, span, "Использование: соединение.отправить(текст)");
+        };
+        with_socket(interpreter, &args, span, |mut stream: &TcpStream| {
+            stream
+                .write_all(text.as_bytes())
+                .map_err(|error| io_error("отправить данные", error, span))?;
+            Ok(Value::Number(text.len() as i64))
+        })
+    });
+
+    // --- соединение.принять(размер = 4096) -> строка, пустая после закрытия другой стороной ---
+    define_method!(class_def, interner, method::RECEIVE.canonical => (interpreter, args, span) {
+        let size = match CallArgListExt::get_value(&args, 1) {
+            None => DEFAULT_RECEIVE_SIZE,
+            Some(Value::Number(size)) if *size > 0 => *size as usize,
+            Some(_) => {
+                return bail_runtime!(TypeError, span, "Размер должен быть положительным числом")
+            }
+        };
+        with_socket(interpreter, &args, span, |mut stream: &TcpStream| {
+            let mut buffer = vec![0; size];
+            let read = stream
+                .read(&mut buffer)
+                .map_err(|error| io_error("принять данные", error, span))?;
+            Ok(Value::Text(String::from_utf8_lossy(&buffer[..read]).into()))
+        })
+    });
+
+    // --- соединение.закрыть() ---
+    define_method!(class_def, interner, method::CLOSE.canonical => (interpreter, args, span) {
+        close_socket(interpreter, &args, span)
+    });
+
+    // --- соединение.отправить_асинхронно(текст), соединение.принять_асинхронно(размер) -> Обещание ---
+    define_async_variant(
+        &mut class_def,
+        interner,
+        method::SEND.canonical,
+        method::SEND_ASYNC.canonical,
+    );
+    define_async_variant(
+        &mut class_def,
+        interner,
+        method::RECEIVE.canonical,
+        method::RECEIVE_ASYNC.canonical,
+    );
+
+    (name, SharedMut::new(class_def))
+}
+
+pub fn setup_tcp_listener_class(
+    interner: &SharedInterner,
+) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner.write(|i| i.get_or_intern(class::TCP_LISTENER.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    // --- сервер.принять_клиента() -> ТСПСоединение, ждёт подключения ---
+    define_method!(class_def, interner, method::ACCEPT.canonical => (interpreter, args, span) {
+        let stream = with_socket(interpreter, &args, span, |listener: &TcpListener| {
+            listener
+                .accept()
+                .map(|(stream, _)| stream)
+                .map_err(|error| io_error("принять клиента", error, span))
+        })?;
+        socket_object(interpreter, class::TCP_CONNECTION.names.canonical, stream, span)
+    });
+
+    // --- сервер.порт() -> число, полезно при прослушивании порта 0 ---
+    define_method!(class_def, interner, method::PORT.canonical => (interpreter, args, span) {
+        with_socket(interpreter, &args, span, |listener: &TcpListener| {
+            listener
+                .local_addr()
+                .map(|address| Value::Number(i64::from(address.port())))
+                .map_err(|error| io_error("узнать порт", error, span))
+        })
+    });
+
+    // --- сервер.закрыть() ---
+    define_method!(class_def, interner, method::CLOSE.canonical => (interpreter, args, span) {
+        close_socket(interpreter, &args, span)
+    });
+
+    // --- сервер.принять_клиента_асинхронно() -> Обещание ---
+    define_async_variant(
+        &mut class_def,
+        interner,
+        method::ACCEPT.canonical,
+        method::ACCEPT_ASYNC.canonical,
+    );
+
+    (name, SharedMut::new(class_def))
+}
@@ -1,77 +1,342 @@
-use crate::ast::prelude::{Span, Visibility};
+use crate::ast::prelude::{ErrorData, Span, Visibility};
 use crate::builtins::registry::*;
-use crate::define_method;
 use crate::interpreter::prelude::{
-    CallArgListExt, RuntimeClassDefinition, RuntimeFieldData, SharedInterner, Value,
+    CallArgListExt, CallArgValue, Interpreter, RuntimeClassDefinition, RuntimeError,
+    RuntimeFieldData, SharedInterner, Value,
 };
 use crate::shared::SharedMut;
-use std::io::{stdin, stdout, Write};
+use crate::{bail_runtime, define_method, runtime_error};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::terminal;
+use std::collections::HashMap;
+use std::io::{stdin, stdout, IsTerminal, Read, Write};
 use string_interner::DefaultSymbol as Symbol;
 
+/// Style and color constants of `Терминал`; `Терминал.стиль` also accepts their names.
+const STYLES: [(&str, &str); 42] = [
+    ("сброс", "\x1b[0m"),
+    ("жирный", "\x1b[1m"),
+    ("тусклый", "\x1b[2m"),
+    ("курсив", "\x1b[3m"),
+    ("подчеркнутый", "\x1b[4m"),
+    ("мигающий", "\x1b[5m"),
+    ("быстро_мигающий", "\x1b[6m"),
+    ("инверсия", "\x1b[7m"),
+    ("скрытый", "\x1b[8m"),
+    ("зачеркнутый", "\x1b[9m"),
+    // --- Цвета текста (Стандартные) ---
+    ("черный", "\x1b[30m"),
+    ("красный", "\x1b[31m"),
+    ("зеленый", "\x1b[32m"),
+    ("желтый", "\x1b[33m"),
+    ("синий", "\x1b[34m"),
+    ("пурпурный", "\x1b[35m"),
+    ("циан", "\x1b[36m"),
+    ("белый", "\x1b[37m"),
+    // --- Цвета текста (Яркие) ---
+    ("серый", "\x1b[90m"),
+    ("ярко_красный", "\x1b[91m"),
+    ("ярко_зеленый", "\x1b[92m"),
+    ("ярко_желтый", "\x1b[93m"),
+    ("ярко_синий", "\x1b[94m"),
+    ("ярко_пурпурный", "\x1b[95m"),
+    ("ярко_циан", "\x1b[96m"),
+    ("ярко_белый", "\x1b[97m"),
+    // --- Цвета фона (Стандартные) ---
+    ("фон_черный", "\x1b[40m"),
+    ("фон_красный", "\x1b[41m"),
+    ("фон_зеленый", "\x1b[42m"),
+    ("фон_желтый", "\x1b[43m"),
+    ("фон_синий", "\x1b[44m"),
+    ("фон_пурпурный", "\x1b[45m"),
+    ("фон_циан", "\x1b[46m"),
+    ("фон_белый", "\x1b[47m"),
+    // --- Цвета фона (Яркие) ---
+    ("фон_серый", "\x1b[100m"),
+    ("фон_ярко_красный", "\x1b[101m"),
+    ("фон_ярко_зеленый", "\x1b[102m"),
+    ("фон_ярко_желтый", "\x1b[103m"),
+    ("фон_ярко_синий", "\x1b[104m"),
+    ("фон_ярко_пурпурный", "\x1b[105m"),
+    ("фон_ярко_циан", "\x1b[106m"),
+    ("фон_ярко_белый", "\x1b[107m"),
+];
+
+/// Colors are off when NO_COLOR is set or output goes to a file or a pipe,
+/// unless FORCE_COLOR asks for them anyway.
+fn colors_enabled() -> bool {
+    let set = |name: &str| std::env::var_os(name).is_some_and(|value| !value.is_empty());
+    !set("NO_COLOR") && (set("FORCE_COLOR") || stdout().is_terminal())
+}
+
+/// `38` selects the text color, `48` the background.
+fn color_code(layer: u8, args: &[CallArgValue], span: Span) -> Result<String, RuntimeError> {
+    let channel = |value: Option<&Value>| match value {
+        Some(Value::Number(n)) if (0..=255).contains(n) => Ok(*n as u8),
+        _ => bail_runtime!(
+            TypeError,
+            span,
+            "Составляющая цвета должна быть числом 0..255"
+        ),
+    };
+    let rgb = match (args.first_value(), args.get_value(1)) {
+        // Один аргумент: номер из палитры в 256 цветов или "#rrggbb".
+        (Some(Value::Number(_)), None) => {
+            return Ok(format!(
+                "\x1b[{};5;{}m",
+                layer,
+                channel(args.first_value())?
+            ))
+        }
+        (Some(Value::Text(hex)), None) => {
+            let digits = hex.strip_prefix('#').unwrap_or(hex);
+            match (digits.len(), u32::from_str_radix(digits, 16)) {
+                (6, Ok(rgb)) => [(rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8],
+                _ => return bail_runtime!(InvalidOperation, span, "Неверный цвет '{}'", hex),
+            }
+        }
+        _ => [
+            channel(args.first_value())?,
+            channel(args.get_value(1))?,
+            channel(args.get_value(2))?,
+        ],
+    };
+    Ok(format!(
+        "\x1b[{};2;{};{};{}m",
+        layer, rgb[0], rgb[1], rgb[2]
+    ))
+}
+
+/// Prints an ANSI escape sequence right away, without waiting for a newline.
+fn emit(sequence: &str) -> Result<Value, RuntimeError> {
+    print!("{}", sequence);
+    let _ = stdout().flush();
+    Ok(Value::Empty)
+}
+
+/// Screen coordinates count from 1, like in ANSI sequences.
+fn coordinate(value: Option<&Value>, span: Span) -> Result<i64, RuntimeError> {
+    match value {
+        None | Some(Value::Empty) => Ok(1),
+        Some(Value::Number(n)) if *n >= 1 => Ok(*n),
+        Some(_) => bail_runtime!(TypeError, span, "Координата должна быть числом от 1"),
+    }
+}
+
+/// Name of a key as the script sees it: the character itself or a word for special keys.
+fn key_name(code: KeyCode) -> Option<String> {
+    let name = match code {
+        KeyCode::Char(' ') => "пробел",
+        KeyCode::Char(c) => return Some(c.to_string()),
+        KeyCode::Up => "вверх",
+        KeyCode::Down => "вниз",
+        KeyCode::Left => "влево",
+        KeyCode::Right => "вправо",
+        KeyCode::Enter => "ввод",
+        KeyCode::Esc => "выход",
+        KeyCode::Tab => "табуляция",
+        KeyCode::Backspace => "стереть",
+        KeyCode::Delete => "удалить",
+        KeyCode::Home => "начало",
+        KeyCode::End => "конец",
+        KeyCode::PageUp => "страница_вверх",
+        KeyCode::PageDown => "страница_вниз",
+        KeyCode::F(n) => return Some(format!("ф{}", n)),
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
+/// Waits for one keypress in raw mode, so neither Enter nor echo is needed.
+fn read_key(span: Span) -> Result<Value, RuntimeError> {
+    let io_error = |error: std::io::Error| {
+        runtime_error!(IOError, span, "Не удалось прочитать клавишу: {}", error)
+    };
+    terminal::enable_raw_mode().map_err(io_error)?;
+    let key = loop {
+        match event::read() {
+            Ok(Event::Key(key)) if key.kind != KeyEventKind::Release => {
+                // В сыром режиме Ctrl+C не прерывает программу сам.
+                if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+                    break Err(runtime_error!(
+                        InvalidOperation,
+                        span,
+                        "Ввод прерван (Ctrl+C)"
+                    ));
+                }
+                if let Some(name) = key_name(key.code) {
+                    break Ok(Value::Text(name.into()));
+                }
+            }
+            Ok(_) => {}
+            Err(error) => break Err(io_error(error)),
+        }
+    };
+    terminal::disable_raw_mode().map_err(io_error)?;
+    key
+}
+
+/// Without a terminal (input from a pipe or file) keys are just the next characters.
+fn read_piped_key(span: Span) -> Result<Value, RuntimeError> {
+    let mut input = stdin().lock();
+    let mut buffer = [0u8; 4];
+    let mut length = 0;
+    loop {
+        match input.read(&mut buffer[length..length + 1]) {
+            Ok(0) if length == 0 => return Ok(Value::Empty),
+            Ok(0) => return bail_runtime!(IOError, span, "Ввод не является UTF-8"),
+            Ok(_) => length += 1,
+            Err(error) => {
+                return bail_runtime!(IOError, span, "Не удалось прочитать клавишу: {}", error)
+            }
+        }
+        match std::str::from_utf8(&buffer[..length]) {
+            Ok(text) => {
+                let code = match text {
+                    "\n" => KeyCode::Enter,
+                    "\t" => KeyCode::Tab,
+                    text => KeyCode::Char(text.chars().next().unwrap_or_default()),
+                };
+                return Ok(key_name(code).map_or(Value::Empty, |name| Value::Text(name.into())));
+            }
+            Err(error) if error.error_len().is_none() && length < buffer.len() => {}
+            Err(_) => return bail_runtime!(IOError, span, "Ввод не является UTF-8"),
+        }
+    }
+}
+
+/// `[█████░░░░░]  50% 5/10`, drawn over the current line.
+fn progress_bar(current: i64, total: i64, width: usize) -> String {
+    let current = current.clamp(0, total);
+    let filled = if total == 0 {
+        width
+    } else {
+        (current as i128 * width as i128 / total as i128) as usize
+    };
+    let percent = if total == 0 {
+        100
+    } else {
+        current as i128 * 100 / total as i128
+    };
+    format!(
+        "\r[{}{}] {:>3}% {}/{}",
+        "█".repeat(filled),
+        "░".repeat(width - filled),
+        percent,
+        current,
+        total
+    )
+}
+
+/// Renders rows (dicts) as aligned columns; numbers are aligned to the right.
+fn render_table(
+    interpreter: &Interpreter,
+    rows: &[Value],
+    columns: Option<Vec<String>>,
+    span: Span,
+) -> Result<String, RuntimeError> {
+    let mut dicts = Vec::with_capacity(rows.len());
+    for row in rows {
+        match row {
+            Value::Dict(entries) => dicts.push(entries.read(|entries| {
+                entries
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value.clone()))
+                    .collect::<HashMap<_, _>>()
+            })),
+            _ => return bail_runtime!(TypeError, span, "Строка таблицы должна быть словарём"),
+        }
+    }
+    // Словарь не хранит порядок ключей, поэтому по умолчанию столбцы идут по алфавиту.
+    let columns = columns.unwrap_or_else(|| {
+        let mut names: Vec<String> = dicts.iter().flat_map(|row| row.keys().cloned()).collect();
+        names.sort();
+        names.dedup();
+        names
+    });
+
+    let cells: Vec<Vec<(String, bool)>> = dicts
+        .iter()
+        .map(|row| {
+            columns
+                .iter()
+                .map(|column| match row.get(column) {
+                    None | Some(Value::Empty) => (String::new(), false),
+                    Some(value @ (Value::Number(_) | Value::Float(_))) => {
+                        (interpreter.format_value(value), true)
+                    }
+                    Some(value) => (interpreter.format_value(value), false),
+                })
+                .collect()
+        })
+        .collect();
+    let widths: Vec<usize> = columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            cells
+                .iter()
+                .map(|row| row[index].0.chars().count())
+                .chain([column.chars().count()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let pad = |text: &str, width: usize, right: bool| {
+        let fill = " ".repeat(width - text.chars().count());
+        if right {
+            format!("{}{}", fill, text)
+        } else {
+            format!("{}{}", text, fill)
+        }
+    };
+    let line = |row: Vec<String>| row.join(" | ").trim_end().to_string();
+
+    let mut lines = Vec::with_capacity(cells.len() + 2);
+    lines.push(line(
+        columns
+            .iter()
+            .zip(&widths)
+            .map(|(column, width)| pad(column, *width, false))
+            .collect(),
+    ));
+    lines.push(
+        widths
+            .iter()
+            .map(|width| "-".repeat(*width))
+            .collect::<Vec<_>>()
+            .join("-+-"),
+    );
+    for row in cells {
+        lines.push(line(
+            row.iter()
+                .zip(&widths)
+                .map(|((text, right), width)| pad(text, *width, *right))
+                .collect(),
+        ));
+    }
+    Ok(lines.join("\n"))
+}
+
 pub fn setup_terminal_class(
     interner_ref: &SharedInterner,
 ) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
     let name_sym = interner_ref.write(|i| i.get_or_intern(class::TERMINAL.names.canonical));
     let mut class_def = RuntimeClassDefinition::new(name_sym, Span::default());
 
-    let colors = [
-        ("сброс", "\x1b[0m"),
-        ("жирный", "\x1b[1m"),
-        ("тусклый", "\x1b[2m"),
-        ("курсив", "\x1b[3m"),
-        ("подчеркнутый", "\x1b[4m"),
-        ("мигающий", "\x1b[5m"),
-        ("быстро_мигающий", "\x1b[6m"),
-        ("инверсия", "\x1b[7m"),
-        ("скрытый", "\x1b[8m"),
-        ("зачеркнутый", "\x1b[9m"),
-        // --- Цвета текста (Стандартные) ---
-        ("черный", "\x1b[30m"),
-        ("красный", "\x1b[31m"),
-        ("зеленый", "\x1b[32m"),
-        ("желтый", "\x1b[33m"),
-        ("синий", "\x1b[34m"),
-        ("пурпурный", "\x1b[35m"),
-        ("циан", "\x1b[36m"),
-        ("белый", "\x1b[37m"),
-        // --- Цвета текста (Яркие) ---
-        ("серый", "\x1b[90m"),
-        ("ярко_красный", "\x1b[91m"),
-        ("ярко_зеленый", "\x1b[92m"),
-        ("ярко_желтый", "\x1b[93m"),
-        ("ярко_синий", "\x1b[94m"),
-        ("ярко_пурпурный", "\x1b[95m"),
-        ("ярко_циан", "\x1b[96m"),
-        ("ярко_белый", "\x1b[97m"),
-        // --- Цвета фона (Стандартные) ---
-        ("фон_черный", "\x1b[40m"),
-        ("фон_красный", "\x1b[41m"),
-        ("фон_зеленый", "\x1b[42m"),
-        ("фон_желтый", "\x1b[43m"),
-        ("фон_синий", "\x1b[44m"),
-        ("фон_пурпурный", "\x1b[45m"),
-        ("фон_циан", "\x1b[46m"),
-        ("фон_белый", "\x1b[47m"),
-        // --- Цвета фона (Яркие) ---
-        ("фон_серый", "\x1b[100m"),
-        ("фон_ярко_красный", "\x1b[101m"),
-        ("фон_ярко_зеленый", "\x1b[102m"),
-        ("фон_ярко_желтый", "\x1b[103m"),
-        ("фон_ярко_синий", "\x1b[104m"),
-        ("фон_ярко_пурпурный", "\x1b[105m"),
-        ("фон_ярко_циан", "\x1b[106m"),
-        ("фон_ярко_белый", "\x1b[107m"),
-    ];
-
-    for (name, code) in colors {
+    // Без цвета константы пустые, и скрипт печатает обычный текст.
+    let enabled = colors_enabled();
+    for (name, code) in STYLES {
         let sym = interner_ref.write(|i| i.get_or_intern(name));
+        let code = if enabled { code } else { "" };
 
         class_def.fields.insert(
             sym,
             (
                 Visibility::Public,
                 true,
-                RuntimeFieldData::Value(SharedMut::new(Value::Text(code.to_string()))),
+                RuntimeFieldData::Value(SharedMut::new(Value::Text(code.into()))),
             ),
         );
     }
@@ -84,9 +349,19 @@ pub fn setup_terminal_class(
         Ok(Value::Empty)
     });
 
+    // --- Терминал.очистить_экран(): то же, что очистить() ---
+    define_method!(class_def, interner_ref, @static method::CLEAR_SCREEN.canonical => (_, _, _) {
+        emit("\x1B[2J\x1B[1;1H")
+    });
+
+    // --- Терминал.очистить_строку(): стирает текущую строку и возвращает курсор в её начало ---
+    define_method!(class_def, interner_ref, @static method::CLEAR_LINE.canonical => (_, _, _) {
+        emit("\x1B[2K\r")
+    });
+
     // Метод: Терминал.заголовок(текст)
     define_method!(class_def, interner_ref, @static method::TITLE.canonical => (interpreter, args, _) {
-        let title = CallArgListExt::get_value(&args, 1)
+        let title = CallArgListExt::first_value(&args)
             .map(|v| interpreter.format_value(v))
             .unwrap_or_default();
         print!("\x1b]0;{}\x07", title);
@@ -111,24 +386,24 @@ pub fn setup_terminal_class(
     });
 
     // --- Терминал.позиция(х, у) ---
-    define_method!(class_def, interner_ref, @static method::POSITION.canonical => (_, args, _span) {
-        let x = CallArgListExt::get_value(&args, 1)
-            .and_then(|v| v.as_i64())
-            .unwrap_or(1);
-        let y = CallArgListExt::get_value(&args, 2)
-            .and_then(|v| v.as_i64())
-            .unwrap_or(1);
+    define_method!(class_def, interner_ref, @static method::POSITION.canonical => (_, args, span) {
+        let x = coordinate(CallArgListExt::first_value(&args), span)?;
+        let y = coordinate(CallArgListExt::get_value(&args, 1), span)?;
         // ANSI: \x1b[Y;XH (отсчет с 1)
-        print!("\x1b[{};{}H", y, x);
-        let _ = stdout().flush();
-        Ok(Value::Empty)
+        emit(&format!("\x1b[{};{}H", y, x))
+    });
+
+    // --- Терминал.переместить_курсор(строка, столбец) ---
+    define_method!(class_def, interner_ref, @static method::MOVE_CURSOR.canonical => (_, args, span) {
+        let row = coordinate(CallArgListExt::first_value(&args), span)?;
+        let column = coordinate(CallArgListExt::get_value(&args, 1), span)?;
+        emit(&format!("\x1b[{};{}H", row, column))
     });
 
     // --- Терминал.пауза(сообщение) ---
     define_method!(class_def, interner_ref, @static method::PAUSE.canonical => (_, args, _) {
-        let msg = CallArgListExt::get_value(&args, 1)
+        let msg = CallArgListExt::first_value(&args)
             .and_then(|v| v.as_str())
-            .map(|s| s.as_str())
             .unwrap_or("Нажмите Enter, чтобы продолжить...");
 
         print!("{}", msg);
@@ -140,5 +415,110 @@ pub fn setup_terminal_class(
         Ok(Value::Empty)
     });
 
+    // --- Терминал.цвет(к, з, с) / цвет(номер) / цвет("#rrggbb") -> Text ---
+    define_method!(class_def, interner_ref, @static method::COLOR.canonical => (_, args, span) {
+        let code = color_code(38, &args, span)?;
+        Ok(Value::Text(if colors_enabled() { code.into() } else { "".into() }))
+    });
+
+    // --- Терминал.фон(к, з, с) / фон(номер) / фон("#rrggbb") -> Text ---
+    define_method!(class_def, interner_ref, @static method::BACKGROUND.canonical => (_, args, span) {
+        let code = color_code(48, &args, span)?;
+        Ok(Value::Text(if colors_enabled() { code.into() } else { "".into() }))
+    });
+
+    // --- Терминал.стиль(текст, стиль, ...) -> Text, оформленный и со сбросом в конце ---
+    define_method!(class_def, interner_ref, @static method::STYLE.canonical => (interpreter, args, span) {
+        let Some(text) = args.first_value() else {
+            return bail_runtime!(TypeError, span, "Использование: Терминал.стиль(текст, стиль, ...)");
+        };
+        let text = interpreter.format_value(text);
+        let mut styled = String::new();
+        for arg in &args[1..] {
+            match &arg.value {
+                // Имя константы ("жирный") или готовая последовательность (Терминал.цвет(...)).
+                Value::Text(style) => match STYLES.iter().find(|(name, _)| *name == &**style) {
+                    Some((_, code)) => styled.push_str(code),
+                    // Пустая строка — константа или цвет при выключенном оформлении.
+                    None if style.is_empty() || style.starts_with('\x1b') => styled.push_str(style),
+                    None => return bail_runtime!(InvalidOperation, span, "Неизвестный стиль '{}'", style),
+                },
+                _ => return bail_runtime!(TypeError, span, "Стиль должен быть строкой"),
+            }
+        }
+        if !colors_enabled() {
+            return Ok(Value::Text(text.into()));
+        }
+        Ok(Value::Text(format!("{}{}\x1b[0m", styled, text).into()))
+    });
+
+    // --- Терминал.цвета_включены() -> Boolean ---
+    define_method!(class_def, interner_ref, @static method::COLORS_ENABLED.canonical => (_, _, _) {
+        Ok(Value::Boolean(colors_enabled()))
+    });
+
+    // --- Терминал.прогресс(текущий, всего, ширина = 30): перерисовывает полосу в той же строке ---
+    define_method!(class_def, interner_ref, @static method::PROGRESS.canonical => (_, args, span) {
+        let usage = "Использование: Терминал.прогресс(текущий, всего, ширина)";
+        let (Some(Value::Number(current)), Some(Value::Number(total))) = (
+            CallArgListExt::first_value(&args),
+            CallArgListExt::get_value(&args, 1),
+        ) else {
+            return bail_runtime!(TypeError, span, "{}", usage);
+        };
+        let width = match CallArgListExt::get_value(&args, 2) {
+            None | Some(Value::Empty) => 30,
+            Some(Value::Number(width)) if *width > 0 => *width as usize,
+            Some(_) => return bail_runtime!(TypeError, span, "{}", usage),
+        };
+        if *total < 0 {
+            return bail_runtime!(InvalidOperation, span, "Всего не может быть отрицательным");
+        }
+        let mut bar = progress_bar(*current, *total, width);
+        // Завершённая полоса остаётся на экране, дальше печать идёт с новой строки.
+        if current >= total {
+            bar.push('\n');
+        }
+        emit(&bar)
+    });
+
+    // --- Терминал.таблица(строки, столбцы = пустота): печатает список словарей столбцами ---
+    define_method!(class_def, interner_ref, @static method::TABLE.canonical => (interpreter, args, span) {
+        let rows = match CallArgListExt::first_value(&args) {
+            Some(Value::List(rows)) => rows.read(|rows| rows.clone()),
+            Some(Value::Array(rows)) => rows.to_vec(),
+            _ => return bail_runtime!(TypeError, span, "Использование: Терминал.таблица(список словарей, столбцы)"),
+        };
+        let columns = match CallArgListExt::get_value(&args, 1) {
+            None | Some(Value::Empty) => None,
+            Some(Value::List(columns)) => Some(columns.read(|columns| columns.clone())),
+            Some(Value::Array(columns)) => Some(columns.to_vec()),
+            Some(_) => return bail_runtime!(TypeError, span, "Столбцы должны быть списком строк"),
+        };
+        let columns = columns
+            .map(|columns| {
+                columns
+                    .iter()
+                    .map(|column| match column {
+                        Value::Text(name) => Ok(name.to_string()),
+                        _ => bail_runtime!(TypeError, span, "Столбцы должны быть списком строк"),
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+        println!("{}", render_table(interpreter, &rows, columns, span)?);
+        Ok(Value::Empty)
+    });
+
+    // --- Терминал.клавиша() -> Text: символ или имя клавиши ("вверх", "ввод", ...) ---
+    define_method!(class_def, interner_ref, @static method::KEY.canonical => (_, _, span) {
+        let _ = stdout().flush();
+        if stdin().is_terminal() {
+            read_key(span)
+        } else {
+            read_piped_key(span)
+        }
+    });
+
     (name_sym, SharedMut::new(class_def))
 }
@@ -1,9 +1,10 @@
 use crate::ast::prelude::{ErrorData, Span};
 use crate::builtins::iterator::values_from_iterable;
 use crate::builtins::registry::*;
+use crate::interpreter::operations::repeat_text;
 use crate::interpreter::prelude::RuntimeClassDefinition;
 use crate::interpreter::prelude::{
-    CallArgListExt, Interpreter, RuntimeError, RuntimeIterator, SharedInterner, Value,
+    CallArgListExt, CallArgValue, Interpreter, RuntimeError, RuntimeIterator, SharedInterner, Value,
 };
 use crate::shared::SharedMut;
 use crate::{
@@ -24,13 +25,13 @@ pub fn setup_text_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtime
         if let Some(Value::Object(instance)) = CallArgListExt::first_value(&args) {
             let content = match CallArgListExt::get_value(&args, 1) {
                 Some(Value::Text(s)) => s.clone(),
-                Some(Value::Number(n)) => n.to_string(),
-                Some(Value::Float(f)) => f.to_string(),
-                Some(Value::Boolean(b)) => b.to_string(),
-                _ => String::new(),
+                Some(Value::Number(n)) => n.to_string().into(),
+                Some(Value::Float(f)) => f.to_string().into(),
+                Some(Value::Boolean(b)) => b.to_string().into(),
+                _ => "".into(),
             };
 
-            let data_sym = interp.interner.write(|i| i.get_or_intern("__data"));
+            let data_sym = interp.symbols.data;
             instance.write(|i| i.field_values.insert(data_sym, Value::Text(content)));
         }
         Ok(Value::Empty)
@@ -49,6 +50,62 @@ pub fn setup_text_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtime
         }
     });
 
+    // байт_длина() -> Number: размер в байтах UTF-8
+    define_method!(class_def, interner, method::BYTE_LEN.canonical => (_interp, args, span) {
+        if let Some(Value::Text(s)) = CallArgListExt::first_value(&args) {
+            Ok(Value::Number(s.len() as i64))
+        } else {
+            bail_runtime!(TypeError, span, "Ожидалась строка")
+        }
+    });
+
+    // символы() -> List из строк по одному символу
+    define_method!(class_def, interner, method::CHARS.canonical => (_interp, args, span) {
+        if let Some(Value::Text(s)) = CallArgListExt::first_value(&args) {
+            let chars = s.chars().map(|ch| Value::Text(ch.to_string().into())).collect();
+            Ok(Value::List(SharedMut::new(chars)))
+        } else {
+            bail_runtime!(TypeError, span, "Ожидалась строка")
+        }
+    });
+
+    // код_символа(индекс = 0) -> Number: код Unicode символа
+    define_method!(class_def, interner, method::CHAR_CODE.canonical => (_interp, args, span) {
+        let Some(Value::Text(s)) = CallArgListExt::first_value(&args) else {
+            return bail_runtime!(TypeError, span, "Ожидалась строка");
+        };
+        let index = CallArgListExt::get_value(&args, 1)
+            .unwrap_or(&Value::Number(0))
+            .resolve_index(s.chars().count(), span)?;
+        match s.chars().nth(index) {
+            Some(ch) => Ok(Value::Number(i64::from(u32::from(ch)))),
+            None => bail_runtime!(InvalidOperation, span, "Индекс {} вне строки", index),
+        }
+    });
+
+    // Строка.символ_из_кода(код) -> Text
+    define_method!(class_def, interner, @static method::FROM_CHAR_CODE.canonical => (_interp, args, span) {
+        let Some(Value::Number(code)) = CallArgListExt::first_value(&args) else {
+            return bail_runtime!(TypeError, span, "Использование: Строка.символ_из_кода(код)");
+        };
+        match u32::try_from(*code).ok().and_then(char::from_u32) {
+            Some(ch) => Ok(Value::Text(ch.to_string().into())),
+            None => bail_runtime!(InvalidOperation, span, "Нет символа с кодом {}", code),
+        }
+    });
+
+    // с_заглавной() -> Text: первая буква в верхнем регистре
+    define_method!(class_def, interner, method::CAPITALIZE.canonical => (_interp, args, span) {
+        let Some(Value::Text(s)) = CallArgListExt::first_value(&args) else {
+            return bail_runtime!(TypeError, span, "Ожидалась строка");
+        };
+        let mut chars = s.chars();
+        Ok(Value::Text(match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect::<String>().into(),
+            None => "".into(),
+        }))
+    });
+
     // split(separator: Text) -> List
     define_method!(class_def, interner, method::SPLIT.canonical => (_interp, args, span) {
         if let (Some(Value::Text(s)), Some(Value::Text(sep))) = (
@@ -56,8 +113,8 @@ pub fn setup_text_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtime
             CallArgListExt::get_value(&args, 1),
         ) {
             let parts: Vec<Value> = s
-                .split(sep)
-                .map(|part| Value::Text(part.to_string()))
+                .split(&**sep)
+                .map(|part| Value::Text(part.into()))
                 .collect();
             Ok(Value::List(SharedMut::new(parts)))
         } else {
@@ -72,7 +129,7 @@ pub fn setup_text_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtime
     // upper() -> Text
     define_method!(class_def, interner, method::UPPER.canonical => (_interp, args, span) {
         if let Some(Value::Text(s)) = CallArgListExt::first_value(&args) {
-            Ok(Value::Text(s.to_uppercase()))
+            Ok(Value::Text(s.to_uppercase().into()))
         } else {
             bail_runtime!(
                 TypeError,
@@ -85,7 +142,7 @@ pub fn setup_text_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtime
     // lower() -> Text
     define_method!(class_def, interner, method::LOWER.canonical => (_interp, args, span) {
         if let Some(Value::Text(s)) = CallArgListExt::first_value(&args) {
-            Ok(Value::Text(s.to_lowercase()))
+            Ok(Value::Text(s.to_lowercase().into()))
         } else {
             bail_runtime!(
                 TypeError,
@@ -101,7 +158,7 @@ pub fn setup_text_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtime
             CallArgListExt::first_value(&args),
             CallArgListExt::get_value(&args, 1),
         ) {
-            Ok(Value::Boolean(s.contains(sub)))
+            Ok(Value::Boolean(s.contains(&**sub)))
         } else {
             bail_runtime!(
                 TypeError,
@@ -118,7 +175,7 @@ pub fn setup_text_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtime
             CallArgListExt::get_value(&args, 1),
             CallArgListExt::get_value(&args, 2),
         ) {
-            Ok(Value::Text(s.replace(old, new)))
+            Ok(Value::Text(s.replace(&**old, new).into()))
         } else {
             bail_runtime!(
                 TypeError,
@@ -130,7 +187,7 @@ pub fn setup_text_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtime
 
     define_method!(class_def, interner, method::TRIM.canonical => (_interp, args, span) {
         if let Some(Value::Text(s)) = CallArgListExt::first_value(&args) {
-            Ok(Value::Text(s.trim().to_string()))
+            Ok(Value::Text(s.trim().into()))
         } else {
             bail_runtime!(TypeError, span, "Ожидалась строка")
         }
@@ -141,7 +198,7 @@ pub fn setup_text_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtime
             CallArgListExt::first_value(&args),
             CallArgListExt::get_value(&args, 1),
         ) {
-            Ok(Value::Boolean(s.starts_with(prefix)))
+            Ok(Value::Boolean(s.starts_with(&**prefix)))
         } else {
             bail_runtime!(TypeError, span, "Использование: str.начинается_с(prefix)")
         }
@@ -152,12 +209,58 @@ pub fn setup_text_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtime
             CallArgListExt::first_value(&args),
             CallArgListExt::get_value(&args, 1),
         ) {
-            Ok(Value::Boolean(s.ends_with(suffix)))
+            Ok(Value::Boolean(s.ends_with(&**suffix)))
         } else {
             bail_runtime!(TypeError, span, "Использование: str.заканчивается_на(suffix)")
         }
     });
 
+    // найти(подстрока, от = 0) -> Number: индекс символа или -1
+    define_method!(class_def, interner, method::FIND.canonical => (_interp, args, span) {
+        let (Some(Value::Text(s)), Some(Value::Text(sub))) = (
+            CallArgListExt::first_value(&args),
+            CallArgListExt::get_value(&args, 1),
+        ) else {
+            return bail_runtime!(TypeError, span, "Использование: str.найти(подстрока, от)");
+        };
+        let from = match CallArgListExt::get_value(&args, 2) {
+            None | Some(Value::Empty) => 0,
+            Some(Value::Number(n)) if *n >= 0 => *n as usize,
+            Some(_) => return bail_runtime!(TypeError, span, "Начало поиска должно быть неотрицательным числом"),
+        };
+        // Индексы считаются в символах, как у len() и s[i], а не в байтах UTF-8.
+        let Some(start) = s.char_indices().map(|(i, _)| i).chain([s.len()]).nth(from) else {
+            return Ok(Value::Number(-1));
+        };
+        Ok(Value::Number(match s[start..].find(&**sub) {
+            Some(offset) => (from + s[start..start + offset].chars().count()) as i64,
+            None => -1,
+        }))
+    });
+
+    // повторить(n) -> Text
+    define_method!(class_def, interner, method::REPEAT.canonical => (interp, args, span) {
+        let (Some(Value::Text(s)), Some(Value::Number(n))) = (
+            CallArgListExt::first_value(&args),
+            CallArgListExt::get_value(&args, 1),
+        ) else {
+            return bail_runtime!(TypeError, span, "Использование: str.повторить(n)");
+        };
+        Ok(Value::Text(repeat_text(interp, s, *n, span)?.into()))
+    });
+
+    // дополнить_слева(ширина, символ = " ") -> Text
+    define_method!(class_def, interner, method::PAD_LEFT.canonical => (interp, args, span) {
+        let (s, fill) = padding(interp, &args, "дополнить_слева", span)?;
+        Ok(Value::Text((fill + s).into()))
+    });
+
+    // дополнить_справа(ширина, символ = " ") -> Text
+    define_method!(class_def, interner, method::PAD_RIGHT.canonical => (interp, args, span) {
+        let (s, fill) = padding(interp, &args, "дополнить_справа", span)?;
+        Ok(Value::Text((s.to_string() + &fill).into()))
+    });
+
     define_method!(class_def, interner, method::ITERATOR.canonical => (_, args, span) {
         let Some(value) = CallArgListExt::first_value(&args) else {
             return bail_runtime!(TypeError, span, "Ожидалась строка");
@@ -168,11 +271,39 @@ pub fn setup_text_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtime
     (name, SharedMut::new(class_def))
 }
 
+/// The string and the fill that brings it up to the requested width in characters.
+fn padding<'a>(
+    interpreter: &Interpreter,
+    args: &'a [CallArgValue],
+    usage: &str,
+    span: Span,
+) -> Result<(&'a str, String), RuntimeError> {
+    let (Some(Value::Text(s)), Some(Value::Number(width))) = (
+        CallArgListExt::first_value(args),
+        CallArgListExt::get_value(args, 1),
+    ) else {
+        return bail_runtime!(
+            TypeError,
+            span,
+            "Использование: str.{}(ширина, символ)",
+            usage
+        );
+    };
+    let fill = match CallArgListExt::get_value(args, 2) {
+        None | Some(Value::Empty) => ' ',
+        Some(Value::Text(fill)) if fill.chars().count() == 1 => fill.chars().next().unwrap_or(' '),
+        Some(_) => return bail_runtime!(TypeError, span, "Заполнитель должен быть одним символом"),
+    };
+    let missing = width.saturating_sub(s.chars().count() as i64);
+    let fill = repeat_text(interpreter, fill.encode_utf8(&mut [0; 4]), missing, span)?;
+    Ok((s, fill))
+}
+
 pub fn setup_text_func(interpreter: &mut Interpreter, interner: &SharedInterner) {
     define_builtin!(interpreter, interner, function::STRING.canonical => (_, arguments, span) {
         expect_args!(arguments, 1, span, function::STRING.canonical);
         let n: String = arguments[0].value.clone().try_into()?;
-        Ok(Value::Text(n))
+        Ok(Value::Text(n.into()))
     });
 
     define_builtin!(interpreter, interner, function::STRING_FROM_POINTER.canonical => (_, arguments, span){
@@ -212,7 +343,7 @@ fn native_pointer_address(value: &Value, span: Span) -> Result<usize, RuntimeErr
 
 fn copy_utf8_from_c_string(address: usize, span: Span) -> Result<Value, RuntimeError> {
     if address == 0 {
-        return Ok(Value::Text(String::new()));
+        return Ok(Value::Text("".into()));
     }
 
     // SAFETY: the trusted native library must return a readable NUL-terminated
@@ -252,7 +383,7 @@ fn copy_utf8_from_pointer(
         );
     }
     if byte_length == 0 {
-        return Ok(Value::Text(String::new()));
+        return Ok(Value::Text("".into()));
     }
     if address == 0 {
         return bail_runtime!(InvalidOperation, span, "Native string pointer is null");
@@ -276,7 +407,7 @@ fn copy_utf8_bytes(bytes: &[u8], span: Span) -> Result<Value, RuntimeError> {
     let text = std::str::from_utf8(bytes).map_err(|err| {
         runtime_error!(TypeError, span, "Native string is not valid UTF-8: {err}")
     })?;
-    Ok(Value::Text(text.to_owned()))
+    Ok(Value::Text(text.into()))
 }
 
 #[cfg(test)]
@@ -321,7 +452,7 @@ mod tests {
 
         assert!(matches!(
             copy_utf8_from_pointer(address, text.len() as i64, Span::default()),
-            Ok(Value::Text(value)) if value == text
+            Ok(Value::Text(value)) if &*value == text
         ));
     }
 
@@ -332,7 +463,7 @@ mod tests {
 
         assert!(matches!(
             copy_utf8_from_c_string(address, Span::default()),
-            Ok(Value::Text(value)) if value == "native c string"
+            Ok(Value::Text(value)) if &*value == "native c string"
         ));
     }
 
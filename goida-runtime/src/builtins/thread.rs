@@ -1,17 +1,18 @@
 use crate::ast::prelude::{ErrorData, Span};
+use crate::builtins::iterator::call_callable;
 use crate::builtins::registry::*;
 use crate::interpreter::prelude::RuntimeClassDefinition;
 use crate::interpreter::prelude::{
-    CallArgListExt, CallArgValue, RuntimeError, RuntimeMutex, RuntimeRwLock, RuntimeThread,
-    SharedInterner, Value,
+    CallArgListExt, CallArgValue, Interpreter, RuntimeError, RuntimeMutex, RuntimeRwLock,
+    RuntimeThread, SharedInterner, Value,
 };
 use crate::shared::SharedMut;
-use crate::traits::prelude::{CoreOperations, InterpreterFunctions};
-use crate::{bail_runtime, define_constructor, define_method, runtime_error};
+use crate::traits::prelude::InterpreterFunctions;
+use crate::{bail_runtime, define_builtin, define_constructor, define_method, runtime_error};
 use string_interner::DefaultSymbol as Symbol;
 
 fn spawn_thread(
-    interp: &crate::interpreter::prelude::Interpreter,
+    interp: &Interpreter,
     callable: Value,
     arguments: Vec<CallArgValue>,
     module_id: Symbol,
@@ -21,24 +22,16 @@ fn spawn_thread(
         Value::Function(function) => {
             let mut thread_interpreter = interp.fork_for_thread();
             let handle = std::thread::spawn(move || {
-                let result = thread_interpreter
-                    .call_function(function, arguments, module_id, span)
-                    .map(|_| ());
-
-                match result {
-                    Err(RuntimeError::Return(..)) => Ok(()),
-                    other => other,
-                }?;
-
-                thread_interpreter.join_background_threads(module_id, span)
+                let result =
+                    thread_interpreter.call_function(&function, arguments, module_id, span)?;
+                thread_interpreter.join_background_threads(module_id, span)?;
+                Ok(result)
             });
             Ok(RuntimeThread::new(handle))
         }
         Value::Builtin(builtin) => {
             let thread_interpreter = interp.fork_for_thread();
-            let handle = std::thread::spawn(move || {
-                builtin(&thread_interpreter, arguments, span).map(|_| ())
-            });
+            let handle = std::thread::spawn(move || builtin(&thread_interpreter, arguments, span));
             Ok(RuntimeThread::new(handle))
         }
         _ => bail_runtime!(TypeError, span, "Поток можно создать только из функции"),
@@ -46,7 +39,7 @@ fn spawn_thread(
 }
 
 fn thread_from_args(
-    interp: &crate::interpreter::prelude::Interpreter,
+    interp: &Interpreter,
     args: &[CallArgValue],
     callable_index: usize,
     span: Span,
@@ -58,6 +51,76 @@ fn thread_from_args(
     spawn_thread(interp, callable, arguments, span.file_id, span)
 }
 
+// Все значения можно передавать между потоками: коллекции и объекты
+// разделяются по ссылке под внутренней блокировкой, остальное копируется.
+const _: fn() = || {
+    fn assert_shareable<T: Send + Sync>() {}
+    assert_shareable::<Value>();
+};
+
+/// Runs `callable` over `items` on scoped OS threads, keeping the input order.
+fn parallel_map(
+    interp: &Interpreter,
+    items: Vec<Value>,
+    callable: Value,
+    span: Span,
+) -> Result<Vec<Value>, RuntimeError> {
+    if !matches!(callable, Value::Function(_) | Value::Builtin(_)) {
+        return bail_runtime!(TypeError, span, "параллельно_карта ожидает функцию");
+    }
+    let workers = std::thread::available_parallelism()
+        .map(usize::from)
+        .unwrap_or(1)
+        .min(items.len())
+        .max(1);
+    let chunk_size = items.len().div_ceil(workers).max(1);
+
+    std::thread::scope(|scope| {
+        let handles = items
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let worker = interp.fork_for_thread();
+                let callable = callable.clone();
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|item| {
+                            call_callable(&worker, callable.clone(), vec![item.clone()], span)
+                        })
+                        .collect::<Result<Vec<_>, _>>()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut results = Vec::with_capacity(items.len());
+        for handle in handles {
+            match handle.join() {
+                Ok(chunk) => results.extend(chunk?),
+                Err(_) => return bail_runtime!(Panic, span, "Поток завершился аварийно"),
+            }
+        }
+        Ok(results)
+    })
+}
+
+pub fn setup_parallel_func(interpreter: &mut Interpreter, interner: &SharedInterner) {
+    define_builtin!(interpreter, interner, function::PARALLEL_MAP.canonical => (interp, args, span) {
+        let (Some(items), Some(callable)) = (
+            CallArgListExt::first_value(&args),
+            CallArgListExt::get_value(&args, 1),
+        ) else {
+            return bail_runtime!(TypeError, span, "Использование: параллельно_карта(список, функция)");
+        };
+        let items = match items {
+            Value::List(items) => items.read(|items| items.clone()),
+            Value::Array(items) => items.to_vec(),
+            _ => return bail_runtime!(TypeError, span, "параллельно_карта ожидает список или массив"),
+        };
+        let results = parallel_map(interp, items, callable.clone(), span)?;
+        Ok(interp.manage_value(Value::List(SharedMut::new(results))))
+    });
+}
+
 fn wait_mutex_access(mutex: &RuntimeMutex, span: Span) -> Result<(), RuntimeError> {
     let current = std::thread::current().id();
     let (state_lock, cvar) = &*mutex.state;
@@ -282,7 +345,7 @@ pub fn setup_thread_class(
         }
 
         let thread = thread_from_args(interp, &args, 1, span)?;
-        let data_sym = interp.intern_string("__data");
+        let data_sym = interp.symbols.data;
         instance.write(|i| {
             i.field_values.insert(data_sym, Value::Thread(thread));
         });
@@ -294,6 +357,26 @@ pub fn setup_thread_class(
         Ok(Value::Thread(thread))
     });
 
+    // --- Поток.запустить(функция, аргументы) -> Поток, аргументы передаются списком ---
+    define_method!(class_def, interner, @static method::SPAWN.canonical => (interp, args, span) {
+        let Some(callable) = CallArgListExt::first_value(&args).cloned() else {
+            return bail_runtime!(TypeError, span, "Использование: Поток.запустить(функция, аргументы)");
+        };
+        let arguments = match CallArgListExt::get_value(&args, 1) {
+            None | Some(Value::Empty) => Vec::new(),
+            Some(Value::List(items)) => items.read(|items| items.clone()),
+            Some(Value::Array(items)) => items.to_vec(),
+            Some(_) => return bail_runtime!(TypeError, span, "Аргументы потока передаются списком"),
+        };
+        let arguments = arguments
+            .into_iter()
+            .map(|value| CallArgValue { name: None, value })
+            .collect();
+        let thread = spawn_thread(interp, callable, arguments, span.file_id, span)?;
+        Ok(Value::Thread(thread))
+    });
+
+    // --- поток.подождать() -> результат функции потока ---
     define_method!(class_def, interner, method::JOIN_THREAD.canonical => (interp, args, span) {
         if let Some(Value::Thread(thread_value)) = CallArgListExt::first_value(&args) {
             interp.join_thread_handle(thread_value, span)
@@ -316,7 +399,7 @@ pub fn setup_mutex_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtim
         let initial = CallArgListExt::get_value(&args, 1)
             .cloned()
             .unwrap_or(Value::Empty);
-        let data_sym = interp.intern_string("__data");
+        let data_sym = interp.symbols.data;
         instance.write(|i| {
             i.field_values.insert(data_sym, Value::Mutex(RuntimeMutex::new(initial)));
         });
@@ -371,6 +454,35 @@ pub fn setup_mutex_class(interner: &SharedInterner) -> (Symbol, SharedMut<Runtim
         }
     });
 
+    // --- замок.с_замком(функция) -> новое значение, функция получает текущее ---
+    define_method!(class_def, interner, method::WITH_LOCK.canonical => (interp, args, span) {
+        let (Some(Value::Mutex(mutex)), Some(callable)) = (
+            CallArgListExt::first_value(&args),
+            CallArgListExt::get_value(&args, 1),
+        ) else {
+            return bail_runtime!(TypeError, span, "Использование: замок.с_замком(функция)");
+        };
+        lock_mutex(mutex, span)?;
+        let current = mutex
+            .value
+            .lock()
+            .map(|guard| guard.clone())
+            .map_err(|_| runtime_error!(InvalidOperation, span, "Мьютекс поврежден"));
+        let result = current
+            .and_then(|current| call_callable(interp, callable.clone(), vec![current], span))
+            .and_then(|updated| {
+                let mut guard = mutex
+                    .value
+                    .lock()
+                    .map_err(|_| runtime_error!(InvalidOperation, span, "Мьютекс поврежден"))?;
+                *guard = updated.clone();
+                Ok(updated)
+            });
+        // Замок освобождается и тогда, когда функция завершилась ошибкой.
+        unlock_mutex(mutex, span)?;
+        result
+    });
+
     (name, SharedMut::new(class_def))
 }
 
@@ -387,7 +499,7 @@ pub fn setup_rwlock_class(
         let initial = CallArgListExt::get_value(&args, 1)
             .cloned()
             .unwrap_or(Value::Empty);
-        let data_sym = interp.intern_string("__data");
+        let data_sym = interp.symbols.data;
         instance.write(|i| {
             i.field_values.insert(data_sym, Value::RwLock(RuntimeRwLock::new(initial)));
         });
@@ -0,0 +1,174 @@
+use crate::ast::prelude::{ErrorData, Span};
+use crate::builtins::encoding::url_encode;
+use crate::builtins::registry::*;
+use crate::interpreter::prelude::{
+    CallArgListExt, DictKey, FastHashMap, RuntimeClassDefinition, RuntimeError, SharedInterner,
+    Value,
+};
+use crate::shared::SharedMut;
+use crate::{bail_runtime, define_method, runtime_error};
+use percent_encoding::percent_decode_str;
+use string_interner::DefaultSymbol as Symbol;
+use url::Url;
+
+fn text_of(value: &Value) -> String {
+    match value {
+        Value::Text(text) => text.to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// `ключ=значение&...` with keys in sorted order, so the result does not depend on the dict.
+fn encode_query(query: &FastHashMap<DictKey, Value>, span: Span) -> Result<String, RuntimeError> {
+    let mut keys: Vec<_> = query.keys().collect();
+    keys.sort();
+    let mut pairs = Vec::with_capacity(keys.len());
+    for key in keys {
+        let values = match &query[key] {
+            Value::List(items) => items.read(|items| items.clone()),
+            Value::Array(items) => items.to_vec(),
+            Value::Empty => continue,
+            value @ (Value::Text(_) | Value::Number(_) | Value::Float(_) | Value::Boolean(_)) => {
+                vec![value.clone()]
+            }
+            _ => {
+                return bail_runtime!(
+                    TypeError,
+                    span,
+                    "Параметр '{}' нельзя записать в запрос",
+                    key
+                )
+            }
+        };
+        // Список значений даёт повторяющийся параметр: `тег=а&тег=б`.
+        for value in values {
+            pairs.push(format!(
+                "{}={}",
+                url_encode(&key.to_string()),
+                url_encode(&text_of(&value))
+            ));
+        }
+    }
+    Ok(pairs.join("&"))
+}
+
+fn parse_url(text: &str, span: Span) -> Result<Value, RuntimeError> {
+    let url = Url::parse(text).map_err(|error| {
+        runtime_error!(
+            InvalidOperation,
+            span,
+            "Неверный адрес '{}': {}",
+            text,
+            error
+        )
+    })?;
+    // Путь и фрагмент отдаются раскодированными, как их и писали.
+    let decoded = |value: &str| Value::Text(percent_decode_str(value).decode_utf8_lossy().into());
+
+    let mut query = FastHashMap::default();
+    for (key, value) in url.query_pairs() {
+        query.insert(key.into_owned().into(), Value::Text(value.into()));
+    }
+    let user = match url.username() {
+        "" => Value::Empty,
+        user => Value::Text(user.into()),
+    };
+
+    let parts = FastHashMap::from_iter([
+        ("схема".into(), Value::Text(url.scheme().into())),
+        (
+            "хост".into(),
+            url.host_str()
+                .map_or(Value::Empty, |host| Value::Text(host.into())),
+        ),
+        (
+            "порт".into(),
+            url.port_or_known_default()
+                .map_or(Value::Empty, |port| Value::Number(i64::from(port))),
+        ),
+        ("путь".into(), decoded(url.path())),
+        ("запрос".into(), Value::Dict(SharedMut::new(query))),
+        (
+            "фрагмент".into(),
+            url.fragment().map_or(Value::Empty, decoded),
+        ),
+        ("пользователь".into(), user),
+    ]);
+    Ok(Value::Dict(SharedMut::new(parts)))
+}
+
+fn build_url(parts: &FastHashMap<DictKey, Value>, span: Span) -> Result<String, RuntimeError> {
+    let part = |name: &str| match parts.get(&name.into()) {
+        None | Some(Value::Empty) => None,
+        Some(value) => Some(text_of(value)),
+    };
+    let Some(scheme) = part("схема") else {
+        return bail_runtime!(InvalidOperation, span, "Для адреса нужна 'схема'");
+    };
+    let invalid =
+        |error: String| runtime_error!(InvalidOperation, span, "Неверный адрес: {}", error);
+
+    let mut url = Url::parse(&format!(
+        "{}://{}",
+        scheme,
+        part("хост").unwrap_or_default()
+    ))
+    .map_err(|error| invalid(error.to_string()))?;
+    if let Some(port) = parts.get(&"порт".into()) {
+        match port {
+            Value::Empty => {}
+            Value::Number(port) if (0..=65535).contains(port) => {
+                url.set_port(Some(*port as u16))
+                    .map_err(|_| invalid(format!("у схемы '{}' не бывает порта", scheme)))?
+            }
+            _ => return bail_runtime!(TypeError, span, "Порт должен быть числом 0..65535"),
+        }
+    }
+    if let Some(path) = part("путь") {
+        url.set_path(&path);
+    }
+    match parts.get(&"запрос".into()) {
+        None | Some(Value::Empty) => {}
+        Some(Value::Dict(query)) => {
+            let query = query.read(|query| encode_query(query, span))?;
+            url.set_query((!query.is_empty()).then_some(query.as_str()));
+        }
+        Some(Value::Text(query)) => url.set_query(Some(query)),
+        Some(_) => {
+            return bail_runtime!(TypeError, span, "Запрос должен быть словарём или строкой")
+        }
+    }
+    url.set_fragment(part("фрагмент").as_deref());
+    Ok(url.to_string())
+}
+
+pub fn setup_url_class(interner: &SharedInterner) -> (Symbol, SharedMut<RuntimeClassDefinition>) {
+    let name = interner.write(|i| i.get_or_intern(class::URL.names.canonical));
+    let mut class_def = RuntimeClassDefinition::new(name, Span::default());
+
+    // --- УРЛ.разобрать(адрес) -> Dict со схемой, хостом, портом, путём, запросом и фрагментом ---
+    define_method!(class_def, interner, @static method::PARSE.canonical => (_, args, span) {
+        let Some(Value::Text(text)) = CallArgListExt::first_value(&args) else {
+            return bail_runtime!(TypeError, span, "Использование: УРЛ.разобрать(адрес)");
+        };
+        parse_url(text, span)
+    });
+
+    // --- УРЛ.собрать(части) -> Text, части как у УРЛ.разобрать ---
+    define_method!(class_def, interner, @static method::BUILD.canonical => (_, args, span) {
+        let Some(Value::Dict(parts)) = CallArgListExt::first_value(&args) else {
+            return bail_runtime!(TypeError, span, "Использование: УРЛ.собрать(словарь частей)");
+        };
+        Ok(Value::Text(parts.read(|parts| build_url(parts, span))?.into()))
+    });
+
+    // --- УРЛ.закодировать_запрос(словарь) -> Text вида "а=1&б=2" ---
+    define_method!(class_def, interner, @static method::ENCODE_QUERY.canonical => (_, args, span) {
+        let Some(Value::Dict(query)) = CallArgListExt::first_value(&args) else {
+            return bail_runtime!(TypeError, span, "Использование: УРЛ.закодировать_запрос(словарь)");
+        };
+        Ok(Value::Text(query.read(|query| encode_query(query, span))?.into()))
+    });
+
+    (name, SharedMut::new(class_def))
+}
@@ -1,13 +1,14 @@
 use crate::ast::prelude::{ClassDefinition, ErrorData, ExprId, Span, Visibility};
 use crate::ast::program::{FieldData, MethodType};
 use crate::interpreter::prelude::{
-    CallArgValue, ClassInstance, Interpreter, RuntimeClassDefinition, RuntimeError,
+    CallArgValue, ClassInstance, FastHashMap, Interpreter, RuntimeClassDefinition, RuntimeError,
     RuntimeFieldData, RuntimeMethodType, Value,
 };
 use crate::shared::SharedMut;
-use crate::traits::prelude::InterpreterClasses;
+use crate::traits::prelude::{CoreOperations, InterpreterClasses};
 use crate::vm::Vm;
-use std::collections::HashMap;
+use crate::{bail_runtime, runtime_error};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use string_interner::DefaultSymbol as Symbol;
 
@@ -24,11 +25,9 @@ impl InterpreterClasses for Interpreter {
         match method {
             RuntimeMethodType::User(func) => {
                 let method_module = func.module.unwrap_or(current_module_id);
-                let method_name = self
-                    .modules
-                    .get(&method_module)
-                    .and_then(|m| m.arena.resolve_symbol(&self.interner, func.name))
-                    .unwrap_or_else(|| "неизвестно".to_string());
+                let _timer = self.profile_timer(|| {
+                    format!("метод {}", self.definition_name(method_module, func.name))
+                });
                 let mut arguments = arguments;
                 if !matches!(this_obj, Value::Empty) {
                     arguments.insert(
@@ -51,9 +50,8 @@ impl InterpreterClasses for Interpreter {
                 let execution_result = self.scoped_method_context(|interpreter| {
                     interpreter.scoped_child_environment(
                         |local_env| {
-                            for (param, arg_value) in func.params.iter().zip(final_arguments.iter())
-                            {
-                                local_env.define(param.name, arg_value.clone());
+                            for (param, arg_value) in func.params.iter().zip(final_arguments) {
+                                local_env.define(param.name, arg_value);
                             }
                         },
                         |interpreter| {
@@ -64,34 +62,46 @@ impl InterpreterClasses for Interpreter {
                                     "Compiled method body is missing"
                                 )
                             })?;
-                            Vm::new(interpreter, method_module).run(chunk)
+                            if chunk.generator {
+                                let frame_name = format!(
+                                    "метод {}",
+                                    interpreter.definition_name(method_module, func.name)
+                                );
+                                return Ok(Value::Generator(interpreter.start_generator(
+                                    chunk.clone(),
+                                    method_module,
+                                    frame_name,
+                                    span,
+                                )));
+                            }
+                            Vm::new(interpreter, method_module)
+                                .run(chunk)
+                                .map(|()| Value::Empty)
                         },
                     )
                 });
 
                 match execution_result {
-                    Ok(()) => Ok(Value::Empty),
+                    Ok(value) => Ok(value),
                     Err(RuntimeError::Return(_, val)) => Ok(val),
                     Err(mut e) => {
-                        e.add_stack_frame(format!("метод {}", method_name), span);
+                        let frame_name =
+                            format!("метод {}", self.definition_name(method_module, func.name));
+                        e.add_stack_frame(frame_name, span);
                         Err(e)
                     }
                 }
             }
 
             RuntimeMethodType::Native(builtin) => {
-                let has_receiver = !matches!(this_obj, Value::Empty);
-                let mut final_args =
-                    Vec::with_capacity(arguments.len() + usize::from(has_receiver));
-                if has_receiver {
-                    final_args.push(CallArgValue {
-                        name: None,
-                        value: this_obj,
-                    });
-                }
-                final_args.extend(arguments);
-                builtin(self, final_args, span)
+                builtin(self, with_receiver(this_obj, arguments), span)
             }
+            RuntimeMethodType::Runtime(function) => function(
+                self,
+                with_receiver(this_obj, arguments),
+                current_module_id,
+                span,
+            ),
         }
     }
 
@@ -100,7 +110,7 @@ impl InterpreterClasses for Interpreter {
         class_def: SharedMut<RuntimeClassDefinition>,
         module: Symbol,
     ) -> SharedMut<RuntimeClassDefinition> {
-        let mut methods = HashMap::new();
+        let mut methods = FastHashMap::default();
         class_def.read(|i| {
             for (method_name, (visibility, is_static, method_type)) in &i.methods {
                 let updated_method = match method_type {
@@ -109,8 +119,8 @@ impl InterpreterClasses for Interpreter {
                         Arc::make_mut(&mut updated_func).module = Some(module);
                         RuntimeMethodType::User(updated_func)
                     }
-                    RuntimeMethodType::Native(builtin) => {
-                        RuntimeMethodType::Native(builtin.clone())
+                    RuntimeMethodType::Native(_) | RuntimeMethodType::Runtime(_) => {
+                        method_type.clone()
                     }
                 };
 
@@ -125,6 +135,7 @@ impl InterpreterClasses for Interpreter {
             name: c.name,
             base_class: c.base_class,
             fields: c.fields.clone(),
+            constants: c.constants.clone(),
             methods,
             constructor: c.constructor.as_ref().map(|constructor| match constructor {
                 RuntimeMethodType::User(func_def) => {
@@ -132,20 +143,119 @@ impl InterpreterClasses for Interpreter {
                     Arc::make_mut(&mut updated_func).module = Some(module);
                     RuntimeMethodType::User(updated_func)
                 }
-                RuntimeMethodType::Native(builtin) => RuntimeMethodType::Native(builtin.clone()),
+                RuntimeMethodType::Native(_) | RuntimeMethodType::Runtime(_) => constructor.clone(),
             }),
             span: c.span,
+            doc: c.doc.clone(),
         });
 
         SharedMut::new(new_class_def)
     }
 }
 
+impl Interpreter {
+    /// Checks whether `value` belongs to `class` or to one of its subclasses.
+    pub(crate) fn is_instance_of(&self, value: &Value, class: &Value) -> bool {
+        let Value::Class(class) = class else {
+            return false;
+        };
+        let target = class.read(|class| class.name);
+        let Some(actual) = self.get_class_for_value(value) else {
+            return false;
+        };
+        let mut current = actual.read(|class| (class.name, class.base_class));
+        loop {
+            if current.0 == target {
+                return true;
+            }
+            let Some(base) = current.1 else {
+                return false;
+            };
+            let Some(base_def) = self
+                .modules
+                .values()
+                .find_map(|module| module.classes.get(&base).cloned())
+            else {
+                return false;
+            };
+            current = base_def.read(|class| (class.name, class.base_class));
+        }
+    }
+
+    /// Initializes the static fields of a class in declaration order.
+    pub(crate) fn initialize_static_fields(
+        &mut self,
+        class_def: &SharedMut<RuntimeClassDefinition>,
+        module_id: Symbol,
+    ) -> Result<(), RuntimeError> {
+        let mut pending = class_def.read(|class| {
+            class
+                .fields
+                .iter()
+                .filter_map(|(name, (_, is_static, data))| match data {
+                    RuntimeFieldData::Expression(expr) if *is_static => Some((*expr, *name)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        });
+        // Выражения полей добавляются в арену по порядку, поэтому их номера задают порядок объявления.
+        pending.sort();
+        for (_, name) in pending {
+            self.static_field_value(class_def, name, module_id, class_def.read(|c| c.span))?;
+        }
+        Ok(())
+    }
+
+    /// Value of a static field, running its initializer first if it has not run yet.
+    pub(crate) fn static_field_value(
+        &mut self,
+        class_def: &SharedMut<RuntimeClassDefinition>,
+        name: Symbol,
+        module_id: Symbol,
+        span: Span,
+    ) -> Result<Value, RuntimeError> {
+        let data = class_def.read(|class| class.fields.get(&name).map(|(_, _, data)| data.clone()));
+        let expr = match data {
+            Some(RuntimeFieldData::Value(value)) => return Ok(value.read(Clone::clone)),
+            Some(RuntimeFieldData::Expression(expr)) => expr,
+            None => return bail_runtime!(UndefinedVariable, span, "Property is missing"),
+        };
+        if self
+            .static_initializations
+            .iter()
+            .any(|(class, field)| class.ptr_eq(class_def) && *field == name)
+        {
+            let class_name = class_def.read(|class| class.name);
+            return bail_runtime!(
+                InvalidOperation,
+                span,
+                "Циклическая инициализация статического поля {}.{}",
+                self.resolve_symbol(class_name).unwrap_or_default(),
+                self.resolve_symbol(name).unwrap_or_default()
+            );
+        }
+
+        self.static_initializations.push((class_def.clone(), name));
+        let value = match expr {
+            Some(expr) => Vm::evaluate_compiled(self, module_id, expr),
+            None => Ok(Value::Empty),
+        };
+        self.static_initializations.pop();
+        let value = value?;
+        class_def.write(|class| {
+            if let Some((_, _, data)) = class.fields.get_mut(&name) {
+                *data = RuntimeFieldData::Value(SharedMut::new(value.clone()));
+            }
+        });
+        Ok(value)
+    }
+}
+
 impl ClassInstance {
     /// Создать новый экземпляр класса
     pub fn new(class_name: Symbol, class_ref: SharedMut<RuntimeClassDefinition>) -> Self {
         let mut fields = HashMap::new();
-        let mut field_values = HashMap::new();
+        let mut field_values = FastHashMap::default();
 
         class_ref.read(|class_def| {
             for (name, (_, is_static, data)) in &class_def.fields {
@@ -191,15 +301,23 @@ impl ClassInstance {
     /// Проверить доступность поля (приватный или публичный доступ)
     pub fn is_field_accessible(&self, field_name: &Symbol, is_external_access: bool) -> bool {
         // 1. Сначала проверяем статическое определение в классе (там права доступа)
-        let access_from_class = self.class_ref.read(|class| {
-            class.fields.get(field_name).map(|(vis, _, _)| match vis {
-                Visibility::Public => true,
-                Visibility::Private => !is_external_access,
-            })
-        });
+        let declared = self
+            .class_ref
+            .read(|class| class.fields.get(field_name).map(|(vis, _, _)| vis.clone()));
+        self.is_declared_field_accessible(field_name, declared.as_ref(), is_external_access)
+    }
 
-        if let Some(allowed) = access_from_class {
-            return allowed;
+    /// То же, что `is_field_accessible`, когда видимость поля в классе уже известна
+    pub fn is_declared_field_accessible(
+        &self,
+        field_name: &Symbol,
+        declared: Option<&Visibility>,
+        is_external_access: bool,
+    ) -> bool {
+        match declared {
+            Some(Visibility::Public) => return true,
+            Some(Visibility::Private) => return !is_external_access,
+            None => {}
         }
 
         // 2. Если в классе поле не описано, проверяем, существует ли оно в инстансе
@@ -241,6 +359,7 @@ impl RuntimeClassDefinition {
                     (*name, (visibility.clone(), *is_static, data))
                 })
                 .collect(),
+            constants: class.constants.clone(),
             methods: class
                 .methods
                 .iter()
@@ -261,6 +380,7 @@ impl RuntimeClassDefinition {
                 }
             }),
             span: class.span,
+            doc: class.doc.clone(),
         }
     }
 
@@ -270,9 +390,11 @@ impl RuntimeClassDefinition {
             name,
             base_class: None,
             fields: HashMap::new(),
-            methods: HashMap::new(),
+            constants: HashSet::new(),
+            methods: FastHashMap::default(),
             constructor: None,
             span,
+            doc: None,
         }
     }
 
@@ -281,9 +403,11 @@ impl RuntimeClassDefinition {
             name,
             base_class,
             fields: HashMap::new(),
-            methods: HashMap::new(),
+            constants: HashSet::new(),
+            methods: FastHashMap::default(),
             constructor: None,
             span,
+            doc: None,
         }
     }
 
@@ -341,6 +465,7 @@ impl RuntimeClassDefinition {
                     RuntimeFieldData::Value(_) => None,
                 })
                 .collect(),
+            constants: self.constants.clone(),
             methods: self
                 .methods
                 .iter()
@@ -353,14 +478,29 @@ impl RuntimeClassDefinition {
                             MethodType::User(function.clone()),
                         ),
                     )),
-                    RuntimeMethodType::Native(_) => None,
+                    RuntimeMethodType::Native(_) | RuntimeMethodType::Runtime(_) => None,
                 })
                 .collect(),
             constructor: self.constructor.as_ref().and_then(|method| match method {
                 RuntimeMethodType::User(function) => Some(MethodType::User(function.clone())),
-                RuntimeMethodType::Native(_) => None,
+                RuntimeMethodType::Native(_) | RuntimeMethodType::Runtime(_) => None,
             }),
             span: self.span,
+            doc: self.doc.clone(),
         }
     }
 }
+
+/// Arguments of a builtin method: the receiver, if any, comes first.
+fn with_receiver(this_obj: Value, arguments: Vec<CallArgValue>) -> Vec<CallArgValue> {
+    if matches!(this_obj, Value::Empty) {
+        return arguments;
+    }
+    let mut final_args = Vec::with_capacity(arguments.len() + 1);
+    final_args.push(CallArgValue {
+        name: None,
+        value: this_obj,
+    });
+    final_args.extend(arguments);
+    final_args
+}
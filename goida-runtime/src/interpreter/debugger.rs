@@ -0,0 +1,210 @@
+use crate::ast::prelude::Span;
+use crate::interpreter::prelude::{Environment, Interpreter, Value};
+use crate::traits::prelude::CoreOperations;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::Mutex;
+use string_interner::DefaultSymbol as Symbol;
+
+/// What to do after the debugger stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugCommand {
+    /// Stop at the next statement, entering calls.
+    Step,
+    /// Stop at the next statement of the current function or its callers.
+    Next,
+    /// Run until a breakpoint.
+    Continue,
+}
+
+/// Where execution stopped and what is visible there.
+#[derive(Debug, Clone)]
+pub struct DebugStop {
+    pub file: String,
+    pub line: usize,
+    /// Number of active function and method calls.
+    pub depth: usize,
+    /// Variables visible at the statement, sorted by name.
+    pub variables: Vec<(String, Value)>,
+}
+
+/// Front end that decides how to continue after every stop.
+pub trait DebugFrontend: Send {
+    fn stopped(&mut self, stop: &DebugStop, debugger: &Debugger) -> DebugCommand;
+}
+
+/// Breakpoints by file and line plus the stepping state of a debug session.
+pub struct Debugger {
+    breakpoints: Mutex<HashSet<(String, usize)>>,
+    state: Mutex<StepState>,
+    frontend: Mutex<Box<dyn DebugFrontend>>,
+    /// Byte offsets where lines begin, per file.
+    line_starts: Mutex<HashMap<String, Vec<usize>>>,
+}
+
+struct StepState {
+    command: DebugCommand,
+    /// Depth at which `Next` was requested.
+    depth: usize,
+    /// Line of the previous statement, so one line stops only once.
+    last_line: Option<(String, usize)>,
+}
+
+impl fmt::Debug for Debugger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Debugger")
+            .field("breakpoints", &self.breakpoints)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Debugger {
+    /// Starts stopped at the first statement, so breakpoints can be set there.
+    pub fn new(frontend: Box<dyn DebugFrontend>) -> Self {
+        Self {
+            breakpoints: Mutex::new(HashSet::new()),
+            state: Mutex::new(StepState {
+                command: DebugCommand::Step,
+                depth: 0,
+                last_line: None,
+            }),
+            frontend: Mutex::new(frontend),
+            line_starts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn add_breakpoint(&self, file: &str, line: usize) {
+        self.breakpoints
+            .lock()
+            .unwrap()
+            .insert((file.to_string(), line));
+    }
+
+    /// Returns `false` if there was no breakpoint at that line.
+    pub fn remove_breakpoint(&self, file: &str, line: usize) -> bool {
+        self.breakpoints
+            .lock()
+            .unwrap()
+            .remove(&(file.to_string(), line))
+    }
+
+    /// Breakpoints sorted by file and line.
+    pub fn breakpoints(&self) -> Vec<(String, usize)> {
+        let mut breakpoints = self
+            .breakpoints
+            .lock()
+            .unwrap()
+            .iter()
+            .cloned()
+            .collect::<Vec<_>>();
+        breakpoints.sort();
+        breakpoints
+    }
+
+    /// One-based line of a byte offset; `source` is read once per file.
+    fn line(&self, file: &str, offset: usize, source: impl FnOnce() -> String) -> usize {
+        let mut line_starts = self.line_starts.lock().unwrap();
+        let starts = line_starts.entry(file.to_string()).or_insert_with(|| {
+            std::iter::once(0)
+                .chain(source().match_indices('\n').map(|(index, _)| index + 1))
+                .collect()
+        });
+        starts.partition_point(|start| *start <= offset)
+    }
+
+    fn should_stop(&self, file: &str, line: usize, depth: usize) -> bool {
+        let mut state = self.state.lock().unwrap();
+        let location = (file.to_string(), line);
+        let new_line = state.last_line.as_ref() != Some(&location);
+        let stop = match state.command {
+            DebugCommand::Step => true,
+            DebugCommand::Next => depth <= state.depth,
+            DebugCommand::Continue => false,
+        } || new_line && self.breakpoints.lock().unwrap().contains(&location);
+        state.last_line = Some(location);
+        stop
+    }
+
+    fn resume(&self, command: DebugCommand, depth: usize) {
+        let mut state = self.state.lock().unwrap();
+        state.command = command;
+        state.depth = depth;
+    }
+}
+
+impl Environment {
+    /// Bindings of this frame and its parents; inner frames shadow outer ones.
+    pub(crate) fn visible_bindings(&self, bindings: &mut HashMap<Symbol, Value>) {
+        for name in self.bindings.keys() {
+            if !bindings.contains_key(name) {
+                if let Some(value) = self.get(name) {
+                    bindings.insert(*name, value);
+                }
+            }
+        }
+        if let Some(parent) = &self.parent {
+            parent.read(|parent| parent.visible_bindings(bindings));
+        }
+    }
+
+    fn function_depth(&self) -> usize {
+        usize::from(self.is_function)
+            + self
+                .parent
+                .as_ref()
+                .map_or(0, |parent| parent.read(|parent| parent.function_depth()))
+    }
+}
+
+impl Interpreter {
+    /// Called by the VM before a statement starts; `locals` are the VM slots by name.
+    pub(crate) fn debug_statement(
+        &self,
+        module: Symbol,
+        span: Span,
+        locals: impl FnOnce() -> Vec<(Symbol, Value)>,
+    ) {
+        let Some(debugger) = &self.debugger else {
+            return;
+        };
+        let file = self.get_file_path(&span.file_id);
+        let line = debugger.line(&file, span.start as usize, || {
+            self.source_manager.get_file_content(&file)
+        });
+        let depth = self.environment.read(|env| env.function_depth());
+        if !debugger.should_stop(&file, line, depth) {
+            return;
+        }
+
+        let mut bindings = HashMap::new();
+        bindings.extend(locals());
+        self.environment
+            .read(|env| env.visible_bindings(&mut bindings));
+        if let Some(module) = self.modules.get(&module) {
+            for (name, value) in &module.globals {
+                bindings.entry(*name).or_insert_with(|| value.clone());
+            }
+        }
+        // Функции, классы и модули видны в коде, а не в списке переменных.
+        let mut variables = bindings
+            .into_iter()
+            .filter(|(_, value)| {
+                !matches!(
+                    value,
+                    Value::Builtin(_) | Value::Function(_) | Value::Class(_) | Value::Module(_)
+                )
+            })
+            .filter_map(|(name, value)| Some((self.resolve_symbol(name)?, value)))
+            .collect::<Vec<_>>();
+        variables.sort_by(|left, right| left.0.cmp(&right.0));
+
+        let stop = DebugStop {
+            file,
+            line,
+            depth,
+            variables,
+        };
+        let command = debugger.frontend.lock().unwrap().stopped(&stop, debugger);
+        debugger.resume(command, depth);
+    }
+}
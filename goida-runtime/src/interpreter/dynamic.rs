@@ -0,0 +1,270 @@
+use crate::ast::prelude::{ErrorData, Span};
+use crate::builtins::registry::{function, method, BUILTINS};
+use crate::import_paths::resolve_import_path;
+use crate::interpreter::options::Capability;
+use crate::interpreter::prelude::{CallArgValue, Interpreter, RuntimeError, Value};
+use crate::parser::prelude::{ParseError, Parser};
+use crate::traits::prelude::CoreOperations;
+use crate::{bail_runtime, runtime_error};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use string_interner::DefaultSymbol as Symbol;
+
+/// Global that receives the trailing expression of `выполнить`.
+const EVAL_RESULT: &str = "__результат_выполнения";
+
+impl Interpreter {
+    /// `выполнить` and `подключить_динамически` load new modules and need the calling
+    /// module, which ordinary builtins do not see, so direct calls to them land here.
+    pub(crate) fn call_module_builtin(
+        &mut self,
+        symbol: Symbol,
+        arguments: &[CallArgValue],
+        current_module_id: Symbol,
+        span: Span,
+    ) -> Option<Result<Value, RuntimeError>> {
+        // Имя сверяется раньше всего остального: обычный вызов не должен платить за проверки.
+        let eval = self.interner.read(|interner| {
+            let name = interner.resolve(symbol)?;
+            if BUILTINS
+                .function_names(function::EVAL.canonical)
+                .contains(&name)
+            {
+                Some(true)
+            } else if BUILTINS
+                .function_names(function::IMPORT_DYNAMIC.canonical)
+                .contains(&name)
+            {
+                Some(false)
+            } else {
+                None
+            }
+        })?;
+        let shadowed = matches!(
+            self.environment
+                .read(|environment| environment.get(&symbol)),
+            Some(Value::Function(_))
+        ) || self
+            .modules
+            .get(&current_module_id)
+            .is_some_and(|module| module.functions.contains_key(&symbol));
+        if shadowed {
+            return None;
+        }
+        let result = if eval {
+            self.eval_source(arguments, current_module_id, span)
+        } else {
+            self.import_dynamic(arguments, current_module_id, span)
+        };
+        Some(result.map_err(|mut error| {
+            let name = self.resolve_symbol(symbol).unwrap_or_default();
+            error.add_stack_frame(format!("функция {}", name), span);
+            error
+        }))
+    }
+
+    fn eval_source(
+        &mut self,
+        arguments: &[CallArgValue],
+        current_module_id: Symbol,
+        span: Span,
+    ) -> Result<Value, RuntimeError> {
+        let [CallArgValue {
+            value: Value::Text(source),
+            ..
+        }] = arguments
+        else {
+            return bail_runtime!(TypeError, span, "Использование: выполнить(текст_кода)");
+        };
+        let current = self
+            .modules
+            .get(&current_module_id)
+            .ok_or_else(|| runtime_error!(InvalidOperation, span, "Текущий модуль не найден"))?;
+        let globals = current.globals.clone();
+
+        // Каждый вызов — отдельный модуль: объявленные в нём функции ссылаются на его байткод.
+        let name = format!("<выполнить {}>", self.modules.len());
+        self.source_manager.load_source(&name, source);
+        let result_symbol = self.intern_string(EVAL_RESULT);
+        let mut module = Parser::new(self.interner.clone(), &name, PathBuf::from(&name))
+            .with_predeclared_globals(self.runtime_globals().chain(globals.keys().copied()))
+            .with_result_binding(result_symbol)
+            .parse(source)
+            .map_err(RuntimeError::ImportError)?;
+        let module_id = module.name;
+        for (name, value) in globals {
+            module.globals.entry(name).or_insert(value);
+        }
+        self.load_incremental_module(module);
+        self.interpret_incremental(module_id)?;
+
+        let (result, evaluated) = self
+            .modules
+            .get_mut(&module_id)
+            .map(|module| {
+                (
+                    module.globals.remove(&result_symbol),
+                    module.globals.clone(),
+                )
+            })
+            .unwrap_or_default();
+        // Имена, объявленные или изменённые кодом, остаются в вызывающем модуле.
+        let reserved = self.reserved_globals();
+        if let Some(current) = self.modules.get_mut(&current_module_id) {
+            for (name, value) in evaluated {
+                if !reserved.contains(&name) {
+                    current.set_global(name, value);
+                }
+            }
+        }
+        Ok(result.unwrap_or(Value::Empty))
+    }
+
+    fn import_dynamic(
+        &mut self,
+        arguments: &[CallArgValue],
+        current_module_id: Symbol,
+        span: Span,
+    ) -> Result<Value, RuntimeError> {
+        let [CallArgValue {
+            value: Value::Text(path),
+            ..
+        }] = arguments
+        else {
+            return bail_runtime!(
+                TypeError,
+                span,
+                "Использование: подключить_динамически(путь)"
+            );
+        };
+        self.require(Capability::File, span)?;
+        let current = self
+            .modules
+            .get(&current_module_id)
+            .ok_or_else(|| runtime_error!(InvalidOperation, span, "Текущий модуль не найден"))?;
+        let full_path = resolve_import_path(&current.path, path);
+        let normalized_path = full_path.canonicalize().unwrap_or(full_path.clone());
+        let normalized_name = normalized_path.to_string_lossy().to_string();
+        let module_id = self.intern_string(&normalized_name);
+        // Модуль, уже подключённый статически или раньше, не выполняется повторно.
+        if self.modules.contains_key(&module_id) {
+            return Ok(Value::Module(module_id));
+        }
+
+        let source = std::fs::read_to_string(&full_path).map_err(|error| {
+            import_error(
+                span,
+                format!("Cannot read module {}: {}", full_path.display(), error),
+            )
+        })?;
+        let module = Parser::new(self.interner.clone(), &normalized_name, full_path)
+            .with_predeclared_globals(self.runtime_globals().collect::<Vec<_>>())
+            .parse(&source)
+            .map_err(RuntimeError::ImportError)?;
+        self.load_incremental_module(module);
+        self.interpret(module_id)?;
+        Ok(Value::Module(module_id))
+    }
+
+    /// `Система.перезагрузить(модуль)`: re-runs a module the calling module has loaded,
+    /// named by its alias or path, and returns it.
+    pub(crate) fn reload_method(
+        &mut self,
+        arguments: Vec<CallArgValue>,
+        current_module_id: Symbol,
+        span: Span,
+    ) -> Result<Value, RuntimeError> {
+        let [CallArgValue {
+            value: Value::Text(name),
+            ..
+        }] = arguments.as_slice()
+        else {
+            return bail_runtime!(
+                TypeError,
+                span,
+                "Использование: Система.перезагрузить(модуль)"
+            );
+        };
+        self.find_loaded_module(current_module_id, name)
+            .ok_or_else(|| import_error(span, format!("Модуль '{}' не подключён", name)))
+            .and_then(|module_id| {
+                self.reload_module(module_id, span)?;
+                Ok(Value::Module(module_id))
+            })
+            .map_err(|mut error| {
+                error.add_stack_frame(format!("метод {}", method::RELOAD.canonical), span);
+                error
+            })
+    }
+
+    /// A module loaded by `current_module_id`, named by its alias or by its path.
+    fn find_loaded_module(&self, current_module_id: Symbol, name: &str) -> Option<Symbol> {
+        let current = self.modules.get(&current_module_id)?;
+        let alias = self.interner.read(|i| i.get(name));
+        alias
+            .and_then(|alias| self.resolve_import_alias_symbol(current, alias))
+            .filter(|module_id| self.modules.contains_key(module_id))
+            .or_else(|| self.loaded_module_at(&current.path, name))
+    }
+
+    /// The loaded module whose file `path` names, relative to `base`.
+    pub(crate) fn loaded_module_at(&self, base: &Path, path: &str) -> Option<Symbol> {
+        let full_path = resolve_import_path(base, path);
+        let normalized_name = full_path
+            .canonicalize()
+            .unwrap_or(full_path)
+            .to_string_lossy()
+            .to_string();
+        self.interner
+            .read(|i| i.get(normalized_name.as_str()))
+            .filter(|module_id| self.modules.contains_key(module_id))
+    }
+
+    /// Re-reads and re-parses the file of a loaded module, then runs it in place of the
+    /// old one. Other modules and the values they hold are left as they are.
+    pub(crate) fn reload_module(
+        &mut self,
+        module_id: Symbol,
+        span: Span,
+    ) -> Result<(), RuntimeError> {
+        self.require(Capability::File, span)?;
+        let name = self
+            .resolve_symbol(module_id)
+            .ok_or_else(|| import_error(span, "Модуль не найден".to_string()))?;
+        let path = self
+            .modules
+            .get(&module_id)
+            .map(|module| module.path.clone())
+            .ok_or_else(|| import_error(span, format!("Модуль '{}' не подключён", name)))?;
+        let source = std::fs::read_to_string(&path).map_err(|error| {
+            import_error(
+                span,
+                format!("Cannot read module {}: {}", path.display(), error),
+            )
+        })?;
+        let module = Parser::new(self.interner.clone(), &name, path)
+            .with_predeclared_globals(self.runtime_globals().collect::<Vec<_>>())
+            .parse(&source)
+            .map_err(RuntimeError::ImportError)?;
+        self.replace_module(module)
+    }
+
+    /// Names every module sees without declaring them: builtins and builtin classes.
+    fn runtime_globals(&self) -> impl Iterator<Item = Symbol> + '_ {
+        self.builtins.keys().chain(self.std_classes.keys()).copied()
+    }
+
+    /// Runtime globals plus the builtin error classes every module defines for itself.
+    fn reserved_globals(&self) -> HashSet<Symbol> {
+        let error_classes = BUILTINS
+            .error_classes()
+            .iter()
+            .filter_map(|error| self.interner.read(|i| i.get(error.name)))
+            .collect::<Vec<_>>();
+        self.runtime_globals().chain(error_classes).collect()
+    }
+}
+
+fn import_error(span: Span, message: String) -> RuntimeError {
+    RuntimeError::ImportError(ParseError::ImportError(ErrorData::new(span, message)))
+}
@@ -1,9 +1,10 @@
 use crate::ast::prelude::Span;
 use crate::ast::source::SourceManager;
 use crate::import_paths::resolve_import_path;
-use crate::interpreter::prelude::{Environment, SharedInterner};
+use crate::interpreter::prelude::{Environment, FastHashMap, SharedInterner};
 use crate::interpreter::structs::{
-    Interpreter, Module, RuntimeClassDefinition, RuntimeError, RuntimeFieldData, Value,
+    Interpreter, LoadedModule, Module, RuntimeClassDefinition, RuntimeError, RuntimeFunction,
+    RuntimeSymbols, Value,
 };
 use crate::shared::SharedMut;
 use crate::traits::prelude::CoreOperations;
@@ -15,9 +16,12 @@ use string_interner::DefaultSymbol as Symbol;
 impl CoreOperations for Interpreter {
     fn new(interner: SharedInterner) -> Self {
         Interpreter {
+            symbols: RuntimeSymbols::new(&interner),
             std_classes: HashMap::new(),
-            builtins: HashMap::new(),
-            modules: HashMap::new(),
+            builtins: FastHashMap::default(),
+            modules: FastHashMap::default(),
+            loaded_modules: HashSet::new(),
+            module_loads: SharedMut::new(HashMap::new()),
             native_libraries: HashMap::new(),
             interner,
             environment: SharedMut::new(Environment::new()),
@@ -25,18 +29,27 @@ impl CoreOperations for Interpreter {
             method_depth: 0,
             heap: Arc::new(crate::interpreter::heap::ObjectHeap::default()),
             source_manager: SourceManager::new(),
+            script_args: Arc::new(Vec::new()),
+            profiler: None,
+            debugger: None,
+            options: Default::default(),
+            limits: None,
+            random: crate::builtins::random::new_random(None),
+            generator: None,
+            static_initializations: Vec::new(),
         }
     }
 
     fn load_start_module(&mut self, main_module: Module) -> &mut Self {
         self.modules.clear();
+        self.loaded_modules.clear();
+        self.module_loads = SharedMut::new(HashMap::new());
         self.register_module_tree(main_module);
         self
     }
 
     fn interpret(&mut self, module_id: Symbol) -> Result<(), RuntimeError> {
-        let mut visited = HashSet::new();
-        self.interpret_module(module_id, &mut visited)
+        self.interpret_module(module_id)
     }
 
     fn resolve_import_alias_symbol(
@@ -84,6 +97,7 @@ impl CoreOperations for Interpreter {
             Value::Text(_) => "Строка",
             Value::List(_) => "Список",
             Value::Array(_) => "Массив",
+            Value::Bytes(_) => "Байты",
             Value::Dict(_) => "Словарь",
             Value::Iterator(_) => "Итератор",
             Value::Thread(_) => "Поток",
@@ -154,6 +168,28 @@ impl Interpreter {
         false
     }
 
+    /// Registers a module without dropping modules loaded by earlier runs.
+    pub(crate) fn load_incremental_module(&mut self, module: Module) {
+        self.register_module_tree(module);
+    }
+
+    /// Swaps a loaded module for a fresh parse of it and runs the new body.
+    ///
+    /// Modules that were already loaded keep their state and are not executed again.
+    pub(crate) fn replace_module(&mut self, mut module: Module) -> Result<(), RuntimeError> {
+        let module_id = module.name;
+        let nested_modules = std::mem::take(&mut module.modules);
+        for nested_module in nested_modules.into_values() {
+            if !self.modules.contains_key(&nested_module.name) {
+                self.register_module_tree(nested_module);
+            }
+        }
+        self.modules.insert(module_id, module);
+        self.loaded_modules.remove(&module_id);
+        self.module_loads.write(|loads| loads.remove(&module_id));
+        self.interpret_module(module_id)
+    }
+
     fn register_module_tree(&mut self, mut module: Module) {
         let module_path = module.path.to_string_lossy().to_string();
         self.source_manager.load_file(module_path.as_str());
@@ -161,37 +197,24 @@ impl Interpreter {
         for nested_module in nested_modules.into_values() {
             self.register_module_tree(nested_module);
         }
+        self.loaded_modules.remove(&module.name);
+        self.module_loads.write(|loads| loads.remove(&module.name));
         self.modules.insert(module.name, module);
     }
 
-    fn interpret_module(
-        &mut self,
-        module_id: Symbol,
-        visited: &mut HashSet<Symbol>,
-    ) -> Result<(), RuntimeError> {
-        if !visited.insert(module_id) {
+    fn interpret_module(&mut self, module_id: Symbol) -> Result<(), RuntimeError> {
+        if !self.loaded_modules.insert(module_id) {
+            return Ok(());
+        }
+        if self.adopt_loaded_module(module_id) {
             return Ok(());
         }
 
-        let module = self.modules.get(&module_id).unwrap().clone();
-
-        for import in &module.imports {
-            if let Some(imported_module_id) =
-                self.resolve_import_alias_symbol(&module, import.item.alias)
-            {
-                self.interpret_module(imported_module_id, visited)?;
-
-                if let Some(imported_module) = self.modules.get(&imported_module_id).cloned() {
-                    if let Some(current_module) = self.modules.get_mut(&module.name) {
-                        for (name, value) in imported_module.globals {
-                            if !current_module.globals.contains_key(&name) {
-                                current_module.set_global(name, value);
-                            }
-                        }
-                    }
-                }
-            }
+        // Без `--eager-imports` импорт выполняется при первом обращении к его именам.
+        if self.options.eager_imports() {
+            self.load_imports(module_id, &mut HashSet::new())?;
         }
+        let module = self.modules.get(&module_id).unwrap().clone();
 
         let result = self.scoped_environment(Environment::new(), |interpreter| {
             if let Some(mod_entry) = interpreter.modules.get(&module.name) {
@@ -208,25 +231,22 @@ impl Interpreter {
                 if let Some(mod_entry) = interpreter.modules.get_mut(&module.name) {
                     mod_entry.set_global(*class_name, class_value);
                 }
+            }
 
-                let fields = class_def.read(|i| i.fields.clone());
-                for (name, (_, is_static, data)) in fields {
-                    if is_static {
-                        if let RuntimeFieldData::Expression(Some(expr_id)) = data {
-                            let val = Vm::evaluate_compiled(interpreter, module.name, expr_id)?;
-
-                            class_def.write(|c| {
-                                if let Some((_, _, target_data)) = c.fields.get_mut(&name) {
-                                    *target_data = RuntimeFieldData::Value(SharedMut::new(val));
-                                }
-                            });
-                        }
-                    }
-                }
+            // Статические поля вычисляются в порядке объявления классов, когда все
+            // классы модуля уже видны; ссылки вперёд инициализируются по требованию.
+            let mut classes = module.classes.values().cloned().collect::<Vec<_>>();
+            classes.sort_by_key(|class_def| class_def.read(|c| c.span.start));
+            for class_def in classes {
+                interpreter.initialize_static_fields(&class_def, module.name)?;
             }
 
             for (function_name, function_fn) in &module.functions {
-                let func_value = Value::Function(function_fn.clone());
+                let mut function_fn = function_fn.clone();
+                Arc::make_mut(&mut function_fn)
+                    .module
+                    .get_or_insert(module.name);
+                let func_value = Value::Function(Arc::new(RuntimeFunction::new(function_fn)));
                 interpreter
                     .environment
                     .write(|env| env.define(*function_name, func_value.clone()));
@@ -236,6 +256,10 @@ impl Interpreter {
             }
 
             for (builtin_name, builtin_fn) in &interpreter.builtins.clone() {
+                // Функции модуля перекрывают одноимённые встроенные.
+                if module.functions.contains_key(builtin_name) {
+                    continue;
+                }
                 interpreter
                     .environment
                     .write(|env| env.define(*builtin_name, Value::Builtin(builtin_fn.clone())));
@@ -265,10 +289,173 @@ impl Interpreter {
 
             Ok(())
         });
+        if result.is_ok() {
+            self.publish_loaded_module(module_id);
+        }
         self.heap.collect_cycles();
         result
     }
 
+    /// Takes over the globals of a module that a fork of this interpreter has
+    /// already run. Slot cells are shared, so the values are read from them.
+    fn adopt_loaded_module(&mut self, module_id: Symbol) -> bool {
+        let Some(loaded) = self
+            .module_loads
+            .read(|loads| loads.get(&module_id).cloned())
+        else {
+            return false;
+        };
+        let Some(module) = self.modules.get_mut(&module_id) else {
+            return false;
+        };
+        module.globals = loaded.globals;
+        for (slot, cell) in loaded.global_slots.iter().enumerate() {
+            if let (Some(cell), Some(name)) = (cell, module.hir.global_names.get(slot)) {
+                module.globals.insert(*name, cell.read(Value::clone));
+            }
+        }
+        module.global_slots = loaded.global_slots;
+        true
+    }
+
+    fn publish_loaded_module(&self, module_id: Symbol) {
+        let Some(module) = self.modules.get(&module_id) else {
+            return;
+        };
+        let loaded = LoadedModule {
+            globals: module.globals.clone(),
+            global_slots: module.global_slots.clone(),
+        };
+        self.module_loads.write(|loads| {
+            loads.entry(module_id).or_insert(loaded);
+        });
+    }
+
+    /// Runs an incremental input after all of its imports, so that the names
+    /// they declare are among the globals it leaves to the next input.
+    pub(crate) fn interpret_incremental(&mut self, module_id: Symbol) -> Result<(), RuntimeError> {
+        self.load_imports(module_id, &mut HashSet::new())?;
+        self.interpret_module(module_id)
+    }
+
+    /// Runs the imports of a module, and theirs, and copies their globals into
+    /// it, as `--eager-imports` does before every module.
+    fn load_imports(
+        &mut self,
+        module_id: Symbol,
+        visited: &mut HashSet<Symbol>,
+    ) -> Result<(), RuntimeError> {
+        if !visited.insert(module_id) {
+            return Ok(());
+        }
+        let Some(module) = self.modules.get(&module_id) else {
+            return Ok(());
+        };
+        let imported_modules = module
+            .imports
+            .iter()
+            .filter_map(|import| self.resolve_import_alias_symbol(module, import.item.alias))
+            .collect::<Vec<_>>();
+        for imported_module_id in imported_modules {
+            if !self.loaded_modules.contains(&imported_module_id) {
+                self.load_imports(imported_module_id, visited)?;
+                self.interpret_module(imported_module_id)?;
+            }
+
+            let imported_globals = self
+                .modules
+                .get(&imported_module_id)
+                .map(|imported| imported.globals.clone());
+            if let Some(imported_globals) = imported_globals {
+                if let Some(current_module) = self.modules.get_mut(&module_id) {
+                    for (name, value) in imported_globals {
+                        if !current_module.globals.contains_key(&name) {
+                            current_module.set_global(name, value);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs a registered module whose body has not run yet.
+    pub(crate) fn ensure_module_loaded(&mut self, module_id: Symbol) -> Result<(), RuntimeError> {
+        if self.loaded_modules.contains(&module_id) || !self.modules.contains_key(&module_id) {
+            return Ok(());
+        }
+        self.interpret_module(module_id)
+    }
+
+    /// Like [`Interpreter::resolve_module_member_value`], but first runs the
+    /// module and, if the member comes from one of its imports, that import.
+    pub(crate) fn load_module_member(
+        &mut self,
+        module_id: Symbol,
+        member: Symbol,
+    ) -> Result<Option<(Symbol, Value)>, RuntimeError> {
+        self.ensure_module_loaded(module_id)?;
+        if let Some(owner) = self.module_declaring(module_id, member, &mut HashSet::new()) {
+            self.ensure_module_loaded(owner)?;
+        }
+        Ok(self.resolve_module_member_value(module_id, member))
+    }
+
+    /// A name the module itself does not define, taken from the import that
+    /// declares it. The value is copied into the module's globals, as running
+    /// the imports up front would have done.
+    pub(crate) fn load_imported_global(
+        &mut self,
+        module_id: Symbol,
+        name: Symbol,
+    ) -> Result<Option<(Symbol, Value)>, RuntimeError> {
+        let Some(module) = self.modules.get(&module_id) else {
+            return Ok(None);
+        };
+        let imported_modules = module
+            .imports
+            .iter()
+            .filter_map(|import| self.resolve_import_alias_symbol(module, import.item.alias))
+            .collect::<Vec<_>>();
+        for imported_module_id in imported_modules {
+            if let Some(found) = self.load_module_member(imported_module_id, name)? {
+                if let Some(module) = self.modules.get_mut(&module_id) {
+                    module.set_global(name, found.1.clone());
+                }
+                return Ok(Some(found));
+            }
+        }
+        Ok(None)
+    }
+
+    /// The module that declares `member`, searching `module_id` and then its
+    /// imports without running any of them.
+    fn module_declaring(
+        &self,
+        module_id: Symbol,
+        member: Symbol,
+        visited: &mut HashSet<Symbol>,
+    ) -> Option<Symbol> {
+        if !visited.insert(module_id) {
+            return None;
+        }
+        let module = self.modules.get(&module_id)?;
+        if module.functions.contains_key(&member)
+            || module.classes.contains_key(&member)
+            || module.globals.contains_key(&member)
+            || module.hir.global_names.contains(&member)
+        {
+            return Some(module_id);
+        }
+        module
+            .imports
+            .iter()
+            .filter_map(|import| self.resolve_import_alias_symbol(module, import.item.alias))
+            .find_map(|imported_module_id| {
+                self.module_declaring(imported_module_id, member, visited)
+            })
+    }
+
     pub(crate) fn resolve_module_member_value(
         &self,
         module_id: Symbol,
@@ -291,7 +478,16 @@ impl Interpreter {
         let module = self.modules.get(&module_id)?;
 
         if let Some(function) = module.functions.get(&member) {
-            return Some((module_id, Value::Function(function.clone())));
+            // Выполненный модуль хранит функцию уже привязанной к себе.
+            if let Some(value @ Value::Function(_)) = module.globals.get(&member) {
+                return Some((module_id, value.clone()));
+            }
+            let mut function = function.clone();
+            Arc::make_mut(&mut function).module.get_or_insert(module_id);
+            return Some((
+                module_id,
+                Value::Function(Arc::new(RuntimeFunction::new(function))),
+            ));
         }
 
         if let Some(class) = module.classes.get(&member) {
@@ -319,6 +515,8 @@ impl Interpreter {
             std_classes: self.std_classes.clone(),
             builtins: self.builtins.clone(),
             modules: self.modules.clone(),
+            loaded_modules: self.loaded_modules.clone(),
+            module_loads: self.module_loads.clone(),
             native_libraries: self.native_libraries.clone(),
             interner: self.interner.clone(),
             environment: self.environment.clone(),
@@ -326,6 +524,16 @@ impl Interpreter {
             method_depth: self.method_depth,
             heap: self.heap.clone(),
             source_manager: SourceManager::new(),
+            script_args: self.script_args.clone(),
+            profiler: self.profiler.clone(),
+            // Потоки не останавливаются в отладчике: им некому отвечать.
+            debugger: None,
+            options: self.options.clone(),
+            limits: self.limits.clone(),
+            random: self.random.clone(),
+            generator: None,
+            static_initializations: Vec::new(),
+            symbols: self.symbols,
         }
     }
 
@@ -1,8 +1,12 @@
+use crate::ast::prelude::FunctionDefinition;
 use crate::ast::prelude::{ErrorData, Span};
-use crate::interpreter::prelude::{Environment, Interpreter, RuntimeError, Value, VariableSlot};
+use crate::interpreter::prelude::{
+    Environment, FastHashMap, Interpreter, RuntimeError, RuntimeFunction, Value, VariableSlot,
+};
 use crate::shared::SharedMut;
 use crate::{bail_runtime, runtime_error};
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
+use std::sync::Arc;
 use string_interner::DefaultSymbol as Symbol;
 
 use VariableSlot::{GlobalSlot, LocalSlot, UpvalueSlot};
@@ -12,7 +16,7 @@ impl Environment {
         Environment {
             slots: Vec::new(),
             upvalues: Vec::new(),
-            bindings: HashMap::new(),
+            bindings: FastHashMap::default(),
             constants: HashSet::new(),
             parent: None,
             is_function: false,
@@ -31,7 +35,7 @@ impl Environment {
         Environment {
             slots: Vec::new(),
             upvalues: Vec::new(),
-            bindings: HashMap::new(),
+            bindings: FastHashMap::default(),
             constants: HashSet::new(),
             parent: Some(parent),
             is_function,
@@ -49,6 +53,18 @@ impl Environment {
         self.bindings.insert(name, slot);
     }
 
+    /// Binds `name` to a cell shared with the frame that created the function.
+    pub(crate) fn capture(&mut self, name: Symbol, cell: SharedMut<Value>) {
+        let slot = UpvalueSlot(self.upvalues.len() as u32);
+        self.upvalues.push(cell);
+        self.bindings.insert(name, slot);
+    }
+
+    fn cell(&self, name: Symbol) -> Option<SharedMut<Value>> {
+        let slot = self.bindings.get(&name).copied()?;
+        self.slot_value(slot).cloned()
+    }
+
     pub(crate) fn define_const(&mut self, name: Symbol, value: Value) {
         self.define(name, value);
         if let Some(slot) = self.bindings.get(&name).copied() {
@@ -151,6 +167,44 @@ impl Interpreter {
         self.scoped_environment(environment, execute)
     }
 
+    /// Creates a function value that shares the cells of `captures` with the
+    /// current frame. A nested function captures its own name before it is
+    /// defined, so a missing cell is created empty and filled by the definition.
+    pub(crate) fn make_function(
+        &self,
+        definition: FunctionDefinition,
+        captures: &[Symbol],
+    ) -> Arc<RuntimeFunction> {
+        let upvalues = captures
+            .iter()
+            .map(|name| (*name, self.capture_cell(*name)))
+            .collect();
+        Arc::new(RuntimeFunction {
+            definition: Arc::new(definition),
+            upvalues,
+        })
+    }
+
+    fn capture_cell(&self, name: Symbol) -> SharedMut<Value> {
+        let mut search = self.environment.clone();
+        loop {
+            if let Some(cell) = search.read(|environment| environment.cell(name)) {
+                return cell;
+            }
+            if search.read(|environment| environment.is_function) {
+                break;
+            }
+            let Some(parent) = search.read(|environment| environment.parent.clone()) else {
+                break;
+            };
+            search = parent;
+        }
+        self.environment.write(|environment| {
+            environment.define(name, Value::Empty);
+            environment.cell(name).expect("defined variable has a cell")
+        })
+    }
+
     pub(crate) fn scoped_method_context<R>(
         &mut self,
         execute: impl FnOnce(&mut Self) -> Result<R, RuntimeError>,
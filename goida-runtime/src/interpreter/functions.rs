@@ -1,26 +1,30 @@
 use crate::ast::prelude::{ErrorData, FunctionDefinition, Parameter, Span};
-use crate::interpreter::structs::{CallArgValue, Interpreter, RuntimeError, Value};
+use crate::interpreter::structs::{
+    BuiltinFn, CallArgValue, Interpreter, RuntimeError, RuntimeFunction, Value,
+};
 use crate::traits::prelude::{CoreOperations, InterpreterFunctions};
 use crate::vm::Vm;
 use crate::{bail_runtime, runtime_error};
-use std::sync::Arc;
 use string_interner::DefaultSymbol as Symbol;
 
 impl InterpreterFunctions for Interpreter {
     fn call_function(
         &mut self,
-        function: Arc<FunctionDefinition>,
+        function: &RuntimeFunction,
         arguments: Vec<CallArgValue>,
         current_module_id: Symbol,
         span: Span,
     ) -> Result<Value, RuntimeError> {
-        let function_name = self
-            .modules
-            .get(&current_module_id)
-            .and_then(|m| m.arena.resolve_symbol(&self.interner, function.name))
-            .unwrap_or_else(|| "неизвестно".to_string());
+        // Тело функции лежит в байткоде модуля, где она объявлена.
+        let current_module_id = function.module.unwrap_or(current_module_id);
+        let _timer = self.profile_timer(|| {
+            format!(
+                "функция {}",
+                self.definition_name(current_module_id, function.name)
+            )
+        });
         let final_arguments =
-            self.bind_call_arguments(&function, arguments, current_module_id, span, "Функция")?;
+            self.bind_call_arguments(function, arguments, current_module_id, span, "Функция")?;
 
         let chunk = self
             .modules
@@ -29,23 +33,43 @@ impl InterpreterFunctions for Interpreter {
             .cloned();
         let execution_result = self.scoped_child_function_environment(
             |local_env| {
-                for (param, arg_value) in function.params.iter().zip(final_arguments.iter()) {
-                    local_env.define(param.name, arg_value.clone());
+                for (name, cell) in &function.upvalues {
+                    local_env.capture(*name, cell.clone());
+                }
+                for (param, arg_value) in function.params.iter().zip(final_arguments) {
+                    local_env.define(param.name, arg_value);
                 }
             },
             |interpreter| {
                 let chunk = chunk.as_ref().ok_or_else(|| {
                     runtime_error!(InvalidOperation, span, "Compiled function body is missing")
                 })?;
-                Vm::new(interpreter, current_module_id).run(chunk)
+                if chunk.generator {
+                    let frame_name = format!(
+                        "функция {}",
+                        interpreter.definition_name(current_module_id, function.name)
+                    );
+                    return Ok(Value::Generator(interpreter.start_generator(
+                        chunk.clone(),
+                        current_module_id,
+                        frame_name,
+                        span,
+                    )));
+                }
+                Vm::new(interpreter, current_module_id)
+                    .run(chunk)
+                    .map(|()| Value::Empty)
             },
         );
 
         match execution_result {
-            Ok(()) => Ok(Value::Empty),
+            Ok(value) => Ok(value),
             Err(RuntimeError::Return(_, val)) => Ok(val),
             Err(mut e) => {
-                let frame_name = format!("функция {}", function_name);
+                let frame_name = format!(
+                    "функция {}",
+                    self.definition_name(current_module_id, function.name)
+                );
                 e.add_stack_frame(frame_name, span);
                 Err(e)
             }
@@ -59,18 +83,17 @@ impl InterpreterFunctions for Interpreter {
         current_module_id: Symbol,
         span: Span,
     ) -> Result<Value, RuntimeError> {
-        let name_str = self.resolve_symbol(name).unwrap();
+        if let Some(result) = self.call_module_builtin(name, &arguments, current_module_id, span) {
+            return result;
+        }
 
         if let Some(val) = self.environment.read(|env| env.get(&name)) {
             match val {
                 Value::Function(func) => {
-                    return self.call_function(func.clone(), arguments, current_module_id, span);
+                    return self.call_function(&func, arguments, current_module_id, span);
                 }
                 Value::Builtin(builtin) => {
-                    return builtin(self, arguments, span).map_err(|mut err| {
-                        err.add_stack_frame(format!("функция {}", name_str), span);
-                        err
-                    });
+                    return self.call_named_builtin(&builtin, name, arguments, span);
                 }
                 _ => {}
             }
@@ -81,59 +104,103 @@ impl InterpreterFunctions for Interpreter {
             .get(&current_module_id)
             .ok_or_else(|| runtime_error!(InvalidOperation, span, "Текущий модуль не найден"))?;
 
-        if let Some(dot_index) = name_str.find('.') {
-            let mod_part = &name_str[..dot_index];
-            let func_part = &name_str[dot_index + 1..];
-
+        let qualified = self.interner.read(|i| {
+            i.resolve(name)
+                .and_then(|name| name.split_once('.'))
+                .map(|(module, member)| (module.to_string(), member.to_string()))
+        });
+        if let Some((mod_part, func_part)) = qualified {
             let mod_sym = self.interner.write(|i| i.get_or_intern(mod_part));
             let func_sym = self.interner.write(|i| i.get_or_intern(func_part));
 
             let target_module_symbol = self.resolve_import_alias_symbol(current_module, mod_sym);
 
-            if let Some((definition_module_id, value)) =
-                target_module_symbol.and_then(|sym| self.resolve_module_member_value(sym, func_sym))
-            {
+            let member = match target_module_symbol {
+                Some(module_id) => self.load_module_member(module_id, func_sym)?,
+                None => None,
+            };
+            if let Some((definition_module_id, value)) = member {
                 return match value {
                     Value::Function(func) => {
-                        self.call_function(func.clone(), arguments, definition_module_id, span)
+                        self.call_function(&func, arguments, definition_module_id, span)
                     }
-                    Value::Builtin(builtin) => builtin(self, arguments, span).map_err(|mut err| {
-                        err.add_stack_frame(format!("функция {}", name_str), span);
-                        err
-                    }),
-                    _ => bail_runtime!(UndefinedFunction, span, "{}", name_str),
+                    Value::Builtin(builtin) => {
+                        self.call_named_builtin(&builtin, name, arguments, span)
+                    }
+                    _ => self.undefined_function(name, span),
                 };
             }
-            return bail_runtime!(UndefinedFunction, span, "{}", name_str);
+            return self.undefined_function(name, span);
         }
 
         if let Some(function) = current_module.functions.get(&name) {
-            let func_clone = function.clone();
-            return self.call_function(func_clone, arguments, current_module_id, span);
+            let function = RuntimeFunction::new(function.clone());
+            return self.call_function(&function, arguments, current_module_id, span);
         }
 
-        if let Some(Value::Function(func)) = current_module.globals.get(&name) {
-            return self.call_function(func.clone(), arguments, current_module_id, span);
-        }
-        if let Some(Value::Builtin(builtin)) = current_module.globals.get(&name) {
-            return builtin(self, arguments, span).map_err(|mut err| {
-                err.add_stack_frame(format!("функция {}", name_str), span);
-                err
-            });
+        match current_module.globals.get(&name) {
+            Some(Value::Function(func)) => {
+                let func = func.clone();
+                return self.call_function(&func, arguments, current_module_id, span);
+            }
+            Some(Value::Builtin(builtin)) => {
+                let builtin = builtin.clone();
+                return self.call_named_builtin(&builtin, name, arguments, span);
+            }
+            _ => {}
         }
 
         if let Some(builtin_fn) = self.builtins.get(&name) {
             return builtin_fn(self, arguments, span).map_err(|mut err| {
-                err.add_stack_frame(format!("функция {}", name_str), span);
+                err.add_stack_frame(format!("функция {}", self.name_of(name)), span);
                 err
             });
         }
 
-        bail_runtime!(UndefinedFunction, span, "{}", name_str)
+        match self.load_imported_global(current_module_id, name)? {
+            Some((_, Value::Function(func))) => {
+                self.call_function(&func, arguments, current_module_id, span)
+            }
+            Some((_, Value::Builtin(builtin))) => {
+                self.call_named_builtin(&builtin, name, arguments, span)
+            }
+            _ => self.undefined_function(name, span),
+        }
     }
 }
 
 impl Interpreter {
+    /// Name of a function or method as written in the module that declares it;
+    /// resolved only when a profiler frame or an error needs it.
+    pub(crate) fn definition_name(&self, module_id: Symbol, name: Symbol) -> String {
+        self.modules
+            .get(&module_id)
+            .and_then(|m| m.arena.resolve_symbol(&self.interner, name))
+            .unwrap_or_else(|| "неизвестно".to_string())
+    }
+
+    fn name_of(&self, name: Symbol) -> String {
+        self.resolve_symbol(name).unwrap_or_default()
+    }
+
+    fn undefined_function(&self, name: Symbol, span: Span) -> Result<Value, RuntimeError> {
+        bail_runtime!(UndefinedFunction, span, "{}", self.name_of(name))
+    }
+
+    fn call_named_builtin(
+        &mut self,
+        builtin: &BuiltinFn,
+        name: Symbol,
+        arguments: Vec<CallArgValue>,
+        span: Span,
+    ) -> Result<Value, RuntimeError> {
+        let _timer = self.profile_timer(|| format!("встроенная {}", self.name_of(name)));
+        builtin(self, arguments, span).map_err(|mut err| {
+            err.add_stack_frame(format!("функция {}", self.name_of(name)), span);
+            err
+        })
+    }
+
     pub(crate) fn bind_call_arguments(
         &mut self,
         function: &FunctionDefinition,
@@ -142,11 +209,13 @@ impl Interpreter {
         span: Span,
         kind_label: &str,
     ) -> Result<Vec<Value>, RuntimeError> {
-        let function_name = self
-            .modules
-            .get(&current_module_id)
-            .and_then(|m| m.arena.resolve_symbol(&self.interner, function.name))
-            .unwrap_or_else(|| "неизвестно".to_string());
+        let function_symbol = function.name;
+        let name_interner = self.interner.clone();
+        let function_name = move || {
+            name_interner
+                .read(|i| i.resolve(function_symbol).map(|s| s.to_string()))
+                .unwrap_or_else(|| "неизвестно".to_string())
+        };
 
         let interner = self.interner.clone();
         let resolve_symbol = move |symbol| {
@@ -165,7 +234,7 @@ impl Interpreter {
                     "Аргумент '{}' для {} {} не передан",
                     param_name,
                     kind_label,
-                    function_name
+                    function_name()
                 )
             }
         };
@@ -186,7 +255,7 @@ impl Interpreter {
         arguments: Vec<CallArgValue>,
         span: Span,
         kind_label: &str,
-        callable_name: &str,
+        callable_name: impl Fn() -> String,
         mut resolve_symbol: impl FnMut(Symbol) -> String,
         missing: &mut impl FnMut(&Parameter) -> Result<Value, RuntimeError>,
     ) -> Result<Vec<Value>, RuntimeError> {
@@ -217,7 +286,7 @@ impl Interpreter {
                                 "Неизвестный именованный аргумент '{}' для {} {}",
                                 name_str,
                                 kind_label,
-                                callable_name
+                                callable_name()
                             );
                         }
                     };
@@ -230,7 +299,7 @@ impl Interpreter {
                             "Аргумент '{}' для {} {} передан несколько раз",
                             name_str,
                             kind_label,
-                            callable_name
+                            callable_name()
                         );
                     }
 
@@ -250,7 +319,7 @@ impl Interpreter {
                             span,
                             "{} {} ожидает {} аргументов, получено {}",
                             kind_label,
-                            callable_name,
+                            callable_name(),
                             total_params,
                             positional_index + 1
                         );
@@ -0,0 +1,71 @@
+use crate::ast::prelude::Span;
+use crate::bytecode::Chunk;
+use crate::interpreter::prelude::{
+    GeneratorChannel, GeneratorSink, Interpreter, RuntimeError, RuntimeGenerator, Value,
+};
+use crate::vm::Vm;
+use std::sync::mpsc::sync_channel;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread;
+use string_interner::DefaultSymbol as Symbol;
+
+impl Interpreter {
+    /// Создаём генератор из тела функции, окружение которой уже подготовлено.
+    /// Тело выполняется в отдельном потоке и стоит до первого запроса значения.
+    pub(crate) fn start_generator(
+        &self,
+        chunk: Arc<Chunk>,
+        module: Symbol,
+        frame: String,
+        span: Span,
+    ) -> RuntimeGenerator {
+        let (resume_sender, resume) = sync_channel(0);
+        let (values, values_receiver) = sync_channel(0);
+        let mut interpreter = self.fork_for_thread();
+        thread::spawn(move || {
+            // Генератор выбросили, не запросив ни одного значения.
+            if resume.recv().is_err() {
+                return;
+            }
+            interpreter.generator = Some(Mutex::new(GeneratorSink {
+                values: values.clone(),
+                resume,
+            }));
+            let result = match Vm::new(&mut interpreter, module).run(&chunk) {
+                Err(RuntimeError::Return(..)) => Ok(()),
+                result => result,
+            }
+            .and_then(|()| interpreter.join_background_threads(module, span));
+            if let Err(mut error) = result {
+                error.add_stack_frame(frame, span);
+                let _ = values.send(Err(error));
+            }
+        });
+        RuntimeGenerator {
+            channel: Arc::new(Mutex::new(GeneratorChannel {
+                resume: Some(resume_sender),
+                values: values_receiver,
+            })),
+        }
+    }
+}
+
+impl RuntimeGenerator {
+    /// Выполняем тело до следующего `выдать`; `None`, когда тело завершилось.
+    pub(crate) fn resume(&self) -> Option<Result<Value, RuntimeError>> {
+        let mut channel = self.channel.lock().unwrap_or_else(PoisonError::into_inner);
+        let step = match channel.resume.as_ref()?.send(()) {
+            Ok(()) => channel.values.recv().ok(),
+            Err(_) => None,
+        };
+        if !matches!(step, Some(Ok(_))) {
+            channel.resume = None;
+        }
+        step
+    }
+
+    /// Забираем все оставшиеся значения генератора.
+    pub(crate) fn collect(&self) -> Result<Vec<Value>, RuntimeError> {
+        std::iter::from_fn(|| self.resume()).collect()
+    }
+}
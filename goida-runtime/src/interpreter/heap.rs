@@ -1,8 +1,10 @@
-use crate::interpreter::structs::{ClassInstance, Value};
+use crate::interpreter::structs::{ClassInstance, DictKey, FastHashMap, Value};
 use crate::shared::SharedMut;
 use goida_model::WeakSharedMut;
 use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex, RwLock, Weak};
+use string_interner::DefaultSymbol as Symbol;
 
 pub type ObjectId = u64;
 
@@ -12,6 +14,9 @@ const COLLECTION_GROWTH_FACTOR: usize = 2;
 #[derive(Debug)]
 pub struct ObjectHeap {
     state: Mutex<HeapState>,
+    /// Bytes in use as of the last measurement plus those reserved since, for
+    /// the memory limit.
+    allocated: AtomicUsize,
 }
 
 #[derive(Debug)]
@@ -31,7 +36,7 @@ struct HeapEntry {
 enum WeakObject {
     Object(WeakSharedMut<ClassInstance>),
     List(WeakSharedMut<Vec<Value>>),
-    Dict(WeakSharedMut<HashMap<String, Value>>),
+    Dict(WeakSharedMut<FastHashMap<DictKey, Value>>),
     Mutex(Weak<Mutex<Value>>),
     RwLock(Weak<RwLock<Value>>),
 }
@@ -40,7 +45,7 @@ enum WeakObject {
 enum LiveObject {
     Object(SharedMut<ClassInstance>),
     List(SharedMut<Vec<Value>>),
-    Dict(SharedMut<HashMap<String, Value>>),
+    Dict(SharedMut<FastHashMap<DictKey, Value>>),
     Mutex(Arc<Mutex<Value>>),
     RwLock(Arc<RwLock<Value>>),
 }
@@ -161,6 +166,39 @@ impl ObjectHeap {
         }
     }
 
+    /// Rough number of bytes held by live tracked containers, texts inside them included.
+    pub fn approximate_size(&self) -> usize {
+        let state = self
+            .state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        state
+            .objects
+            .values()
+            .filter_map(|entry| entry.object.upgrade())
+            .map(|object| object.shallow_size())
+            .sum()
+    }
+
+    /// Measures live containers and restarts the allocation count from there.
+    pub(crate) fn measure(&self) -> usize {
+        let size = self.approximate_size();
+        self.allocated.store(size, Ordering::Relaxed);
+        size
+    }
+
+    /// Counts `bytes` as allocated if the total stays within `limit`. Before
+    /// refusing, the heap is measured again, since values counted earlier may
+    /// have been freed since.
+    pub(crate) fn reserve(&self, bytes: usize, limit: usize) -> bool {
+        let fits = |used: usize| used.checked_add(bytes).is_some_and(|total| total <= limit);
+        if !fits(self.allocated.load(Ordering::Relaxed)) && !fits(self.measure()) {
+            return false;
+        }
+        self.allocated.fetch_add(bytes, Ordering::Relaxed);
+        true
+    }
+
     #[cfg(test)]
     fn tracked_count(&self) -> usize {
         self.state
@@ -179,6 +217,7 @@ impl Default for ObjectHeap {
                 collection_threshold: INITIAL_COLLECTION_THRESHOLD,
                 objects: HashMap::new(),
             }),
+            allocated: AtomicUsize::new(0),
         }
     }
 }
@@ -238,11 +277,45 @@ impl LiveObject {
         }
     }
 
+    /// The container itself and the texts it holds directly; nested containers count separately.
+    fn shallow_size(&self) -> usize {
+        let value_size = |value: &Value| {
+            std::mem::size_of::<Value>()
+                + match value {
+                    Value::Text(text) => text.len(),
+                    _ => 0,
+                }
+        };
+        match self {
+            Self::Object(value) => value.read(|value| {
+                value
+                    .field_values
+                    .values()
+                    .map(|field| std::mem::size_of::<Symbol>() + value_size(field))
+                    .sum()
+            }),
+            Self::List(value) => value.read(|value| value.iter().map(value_size).sum()),
+            Self::Dict(value) => value.read(|value| {
+                value
+                    .iter()
+                    .map(|(key, item)| {
+                        let key_size = match key {
+                            DictKey::Text(text) => text.len(),
+                            DictKey::Number(_) | DictKey::Boolean(_) => 0,
+                        };
+                        std::mem::size_of::<DictKey>() + key_size + value_size(item)
+                    })
+                    .sum()
+            }),
+            Self::Mutex(_) | Self::RwLock(_) => std::mem::size_of::<Value>(),
+        }
+    }
+
     fn clear(&self) {
         match self {
             Self::Object(value) => value.write(|value| value.field_values.clear()),
             Self::List(value) => value.write(Vec::clear),
-            Self::Dict(value) => value.write(HashMap::clear),
+            Self::Dict(value) => value.write(FastHashMap::clear),
             Self::Mutex(value) => {
                 *value
                     .lock()
@@ -406,8 +479,8 @@ mod tests {
     #[test]
     fn collects_mutually_referencing_dicts() {
         let heap = ObjectHeap::default();
-        let left = Value::Dict(SharedMut::new(HashMap::new()));
-        let right = Value::Dict(SharedMut::new(HashMap::new()));
+        let left = Value::Dict(SharedMut::new(FastHashMap::default()));
+        let right = Value::Dict(SharedMut::new(FastHashMap::default()));
         heap.adopt(&left);
         heap.adopt(&right);
         let Value::Dict(left_dict) = &left else {
@@ -0,0 +1,127 @@
+use crate::ast::prelude::{Span, Visibility};
+use crate::interpreter::prelude::{
+    BuiltinFn, CallArgValue, Interpreter, RuntimeClassDefinition, RuntimeError, Value,
+};
+use crate::shared::SharedMut;
+use crate::traits::prelude::CoreOperations;
+use std::sync::Arc;
+
+/// Rust function an embedding application exposes to scripts.
+pub type HostFunction =
+    dyn Fn(&Interpreter, Vec<Value>) -> Result<Value, RuntimeError> + Send + Sync;
+
+fn host_builtin(
+    function: impl Fn(&Interpreter, Vec<Value>) -> Result<Value, RuntimeError> + Send + Sync + 'static,
+) -> BuiltinFn {
+    BuiltinFn(Arc::new(
+        move |interpreter: &Interpreter, arguments: Vec<CallArgValue>, span: Span| {
+            for argument in &arguments {
+                interpreter.adopt_value(&argument.value);
+            }
+            let values = arguments
+                .into_iter()
+                .map(|argument| argument.value)
+                .collect();
+            // Ошибки хоста без места в коде указывают на сам вызов.
+            let result = function(interpreter, values).map_err(|mut err| {
+                if let Some(data) = err.error_data_mut() {
+                    if data.location == Span::default() {
+                        data.location = span;
+                    }
+                }
+                err
+            });
+            interpreter.manage_result(result)
+        },
+    ))
+}
+
+/// Class defined in Rust; instance methods get the object first, static ones only the arguments.
+pub struct HostClass {
+    name: String,
+    constructor: Option<Arc<HostFunction>>,
+    methods: Vec<(String, bool, Arc<HostFunction>)>,
+}
+
+impl HostClass {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            constructor: None,
+            methods: Vec::new(),
+        }
+    }
+
+    /// Runs on `новый`, with the new object followed by the call arguments.
+    pub fn constructor(
+        mut self,
+        function: impl Fn(&Interpreter, Vec<Value>) -> Result<Value, RuntimeError>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        self.constructor = Some(Arc::new(function));
+        self
+    }
+
+    pub fn method(
+        mut self,
+        name: &str,
+        function: impl Fn(&Interpreter, Vec<Value>) -> Result<Value, RuntimeError>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        self.methods
+            .push((name.to_string(), false, Arc::new(function)));
+        self
+    }
+
+    pub fn static_method(
+        mut self,
+        name: &str,
+        function: impl Fn(&Interpreter, Vec<Value>) -> Result<Value, RuntimeError>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        self.methods
+            .push((name.to_string(), true, Arc::new(function)));
+        self
+    }
+}
+
+impl Interpreter {
+    /// Makes `function` callable from scripts as `name`, replacing a builtin of that name.
+    pub fn register_builtin(
+        &mut self,
+        name: &str,
+        function: impl Fn(&Interpreter, Vec<Value>) -> Result<Value, RuntimeError>
+            + Send
+            + Sync
+            + 'static,
+    ) {
+        let symbol = self.intern_string(name);
+        self.builtins.insert(symbol, host_builtin(function));
+    }
+
+    /// Makes `class` available to scripts like the standard classes.
+    pub fn register_class(&mut self, class: HostClass) {
+        let symbol = self.intern_string(&class.name);
+        let mut definition = RuntimeClassDefinition::new(symbol, Span::default());
+        if let Some(constructor) = class.constructor {
+            definition.set_constructor(host_builtin(move |interpreter, arguments| {
+                constructor(interpreter, arguments)
+            }));
+        }
+        for (name, is_static, method) in class.methods {
+            definition.add_method(
+                self.intern_string(&name),
+                Visibility::Public,
+                is_static,
+                host_builtin(move |interpreter, arguments| method(interpreter, arguments)),
+            );
+        }
+        self.std_classes.insert(symbol, SharedMut::new(definition));
+    }
+}
@@ -1,13 +1,19 @@
 pub mod classes;
+pub mod debugger;
+mod dynamic;
 pub mod engine;
 pub mod environment;
 pub mod functions;
+pub mod generator;
 pub mod heap;
+pub mod host;
 pub mod native;
 mod native_support;
 mod native_types;
 pub mod objects;
 pub mod operations;
+pub mod options;
 pub mod prelude;
+pub mod profiler;
 pub mod runtime;
 pub mod structs;
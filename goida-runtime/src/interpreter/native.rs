@@ -2,6 +2,7 @@ use crate::ast::prelude::{ErrorData, NativeLibraryDefinition, Span};
 use crate::interpreter::native_support::{
     load_native_library, native_library_path_candidates, NativeFfiArgValue, NativeFfiKind,
 };
+use crate::interpreter::options::Capability;
 use crate::interpreter::prelude::{
     BuiltinFn, CallArgValue, Interpreter, LoadedNativeLibrary, NativeFunctionBinding,
     NativeGlobalBinding, RuntimeError, Value,
@@ -22,6 +23,8 @@ impl Interpreter {
         definition: NativeLibraryDefinition,
         current_module_id: Symbol,
     ) -> Result<(), RuntimeError> {
+        // Нативная библиотека может всё, поэтому в песочнице она считается системной функцией.
+        self.require(Capability::System, definition.span)?;
         let path =
             self.resolve_native_library_path(current_module_id, definition.path, definition.span)?;
         self.ensure_native_library_loaded(&path, definition.span)?;
@@ -127,7 +130,7 @@ impl Interpreter {
             arguments,
             span,
             "native",
-            &function_name,
+            || function_name.clone(),
             resolve_symbol,
             &mut missing,
         )?;
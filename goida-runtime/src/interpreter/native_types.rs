@@ -66,10 +66,10 @@ impl Interpreter {
                 Value::Pointer(address) => Ok(NativeFfiArgValue::Pointer(address as *mut c_void)),
                 Value::Empty => Ok(NativeFfiArgValue::Pointer(std::ptr::null_mut())),
                 Value::Text(s) => {
-                    let mut s_with_zero = s.clone();
+                    let mut s_with_zero = s.to_string();
                     s_with_zero.push('\0');
 
-                    let managed_value = Value::Text(s_with_zero);
+                    let managed_value = Value::Text(s_with_zero.into());
                     let boxed = Box::new(managed_value);
 
                     let ptr = if let Value::Text(ref inner_s) = *boxed {
@@ -1,31 +1,42 @@
 use crate::ast::prelude::{ErrorData, Span};
 use crate::interpreter::structs::{
-    CallArgValue, Interpreter, Module, RuntimeClassDefinition, RuntimeError, Value,
+    CallArgValue, Interpreter, RuntimeClassDefinition, RuntimeError, Value,
 };
-use crate::runtime_error;
 use crate::shared::SharedMut;
 use crate::traits::prelude::{CoreOperations, InterpreterClasses};
+use crate::{bail_runtime, runtime_error};
 use string_interner::DefaultSymbol as Symbol;
 
 impl Interpreter {
-    fn resolve_module_path(&self, current_module: &Module, parts: &[&str]) -> Option<Symbol> {
-        let (first, rest) = parts.split_first()?;
+    fn resolve_module_path(
+        &mut self,
+        current_module_id: Symbol,
+        parts: &[&str],
+    ) -> Result<Option<Symbol>, RuntimeError> {
+        let Some((first, rest)) = parts.split_first() else {
+            return Ok(None);
+        };
         let first_symbol = self.intern_string(first);
-        let mut module_id = self.resolve_import_alias_symbol(current_module, first_symbol)?;
+        let Some(mut module_id) = self
+            .modules
+            .get(&current_module_id)
+            .and_then(|current| self.resolve_import_alias_symbol(current, first_symbol))
+        else {
+            return Ok(None);
+        };
 
         for part in rest {
             let part_symbol = self.intern_string(part);
-            let (_, value) = self.resolve_module_member_value(module_id, part_symbol)?;
-            match value {
-                Value::Module(next_module_id) => module_id = next_module_id,
-                _ => return None,
+            match self.load_module_member(module_id, part_symbol)? {
+                Some((_, Value::Module(next_module_id))) => module_id = next_module_id,
+                _ => return Ok(None),
             }
         }
-        Some(module_id)
+        Ok(Some(module_id))
     }
 
     pub(crate) fn resolve_class_for_creation(
-        &self,
+        &mut self,
         class_name: Symbol,
         current_module_id: Symbol,
         span: Span,
@@ -39,12 +50,13 @@ impl Interpreter {
         if parts.len() > 1 {
             let class_name = parts.last().copied().unwrap_or_default();
             let module_parts = &parts[..parts.len() - 1];
-            let current = self.modules.get(&current_module_id).ok_or_else(|| {
-                runtime_error!(InvalidOperation, span, "Current module is missing")
-            })?;
+            if !self.modules.contains_key(&current_module_id) {
+                return bail_runtime!(InvalidOperation, span, "Current module is missing");
+            }
             let module_id = self
-                .resolve_module_path(current, module_parts)
+                .resolve_module_path(current_module_id, module_parts)?
                 .ok_or_else(|| runtime_error!(InvalidOperation, span, "Module is missing"))?;
+            self.ensure_module_loaded(module_id)?;
             let module = self
                 .modules
                 .get(&module_id)
@@ -58,13 +70,19 @@ impl Interpreter {
             return Ok((class, module.name));
         }
 
-        self.modules
+        let class = self
+            .modules
             .get(&current_module_id)
             .and_then(|module| module.classes.get(&class_name))
             .or_else(|| self.std_classes.get(&class_name))
-            .cloned()
-            .map(|class| (class, current_module_id))
-            .ok_or_else(|| runtime_error!(UndefinedVariable, span, "Class '{}' is missing", name))
+            .cloned();
+        if let Some(class) = class {
+            return Ok((class, current_module_id));
+        }
+        match self.load_imported_global(current_module_id, class_name)? {
+            Some((module_id, Value::Class(class))) => Ok((class, module_id)),
+            _ => bail_runtime!(UndefinedVariable, span, "Class '{}' is missing", name),
+        }
     }
 
     pub(crate) fn instantiate_class(
@@ -88,7 +106,7 @@ impl Interpreter {
             )?;
         }
 
-        let data_key = self.intern_string("__data");
+        let data_key = self.symbols.data;
         Ok(instance
             .write(|instance| instance.field_values.remove(&data_key))
             .unwrap_or(Value::Object(instance)))
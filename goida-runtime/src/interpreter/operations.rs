@@ -1,23 +1,218 @@
 use crate::ast::prelude::{ErrorData, Span};
-use crate::interpreter::structs::{Interpreter, RuntimeError, Value};
+use crate::builtins::datetime::temporal_millis;
+use crate::builtins::iterator::collect_iterator;
+use crate::builtins::set::set_contains;
+use crate::interpreter::structs::{DictKey, Interpreter, RuntimeError, Value};
 use crate::shared::SharedMut;
 use crate::traits::prelude::ValueOperations;
 use crate::{bail_runtime, runtime_error};
+use std::cmp::Ordering;
 use std::sync::Arc;
 
+/// Result of integer arithmetic, or `ОшибкаПереполнения` if it does not fit in `i64`.
+pub(crate) fn checked_number(
+    result: Option<i64>,
+    span: Span,
+    left: i64,
+    operator: &str,
+    right: i64,
+) -> Result<Value, RuntimeError> {
+    result.map(Value::Number).ok_or_else(|| {
+        runtime_error!(
+            Overflow,
+            span,
+            "Результат {} {} {} не помещается в число",
+            left,
+            operator,
+            right
+        )
+    })
+}
+
+fn repeat_overflow(count: usize, span: Span) -> RuntimeError {
+    runtime_error!(
+        Overflow,
+        span,
+        "Повторение {} раз не помещается в память",
+        count
+    )
+}
+
+/// Number of copies for `строка * n` and `список * n`; a negative count gives an empty value.
+///
+/// The total size is checked against the memory limit before anything is built, and a size
+/// no allocation can have fails with `ОшибкаПереполнения` instead of aborting.
+fn repeat_count(
+    interpreter: &Interpreter,
+    len: usize,
+    item_size: usize,
+    count: i64,
+    span: Span,
+) -> Result<usize, RuntimeError> {
+    let count = usize::try_from(count.max(0)).unwrap_or(usize::MAX);
+    let bytes = len
+        .checked_mul(count)
+        .and_then(|total| total.checked_mul(item_size))
+        .filter(|bytes| *bytes <= isize::MAX as usize)
+        .ok_or_else(|| repeat_overflow(count, span))?;
+    interpreter.check_allocation(bytes, span)?;
+    Ok(count)
+}
+
+/// `text` repeated `count` times, for `строка * n`, `повторить` and padding.
+pub(crate) fn repeat_text(
+    interpreter: &Interpreter,
+    text: &str,
+    count: i64,
+    span: Span,
+) -> Result<String, RuntimeError> {
+    let count = repeat_count(interpreter, text.len(), 1, count, span)?;
+    if text.is_empty() {
+        return Ok(String::new());
+    }
+    let mut output = String::new();
+    output
+        .try_reserve_exact(text.len() * count)
+        .map_err(|_| repeat_overflow(count, span))?;
+    for _ in 0..count {
+        output.push_str(text);
+    }
+    Ok(output)
+}
+
+fn repeat_values(items: &[Value], count: usize, span: Span) -> Result<Vec<Value>, RuntimeError> {
+    let mut output = Vec::new();
+    output
+        .try_reserve_exact(items.len() * count)
+        .map_err(|_| repeat_overflow(count, span))?;
+    if !items.is_empty() {
+        for _ in 0..count {
+            output.extend_from_slice(items);
+        }
+    }
+    Ok(output)
+}
+
+impl Interpreter {
+    /// Orders two `ДатаВремя` or two `Длительность` objects by their milliseconds.
+    fn compare_temporal(&self, left: &Value, right: &Value) -> Option<Ordering> {
+        let (left_kind, left_ms) = temporal_millis(self, left)?;
+        let (right_kind, right_ms) = temporal_millis(self, right)?;
+        (left_kind == right_kind).then(|| left_ms.cmp(&right_ms))
+    }
+
+    /// `элемент в коллекция`: an equal item of a list, array, iterator or `Множество`,
+    /// a key of a dict, a substring of a text or a byte value of bytes.
+    pub(crate) fn contains_value(
+        &self,
+        container: &Value,
+        item: &Value,
+        span: Span,
+    ) -> Result<bool, RuntimeError> {
+        match container {
+            Value::List(items) => {
+                Ok(items.read(|items| items.iter().any(|value| self.values_equal(value, item))))
+            }
+            Value::Array(items) => Ok(items.iter().any(|value| self.values_equal(value, item))),
+            Value::Iterator(iterator) => Ok(collect_iterator(self, iterator, span)?
+                .iter()
+                .any(|value| self.values_equal(value, item))),
+            // Значение, которое не может быть ключом, в словаре не встречается.
+            Value::Dict(entries) => Ok(DictKey::from_value(item, span)
+                .is_ok_and(|key| entries.read(|entries| entries.contains_key(&key)))),
+            Value::Text(text) => match item {
+                Value::Text(part) => Ok(text.contains(&**part)),
+                _ => bail_runtime!(
+                    TypeError,
+                    span,
+                    "В строке можно искать только строку, получено {}",
+                    self.type_name(item)
+                ),
+            },
+            Value::Bytes(bytes) => Ok(matches!(item, Value::Number(byte)
+                if u8::try_from(*byte).is_ok_and(|byte| bytes.contains(&byte)))),
+            Value::Object(instance) => match set_contains(self, instance, item) {
+                Some(found) => Ok(found),
+                None => bail_runtime!(
+                    TypeError,
+                    span,
+                    "Оператор 'в' не применим к {}",
+                    self.type_name(container)
+                ),
+            },
+            _ => bail_runtime!(
+                TypeError,
+                span,
+                "Оператор 'в' не применим к {}",
+                self.type_name(container)
+            ),
+        }
+    }
+
+    /// `<`, `>`, `<=` and `>=` over the natural order; a NaN operand makes the comparison false.
+    fn compare_ordered(
+        &self,
+        left: &Value,
+        right: &Value,
+        span: Span,
+        test: fn(Ordering) -> bool,
+    ) -> Result<Value, RuntimeError> {
+        let numeric = |value: &Value| matches!(value, Value::Number(_) | Value::Float(_));
+        match self.natural_order(left, right) {
+            Some(ordering) => Ok(Value::Boolean(test(ordering))),
+            None if numeric(left) && numeric(right) => Ok(Value::Boolean(false)),
+            None => bail_runtime!(
+                TypeMismatch,
+                span,
+                "Нельзя сравнить {} и {}: сравнение применимо к числам, строкам, логическим значениям и датам",
+                self.type_name(left),
+                self.type_name(right)
+            ),
+        }
+    }
+
+    /// Natural order used by sorting and comparisons: numbers, strings (by code point),
+    /// booleans and dates/durations among themselves.
+    pub(crate) fn natural_order(&self, left: &Value, right: &Value) -> Option<Ordering> {
+        match (left, right) {
+            (Value::Number(a), Value::Number(b)) => Some(a.cmp(b)),
+            (Value::Float(a), Value::Float(b)) => a.partial_cmp(b),
+            (Value::Number(a), Value::Float(b)) => (*a as f64).partial_cmp(b),
+            (Value::Float(a), Value::Number(b)) => a.partial_cmp(&(*b as f64)),
+            (Value::Text(a), Value::Text(b)) => Some(a.cmp(b)),
+            (Value::Boolean(a), Value::Boolean(b)) => Some(a.cmp(b)),
+            _ => self.compare_temporal(left, right),
+        }
+    }
+}
+
 impl ValueOperations for Interpreter {
     fn add_values(&self, left: Value, right: Value, span: Span) -> Result<Value, RuntimeError> {
         match (&left, &right) {
-            (Value::Number(a), Value::Number(b)) => Ok(Value::Number(a + b)),
+            (Value::Number(a), Value::Number(b)) => {
+                checked_number(a.checked_add(*b), span, *a, "+", *b)
+            }
             (Value::Float(a), Value::Float(b)) => Ok(Value::Float(a + b)),
             (Value::Number(a), Value::Float(b)) => Ok(Value::Float(*a as f64 + *b)),
             (Value::Float(a), Value::Number(b)) => Ok(Value::Float(*a + *b as f64)),
 
-            (Value::Text(a), Value::Text(b)) => Ok(Value::Text(format!("{}{}", a, b))),
-            (Value::Text(a), any) => Ok(Value::Text(format!("{}{}", a, self.format_value(any)))),
-            (any, Value::Text(b)) => Ok(Value::Text(format!("{}{}", self.format_value(any), b))),
+            (Value::Text(a), Value::Text(b)) => {
+                self.check_allocation(a.len().saturating_add(b.len()), span)?;
+                Ok(Value::Text(format!("{}{}", a, b).into()))
+            }
+            (Value::Text(a), any) => {
+                let b = self.format_value(any);
+                self.check_allocation(a.len().saturating_add(b.len()), span)?;
+                Ok(Value::Text(format!("{}{}", a, b).into()))
+            }
+            (any, Value::Text(b)) => {
+                let a = self.format_value(any);
+                self.check_allocation(a.len().saturating_add(b.len()), span)?;
+                Ok(Value::Text(format!("{}{}", a, b).into()))
+            }
 
             (Value::List(a), Value::List(b)) => {
+                self.check_items(a.read(Vec::len).saturating_add(b.read(Vec::len)), span)?;
                 let new_vec = a.read(|vec_a| {
                     b.read(|vec_b| {
                         let mut combined = vec_a.clone();
@@ -29,6 +224,10 @@ impl ValueOperations for Interpreter {
             }
 
             (Value::Dict(a), Value::Dict(b)) => {
+                self.check_items(
+                    a.read(|a| a.len()).saturating_add(b.read(|b| b.len())),
+                    span,
+                )?;
                 let new_dict = a.read(|dict_a| {
                     b.read(|dict_b| {
                         let mut combined = dict_a.clone();
@@ -48,6 +247,12 @@ impl ValueOperations for Interpreter {
                 Ok(Value::Array(Arc::new(new_vec)))
             }
 
+            (Value::Bytes(a), Value::Bytes(b)) => {
+                let mut new_bytes = (**a).clone();
+                new_bytes.extend_from_slice(b);
+                Ok(Value::Bytes(Arc::new(new_bytes)))
+            }
+
             _ => bail_runtime!(
                 TypeMismatch,
                 span,
@@ -63,7 +268,9 @@ impl ValueOperations for Interpreter {
         span: Span,
     ) -> Result<Value, RuntimeError> {
         match (left, right) {
-            (Value::Number(a), Value::Number(b)) => Ok(Value::Number(a - b)),
+            (Value::Number(a), Value::Number(b)) => {
+                checked_number(a.checked_sub(b), span, a, "-", b)
+            }
             (Value::Float(a), Value::Float(b)) => Ok(Value::Float(a - b)),
             (Value::Number(a), Value::Float(b)) => Ok(Value::Float((a as f64) - b)),
             (Value::Float(a), Value::Number(b)) => Ok(Value::Float(a - (b as f64))),
@@ -79,11 +286,36 @@ impl ValueOperations for Interpreter {
         span: Span,
     ) -> Result<Value, RuntimeError> {
         match (left, right) {
-            (Value::Number(a), Value::Number(b)) => Ok(Value::Number(a * b)),
+            (Value::Number(a), Value::Number(b)) => {
+                checked_number(a.checked_mul(b), span, a, "*", b)
+            }
             (Value::Float(a), Value::Float(b)) => Ok(Value::Float(a * b)),
             (Value::Number(a), Value::Float(b)) => Ok(Value::Float((a as f64) * b)),
             (Value::Float(a), Value::Number(b)) => Ok(Value::Float(a * (b as f64))),
-            _ => bail_runtime!(TypeMismatch, span, "Умножение применимо только к числам"),
+            (Value::Text(text), Value::Number(count))
+            | (Value::Number(count), Value::Text(text)) => {
+                Ok(Value::Text(repeat_text(self, &text, count, span)?.into()))
+            }
+            (Value::List(items), Value::Number(count))
+            | (Value::Number(count), Value::List(items)) => {
+                let items = items.read(|items| items.clone());
+                let count =
+                    repeat_count(self, items.len(), std::mem::size_of::<Value>(), count, span)?;
+                Ok(Value::List(SharedMut::new(repeat_values(
+                    &items, count, span,
+                )?)))
+            }
+            (Value::Array(items), Value::Number(count))
+            | (Value::Number(count), Value::Array(items)) => {
+                let count =
+                    repeat_count(self, items.len(), std::mem::size_of::<Value>(), count, span)?;
+                Ok(Value::Array(Arc::new(repeat_values(&items, count, span)?)))
+            }
+            _ => bail_runtime!(
+                TypeMismatch,
+                span,
+                "Умножение применимо к числам, а также к строке, списку или массиву и числу"
+            ),
         }
     }
 
@@ -92,8 +324,12 @@ impl ValueOperations for Interpreter {
             (Value::Number(a), Value::Number(b)) => {
                 if b == 0 {
                     bail_runtime!(DivisionByZero, span, "Деление на 0 запрещено")
+                } else if a.checked_rem(b).is_some_and(|rest| rest != 0) {
+                    // Неделящиеся нацело числа дают дробь; целую часть даёт `цел_дел`.
+                    Ok(Value::Float(a as f64 / b as f64))
                 } else {
-                    Ok(Value::Number(a / b))
+                    // `i64::MIN / -1` — единственное переполняющееся деление.
+                    checked_number(a.checked_div(b), span, a, "/", b)
                 }
             }
             (Value::Float(a), Value::Float(b)) => {
@@ -127,7 +363,7 @@ impl ValueOperations for Interpreter {
                 if b == 0 {
                     bail_runtime!(DivisionByZero, span, "Деление на 0 запрещено")
                 } else {
-                    Ok(Value::Number(a % b))
+                    checked_number(a.checked_rem(b), span, a, "%", b)
                 }
             }
             (Value::Float(a), Value::Float(b)) => {
@@ -165,23 +401,11 @@ impl ValueOperations for Interpreter {
         right: Value,
         span: Span,
     ) -> Result<Value, RuntimeError> {
-        match (left, right) {
-            (Value::Number(a), Value::Number(b)) => Ok(Value::Boolean(a > b)),
-            (Value::Float(a), Value::Float(b)) => Ok(Value::Boolean(a > b)),
-            (Value::Number(a), Value::Float(b)) => Ok(Value::Boolean((a as f64) > b)),
-            (Value::Float(a), Value::Number(b)) => Ok(Value::Boolean(a > (b as f64))),
-            _ => bail_runtime!(TypeMismatch, span, "Сравнение применимо только к числам"),
-        }
+        self.compare_ordered(&left, &right, span, Ordering::is_gt)
     }
 
     fn compare_less(&self, left: Value, right: Value, span: Span) -> Result<Value, RuntimeError> {
-        match (left, right) {
-            (Value::Number(a), Value::Number(b)) => Ok(Value::Boolean(a < b)),
-            (Value::Float(a), Value::Float(b)) => Ok(Value::Boolean(a < b)),
-            (Value::Number(a), Value::Float(b)) => Ok(Value::Boolean((a as f64) < b)),
-            (Value::Float(a), Value::Number(b)) => Ok(Value::Boolean(a < (b as f64))),
-            _ => bail_runtime!(TypeMismatch, span, "Сравнение применимо только к числам"),
-        }
+        self.compare_ordered(&left, &right, span, Ordering::is_lt)
     }
 
     fn compare_greater_equal(
@@ -190,13 +414,7 @@ impl ValueOperations for Interpreter {
         right: Value,
         span: Span,
     ) -> Result<Value, RuntimeError> {
-        match (left, right) {
-            (Value::Number(a), Value::Number(b)) => Ok(Value::Boolean(a >= b)),
-            (Value::Float(a), Value::Float(b)) => Ok(Value::Boolean(a >= b)),
-            (Value::Number(a), Value::Float(b)) => Ok(Value::Boolean((a as f64) >= b)),
-            (Value::Float(a), Value::Number(b)) => Ok(Value::Boolean(a >= (b as f64))),
-            _ => bail_runtime!(TypeMismatch, span, "Сравнение применимо только к числам"),
-        }
+        self.compare_ordered(&left, &right, span, Ordering::is_ge)
     }
 
     fn compare_less_equal(
@@ -205,12 +423,13 @@ impl ValueOperations for Interpreter {
         right: Value,
         span: Span,
     ) -> Result<Value, RuntimeError> {
-        match (left, right) {
-            (Value::Number(a), Value::Number(b)) => Ok(Value::Boolean(a <= b)),
-            (Value::Float(a), Value::Float(b)) => Ok(Value::Boolean(a <= b)),
-            (Value::Number(a), Value::Float(b)) => Ok(Value::Boolean((a as f64) <= b)),
-            (Value::Float(a), Value::Number(b)) => Ok(Value::Boolean(a <= (b as f64))),
-            _ => bail_runtime!(TypeMismatch, span, "Сравнение применимо только к числам"),
+        self.compare_ordered(&left, &right, span, Ordering::is_le)
+    }
+
+    fn values_equal(&self, left: &Value, right: &Value) -> bool {
+        match self.compare_temporal(left, right) {
+            Some(ordering) => ordering.is_eq(),
+            None => left == right,
         }
     }
 }
@@ -0,0 +1,252 @@
+use crate::ast::prelude::{ErrorData, Span};
+use crate::interpreter::heap::ObjectHeap;
+use crate::interpreter::prelude::{Interpreter, RuntimeError, Value};
+use crate::{bail_runtime, runtime_error};
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// Group of builtins that reach outside the interpreter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Capability {
+    File,
+    System,
+    Network,
+    Subprocess,
+}
+
+impl Capability {
+    pub const ALL: [Capability; 4] = [
+        Capability::File,
+        Capability::System,
+        Capability::Network,
+        Capability::Subprocess,
+    ];
+
+    /// Accepts the Russian and English names used by `--allow`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "файлы" | "file" => Some(Capability::File),
+            "система" | "system" => Some(Capability::System),
+            "сеть" | "network" => Some(Capability::Network),
+            "процессы" | "subprocess" => Some(Capability::Subprocess),
+            _ => None,
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Capability::File => "работу с файлами",
+            Capability::System => "системные функции",
+            Capability::Network => "работу с сетью",
+            Capability::Subprocess => "запуск процессов",
+        }
+    }
+}
+
+/// Settings an embedder fixes before running code; the default allows everything.
+#[derive(Debug, Clone)]
+pub struct InterpreterOptions {
+    allowed: HashSet<Capability>,
+    time_limit: Option<Duration>,
+    operation_limit: Option<u64>,
+    memory_limit: Option<usize>,
+    seed: Option<u64>,
+    eager_imports: bool,
+}
+
+impl Default for InterpreterOptions {
+    fn default() -> Self {
+        Self {
+            allowed: Capability::ALL.into_iter().collect(),
+            time_limit: None,
+            operation_limit: None,
+            memory_limit: None,
+            seed: None,
+            eager_imports: false,
+        }
+    }
+}
+
+impl InterpreterOptions {
+    /// No file, system, network or subprocess access until allowed one by one.
+    pub fn sandboxed() -> Self {
+        Self {
+            allowed: HashSet::new(),
+            ..Self::default()
+        }
+    }
+
+    pub fn allow(mut self, capability: Capability) -> Self {
+        self.allowed.insert(capability);
+        self
+    }
+
+    pub fn is_allowed(&self, capability: Capability) -> bool {
+        self.allowed.contains(&capability)
+    }
+
+    /// Wall-clock time one execution may take.
+    pub fn with_time_limit(mut self, limit: Duration) -> Self {
+        self.time_limit = Some(limit);
+        self
+    }
+
+    /// Number of bytecode instructions one execution may run.
+    pub fn with_operation_limit(mut self, limit: u64) -> Self {
+        self.operation_limit = Some(limit);
+        self
+    }
+
+    /// Approximate number of bytes live collections and texts may hold.
+    pub fn with_memory_limit(mut self, bytes: usize) -> Self {
+        self.memory_limit = Some(bytes);
+        self
+    }
+
+    /// Seed of the `Случай` generator, for runs that repeat exactly.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Runs every imported module before the module that imports it, instead
+    /// of on the first access to one of its names.
+    pub fn with_eager_imports(mut self) -> Self {
+        self.eager_imports = true;
+        self
+    }
+
+    pub(crate) fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    pub(crate) fn eager_imports(&self) -> bool {
+        self.eager_imports
+    }
+}
+
+/// Budget of one execution, shared with the threads it starts.
+#[derive(Debug)]
+pub(crate) struct ExecutionLimits {
+    started: Instant,
+    time_limit: Option<Duration>,
+    operation_limit: Option<u64>,
+    memory_limit: Option<usize>,
+    operations: AtomicU64,
+}
+
+impl ExecutionLimits {
+    /// Clock reading is comparatively slow, so time is checked every this many operations.
+    const TIME_CHECK_INTERVAL: u64 = 1024;
+    /// Measuring the heap walks every tracked container, so it is done even less often.
+    const MEMORY_CHECK_INTERVAL: u64 = 4096;
+
+    pub(crate) fn start(options: &InterpreterOptions) -> Option<Self> {
+        if options.time_limit.is_none()
+            && options.operation_limit.is_none()
+            && options.memory_limit.is_none()
+        {
+            return None;
+        }
+        Some(Self {
+            started: Instant::now(),
+            time_limit: options.time_limit,
+            operation_limit: options.operation_limit,
+            memory_limit: options.memory_limit,
+            operations: AtomicU64::new(0),
+        })
+    }
+
+    /// Counts one operation and fails once a limit is exceeded.
+    pub(crate) fn tick(&self, heap: &ObjectHeap, span: Span) -> Result<(), RuntimeError> {
+        let operations = self.operations.fetch_add(1, Ordering::Relaxed) + 1;
+        if let Some(limit) = self.operation_limit.filter(|limit| operations > *limit) {
+            return bail_runtime!(
+                LimitExceeded,
+                span,
+                "превышен лимит выполнения: больше {} операций",
+                limit
+            );
+        }
+        if operations.is_multiple_of(Self::TIME_CHECK_INTERVAL) {
+            if let Some(limit) = self
+                .time_limit
+                .filter(|limit| self.started.elapsed() > *limit)
+            {
+                return bail_runtime!(
+                    LimitExceeded,
+                    span,
+                    "превышен лимит выполнения: дольше {} мс",
+                    limit.as_millis()
+                );
+            }
+        }
+        if operations.is_multiple_of(Self::MEMORY_CHECK_INTERVAL) {
+            if let Some(limit) = self.memory_limit {
+                self.check_memory(heap.measure(), limit, span)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Counts a value of `bytes` against the memory in use before it is built,
+    /// e.g. a repeated text or list.
+    pub(crate) fn check_allocation(
+        &self,
+        heap: &ObjectHeap,
+        bytes: usize,
+        span: Span,
+    ) -> Result<(), RuntimeError> {
+        match self.memory_limit {
+            Some(limit) if !heap.reserve(bytes, limit) => Self::memory_exceeded(limit, span),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_memory(&self, used: usize, limit: usize, span: Span) -> Result<(), RuntimeError> {
+        if used <= limit {
+            return Ok(());
+        }
+        Self::memory_exceeded(limit, span)
+    }
+
+    fn memory_exceeded(limit: usize, span: Span) -> Result<(), RuntimeError> {
+        bail_runtime!(
+            LimitExceeded,
+            span,
+            "превышен лимит памяти: больше {} байт",
+            limit
+        )
+    }
+}
+
+impl Interpreter {
+    /// Fails if a value of `bytes` would take the memory in use over the limit.
+    /// Called before a large text or collection is built, so the limit holds
+    /// between heap samples too.
+    pub(crate) fn check_allocation(&self, bytes: usize, span: Span) -> Result<(), RuntimeError> {
+        match &self.limits {
+            Some(limits) => limits.check_allocation(&self.heap, bytes, span),
+            None => Ok(()),
+        }
+    }
+
+    /// [`Self::check_allocation`] for a list, array or dict of `count` values.
+    pub(crate) fn check_items(&self, count: usize, span: Span) -> Result<(), RuntimeError> {
+        self.check_allocation(count.saturating_mul(size_of::<Value>()), span)
+    }
+
+    /// Fails with `PermissionDenied` unless the options allow `capability`.
+    pub(crate) fn require(&self, capability: Capability, span: Span) -> Result<(), RuntimeError> {
+        if self.options.is_allowed(capability) {
+            return Ok(());
+        }
+        bail_runtime!(
+            PermissionDenied,
+            span,
+            "песочница не разрешает {}",
+            capability.description()
+        )
+    }
+}
@@ -0,0 +1,101 @@
+use crate::interpreter::structs::Interpreter;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Call counts and wall-clock time per function, shared with forked threads.
+#[derive(Debug, Default)]
+pub struct Profiler {
+    entries: Mutex<HashMap<String, ProfileEntry>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileEntry {
+    pub name: String,
+    pub calls: u64,
+    /// Time spent in the call, nested calls included; recursion is counted once.
+    pub total: Duration,
+    /// Time spent in the function body itself.
+    pub own: Duration,
+}
+
+thread_local! {
+    /// Names of the active profiled calls and the time spent in their callees.
+    static ACTIVE_CALLS: RefCell<Vec<(String, Duration)>> = const { RefCell::new(Vec::new()) };
+}
+
+impl Profiler {
+    fn record(&self, name: String, elapsed: Duration, own: Duration, outermost: bool) {
+        let mut entries = self.entries.lock().unwrap();
+        let entry = entries.entry(name).or_insert_with_key(|name| ProfileEntry {
+            name: name.clone(),
+            calls: 0,
+            total: Duration::ZERO,
+            own: Duration::ZERO,
+        });
+        entry.calls += 1;
+        entry.own += own;
+        if outermost {
+            entry.total += elapsed;
+        }
+    }
+
+    /// Entries sorted by own time, the most expensive first.
+    pub fn report(&self) -> Vec<ProfileEntry> {
+        let mut entries = self
+            .entries
+            .lock()
+            .unwrap()
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+        entries.sort_by(|left, right| {
+            right
+                .own
+                .cmp(&left.own)
+                .then_with(|| left.name.cmp(&right.name))
+        });
+        entries
+    }
+}
+
+/// Records the time until drop; created only while profiling is enabled.
+pub(crate) struct ProfileTimer {
+    profiler: Arc<Profiler>,
+    name: String,
+    started: Instant,
+}
+
+impl Drop for ProfileTimer {
+    fn drop(&mut self) {
+        let elapsed = self.started.elapsed();
+        let (children, outermost) = ACTIVE_CALLS.with_borrow_mut(|active| {
+            let (_, children) = active.pop().unwrap_or_default();
+            if let Some((_, parent_children)) = active.last_mut() {
+                *parent_children += elapsed;
+            }
+            let outermost = !active.iter().any(|(name, _)| *name == self.name);
+            (children, outermost)
+        });
+        self.profiler.record(
+            std::mem::take(&mut self.name),
+            elapsed,
+            elapsed.saturating_sub(children),
+            outermost,
+        );
+    }
+}
+
+impl Interpreter {
+    pub(crate) fn profile_timer(&self, name: impl FnOnce() -> String) -> Option<ProfileTimer> {
+        let profiler = self.profiler.clone()?;
+        let name = name();
+        ACTIVE_CALLS.with_borrow_mut(|active| active.push((name.clone(), Duration::ZERO)));
+        Some(ProfileTimer {
+            profiler,
+            name,
+            started: Instant::now(),
+        })
+    }
+}
@@ -1,9 +1,18 @@
 use crate::ast::prelude::{ErrorData, Span};
 use crate::builtins::iterator::collect_iterator;
-use crate::interpreter::prelude::{Interpreter, RuntimeError, Value};
+use crate::builtins::registry::method;
+use crate::interpreter::prelude::{
+    DictKey, Interpreter, RuntimeError, RuntimeMethodType, ThreadJoin, Value,
+};
+use crate::traits::prelude::{
+    CoreOperations, InterpreterClasses, ObjectIterator, ValueIterator, ITERATOR_METHOD, NEXT_METHOD,
+};
 use crate::{bail_runtime, runtime_error};
 use string_interner::DefaultSymbol as Symbol;
 
+/// Method `с (...) { ... }` calls on leaving the block, preferred over `закрыть`.
+const EXIT_METHOD: &str = "__выход";
+
 impl Interpreter {
     pub(crate) fn assign_identifier(
         &mut self,
@@ -87,26 +96,100 @@ impl Interpreter {
         Ok(())
     }
 
-    pub(crate) fn iterable_values(
-        &self,
+    /// Starts iterating `value`: collections are walked in place, a generator is
+    /// resumed item by item, and objects follow `__итератор`/`__следующий`.
+    pub(crate) fn iterate(
+        &mut self,
         value: Value,
         span: Span,
-    ) -> Result<Vec<Value>, RuntimeError> {
-        match value {
-            Value::List(values) => Ok(values.read(Clone::clone)),
-            Value::Array(values) => Ok(values.as_ref().clone()),
-            Value::Text(value) => Ok(value
+    ) -> Result<Box<dyn ValueIterator>, RuntimeError> {
+        let values = match value {
+            Value::List(values) => values.read(Clone::clone),
+            Value::Array(values) => values.as_ref().clone(),
+            Value::Bytes(bytes) => bytes
+                .iter()
+                .map(|byte| Value::Number(i64::from(*byte)))
+                .collect(),
+            Value::Text(value) => value
                 .chars()
-                .map(|character| Value::Text(character.to_string()))
-                .collect()),
-            Value::Dict(values) => Ok(values.read(|values| {
+                .map(|character| Value::Text(character.to_string().into()))
+                .collect(),
+            Value::Dict(values) => values.read(|values| {
                 let mut keys = values.keys().cloned().collect::<Vec<_>>();
                 keys.sort();
-                keys.into_iter().map(Value::Text).collect()
-            })),
-            Value::Iterator(iterator) => collect_iterator(self, &iterator, span),
-            _ => bail_runtime!(TypeError, span, "Value is not iterable"),
+                keys.iter().map(DictKey::to_value).collect()
+            }),
+            Value::Iterator(iterator) => collect_iterator(self, &iterator, span)?,
+            Value::Generator(generator) => return Ok(Box::new(generator)),
+            Value::Object(_) => return self.iterate_object(value, span),
+            _ => return bail_runtime!(TypeError, span, "Value is not iterable"),
+        };
+        Ok(Box::new(values.into_iter()))
+    }
+
+    pub(crate) fn iterable_values(
+        &mut self,
+        value: Value,
+        span: Span,
+    ) -> Result<Vec<Value>, RuntimeError> {
+        let mut iterator = self.iterate(value, span)?;
+        std::iter::from_fn(|| iterator.next_value(self, span)).collect()
+    }
+
+    fn iterate_object(
+        &mut self,
+        object: Value,
+        span: Span,
+    ) -> Result<Box<dyn ValueIterator>, RuntimeError> {
+        let iterator = match self.magic_method(&object, ITERATOR_METHOD) {
+            Some((method, module)) => self.call_method(method, Vec::new(), object, module, span)?,
+            None => object,
+        };
+        if let Some((next, module)) = self.magic_method(&iterator, NEXT_METHOD) {
+            return Ok(Box::new(ObjectIterator {
+                object: iterator,
+                next,
+                module,
+            }));
+        }
+        if matches!(iterator, Value::Object(_)) {
+            return bail_runtime!(TypeError, span, "Value is not iterable");
         }
+        self.iterate(iterator, span)
+    }
+
+    /// Method that releases a resource bound by `с`: `__выход`, otherwise `закрыть`.
+    pub(crate) fn resource_closer(
+        &self,
+        resource: &Value,
+        span: Span,
+    ) -> Result<(RuntimeMethodType, Symbol), RuntimeError> {
+        std::iter::once(EXIT_METHOD)
+            .chain(std::iter::once(method::CLOSE.canonical))
+            .chain(method::CLOSE.names.iter().copied())
+            .find_map(|name| self.magic_method(resource, name))
+            .ok_or_else(|| {
+                runtime_error!(
+                    TypeError,
+                    span,
+                    "Value used in 'with' has neither '{}' nor '{}' method",
+                    EXIT_METHOD,
+                    method::CLOSE.names[0]
+                )
+            })
+    }
+
+    fn magic_method(&self, value: &Value, name: &str) -> Option<(RuntimeMethodType, Symbol)> {
+        if !matches!(value, Value::Object(_)) {
+            return None;
+        }
+        let name = self.intern_string(name);
+        self.get_class_for_value(value)?.read(|class| {
+            class.methods.get(&name).map(|(_, _, method)| {
+                let module = method.get_module().unwrap_or(class.span.file_id);
+                (method.clone(), module)
+            })
+        })
     }
 
     pub(crate) fn join_thread_handle(
@@ -114,18 +197,25 @@ impl Interpreter {
         thread: &crate::interpreter::structs::RuntimeThread,
         span: Span,
     ) -> Result<Value, RuntimeError> {
-        let handle = thread
+        let mut state = thread
             .handle
             .lock()
-            .map_err(|_| runtime_error!(InvalidOperation, span, "Thread lock is poisoned"))?
-            .take();
-        match handle {
-            Some(handle) => match handle.join() {
-                Ok(Ok(())) => Ok(Value::Empty),
-                Ok(Err(error)) => Err(error),
-                Err(_) => bail_runtime!(Panic, span, "Thread panicked"),
-            },
-            None => Ok(Value::Empty),
+            .map_err(|_| runtime_error!(InvalidOperation, span, "Thread lock is poisoned"))?;
+        // Повторное ожидание возвращает уже полученный результат.
+        let handle = match std::mem::replace(&mut *state, ThreadJoin::Finished(Value::Empty)) {
+            ThreadJoin::Running(handle) => handle,
+            ThreadJoin::Finished(value) => {
+                *state = ThreadJoin::Finished(value.clone());
+                return Ok(value);
+            }
+        };
+        match handle.join() {
+            Ok(Ok(value)) => {
+                *state = ThreadJoin::Finished(value.clone());
+                Ok(value)
+            }
+            Ok(Err(error)) => Err(error),
+            Err(_) => bail_runtime!(Panic, span, "Thread panicked"),
         }
     }
 
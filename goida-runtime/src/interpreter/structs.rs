@@ -5,6 +5,10 @@ use crate::ast::prelude::{
     AstArena, ErrorData, ExprId, FunctionDefinition, Import, Parameter, Span, StmtId, Visibility,
 };
 use crate::ast::source::SourceManager;
+use crate::builtins::{
+    bigint, channel, database, datetime, file, http, promise, queue, regex, set, stopwatch,
+    string_builder, tcp,
+};
 use crate::bytecode::BytecodeModule;
 use crate::hir::HirModule;
 use crate::parser::structs::ParseError;
@@ -13,11 +17,16 @@ use libloading::Library;
 use std::fmt::Debug;
 use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
+use std::sync::mpsc::{Receiver, SyncSender};
 use std::sync::{Arc, Condvar, Mutex, RwLock};
 use std::thread::{JoinHandle, ThreadId};
 use string_interner::DefaultSymbol as Symbol;
 
-pub type ThreadJoinState = Arc<Mutex<Option<JoinHandle<Result<(), RuntimeError>>>>>;
+/// Hash map for identifier tables, instance fields and dictionaries. Their keys are
+/// symbols and short strings, where foldhash is much cheaper than the default SipHash.
+pub type FastHashMap<K, V> = HashMap<K, V, foldhash::fast::RandomState>;
+
+pub type ThreadJoinState = Arc<Mutex<ThreadJoin>>;
 pub type BuiltinCallback =
     dyn Fn(&Interpreter, Vec<CallArgValue>, Span) -> Result<Value, RuntimeError> + Send + Sync;
 
@@ -27,18 +36,20 @@ pub enum Value {
     Number(i64),
     Float(f64),
     Pointer(usize),
-    Text(String),
+    Text(Arc<str>),
     Boolean(bool),
     Object(SharedMut<ClassInstance>),
     Class(SharedMut<RuntimeClassDefinition>),
-    Function(Arc<FunctionDefinition>),
+    Function(Arc<RuntimeFunction>),
     Builtin(BuiltinFn),
     Module(Symbol),
     List(SharedMut<Vec<Value>>),
     Array(Arc<Vec<Value>>),
-    Dict(SharedMut<HashMap<String, Value>>),
+    Bytes(Arc<Vec<u8>>),
+    Dict(SharedMut<FastHashMap<DictKey, Value>>),
     Iterator(RuntimeIterator),
     Thread(RuntimeThread),
+    Generator(RuntimeGenerator),
     Mutex(RuntimeMutex),
     RwLock(RuntimeRwLock),
     NativeResource(SharedMut<Box<dyn Any + Send + Sync>>),
@@ -46,6 +57,14 @@ pub enum Value {
     Empty,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// Dictionary key: `словарь[1]`, `словарь["1"]` and `словарь[истина]` are distinct entries.
+pub enum DictKey {
+    Number(i64),
+    Text(Arc<str>),
+    Boolean(bool),
+}
+
 #[derive(Clone, Debug)]
 /// Lazy iterator pipeline over runtime values.
 pub struct RuntimeIterator {
@@ -78,6 +97,13 @@ pub enum IteratorStep {
     Filter(Value),
 }
 
+#[derive(Debug)]
+/// State of a language-level thread: still running, or joined with its result.
+pub enum ThreadJoin {
+    Running(JoinHandle<Result<Value, RuntimeError>>),
+    Finished(Value),
+}
+
 #[derive(Clone, Debug)]
 /// Join handle for a language-level background thread.
 pub struct RuntimeThread {
@@ -86,13 +112,34 @@ pub struct RuntimeThread {
 
 impl RuntimeThread {
     /// Wraps a spawned Rust thread as a Goida runtime thread.
-    pub fn new(handle: JoinHandle<Result<(), RuntimeError>>) -> Self {
+    pub fn new(handle: JoinHandle<Result<Value, RuntimeError>>) -> Self {
         Self {
-            handle: Arc::new(Mutex::new(Some(handle))),
+            handle: Arc::new(Mutex::new(ThreadJoin::Running(handle))),
         }
     }
 }
 
+#[derive(Clone, Debug)]
+/// Generator returned by a function with `выдать`. Its body runs on its own thread,
+/// starts on the first request and pauses after every value it hands over.
+pub struct RuntimeGenerator {
+    pub(crate) channel: Arc<Mutex<GeneratorChannel>>,
+}
+
+#[derive(Debug)]
+/// Consumer end of a generator; `resume` is dropped once the body has finished.
+pub(crate) struct GeneratorChannel {
+    pub(crate) resume: Option<SyncSender<()>>,
+    pub(crate) values: Receiver<Result<Value, RuntimeError>>,
+}
+
+#[derive(Debug)]
+/// Producer end, owned by the interpreter that runs a generator body.
+pub(crate) struct GeneratorSink {
+    pub(crate) values: SyncSender<Result<Value, RuntimeError>>,
+    pub(crate) resume: Receiver<()>,
+}
+
 #[derive(Clone, Debug)]
 /// Runtime mutex value with reentrant ownership tracking.
 pub struct RuntimeMutex {
@@ -204,10 +251,41 @@ impl CallArgListExt for Vec<CallArgValue> {
 /// Native/built-in function callable from Goida code.
 pub struct BuiltinFn(pub Arc<BuiltinCallback>);
 
+#[derive(Debug)]
+/// User function value with the variables it captured from enclosing functions.
+pub struct RuntimeFunction {
+    pub definition: Arc<FunctionDefinition>,
+    /// Cells shared with the frames that created the function, by name.
+    pub(crate) upvalues: Vec<(Symbol, SharedMut<Value>)>,
+}
+
+impl RuntimeFunction {
+    pub fn new(definition: Arc<FunctionDefinition>) -> Self {
+        Self {
+            definition,
+            upvalues: Vec::new(),
+        }
+    }
+}
+
+impl Deref for RuntimeFunction {
+    type Target = FunctionDefinition;
+
+    fn deref(&self) -> &FunctionDefinition {
+        &self.definition
+    }
+}
+
+/// Builtin method that needs the interpreter mutably and the module it is called
+/// from, e.g. to replace a loaded module.
+pub type RuntimeMethodFn =
+    fn(&mut Interpreter, Vec<CallArgValue>, Symbol, Span) -> Result<Value, RuntimeError>;
+
 #[derive(Clone, Debug)]
 pub enum RuntimeMethodType {
     User(Arc<FunctionDefinition>),
     Native(Arc<BuiltinFn>),
+    Runtime(RuntimeMethodFn),
 }
 
 #[derive(Clone, Debug)]
@@ -221,9 +299,12 @@ pub struct RuntimeClassDefinition {
     pub name: Symbol,
     pub base_class: Option<Symbol>,
     pub fields: HashMap<Symbol, (Visibility, bool, RuntimeFieldData)>,
-    pub methods: HashMap<Symbol, (Visibility, bool, RuntimeMethodType)>,
+    /// Static fields declared with `константа`; they cannot be reassigned.
+    pub constants: HashSet<Symbol>,
+    pub methods: FastHashMap<Symbol, (Visibility, bool, RuntimeMethodType)>,
     pub constructor: Option<RuntimeMethodType>,
     pub span: Span,
+    pub doc: Option<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -231,7 +312,7 @@ pub struct RuntimeClassDefinition {
 pub struct ClassInstance {
     pub class_name: Symbol,
     pub fields: HashMap<Symbol, Option<crate::ast::prelude::ExprId>>,
-    pub field_values: HashMap<Symbol, Value>,
+    pub field_values: FastHashMap<Symbol, Value>,
     pub class_ref: SharedMut<RuntimeClassDefinition>,
 }
 
@@ -244,9 +325,15 @@ pub enum RuntimeError {
     TypeMismatch(ErrorData),
     DivisionByZero(ErrorData),
     InvalidOperation(ErrorData),
+    /// Integer arithmetic whose result does not fit in `i64`.
+    Overflow(ErrorData),
     Return(ErrorData, Value),
     TypeError(ErrorData),
     IOError(ErrorData),
+    /// A builtin the interpreter options do not allow.
+    PermissionDenied(ErrorData),
+    /// Time or operation budget is spent; `попробовать` does not catch it.
+    LimitExceeded(ErrorData),
     ImportError(ParseError),
     Panic(ErrorData),
     Raised(ErrorData, String),
@@ -262,7 +349,10 @@ impl RuntimeError {
             RuntimeError::TypeMismatch(_) | RuntimeError::TypeError(_) => "ОшибкаТипа".to_string(),
             RuntimeError::DivisionByZero(_) => "ОшибкаДеленияНаНоль".to_string(),
             RuntimeError::InvalidOperation(_) => "ОшибкаОперации".to_string(),
+            RuntimeError::Overflow(_) => "ОшибкаПереполнения".to_string(),
             RuntimeError::IOError(_) => "ОшибкаВводаВывода".to_string(),
+            RuntimeError::PermissionDenied(_) => "ОшибкаДоступа".to_string(),
+            RuntimeError::LimitExceeded(_) => "ПревышениеЛимита".to_string(),
             RuntimeError::ImportError(_) => "ОшибкаИмпорта".to_string(),
             RuntimeError::Panic(_) => "Паника".to_string(),
             RuntimeError::Raised(_, class_name) => class_name.clone(),
@@ -279,8 +369,11 @@ impl RuntimeError {
             | RuntimeError::TypeMismatch(err)
             | RuntimeError::DivisionByZero(err)
             | RuntimeError::InvalidOperation(err)
+            | RuntimeError::Overflow(err)
             | RuntimeError::TypeError(err)
             | RuntimeError::IOError(err)
+            | RuntimeError::PermissionDenied(err)
+            | RuntimeError::LimitExceeded(err)
             | RuntimeError::Panic(err)
             | RuntimeError::Raised(err, _) => err.message.clone(),
             RuntimeError::ImportError(err) => match err {
@@ -306,14 +399,38 @@ impl RuntimeError {
             | RuntimeError::TypeMismatch(err)
             | RuntimeError::DivisionByZero(err)
             | RuntimeError::InvalidOperation(err)
+            | RuntimeError::Overflow(err)
             | RuntimeError::Return(err, _)
             | RuntimeError::TypeError(err)
             | RuntimeError::IOError(err)
+            | RuntimeError::PermissionDenied(err)
+            | RuntimeError::LimitExceeded(err)
             | RuntimeError::Panic(err)
             | RuntimeError::Raised(err, _) => err.push_frame(name, location),
             RuntimeError::ImportError(_) => {}
         }
     }
+
+    /// Diagnostic payload of the error; `None` for import errors.
+    pub fn error_data_mut(&mut self) -> Option<&mut ErrorData> {
+        match self {
+            RuntimeError::UndefinedVariable(err)
+            | RuntimeError::UndefinedFunction(err)
+            | RuntimeError::UndefinedMethod(err)
+            | RuntimeError::TypeMismatch(err)
+            | RuntimeError::DivisionByZero(err)
+            | RuntimeError::InvalidOperation(err)
+            | RuntimeError::Overflow(err)
+            | RuntimeError::Return(err, _)
+            | RuntimeError::TypeError(err)
+            | RuntimeError::IOError(err)
+            | RuntimeError::PermissionDenied(err)
+            | RuntimeError::LimitExceeded(err)
+            | RuntimeError::Panic(err)
+            | RuntimeError::Raised(err, _) => Some(err),
+            RuntimeError::ImportError(_) => None,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -324,7 +441,7 @@ pub struct Environment {
     /// Direct references captured from visible parent bindings.
     pub(crate) upvalues: Vec<SharedMut<Value>>,
     /// Name resolution table; values themselves are never stored here.
-    pub(crate) bindings: HashMap<Symbol, VariableSlot>,
+    pub(crate) bindings: FastHashMap<Symbol, VariableSlot>,
     pub(crate) constants: HashSet<VariableSlot>,
     pub(crate) parent: Option<SharedMut<Environment>>,
     pub(crate) is_function: bool,
@@ -345,8 +462,13 @@ pub type SharedInterner = goida_model::SharedInterner;
 /// Main interpreter state.
 pub struct Interpreter {
     pub(crate) std_classes: HashMap<Symbol, SharedMut<RuntimeClassDefinition>>,
-    pub(crate) builtins: HashMap<Symbol, BuiltinFn>,
-    pub modules: HashMap<Symbol, Module>,
+    pub(crate) builtins: FastHashMap<Symbol, BuiltinFn>,
+    pub modules: FastHashMap<Symbol, Module>,
+    /// Modules whose body has started running; imports run on first access.
+    pub(crate) loaded_modules: HashSet<Symbol>,
+    /// Modules loaded by this interpreter or any of its forks, so that a module
+    /// first touched inside a thread does not run again on the main thread.
+    pub(crate) module_loads: SharedMut<HashMap<Symbol, LoadedModule>>,
     pub(crate) native_libraries: HashMap<PathBuf, SharedMut<LoadedNativeLibrary>>,
     pub interner: SharedInterner,
     pub(crate) environment: SharedMut<Environment>,
@@ -354,6 +476,82 @@ pub struct Interpreter {
     pub(crate) method_depth: usize,
     pub(crate) heap: Arc<crate::interpreter::heap::ObjectHeap>,
     pub source_manager: SourceManager,
+    /// Arguments after the script path, returned by `Система.аргументы()`.
+    pub(crate) script_args: Arc<Vec<String>>,
+    pub(crate) profiler: Option<Arc<crate::interpreter::profiler::Profiler>>,
+    pub(crate) debugger: Option<Arc<crate::interpreter::debugger::Debugger>>,
+    pub(crate) options: crate::interpreter::options::InterpreterOptions,
+    pub(crate) limits: Option<Arc<crate::interpreter::options::ExecutionLimits>>,
+    pub(crate) random: crate::builtins::random::SharedRandom,
+    /// Set while this interpreter runs a generator body; `выдать` sends through it.
+    pub(crate) generator: Option<Mutex<GeneratorSink>>,
+    /// Static fields whose initializers are running, to report cycles between them.
+    pub(crate) static_initializations: Vec<(SharedMut<RuntimeClassDefinition>, Symbol)>,
+    pub(crate) symbols: RuntimeSymbols,
+}
+
+/// Globals a module body left behind, handed to interpreters that load it later.
+#[derive(Clone, Debug)]
+pub(crate) struct LoadedModule {
+    pub(crate) globals: FastHashMap<Symbol, Value>,
+    pub(crate) global_slots: Vec<Option<SharedMut<Value>>>,
+}
+
+#[derive(Clone, Copy, Debug)]
+/// Names the runtime itself looks up while executing, interned once per interpreter
+/// so hot paths do not take the interner lock.
+pub(crate) struct RuntimeSymbols {
+    /// Hidden field through which built-in constructors hand back a plain value.
+    pub(crate) data: Symbol,
+    /// Name given to every function created by a lambda expression.
+    pub(crate) lambda: Symbol,
+    /// Hidden fields in which built-in classes keep their native state.
+    pub(crate) bigint: Symbol,
+    pub(crate) channel: Symbol,
+    pub(crate) connection: Symbol,
+    pub(crate) elapsed: Symbol,
+    pub(crate) file: Symbol,
+    pub(crate) items: Symbol,
+    pub(crate) millis: Symbol,
+    pub(crate) pattern: Symbol,
+    pub(crate) promise: Symbol,
+    pub(crate) regex: Symbol,
+    pub(crate) set: Symbol,
+    pub(crate) socket: Symbol,
+    pub(crate) started: Symbol,
+    pub(crate) text: Symbol,
+    pub(crate) zone: Symbol,
+    /// Fields of the `ХТТПОтвет` objects that requests return.
+    pub(crate) status: Symbol,
+    pub(crate) headers: Symbol,
+    pub(crate) body: Symbol,
+}
+
+impl RuntimeSymbols {
+    pub(crate) fn new(interner: &SharedInterner) -> Self {
+        interner.write(|interner| Self {
+            data: interner.get_or_intern("__data"),
+            lambda: interner.get_or_intern("<lambda>"),
+            bigint: interner.get_or_intern(bigint::VALUE_FIELD),
+            channel: interner.get_or_intern(channel::CHANNEL_FIELD),
+            connection: interner.get_or_intern(database::CONNECTION_FIELD),
+            elapsed: interner.get_or_intern(stopwatch::ELAPSED_FIELD),
+            file: interner.get_or_intern(file::HANDLE_FIELD),
+            items: interner.get_or_intern(queue::ITEMS_FIELD),
+            millis: interner.get_or_intern(datetime::MILLIS_FIELD),
+            pattern: interner.get_or_intern(regex::PATTERN_FIELD),
+            promise: interner.get_or_intern(promise::PROMISE_FIELD),
+            regex: interner.get_or_intern(regex::REGEX_FIELD),
+            set: interner.get_or_intern(set::SET_FIELD),
+            socket: interner.get_or_intern(tcp::SOCKET_FIELD),
+            started: interner.get_or_intern(stopwatch::STARTED_FIELD),
+            text: interner.get_or_intern(string_builder::TEXT_FIELD),
+            zone: interner.get_or_intern(datetime::ZONE_FIELD),
+            status: interner.get_or_intern(http::STATUS_FIELD),
+            headers: interner.get_or_intern(http::HEADERS_FIELD),
+            body: interner.get_or_intern(http::BODY_FIELD),
+        })
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -375,7 +573,7 @@ pub struct Module {
     pub compiled: CompiledModule,
     pub classes: HashMap<Symbol, SharedMut<RuntimeClassDefinition>>,
     pub modules: HashMap<Symbol, Module>,
-    pub globals: HashMap<Symbol, Value>,
+    pub globals: FastHashMap<Symbol, Value>,
     pub(crate) global_slots: Vec<Option<SharedMut<Value>>>,
 }
 
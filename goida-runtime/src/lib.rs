@@ -1,3 +1,8 @@
+//! Goida interpreter for embedding into Rust programs.
+//!
+//! [`eval_str`] runs a snippet in a fresh session; [`Session`] keeps globals between
+//! calls and takes [`InterpreterOptions`] for sandboxing and limits.
+
 pub use goida_syntax::ast;
 pub(crate) use goida_syntax::import_paths;
 pub mod builtins;
@@ -10,3 +15,13 @@ pub mod session;
 pub mod shared;
 pub mod traits;
 pub(crate) mod vm;
+
+pub use interpreter::host::HostClass;
+pub use interpreter::options::{Capability, InterpreterOptions};
+pub use interpreter::prelude::{DictKey, Interpreter, RuntimeError, Value};
+pub use session::{EvalError, Session};
+
+/// Runs `source` in a new default session and returns its trailing expression value.
+pub fn eval_str(source: &str) -> Result<Value, EvalError> {
+    Session::new().eval(source)
+}
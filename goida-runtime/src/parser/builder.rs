@@ -98,6 +98,7 @@ impl ParserTrait {
             body,
             span: func_span,
             module: None,
+            doc: function.doc,
         };
 
         if top_level {
@@ -175,10 +176,12 @@ impl ParserTrait {
         }
 
         let mut class_def = RuntimeClassDefinition::new_with_base(name, base_class, class_span);
+        class_def.doc = class.doc;
         if let Some(base_symbol) = base_class {
             if let Some(base_def) = self.module.classes.get(&base_symbol) {
                 base_def.read(|base| {
                     class_def.fields.extend(base.fields.clone());
+                    class_def.constants.extend(base.constants.iter().copied());
                     class_def.methods.extend(base.methods.clone());
                     class_def.constructor = base.constructor.clone();
                 });
@@ -201,17 +204,23 @@ impl ParserTrait {
                         field.is_static,
                         RuntimeFieldData::Expression(default_value),
                     );
+                    if field.is_const {
+                        class_def.constants.insert(name);
+                    } else {
+                        class_def.constants.remove(&name);
+                    }
                     let _ = field_type;
                 }
                 syn::ClassItemKind::Constructor(method) => {
-                    let function = self.build_method_function(method, item_span)?;
+                    let function = self.build_method_function(method, base_class, item_span)?;
                     class_def.set_constructor(function);
                 }
                 syn::ClassItemKind::Method(method) => {
                     let method_name = self.intern(&method.name);
                     let visibility = self.visibility(method.visibility.clone());
                     let is_static = method.is_static;
-                    let function = self.build_method_function(method, item_span)?;
+                    let base_class = base_class.filter(|_| !is_static);
+                    let function = self.build_method_function(method, base_class, item_span)?;
                     class_def.add_method(method_name, visibility, is_static, function);
                 }
             }
@@ -229,9 +238,14 @@ impl ParserTrait {
     fn build_method_function(
         &mut self,
         method: syn::ClassMethod,
+        base_class: Option<string_interner::DefaultSymbol>,
         span: Span,
     ) -> Result<FunctionDefinition, ParseError> {
-        let body_items = self.build_items_as_block(method.body)?;
+        let receiver = method.params.first().map(|param| self.intern(&param.name));
+        let outer = std::mem::replace(&mut self.super_context, base_class.zip(receiver));
+        let body_items = self.build_items_as_block(method.body);
+        self.super_context = outer;
+        let body_items = body_items?;
         let body = self
             .module
             .arena
@@ -243,6 +257,7 @@ impl ParserTrait {
             body,
             span,
             module: None,
+            doc: method.doc,
         })
     }
 
@@ -306,6 +321,7 @@ impl ParserTrait {
             output.push(CallArg {
                 name: arg.name.map(|name| self.intern(&name)),
                 value: self.build_expr(arg.value)?,
+                spread: arg.spread,
             });
         }
         Ok(output)
@@ -355,6 +371,9 @@ impl ParserTrait {
             syn::BinaryOp::Ge => BinaryOperator::Ge,
             syn::BinaryOp::And => BinaryOperator::And,
             syn::BinaryOp::Or => BinaryOperator::Or,
+            syn::BinaryOp::In => BinaryOperator::In,
+            syn::BinaryOp::NotIn => BinaryOperator::NotIn,
+            syn::BinaryOp::Is => BinaryOperator::Is,
         }
     }
 
@@ -48,10 +48,33 @@ impl ParserTrait {
                 object: self.build_expr(*object)?,
                 index: self.build_expr(*index)?,
             },
+            syn::ExprKind::Slice { object, start, end } => ExpressionKind::Slice {
+                object: self.build_expr(*object)?,
+                start: start.map(|start| self.build_expr(*start)).transpose()?,
+                end: end.map(|end| self.build_expr(*end)).transpose()?,
+            },
             syn::ExprKind::ObjectCreation { class_name, args } => ExpressionKind::ObjectCreation {
                 class_name: self.intern(&class_name),
                 args: self.build_call_args(args)?,
             },
+            syn::ExprKind::SuperCall { method, args } => {
+                let Some((class, receiver)) = self.super_context else {
+                    return Err(ParseError::InvalidSyntax(ErrorData::new(
+                        span,
+                        "'родитель' доступен только в методах и конструкторах класса-наследника"
+                            .into(),
+                    )));
+                };
+                ExpressionKind::SuperCall {
+                    class,
+                    receiver: self
+                        .module
+                        .arena
+                        .add_expression(ExpressionKind::Identifier(receiver), span),
+                    method: method.map(|method| self.intern(&method)),
+                    args: self.build_call_args(args)?,
+                }
+            }
             syn::ExprKind::Lambda { params, body } => {
                 let params = self.build_params(params)?;
                 let body = match body {
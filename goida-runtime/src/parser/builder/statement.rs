@@ -30,6 +30,34 @@ impl ParserTrait {
                 let value = self.build_expr(value)?;
                 self.build_target_assignment(target, value, span)
             }
+            syn::StmtKind::MultiAssign { targets, values } => {
+                let targets: Vec<ExprId> = targets
+                    .into_iter()
+                    .map(|target| {
+                        let target = self.build_expr(target)?;
+                        self.check_assignment_target(target, span)?;
+                        Ok(target)
+                    })
+                    .collect::<Result<_, ParseError>>()?;
+                if values.len() > 1 && values.len() != targets.len() {
+                    return Err(ParseError::InvalidSyntax(ErrorData::new(
+                        span,
+                        format!(
+                            "Слева {} цели присваивания, а справа {} значения",
+                            targets.len(),
+                            values.len()
+                        ),
+                    )));
+                }
+                let values = values
+                    .into_iter()
+                    .map(|value| self.build_expr(value))
+                    .collect::<Result<_, _>>()?;
+                Ok(self
+                    .module
+                    .arena
+                    .add_statement(StatementKind::MultiAssign { targets, values }, span))
+            }
             syn::StmtKind::CompoundAssign { target, op, value } => {
                 let target = self.build_expr(target)?;
                 let value = self.build_expr(value)?;
@@ -132,6 +160,27 @@ impl ParserTrait {
                     span,
                 ))
             }
+            syn::StmtKind::With {
+                variable,
+                value,
+                body,
+            } => {
+                let value = self.build_expr(value)?;
+                let body_items = self.build_items_as_block(body)?;
+                let body = self
+                    .module
+                    .arena
+                    .add_statement(StatementKind::Block(body_items), span);
+                let variable = self.intern(&variable);
+                Ok(self.module.arena.add_statement(
+                    StatementKind::With {
+                        variable,
+                        value,
+                        body,
+                    },
+                    span,
+                ))
+            }
             syn::StmtKind::Thread { body } => {
                 let body_items = self.build_items_as_block(body)?;
                 let body = self
@@ -143,6 +192,17 @@ impl ParserTrait {
                     .arena
                     .add_statement(StatementKind::Thread { body }, span))
             }
+            syn::StmtKind::Defer { body } => {
+                let body_items = self.build_items_as_block(body)?;
+                let body = self
+                    .module
+                    .arena
+                    .add_statement(StatementKind::Block(body_items), span);
+                Ok(self
+                    .module
+                    .arena
+                    .add_statement(StatementKind::Defer { body }, span))
+            }
             syn::StmtKind::Try { body, handlers } => {
                 let body_items = self.build_items_as_block(body)?;
                 let body = self
@@ -187,6 +247,13 @@ impl ParserTrait {
                     .arena
                     .add_statement(StatementKind::Return(expr), span))
             }
+            syn::StmtKind::Yield(expr) => {
+                let expr = self.build_expr(expr)?;
+                Ok(self
+                    .module
+                    .arena
+                    .add_statement(StatementKind::Yield(expr), span))
+            }
             syn::StmtKind::Expr(expr) => {
                 let expr = self.build_expr(expr)?;
                 Ok(self
@@ -233,6 +300,22 @@ impl ParserTrait {
         }
     }
 
+    /// Only names, object fields and indexes can be assigned to.
+    fn check_assignment_target(&self, target: ExprId, span: Span) -> Result<(), ParseError> {
+        match self.module.arena.get_expression(target).map(|node| &node.kind) {
+            Some(
+                ExpressionKind::Identifier(_)
+                | ExpressionKind::PropertyAccess { .. }
+                | ExpressionKind::Index { .. },
+            ) => Ok(()),
+            _ => Err(ParseError::InvalidSyntax(ErrorData::new(
+                span,
+                "Левая часть присваивания должна быть переменной, полем объекта или индексом списка"
+                    .into(),
+            ))),
+        }
+    }
+
     fn build_for_update(&mut self, update: syn::ForUpdate) -> Result<StmtId, ParseError> {
         match update {
             syn::ForUpdate::Assign { name, value, span } => {
@@ -0,0 +1,341 @@
+use crate::ast::prelude::{
+    AstArena, DataType, FunctionDefinition, RuntimeType, Span, TypeId, Visibility,
+};
+use crate::builtins::registry::BUILTINS;
+use crate::interpreter::prelude::{RuntimeMethodType, SharedInterner};
+use crate::parser::prelude::{ParseError, Parser as ParserTrait};
+
+/// Documentation extracted from one module: its functions and classes in source order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModuleDocs {
+    pub name: String,
+    pub functions: Vec<FunctionDocs>,
+    pub classes: Vec<ClassDocs>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionDocs {
+    pub name: String,
+    pub params: Vec<ParamDocs>,
+    pub return_type: Option<String>,
+    pub doc: Option<String>,
+    pub kind: FunctionKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FunctionKind {
+    Function,
+    Method,
+    StaticMethod,
+    Constructor,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParamDocs {
+    pub name: String,
+    pub type_name: String,
+    /// Source text of the default value.
+    pub default_value: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassDocs {
+    pub name: String,
+    pub base_class: Option<String>,
+    pub doc: Option<String>,
+    pub constructor: Option<FunctionDocs>,
+    /// Public methods only.
+    pub methods: Vec<FunctionDocs>,
+}
+
+impl ParserTrait {
+    /// Parses source and collects its documented API without validating it.
+    pub fn module_docs(self, code: &str) -> Result<ModuleDocs, ParseError> {
+        let interner = self.interner.clone();
+        let module = self.parse_syntax(code)?;
+        let context = DocsContext {
+            arena: &module.arena,
+            interner: &interner,
+            code,
+        };
+
+        let mut functions = module.functions.values().collect::<Vec<_>>();
+        functions.sort_by_key(|function| function.span.start);
+        let functions = functions
+            .into_iter()
+            .map(|function| context.function(function, FunctionKind::Function))
+            .collect();
+
+        let mut classes = module
+            .classes
+            .values()
+            .map(|class| class.read(|class| class.clone()))
+            // Встроенные классы ошибок тоже лежат в модуле, но объявлены не в нём.
+            .filter(|class| {
+                let name = context.symbol(class.name);
+                !BUILTINS
+                    .error_classes()
+                    .iter()
+                    .any(|error| error.name == name)
+            })
+            .collect::<Vec<_>>();
+        classes.sort_by_key(|class| class.span.start);
+        let classes = classes
+            .into_iter()
+            .map(|class| {
+                let user_function = |method: &RuntimeMethodType| match method {
+                    RuntimeMethodType::User(function) => Some(function.clone()),
+                    RuntimeMethodType::Native(_) | RuntimeMethodType::Runtime(_) => None,
+                };
+                let mut methods = class
+                    .methods
+                    .values()
+                    .filter(|(visibility, _, _)| matches!(visibility, Visibility::Public))
+                    .filter_map(|(_, is_static, method)| Some((user_function(method)?, *is_static)))
+                    // Унаследованные методы документируются у базового класса.
+                    .filter(|(function, _)| declared_in(&class.span, &function.span))
+                    .collect::<Vec<_>>();
+                methods.sort_by_key(|(function, _)| function.span.start);
+                ClassDocs {
+                    name: context.symbol(class.name),
+                    base_class: class.base_class.map(|base| context.symbol(base)),
+                    doc: class.doc.clone(),
+                    constructor: class
+                        .constructor
+                        .as_ref()
+                        .and_then(user_function)
+                        .filter(|function| declared_in(&class.span, &function.span))
+                        .map(|function| context.function(&function, FunctionKind::Constructor)),
+                    methods: methods
+                        .into_iter()
+                        .map(|(function, is_static)| {
+                            let kind = if is_static {
+                                FunctionKind::StaticMethod
+                            } else {
+                                FunctionKind::Method
+                            };
+                            context.function(&function, kind)
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        Ok(ModuleDocs {
+            name: module.path.to_string_lossy().to_string(),
+            functions,
+            classes,
+        })
+    }
+}
+
+fn declared_in(class: &Span, member: &Span) -> bool {
+    class.file_id == member.file_id && class.start <= member.start && member.end <= class.end
+}
+
+struct DocsContext<'a> {
+    arena: &'a AstArena,
+    interner: &'a SharedInterner,
+    code: &'a str,
+}
+
+impl DocsContext<'_> {
+    fn symbol(&self, symbol: string_interner::DefaultSymbol) -> String {
+        self.arena
+            .resolve_symbol(self.interner, symbol)
+            .unwrap_or_default()
+    }
+
+    fn function(&self, function: &FunctionDefinition, kind: FunctionKind) -> FunctionDocs {
+        let name = self.symbol(function.name);
+        // Первый параметр метода и конструктора получает сам объект (`это`).
+        let receiver = usize::from(matches!(
+            kind,
+            FunctionKind::Method | FunctionKind::Constructor
+        ));
+        FunctionDocs {
+            name: match kind {
+                FunctionKind::Constructor if name == "new" => "новый".into(),
+                _ => name,
+            },
+            params: function
+                .params
+                .iter()
+                .skip(receiver)
+                .map(|param| ParamDocs {
+                    name: self.symbol(param.name),
+                    type_name: self.type_name(param.param_type),
+                    default_value: param
+                        .default_value
+                        .and_then(|expr| self.arena.get_expression(expr))
+                        .and_then(|expr| {
+                            self.code
+                                .get(expr.span.start as usize..expr.span.end as usize)
+                        })
+                        .map(str::to_string),
+                })
+                .collect(),
+            return_type: function.return_type.map(|id| self.type_name(id)),
+            doc: function.doc.clone(),
+            kind,
+        }
+    }
+
+    fn type_name(&self, id: TypeId) -> String {
+        match self.arena.types.get(id as usize) {
+            Some(DataType::Primitive(primitive)) => primitive.to_string(),
+            Some(DataType::List(_)) => "список".into(),
+            Some(DataType::Array(_)) => "массив".into(),
+            Some(DataType::Dict { .. }) => "словарь".into(),
+            Some(DataType::Function { .. }) => "функция".into(),
+            Some(DataType::Object(name)) => self.symbol(*name),
+            Some(DataType::Runtime(RuntimeType::Class)) => "класс".into(),
+            Some(DataType::Runtime(RuntimeType::Module)) => "модуль".into(),
+            Some(DataType::Runtime(RuntimeType::Resource)) => "ресурс".into(),
+            Some(DataType::Unit) => "пустота".into(),
+            Some(DataType::Any) | None => "неизвестно".into(),
+        }
+    }
+}
+
+impl FunctionDocs {
+    /// Signature as it would be written in source.
+    pub fn signature(&self) -> String {
+        let params = self
+            .params
+            .iter()
+            .map(|param| match &param.default_value {
+                Some(default) => format!("{}: {} = {}", param.name, param.type_name, default),
+                None => format!("{}: {}", param.name, param.type_name),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let prefix = match self.kind {
+            FunctionKind::Function | FunctionKind::Method => "функция",
+            FunctionKind::StaticMethod => "статичный функция",
+            FunctionKind::Constructor => "конструктор",
+        };
+        match &self.return_type {
+            Some(return_type) => format!("{prefix} {}({params}) -> {return_type}", self.name),
+            None => format!("{prefix} {}({params})", self.name),
+        }
+    }
+}
+
+impl ModuleDocs {
+    pub fn to_markdown(&self) -> String {
+        let mut output = format!("# {}\n", self.name);
+        if !self.functions.is_empty() {
+            output.push_str("\n## Функции\n");
+            for function in &self.functions {
+                output.push_str(&format!("\n### {}\n\n", function.name));
+                push_markdown_function(&mut output, function);
+            }
+        }
+        if !self.classes.is_empty() {
+            output.push_str("\n## Классы\n");
+            for class in &self.classes {
+                match &class.base_class {
+                    Some(base) => output.push_str(&format!("\n### {} : {}\n", class.name, base)),
+                    None => output.push_str(&format!("\n### {}\n", class.name)),
+                }
+                if let Some(doc) = &class.doc {
+                    output.push_str(&format!("\n{doc}\n"));
+                }
+                for method in class.constructor.iter().chain(&class.methods) {
+                    output.push_str(&format!("\n#### {}.{}\n\n", class.name, method.name));
+                    push_markdown_function(&mut output, method);
+                }
+            }
+        }
+        output
+    }
+
+    pub fn to_html(&self) -> String {
+        let mut body = format!("<h1>{}</h1>\n", escape_html(&self.name));
+        if !self.functions.is_empty() {
+            body.push_str("<h2>Функции</h2>\n");
+            for function in &self.functions {
+                body.push_str(&format!("<h3>{}</h3>\n", escape_html(&function.name)));
+                push_html_function(&mut body, function);
+            }
+        }
+        if !self.classes.is_empty() {
+            body.push_str("<h2>Классы</h2>\n");
+            for class in &self.classes {
+                let title = match &class.base_class {
+                    Some(base) => format!("{} : {}", class.name, base),
+                    None => class.name.clone(),
+                };
+                body.push_str(&format!("<h3>{}</h3>\n", escape_html(&title)));
+                if let Some(doc) = &class.doc {
+                    body.push_str(&format!("<p>{}</p>\n", escape_html(doc)));
+                }
+                for method in class.constructor.iter().chain(&class.methods) {
+                    body.push_str(&format!(
+                        "<h4>{}.{}</h4>\n",
+                        escape_html(&class.name),
+                        escape_html(&method.name)
+                    ));
+                    push_html_function(&mut body, method);
+                }
+            }
+        }
+        format!(
+            "<!DOCTYPE html>\n<html lang=\"ru\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n<body>\n{body}</body>\n</html>\n",
+            escape_html(&self.name)
+        )
+    }
+}
+
+fn push_markdown_function(output: &mut String, function: &FunctionDocs) {
+    output.push_str(&format!("```goida\n{}\n```\n", function.signature()));
+    if let Some(doc) = &function.doc {
+        output.push_str(&format!("\n{doc}\n"));
+    }
+    if !function.params.is_empty() {
+        output.push_str("\nПараметры:\n\n");
+        for param in &function.params {
+            output.push_str(&format!("- `{}`: `{}`", param.name, param.type_name));
+            if let Some(default) = &param.default_value {
+                output.push_str(&format!(", по умолчанию `{default}`"));
+            }
+            output.push('\n');
+        }
+    }
+}
+
+fn push_html_function(output: &mut String, function: &FunctionDocs) {
+    output.push_str(&format!(
+        "<pre><code>{}</code></pre>\n",
+        escape_html(&function.signature())
+    ));
+    if let Some(doc) = &function.doc {
+        output.push_str(&format!("<p>{}</p>\n", escape_html(doc)));
+    }
+    if !function.params.is_empty() {
+        output.push_str("<ul>\n");
+        for param in &function.params {
+            output.push_str(&format!(
+                "<li><code>{}</code>: <code>{}</code>",
+                escape_html(&param.name),
+                escape_html(&param.type_name)
+            ));
+            if let Some(default) = &param.default_value {
+                output.push_str(&format!(
+                    ", по умолчанию <code>{}</code>",
+                    escape_html(default)
+                ));
+            }
+            output.push_str("</li>\n");
+        }
+        output.push_str("</ul>\n");
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -155,7 +155,14 @@ impl SourceFormatter {
     fn class_item(&mut self, item: &syn::ClassItem) {
         match &item.node {
             syn::ClassItemKind::Field(field) => {
-                let mut parts = modifiers(field.visibility.clone(), field.is_static, self.language);
+                let mut parts = modifiers(
+                    field.visibility.clone(),
+                    field.is_static && !field.is_const,
+                    self.language,
+                );
+                if field.is_const {
+                    parts.push(self.keyword("const", "константа").to_string());
+                }
                 parts.push(format!("{}: {}", field.name, field.type_name));
                 let mut line = parts.join(" ");
                 if let Some(value) = &field.default_value {
@@ -288,6 +295,16 @@ impl SourceFormatter {
                     expr(value, self.language)
                 ));
             }
+            syn::StmtKind::MultiAssign { targets, values } => {
+                let list = |exprs: &[syn::Expr]| {
+                    exprs
+                        .iter()
+                        .map(|value| expr(value, self.language))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+                self.line(format!("{} = {}", list(targets), list(values)));
+            }
             syn::StmtKind::CompoundAssign { target, op, value } => {
                 self.line(format!(
                     "{} {} {}",
@@ -349,6 +366,22 @@ impl SourceFormatter {
                 self.indent -= 1;
                 self.line("}");
             }
+            syn::StmtKind::With {
+                variable,
+                value,
+                body,
+            } => {
+                self.line(format!(
+                    "{} ({} = {}) {{",
+                    self.keyword("with", "с"),
+                    variable,
+                    expr(value, self.language)
+                ));
+                self.indent += 1;
+                self.items(body);
+                self.indent -= 1;
+                self.line("}");
+            }
             syn::StmtKind::Thread { body } => {
                 self.line(format!("{} {{", self.keyword("thread", "поток")));
                 self.indent += 1;
@@ -356,6 +389,13 @@ impl SourceFormatter {
                 self.indent -= 1;
                 self.line("}");
             }
+            syn::StmtKind::Defer { body } => {
+                self.line(format!("{} {{", self.keyword("defer", "отложить")));
+                self.indent += 1;
+                self.items(body);
+                self.indent -= 1;
+                self.line("}");
+            }
             syn::StmtKind::Try { body, handlers } => {
                 self.line(format!("{} {{", self.keyword("try", "попробовать")));
                 self.indent += 1;
@@ -403,6 +443,11 @@ impl SourceFormatter {
                     self.line(self.keyword("return", "вернуть"));
                 }
             }
+            syn::StmtKind::Yield(value) => self.line(format!(
+                "{} {}",
+                self.keyword("yield", "выдать"),
+                expr(value, self.language)
+            )),
             syn::StmtKind::Expr(value) => self.line(expr(value, self.language)),
         }
     }
@@ -489,7 +534,8 @@ impl syn::Visitor for SourceFormatter {
     }
 
     fn visit_comment(&mut self, comment: &syn::Comment) {
-        self.line(format!("// {}", comment.text));
+        let marker = if comment.doc { "///" } else { "//" };
+        self.line(format!("{marker} {}", comment.text));
     }
 }
 
@@ -549,6 +595,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn keeps_doc_comments_as_doc_comments() {
+        let source = "/// doc\nfunction f() {\n}\n";
+        let mut program = grammar::ProgramParser::new()
+            .parse(lex(source))
+            .expect("source should parse");
+        program.comments = crate::parser::parser::collect_comments(source);
+
+        assert_eq!(format_program(&program, FormatLanguage::English), source);
+    }
+
     #[test]
     fn formatted_macro_definition_is_parseable() {
         let source = "macro twice { ($x:expr) => { $x + $x }; }\nvalue = twice!(2)\n";
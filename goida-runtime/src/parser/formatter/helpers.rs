@@ -119,9 +119,11 @@ pub(super) fn expr_with_parent_prec(
         syn::ExprKind::Identifier(name) => name.clone(),
         syn::ExprKind::Binary { op, left, right } => {
             let prec = binary_prec(*op);
+            // `(a < b) < c` keeps its parentheses, otherwise it would read as a chain.
+            let chained = prec == binary_prec(syn::BinaryOp::Eq);
             format!(
                 "{} {} {}",
-                expr_with_parent_prec(left, prec, false, language),
+                expr_with_parent_prec(left, prec, chained, language),
                 binary_op(*op, language),
                 expr_with_parent_prec(right, prec, true, language)
             )
@@ -166,6 +168,20 @@ pub(super) fn expr_with_parent_prec(
                 expr(index, language)
             )
         }
+        syn::ExprKind::Slice { object, start, end } => {
+            let bound = |bound: &Option<Box<syn::Expr>>| {
+                bound
+                    .as_ref()
+                    .map(|bound| expr(bound, language))
+                    .unwrap_or_default()
+            };
+            format!(
+                "{}[{}:{}]",
+                expr_with_parent_prec(object, own_prec, false, language),
+                bound(start),
+                bound(end)
+            )
+        }
         syn::ExprKind::ObjectCreation { class_name, args } => {
             format!(
                 "{} {}({})",
@@ -174,6 +190,16 @@ pub(super) fn expr_with_parent_prec(
                 format_args(args, language)
             )
         }
+        syn::ExprKind::SuperCall { method, args } => {
+            format!(
+                "{}.{}({})",
+                language.select("super", "родитель"),
+                method
+                    .as_deref()
+                    .unwrap_or(language.select("constructor", "конструктор")),
+                format_args(args, language)
+            )
+        }
         syn::ExprKind::Lambda { params, body } => {
             let body = match body {
                 syn::LambdaBody::Expr(value) => expr(value, language),
@@ -206,8 +232,10 @@ pub(super) fn expr_prec(expr: &syn::Expr) -> u8 {
         syn::ExprKind::Unary { .. } => 6,
         syn::ExprKind::FunctionCall { .. }
         | syn::ExprKind::MethodCall { .. }
+        | syn::ExprKind::SuperCall { .. }
         | syn::ExprKind::PropertyAccess { .. }
-        | syn::ExprKind::Index { .. } => 7,
+        | syn::ExprKind::Index { .. }
+        | syn::ExprKind::Slice { .. } => 7,
         _ => 8,
     }
 }
@@ -221,7 +249,10 @@ pub(super) fn binary_prec(op: syn::BinaryOp) -> u8 {
         | syn::BinaryOp::Lt
         | syn::BinaryOp::Le
         | syn::BinaryOp::Gt
-        | syn::BinaryOp::Ge => 3,
+        | syn::BinaryOp::Ge
+        | syn::BinaryOp::In
+        | syn::BinaryOp::NotIn
+        | syn::BinaryOp::Is => 3,
         syn::BinaryOp::Add | syn::BinaryOp::Sub => 4,
         syn::BinaryOp::Mul | syn::BinaryOp::Div | syn::BinaryOp::Mod => 5,
     }
@@ -242,6 +273,9 @@ pub(super) fn binary_op(op: syn::BinaryOp, language: FormatLanguage) -> &'static
         syn::BinaryOp::Ge => ">=",
         syn::BinaryOp::And => language.select("and", "и"),
         syn::BinaryOp::Or => language.select("or", "или"),
+        syn::BinaryOp::In => language.select("in", "в"),
+        syn::BinaryOp::NotIn => language.select("not in", "не в"),
+        syn::BinaryOp::Is => language.select("is", "является"),
     }
 }
 
@@ -257,6 +291,8 @@ pub(super) fn format_args(args: &[syn::CallArg], language: FormatLanguage) -> St
         .map(|arg| {
             if let Some(name) = &arg.name {
                 format!("{name} = {}", expr(&arg.value, language))
+            } else if arg.spread {
+                format!("...{}", expr(&arg.value, language))
             } else {
                 expr(&arg.value, language)
             }
@@ -394,8 +430,15 @@ fn localized_token_text(token: &Token, language: FormatLanguage) -> String {
         Token::KwAs => Some(("as", "как")),
         Token::KwNew => Some(("new", "новый")),
         Token::KwReturn => Some(("return", "вернуть")),
+        Token::KwYield => Some(("yield", "выдать")),
+        Token::KwWith => Some(("with", "с")),
+        Token::KwDefer => Some(("defer", "отложить")),
+        Token::KwSuper => Some(("super", "родитель")),
         Token::KwAnd => Some(("and", "и")),
         Token::KwOr => Some(("or", "или")),
+        Token::KwIn => Some(("in", "в")),
+        Token::KwNot => Some(("not", "не")),
+        Token::KwIs => Some(("is", "является")),
         Token::True => Some(("true", "истина")),
         Token::False => Some(("false", "ложь")),
         Token::Empty => Some(("void", "пустота")),
@@ -1,12 +1,58 @@
 use crate::ast::prelude::*;
 use crate::import_paths::resolve_import_path;
 use crate::interpreter::prelude::Module;
+use crate::parser::parser::parse_program;
 use crate::parser::prelude::{ParseError, Parser as ParserTrait};
-use crate::parser::structs::ModuleLoadState;
+use crate::parser::structs::{ModuleLoadState, ModuleLoader, PreparsedFile};
+use crate::parser::syntax::{ItemKind, Program};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::thread;
 use string_interner::DefaultSymbol as Symbol;
 
 impl ParserTrait {
+    /// Reads and parses every file the program imports, directly or through
+    /// other imports, on several threads. Modules are still built, checked and
+    /// compiled one by one in import order; they just find their syntax trees
+    /// ready. Files that cannot be read or parsed are left to that pass, which
+    /// reports the error at the import.
+    pub(crate) fn prefetch_imports(&self, program: &Program) {
+        let mut seen = HashSet::new();
+        let mut pending = self
+            .module_loader
+            .read(|loader| imported_files(loader, &self.module.path, program, &mut seen));
+        let workers = thread::available_parallelism().map_or(1, |count| count.get());
+        while !pending.is_empty() {
+            let chunk_size = pending.len().div_ceil(workers);
+            let parsed = thread::scope(|scope| {
+                let handles = pending
+                    .chunks(chunk_size)
+                    .map(|files| {
+                        scope.spawn(move || {
+                            files
+                                .iter()
+                                .filter_map(|(path, code)| preparse(path, code.as_deref()))
+                                .collect::<Vec<_>>()
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                handles
+                    .into_iter()
+                    .flat_map(|handle| handle.join().unwrap_or_default())
+                    .collect::<Vec<_>>()
+            });
+            pending = self.module_loader.write(|loader| {
+                let mut next = Vec::new();
+                for (path, file) in parsed {
+                    next.extend(imported_files(loader, &path, &file.syntax, &mut seen));
+                    loader.preparsed.insert(path, file);
+                }
+                next
+            });
+        }
+    }
+
     pub(crate) fn register_imported_type_aliases(
         &mut self,
         alias_symbol: Symbol,
@@ -51,7 +97,11 @@ impl ParserTrait {
                 .unwrap_or_default()
                 .to_string()
         });
-        let full_path = resolve_import_path(&self.module.path, &path_str);
+        let import_key = (self.module.path.clone(), path_str.clone());
+        let full_path = self
+            .module_loader
+            .read(|loader| loader.sources.imports.get(&import_key).cloned())
+            .unwrap_or_else(|| resolve_import_path(&self.module.path, &path_str));
 
         full_path
             .file_stem()
@@ -99,11 +149,26 @@ impl ParserTrait {
                 .insert(normalized_path.clone(), ModuleLoadState::Loading);
         });
 
-        let code = std::fs::read_to_string(&full_path).map_err(|error| {
-            let message = format!("Cannot read module {}: {}", full_path.display(), error);
-            self.cache_failed_module(normalized_path.clone(), message.clone());
-            ParseError::ImportError(ErrorData::new(span, message))
-        })?;
+        let embedded = self.module_loader.read(|loader| {
+            loader
+                .sources
+                .files
+                .get(&full_path)
+                .or_else(|| loader.preparsed.get(&full_path).map(|file| &file.code))
+                .cloned()
+        });
+        let code = match embedded {
+            Some(code) => code,
+            None => std::fs::read_to_string(&full_path).map_err(|error| {
+                let message = format!("Cannot read module {}: {}", full_path.display(), error);
+                self.cache_failed_module(normalized_path.clone(), message.clone());
+                ParseError::ImportError(ErrorData::new(span, message))
+            })?,
+        };
+        self.module_loader.write(|loader| {
+            loader.sources.files.insert(full_path.clone(), code.clone());
+            loader.sources.imports.insert(import_key, full_path.clone());
+        });
 
         let sub_parser = ParserTrait::with_module_loader(
             self.interner.clone(),
@@ -160,3 +225,48 @@ fn parse_error_message(error: &ParseError) -> String {
         | ParseError::ImportError(data) => data.message.clone(),
     }
 }
+
+/// Resolved paths of the files `program` imports that are not loaded, parsed
+/// or about to be parsed yet, with their text when it is served from memory.
+fn imported_files(
+    loader: &ModuleLoader,
+    importer: &Path,
+    program: &Program,
+    seen: &mut HashSet<PathBuf>,
+) -> Vec<(PathBuf, Option<String>)> {
+    program
+        .items
+        .iter()
+        .filter_map(|item| match &item.node {
+            ItemKind::Import(import) => Some(
+                loader
+                    .sources
+                    .imports
+                    .get(&(importer.to_path_buf(), import.path.clone()))
+                    .cloned()
+                    .unwrap_or_else(|| resolve_import_path(importer, &import.path)),
+            ),
+            _ => None,
+        })
+        .filter(|path| {
+            !loader.preparsed.contains_key(path)
+                && !loader
+                    .modules
+                    .contains_key(&path.canonicalize().unwrap_or(path.clone()))
+                && seen.insert(path.clone())
+        })
+        .map(|path| {
+            let code = loader.sources.files.get(&path).cloned();
+            (path, code)
+        })
+        .collect()
+}
+
+fn preparse(path: &Path, code: Option<&str>) -> Option<(PathBuf, PreparsedFile)> {
+    let code = match code {
+        Some(code) => code.to_string(),
+        None => std::fs::read_to_string(path).ok()?,
+    };
+    let syntax = parse_program(&code).ok()?;
+    Some((path.to_path_buf(), PreparsedFile { code, syntax }))
+}
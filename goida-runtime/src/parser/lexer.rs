@@ -1,4 +1,5 @@
 use logos::Logos;
+use std::collections::VecDeque;
 use std::ops::Range;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -82,12 +83,27 @@ pub(crate) enum Token {
     #[token("вернуть")]
     #[token("return")]
     KwReturn,
+    #[token("выдать")]
+    #[token("yield")]
+    KwYield,
     #[token("и")]
     #[token("and")]
     KwAnd,
     #[token("или")]
     #[token("or")]
     KwOr,
+    /// `в` / `in` between two operands; elsewhere it stays an identifier.
+    KwIn,
+    /// `не` / `not` right before `в`.
+    KwNot,
+    /// `является` / `is` between two operands; elsewhere it stays an identifier.
+    KwIs,
+    /// `с` / `with` opening a statement `с (имя = ...) { ... }`; elsewhere it stays an identifier.
+    KwWith,
+    /// `отложить` / `defer` opening a statement `отложить { ... }`; elsewhere it stays an identifier.
+    KwDefer,
+    /// `родитель` / `super` right before `.метод(` or `.конструктор(`; elsewhere it stays an identifier.
+    KwSuper,
     #[token("истина")]
     #[token("true")]
     True,
@@ -148,6 +164,9 @@ pub(crate) enum Token {
     #[token(".")]
     Dot,
     MethodDot,
+    /// `...` before a call argument spreads a collection into positional arguments.
+    #[token("...")]
+    Ellipsis,
     #[token(",")]
     Comma,
     #[token(":")]
@@ -178,80 +197,279 @@ pub(crate) enum Token {
 
 pub(crate) type SpannedToken = Result<(usize, Token, usize), LexicalError>;
 
-pub(crate) fn lex(source: &str) -> impl Iterator<Item = SpannedToken> {
-    let raw = Token::lexer(source)
-        .spanned()
-        .map(|(token, span)| match token {
+/// Ленивый поток токенов: читает исходник по мере запроса парсера.
+pub(crate) struct TokenStream<'src> {
+    source: &'src str,
+    raw: logos::SpannedIter<'src, Token>,
+    lookahead: VecDeque<SpannedToken>,
+    recent: Vec<Token>,
+    previous_significant: Option<Token>,
+    pending: VecDeque<SpannedToken>,
+    finished: bool,
+}
+
+/// The `#!` line a script may start with, so it can be run directly.
+pub(crate) fn shebang(source: &str) -> Option<&str> {
+    source
+        .starts_with("#!")
+        .then(|| source.lines().next().unwrap_or_default())
+}
+
+pub(crate) fn lex(source: &str) -> TokenStream<'_> {
+    let mut raw = Token::lexer(source);
+    // Строка `#!` нужна системе, а не языку: лексер её пропускает, сохраняя смещения.
+    if let Some(shebang) = shebang(source) {
+        raw.bump(shebang.len());
+    }
+    TokenStream {
+        source,
+        raw: raw.spanned(),
+        lookahead: VecDeque::new(),
+        recent: Vec::new(),
+        previous_significant: None,
+        pending: VecDeque::new(),
+        finished: false,
+    }
+}
+
+impl Iterator for TokenStream<'_> {
+    type Item = SpannedToken;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(token) = self.pending.pop_front() {
+                return Some(token);
+            }
+            if self.finished {
+                return None;
+            }
+            match self.pull() {
+                Some(item) => self.process(item),
+                None => self.finish(),
+            }
+        }
+    }
+}
+
+impl TokenStream<'_> {
+    fn read_raw(&mut self) -> Option<SpannedToken> {
+        let (token, span) = self.raw.next()?;
+        Some(match token {
             Ok(token) => Ok((span.start, token, span.end)),
             Err(()) => Err(LexicalError {
-                span: span.clone(),
-                message: format!("Неожиданный токен '{}'", &source[span]),
+                message: format!("Неожиданный токен '{}'", &self.source[span.clone()]),
+                span,
             }),
         })
-        .collect::<Vec<_>>();
+    }
+
+    fn pull(&mut self) -> Option<SpannedToken> {
+        self.lookahead.pop_front().or_else(|| self.read_raw())
+    }
+
+    fn peek(&mut self, offset: usize) -> Option<&SpannedToken> {
+        while self.lookahead.len() <= offset {
+            let item = self.read_raw()?;
+            self.lookahead.push_back(item);
+        }
+        self.lookahead.get(offset)
+    }
 
-    let raw = mark_type_equals(mark_method_dots(mark_lambda_starts(raw)));
-    let mut output = Vec::new();
-    let mut previous_significant: Option<Token> = None;
+    fn peek_is(&mut self, offset: usize, predicate: impl FnOnce(&Token) -> bool) -> bool {
+        matches!(self.peek(offset), Some(Ok((_, token, _))) if predicate(token))
+    }
 
-    for (idx, item) in raw.iter().enumerate() {
+    fn process(&mut self, item: SpannedToken) {
         let Ok((start, token, end)) = item else {
-            output.push(item.clone());
-            continue;
+            self.recent.clear();
+            self.pending.push_back(item);
+            return;
         };
 
-        if *token == Token::Newline {
-            let next = raw[idx + 1..].iter().find_map(|candidate| match candidate {
-                Ok((_, Token::Newline, _)) => None,
-                Ok((_, token, _)) => Some(token),
-                Err(_) => None,
-            });
-
-            if previous_significant.as_ref().is_some_and(can_end_statement)
-                && next.is_none_or(|next| {
-                    can_start_statement_after_newline(previous_significant.as_ref(), next)
-                })
+        let token = self.mark(token);
+        if matches!(token, Token::Ident(_) | Token::Dot | Token::MethodDot) {
+            self.recent.push(token.clone());
+        } else {
+            self.recent.clear();
+            self.recent.push(token.clone());
+        }
+        self.emit(start, token, end);
+    }
+
+    fn mark(&mut self, token: Token) -> Token {
+        match token {
+            Token::LParen if self.closes_before_arrow(0) => Token::LambdaLParen,
+            Token::Dot
+                if self.peek_is(0, |token| matches!(token, Token::Ident(_)))
+                    && self.peek_is(1, |token| *token == Token::LParen)
+                    && !self.closes_before_arrow(2)
+                    && !dot_is_in_object_creation_type(&self.recent) =>
             {
-                output.push(Ok((*start, Token::Semi, *end)));
-                previous_significant = Some(Token::Semi);
+                Token::MethodDot
             }
-            continue;
+            Token::Eq if eq_follows_type_hint(&self.recent) => Token::TypeEq,
+            Token::Ident(ref name) if self.is_instance_word(name) => Token::KwIs,
+            Token::Ident(ref name) if self.is_with_word(name) => Token::KwWith,
+            Token::Ident(ref name) if self.is_defer_word(name) => Token::KwDefer,
+            Token::Ident(ref name) if self.is_super_word(name) => Token::KwSuper,
+            Token::Ident(ref name) if self.is_membership_word(name) => {
+                if matches!(name.as_str(), "в" | "in") {
+                    Token::KwIn
+                } else {
+                    Token::KwNot
+                }
+            }
+            token => token,
+        }
+    }
+
+    /// `в` и `не в` — операторы только между операндами, так что переменная `в` остаётся доступной.
+    fn is_membership_word(&mut self, name: &str) -> bool {
+        match name {
+            "в" | "in" => {
+                self.previous_significant == Some(Token::KwNot)
+                    || self.previous_significant.as_ref().is_some_and(ends_operand)
+                        && self.peek_is(0, starts_operand)
+            }
+            "не" | "not" => {
+                self.previous_significant.as_ref().is_some_and(ends_operand)
+                    && self.peek_is(
+                        0,
+                        |token| matches!(token, Token::Ident(next) if next == "в" || next == "in"),
+                    )
+            }
+            _ => false,
+        }
+    }
+
+    /// `является` — оператор только между операндами, вызов `является(объект, Класс)` не затрагивается.
+    fn is_instance_word(&mut self, name: &str) -> bool {
+        matches!(name, "является" | "is")
+            && self.previous_significant.as_ref().is_some_and(ends_operand)
+            && self.peek_is(0, starts_operand)
+    }
+
+    /// `с` — начало оператора, только если стоит в начале инструкции, а за скобками идёт блок.
+    fn is_with_word(&mut self, name: &str) -> bool {
+        matches!(name, "с" | "with")
+            && matches!(
+                self.previous_significant,
+                None | Some(Token::Semi | Token::LBrace | Token::RBrace)
+            )
+            && self.peek_is(0, |token| *token == Token::LParen)
+            && self.closes_before(1, Token::LBrace)
+    }
+
+    /// `отложить` — начало оператора, только если стоит в начале инструкции перед блоком.
+    fn is_defer_word(&mut self, name: &str) -> bool {
+        matches!(name, "отложить" | "defer")
+            && matches!(
+                self.previous_significant,
+                None | Some(Token::Semi | Token::LBrace | Token::RBrace)
+            )
+            && self.peek_is(0, |token| *token == Token::LBrace)
+    }
+
+    /// `родитель` — обращение к базовому классу, только если за ним идёт вызов метода или конструктора.
+    fn is_super_word(&mut self, name: &str) -> bool {
+        matches!(name, "родитель" | "super")
+            && !matches!(
+                self.previous_significant,
+                Some(Token::Dot | Token::MethodDot)
+            )
+            && self.peek_is(0, |token| *token == Token::Dot)
+            && self.peek_is(1, |token| {
+                matches!(token, Token::Ident(_) | Token::KwConstructor)
+            })
+            && self.peek_is(2, |token| *token == Token::LParen)
+    }
+
+    /// Проверяет, что скобка перед `offset` закрывается и за ней следует `=>`.
+    fn closes_before_arrow(&mut self, offset: usize) -> bool {
+        self.closes_before(offset, Token::FatArrow)
+    }
+
+    /// Проверяет, что скобка перед `offset` закрывается и за ней следует `next`.
+    fn closes_before(&mut self, offset: usize, next: Token) -> bool {
+        let mut depth = 1usize;
+        let mut idx = offset;
+        loop {
+            match self.peek(idx) {
+                Some(Ok((_, Token::LParen | Token::LambdaLParen, _))) => depth += 1,
+                Some(Ok((_, Token::RParen, _))) => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return self.peek_is(idx + 1, |token| *token == next);
+                    }
+                }
+                Some(Ok((_, Token::Newline, _))) if depth == 1 => return false,
+                Some(Err(_)) | None => return false,
+                Some(Ok(_)) => {}
+            }
+            idx += 1;
         }
+    }
 
-        if *token == Token::Semi {
-            if previous_significant != Some(Token::Semi) {
-                output.push(Ok((*start, token.clone(), *end)));
-                previous_significant = Some(Token::Semi);
+    fn next_significant(&mut self) -> Option<Token> {
+        let mut idx = 0;
+        loop {
+            match self.peek(idx)? {
+                Ok((_, Token::Newline, _)) | Err(_) => idx += 1,
+                Ok((_, token, _)) => return Some(token.clone()),
             }
-            continue;
         }
+    }
 
-        if *token == Token::RBrace
-            && previous_significant
+    fn emit(&mut self, start: usize, token: Token, end: usize) {
+        if token == Token::Newline {
+            if self
+                .previous_significant
                 .as_ref()
-                .is_some_and(|token| *token != Token::Semi && can_end_statement(token))
-        {
-            output.push(Ok((*start, Token::Semi, *start)));
-            previous_significant = Some(Token::Semi);
+                .is_some_and(can_end_statement)
+            {
+                let previous = self.previous_significant.clone();
+                if self
+                    .next_significant()
+                    .is_none_or(|next| can_start_statement_after_newline(previous.as_ref(), &next))
+                {
+                    self.pending.push_back(Ok((start, Token::Semi, end)));
+                    self.previous_significant = Some(Token::Semi);
+                }
+            }
+            return;
         }
 
-        output.push(Ok((*start, token.clone(), *end)));
-        if *token != Token::Semi {
-            previous_significant = Some(token.clone());
+        if token == Token::Semi {
+            if self.previous_significant != Some(Token::Semi) {
+                self.pending.push_back(Ok((start, token, end)));
+                self.previous_significant = Some(Token::Semi);
+            }
+            return;
         }
+
+        if token == Token::RBrace && self.ends_open_statement() {
+            self.pending.push_back(Ok((start, Token::Semi, start)));
+            self.previous_significant = Some(Token::Semi);
+        }
+
+        self.previous_significant = Some(token.clone());
+        self.pending.push_back(Ok((start, token, end)));
     }
 
-    if previous_significant
-        .as_ref()
-        .is_some_and(|token| *token != Token::Semi && can_end_statement(token))
-    {
-        let end = source.len();
-        output.push(Ok((end, Token::Semi, end)));
+    fn finish(&mut self) {
+        let end = self.source.len();
+        if self.ends_open_statement() {
+            self.pending.push_back(Ok((end, Token::Semi, end)));
+        }
+        self.pending.push_back(Ok((end, Token::Eof, end)));
+        self.finished = true;
     }
-    let end = source.len();
-    output.push(Ok((end, Token::Eof, end)));
 
-    output.into_iter()
+    fn ends_open_statement(&self) -> bool {
+        self.previous_significant
+            .as_ref()
+            .is_some_and(|token| *token != Token::Semi && can_end_statement(token))
+    }
 }
 
 fn can_end_statement(token: &Token) -> bool {
@@ -270,6 +488,28 @@ fn can_end_statement(token: &Token) -> bool {
     )
 }
 
+fn ends_operand(token: &Token) -> bool {
+    *token != Token::RBrace && can_end_statement(token)
+}
+
+fn starts_operand(token: &Token) -> bool {
+    matches!(
+        token,
+        Token::Ident(_)
+            | Token::String(_)
+            | Token::Number(_)
+            | Token::Float(_)
+            | Token::True
+            | Token::False
+            | Token::Empty
+            | Token::KwNew
+            | Token::LParen
+            | Token::LBracket
+            | Token::Minus
+            | Token::Bang
+    )
+}
+
 fn can_start_statement_after_newline(previous: Option<&Token>, token: &Token) -> bool {
     if matches!(previous, Some(Token::RBrace)) && matches!(token, Token::KwCatch | Token::KwElse) {
         return false;
@@ -298,53 +538,14 @@ fn can_start_statement_after_newline(previous: Option<&Token>, token: &Token) ->
     )
 }
 
-fn mark_lambda_starts(mut tokens: Vec<SpannedToken>) -> Vec<SpannedToken> {
-    let len = tokens.len();
-    for idx in 0..len {
-        let Ok((_, Token::LParen, _)) = tokens[idx] else {
-            continue;
-        };
-
-        if let Some(close_idx) = matching_paren(&tokens, idx) {
-            if matches!(tokens.get(close_idx + 1), Some(Ok((_, Token::FatArrow, _)))) {
-                if let Ok((start, _, end)) = &mut tokens[idx] {
-                    tokens[idx] = Ok((*start, Token::LambdaLParen, *end));
-                }
-            }
-        }
-    }
-    tokens
-}
-
-fn mark_method_dots(mut tokens: Vec<SpannedToken>) -> Vec<SpannedToken> {
-    let len = tokens.len();
-    for idx in 0..len {
-        let Ok((_, Token::Dot, _)) = tokens[idx] else {
-            continue;
-        };
-
-        if matches!(tokens.get(idx + 1), Some(Ok((_, Token::Ident(_), _))))
-            && matches!(tokens.get(idx + 2), Some(Ok((_, Token::LParen, _))))
-            && !dot_is_in_object_creation_type(&tokens, idx)
-        {
-            if let Ok((start, _, end)) = &mut tokens[idx] {
-                tokens[idx] = Ok((*start, Token::MethodDot, *end));
-            }
-        }
-    }
-    tokens
-}
-
-fn dot_is_in_object_creation_type(tokens: &[SpannedToken], dot_idx: usize) -> bool {
-    let mut idx = dot_idx;
+fn dot_is_in_object_creation_type(recent: &[Token]) -> bool {
     let mut expect_ident = true;
 
-    while idx > 0 {
-        idx -= 1;
-        match &tokens[idx] {
-            Ok((_, Token::Ident(_), _)) if expect_ident => expect_ident = false,
-            Ok((_, Token::Dot, _)) if !expect_ident => expect_ident = true,
-            Ok((_, Token::KwNew, _)) if !expect_ident => return true,
+    for token in recent.iter().rev() {
+        match token {
+            Token::Ident(_) if expect_ident => expect_ident = false,
+            Token::Dot if !expect_ident => expect_ident = true,
+            Token::KwNew if !expect_ident => return true,
             _ => return false,
         }
     }
@@ -352,69 +553,21 @@ fn dot_is_in_object_creation_type(tokens: &[SpannedToken], dot_idx: usize) -> bo
     false
 }
 
-fn mark_type_equals(mut tokens: Vec<SpannedToken>) -> Vec<SpannedToken> {
-    let len = tokens.len();
-    for idx in 0..len {
-        let Ok((_, Token::Eq, _)) = tokens[idx] else {
-            continue;
-        };
-
-        if eq_follows_type_hint(&tokens, idx) {
-            if let Ok((start, _, end)) = &mut tokens[idx] {
-                tokens[idx] = Ok((*start, Token::TypeEq, *end));
-            }
-        }
-    }
-    tokens
-}
-
-fn eq_follows_type_hint(tokens: &[SpannedToken], eq_idx: usize) -> bool {
-    let mut idx = eq_idx;
+fn eq_follows_type_hint(recent: &[Token]) -> bool {
     let mut saw_ident = false;
 
-    while idx > 0 {
-        idx -= 1;
-        match &tokens[idx] {
-            Ok((_, Token::Ident(_), _)) => {
-                saw_ident = true;
-            }
-            Ok((_, Token::Dot, _)) if saw_ident => {
-                saw_ident = false;
-            }
-            Ok((_, Token::Colon, _)) if saw_ident => return true,
-            Ok((
-                _,
-                Token::Newline | Token::Semi | Token::LParen | Token::LBrace | Token::Comma,
-                _,
-            )) => {
-                return false;
-            }
-            Ok(_) | Err(_) => return false,
+    for token in recent.iter().rev() {
+        match token {
+            Token::Ident(_) => saw_ident = true,
+            Token::Dot if saw_ident => saw_ident = false,
+            Token::Colon if saw_ident => return true,
+            _ => return false,
         }
     }
 
     false
 }
 
-fn matching_paren(tokens: &[SpannedToken], open_idx: usize) -> Option<usize> {
-    let mut depth = 0usize;
-    for (idx, token) in tokens.iter().enumerate().skip(open_idx) {
-        match token {
-            Ok((_, Token::LParen | Token::LambdaLParen, _)) => depth += 1,
-            Ok((_, Token::RParen, _)) => {
-                depth = depth.saturating_sub(1);
-                if depth == 0 {
-                    return Some(idx);
-                }
-            }
-            Ok((_, Token::Newline, _)) if depth == 1 => return None,
-            Err(_) => return None,
-            _ => {}
-        }
-    }
-    None
-}
-
 fn parse_int(lex: &mut logos::Lexer<'_, Token>) -> Option<i64> {
     lex.slice().parse().ok()
 }
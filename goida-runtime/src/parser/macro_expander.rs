@@ -173,6 +173,16 @@ impl MacroExpander {
                 target: self.expand_expr(target, module_name)?,
                 value: self.expand_expr(value, module_name)?,
             },
+            syn::StmtKind::MultiAssign { targets, values } => syn::StmtKind::MultiAssign {
+                targets: targets
+                    .into_iter()
+                    .map(|target| self.expand_expr(target, module_name))
+                    .collect::<Result<_, _>>()?,
+                values: values
+                    .into_iter()
+                    .map(|value| self.expand_expr(value, module_name))
+                    .collect::<Result<_, _>>()?,
+            },
             syn::StmtKind::CompoundAssign { target, op, value } => syn::StmtKind::CompoundAssign {
                 target: self.expand_expr(target, module_name)?,
                 op,
@@ -222,9 +232,21 @@ impl MacroExpander {
                 iterable: self.expand_expr(iterable, module_name)?,
                 body: self.expand_items(body, module_name)?,
             },
+            syn::StmtKind::With {
+                variable,
+                value,
+                body,
+            } => syn::StmtKind::With {
+                variable,
+                value: self.expand_expr(value, module_name)?,
+                body: self.expand_items(body, module_name)?,
+            },
             syn::StmtKind::Thread { body } => syn::StmtKind::Thread {
                 body: self.expand_items(body, module_name)?,
             },
+            syn::StmtKind::Defer { body } => syn::StmtKind::Defer {
+                body: self.expand_items(body, module_name)?,
+            },
             syn::StmtKind::Try { body, mut handlers } => {
                 for handler in &mut handlers {
                     handler.body =
@@ -248,6 +270,9 @@ impl MacroExpander {
                 expr.map(|expr| self.expand_expr(expr, module_name))
                     .transpose()?,
             ),
+            syn::StmtKind::Yield(expr) => {
+                syn::StmtKind::Yield(self.expand_expr(expr, module_name)?)
+            }
             syn::StmtKind::Expr(expr) => syn::StmtKind::Expr(self.expand_expr(expr, module_name)?),
         };
         Ok(syn::Spanned { node, span })
@@ -331,10 +356,23 @@ impl MacroExpander {
                 object: Box::new(self.expand_expr(*object, module_name)?),
                 index: Box::new(self.expand_expr(*index, module_name)?),
             },
+            syn::ExprKind::Slice { object, start, end } => syn::ExprKind::Slice {
+                object: Box::new(self.expand_expr(*object, module_name)?),
+                start: start
+                    .map(|start| self.expand_expr(*start, module_name).map(Box::new))
+                    .transpose()?,
+                end: end
+                    .map(|end| self.expand_expr(*end, module_name).map(Box::new))
+                    .transpose()?,
+            },
             syn::ExprKind::ObjectCreation { class_name, args } => syn::ExprKind::ObjectCreation {
                 class_name,
                 args: self.expand_call_args(args, module_name)?,
             },
+            syn::ExprKind::SuperCall { method, args } => syn::ExprKind::SuperCall {
+                method,
+                args: self.expand_call_args(args, module_name)?,
+            },
             syn::ExprKind::Lambda { params, body } => syn::ExprKind::Lambda {
                 params,
                 body: match body {
@@ -361,6 +399,7 @@ impl MacroExpander {
                 Ok(syn::CallArg {
                     name: arg.name,
                     value: self.expand_expr(arg.value, module_name)?,
+                    spread: arg.spread,
                 })
             })
             .collect()
@@ -616,10 +655,10 @@ fn delimiters_balanced(tokens: &[syn::MacroToken]) -> bool {
             Token::LParen => stack.push(Token::RParen),
             Token::LBracket => stack.push(Token::RBracket),
             Token::LBrace => stack.push(Token::RBrace),
-            Token::RParen | Token::RBracket | Token::RBrace => {
-                if stack.pop().as_ref() != Some(&token.token) {
-                    return false;
-                }
+            Token::RParen | Token::RBracket | Token::RBrace
+                if stack.pop().as_ref() != Some(&token.token) =>
+            {
+                return false;
             }
             _ => {}
         }
@@ -1,4 +1,5 @@
 mod builder;
+pub mod docs;
 mod formatter;
 mod imports;
 pub(crate) mod lexer;
@@ -1,14 +1,18 @@
 use crate::ast::prelude::*;
-use crate::builtins::registry::{BuiltinParserTarget, BUILTINS};
-use crate::interpreter::prelude::{Module, SharedInterner};
+use crate::ast::symbols::resolve_symbols;
+use crate::builtins::registry::{function, BuiltinParserTarget, BUILTINS};
+use crate::interpreter::prelude::{Module, RuntimeFieldData, RuntimeMethodType, SharedInterner};
 use crate::parser::formatter::format_program;
 use crate::parser::grammar;
-use crate::parser::lexer::{lex, LexicalError, Token};
+use crate::parser::lexer::{lex, shebang, LexicalError, Token};
 use crate::parser::prelude::{FormatLanguage, ParseError, Parser as ParserTrait};
-use crate::parser::structs::ModuleLoader;
+use crate::parser::structs::{ModuleLoader, ProgramSources};
 use crate::shared::SharedMut;
 use lalrpop_util::ParseError as LalrpopParseError;
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
+use string_interner::DefaultSymbol as Symbol;
 
 impl ParserTrait {
     pub fn new(interner: SharedInterner, name: &str, path: PathBuf) -> Self {
@@ -30,19 +34,84 @@ impl ParserTrait {
             module: Module::new(&interner, name, path),
             interner,
             module_loader,
+            predeclared: HashSet::new(),
+            result_binding: None,
+            super_context: None,
         }
     }
 
+    /// Treats the given names as globals that already exist at runtime.
+    pub fn with_predeclared_globals(mut self, names: impl IntoIterator<Item = Symbol>) -> Self {
+        self.predeclared.extend(names);
+        self
+    }
+
+    /// Stores the value of a trailing expression statement in the `name` global.
+    pub fn with_result_binding(mut self, name: Symbol) -> Self {
+        self.result_binding = Some(name);
+        self
+    }
+
+    /// Serves imports from `sources` instead of the disk.
+    pub fn with_sources(self, sources: ProgramSources) -> Self {
+        self.module_loader.write(|loader| loader.sources = sources);
+        self
+    }
+
+    /// Also substitutes the values of `константа` and once-assigned variables into
+    /// the expressions that read them and folds the result, in this module and every
+    /// module it imports.
+    pub fn with_optimizations(self, enabled: bool) -> Self {
+        self.module_loader.write(|loader| loader.optimize = enabled);
+        self
+    }
+
+    /// Parses source and returns every module it imports, in the form
+    /// [`Parser::with_sources`] expects.
+    pub fn collect_sources(self, code: &str) -> Result<ProgramSources, ParseError> {
+        let module_loader = self.module_loader.clone();
+        self.parse(code)?;
+        Ok(module_loader.read(|loader| loader.sources.clone()))
+    }
+
     pub fn parse(mut self, code: &str) -> Result<Module, ParseError> {
         self.install_builtins();
 
         self.parse_into_module(code)?;
+        self.bind_trailing_expression();
         self.validate_module_names()?;
-        self.module.arena.optimize_all(&self.interner);
+        let constants = self.class_constants();
+        self.module
+            .arena
+            .optimize_with_constants(&self.interner, &constants);
+        if self.module_loader.read(|loader| loader.optimize) {
+            let outside = self
+                .globals_are_private()
+                .then(|| self.known_global_names());
+            self.module
+                .arena
+                .propagate_constants(&self.interner, &constants, outside.as_ref());
+        }
         self.lower_module()?;
         Ok(self.module)
     }
 
+    /// Whether only this module writes its globals: code run by `выполнить` assigns
+    /// into the globals of the module that called it.
+    fn globals_are_private(&self) -> bool {
+        let eval = BUILTINS.function_names(function::EVAL.canonical);
+        !self.module.arena.expressions.iter().any(|expression| {
+            let ExpressionKind::FunctionCall { function, .. } = expression.kind else {
+                return false;
+            };
+            matches!(
+                self.module.arena.get_expression(function).map(|e| &e.kind),
+                Some(ExpressionKind::Identifier(name))
+                    if self.interner.read(|i| i.resolve(*name).is_some_and(|name| eval.contains(&name)))
+            )
+        })
+    }
+
     /// Parses and optimizes source without semantic validation or compilation.
     ///
     /// This path is intended for editors and other tools that must keep a
@@ -60,6 +129,122 @@ impl ParserTrait {
         self.parse_syntax(code)
     }
 
+    /// Returns `true` when the source stops before its last construct is closed.
+    pub fn is_incomplete(code: &str) -> bool {
+        matches!(
+            grammar::ProgramParser::new().parse(lex(code)),
+            Err(LalrpopParseError::UnrecognizedEof { .. })
+                | Err(LalrpopParseError::UnrecognizedToken {
+                    token: (_, Token::Eof, _),
+                    ..
+                })
+        )
+    }
+
+    /// Number of tokens the lexer produces for `code`, lexical errors included.
+    pub fn count_tokens(code: &str) -> usize {
+        lex(code).count()
+    }
+
+    /// Renders the lexer output, one token per line with byte span and position.
+    pub fn dump_tokens(code: &str) -> String {
+        let position = |offset: usize| {
+            let prefix = &code[..offset.min(code.len())];
+            let line = prefix.matches('\n').count() + 1;
+            let column = prefix
+                .rsplit('\n')
+                .next()
+                .map_or(0, |line| line.chars().count())
+                + 1;
+            (line, column)
+        };
+        let mut output = String::new();
+        for token in lex(code) {
+            let (start, end, text) = match token {
+                Ok((start, token, end)) => (start, end, format!("{token:?}")),
+                Err(error) => (
+                    error.span.start,
+                    error.span.end,
+                    format!("Ошибка({:?})", error.message),
+                ),
+            };
+            let (line, column) = position(start);
+            output.push_str(&format!("{line}:{column}\t{start}..{end}\t{text}\n"));
+        }
+        output
+    }
+
+    /// Parses source without compiling it and renders the AST arena as JSON.
+    ///
+    /// Interned names are written inline as strings wherever nodes refer to them.
+    pub fn ast_json(self, code: &str) -> Result<serde_json::Value, ParseError> {
+        let interner = self.interner.clone();
+        let module = self.parse_syntax(code)?;
+        let name =
+            |symbol: Symbol| interner.read(|i| i.resolve(symbol).unwrap_or_default().to_owned());
+        Ok(resolve_symbols(&interner, || {
+            let functions = module
+                .functions
+                .iter()
+                .map(|(symbol, function)| (name(*symbol), json!(function)))
+                .collect::<serde_json::Map<_, _>>();
+            let classes = module
+                .classes
+                .iter()
+                .map(|(symbol, class)| {
+                    let class = class.read(|class| {
+                        let method = |method: &RuntimeMethodType| match method {
+                            RuntimeMethodType::User(function) => json!(function),
+                            RuntimeMethodType::Native(_) | RuntimeMethodType::Runtime(_) => {
+                                serde_json::Value::Null
+                            }
+                        };
+                        json!({
+                            "base_class": class.base_class.map(name),
+                            "fields": class.fields.iter().map(|(field, (visibility, is_static, data))| {
+                                let default_value = match data {
+                                    RuntimeFieldData::Expression(expression) => *expression,
+                                    RuntimeFieldData::Value(_) => None,
+                                };
+                                (name(*field), json!({
+                                    "visibility": visibility,
+                                    "is_static": is_static,
+                                    "is_const": class.constants.contains(field),
+                                    "default_value": default_value,
+                                }))
+                            }).collect::<serde_json::Map<_, _>>(),
+                            "methods": class.methods.iter().map(|(method_name, (visibility, is_static, body))| {
+                                (name(*method_name), json!({
+                                    "visibility": visibility,
+                                    "is_static": is_static,
+                                    "function": method(body),
+                                }))
+                            }).collect::<serde_json::Map<_, _>>(),
+                            "constructor": class.constructor.as_ref().map(method),
+                            "span": class.span,
+                            "doc": class.doc,
+                        })
+                    });
+                    (name(*symbol), class)
+                })
+                .collect::<serde_json::Map<_, _>>();
+
+            json!({
+                "module": name(module.name),
+                "body": module.body,
+                "imports": module.imports,
+                "functions": functions,
+                "classes": classes,
+                "arena": module.arena,
+            })
+        }))
+    }
+
+    /// Parses source without compiling it and reports the size of its AST arena.
+    pub fn ast_stats(self, code: &str) -> Result<ArenaStats, ParseError> {
+        Ok(self.parse_syntax(code)?.arena.stats())
+    }
+
     pub fn macro_expansion_preview(&self, code: &str) -> Result<String, ParseError> {
         let syntax = self.parse_source_ast(code)?;
         let syntax = self.expand_macros(syntax)?;
@@ -75,8 +260,56 @@ impl ParserTrait {
         code: &str,
         language: FormatLanguage,
     ) -> Result<String, ParseError> {
-        self.parse_source_ast(code)
-            .map(|syntax| format_program(&syntax, language))
+        let formatted = format_program(&self.parse_source_ast(code)?, language);
+        Ok(match shebang(code) {
+            Some(shebang) => format!("{shebang}\n{formatted}"),
+            None => formatted,
+        })
+    }
+
+    /// Initializers of the class constants declared in this module.
+    fn class_constants(&self) -> HashMap<(Symbol, Symbol), ExprId> {
+        let mut constants = HashMap::new();
+        for (class_name, class) in &self.module.classes {
+            class.read(|class| {
+                for name in &class.constants {
+                    if let Some((_, _, RuntimeFieldData::Expression(Some(value)))) =
+                        class.fields.get(name)
+                    {
+                        constants.insert((*class_name, *name), *value);
+                    }
+                }
+            });
+        }
+        constants
+    }
+
+    fn bind_trailing_expression(&mut self) {
+        let Some(name) = self.result_binding else {
+            return;
+        };
+        let Some(&last) = self.module.body.last() else {
+            return;
+        };
+        let Some(&StatementNode {
+            kind: StatementKind::Expression(value),
+            span,
+        }) = self.module.arena.get_statement(last)
+        else {
+            return;
+        };
+        let assign = self.module.arena.add_statement(
+            StatementKind::Assign {
+                name,
+                is_const: false,
+                type_hint: None,
+                value,
+            },
+            span,
+        );
+        if let Some(last) = self.module.body.last_mut() {
+            *last = assign;
+        }
     }
 
     fn install_builtins(&mut self) {
@@ -89,17 +322,23 @@ impl ParserTrait {
     }
 
     fn parse_into_module(&mut self, code: &str) -> Result<(), ParseError> {
-        let syntax = self.parse_source_ast(code)?;
+        let preparsed = self.module_loader.write(|loader| {
+            loader
+                .preparsed
+                .remove(&self.module.path)
+                .filter(|file| file.code == code)
+        });
+        let syntax = match preparsed {
+            Some(file) => file.syntax,
+            None => self.parse_source_ast(code)?,
+        };
+        self.prefetch_imports(&syntax);
         let syntax = self.expand_macros(syntax)?;
         self.build_program(syntax)
     }
 
     fn parse_source_ast(&self, code: &str) -> Result<crate::parser::syntax::Program, ParseError> {
-        let mut syntax = grammar::ProgramParser::new()
-            .parse(lex(code))
-            .map_err(|err| self.convert_parse_error(code, err))?;
-        syntax.comments = collect_comments(code);
-        Ok(syntax)
+        parse_program(code).map_err(|err| self.convert_parse_error(code, err))
     }
 
     fn lower_module(&mut self) -> Result<(), ParseError> {
@@ -157,12 +396,23 @@ impl ParserTrait {
     }
 }
 
+/// Lexes and parses source into its syntax tree; needs nothing but the text,
+/// so imported files can be parsed on any thread.
+pub(super) fn parse_program(
+    code: &str,
+) -> Result<crate::parser::syntax::Program, LalrpopParseError<usize, Token, LexicalError>> {
+    let mut syntax = grammar::ProgramParser::new().parse(lex(code))?;
+    syntax.comments = collect_comments(code);
+    attach_doc_comments(&mut syntax, code);
+    Ok(syntax)
+}
+
 pub(super) fn collect_comments(code: &str) -> Vec<crate::parser::syntax::Comment> {
     let mut comments = Vec::new();
     let mut in_string = false;
     let mut escaped = false;
     let bytes = code.as_bytes();
-    let mut index = 0;
+    let mut index = shebang(code).map_or(0, str::len);
 
     while index + 1 < bytes.len() {
         match bytes[index] {
@@ -174,9 +424,12 @@ pub(super) fn collect_comments(code: &str) -> Vec<crate::parser::syntax::Comment
                     .find('\n')
                     .map(|offset| index + offset)
                     .unwrap_or(code.len());
+                let doc = code[start..end].starts_with("///");
+                let text_start = start + if doc { 3 } else { 2 };
                 comments.push(crate::parser::syntax::Comment {
-                    text: code[start + 2..end].trim().to_string(),
+                    text: code[text_start..end].trim().to_string(),
                     span: start..end,
+                    doc,
                 });
                 index = end;
                 continue;
@@ -188,6 +441,53 @@ pub(super) fn collect_comments(code: &str) -> Vec<crate::parser::syntax::Comment
     comments
 }
 
+/// Moves `///` comments into the functions, classes and methods they precede.
+fn attach_doc_comments(program: &mut crate::parser::syntax::Program, code: &str) {
+    use crate::parser::syntax::{ClassItemKind, ItemKind};
+
+    let comments = &program.comments;
+    for item in &mut program.items {
+        match &mut item.node {
+            ItemKind::Function(function) => {
+                function.doc = doc_comment_before(comments, code, item.span.start);
+            }
+            ItemKind::Class(class) => {
+                class.doc = doc_comment_before(comments, code, item.span.start);
+                for member in &mut class.items {
+                    if let ClassItemKind::Method(method) | ClassItemKind::Constructor(method) =
+                        &mut member.node
+                    {
+                        method.doc = doc_comment_before(comments, code, member.span.start);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Joins the run of `///` lines that ends right before `offset`.
+fn doc_comment_before(
+    comments: &[crate::parser::syntax::Comment],
+    code: &str,
+    offset: usize,
+) -> Option<String> {
+    let mut cursor = offset;
+    let mut lines = Vec::new();
+    for comment in comments.iter().rev().filter(|c| c.span.end <= offset) {
+        if !comment.doc || !code[comment.span.end..cursor].trim().is_empty() {
+            break;
+        }
+        lines.push(comment.text.as_str());
+        cursor = comment.span.start;
+    }
+    if lines.is_empty() {
+        return None;
+    }
+    lines.reverse();
+    Some(lines.join("\n"))
+}
+
 fn token_range_at(code: &str, location: usize) -> (usize, usize) {
     let start = previous_char_boundary(code, location.min(code.len()));
     let mut end = next_char_boundary(code, location.min(code.len()));
@@ -257,8 +557,15 @@ pub(super) fn token_source_text(token: &Token) -> String {
         Token::KwAs => "as".into(),
         Token::KwNew => "new".into(),
         Token::KwReturn => "return".into(),
+        Token::KwYield => "yield".into(),
         Token::KwAnd => "and".into(),
         Token::KwOr => "or".into(),
+        Token::KwIn => "in".into(),
+        Token::KwNot => "not".into(),
+        Token::KwIs => "is".into(),
+        Token::KwWith => "with".into(),
+        Token::KwDefer => "defer".into(),
+        Token::KwSuper => "super".into(),
         Token::True => "true".into(),
         Token::False => "false".into(),
         Token::Empty => "void".into(),
@@ -285,6 +592,7 @@ pub(super) fn token_source_text(token: &Token) -> String {
         Token::Bang => "!".into(),
         Token::Dollar => "$".into(),
         Token::Dot | Token::MethodDot => ".".into(),
+        Token::Ellipsis => "...".into(),
         Token::Comma => ",".into(),
         Token::Colon => ":".into(),
         Token::LParen | Token::LambdaLParen => "(".into(),
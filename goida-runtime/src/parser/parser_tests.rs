@@ -1,6 +1,6 @@
-use crate::ast::prelude::DataType;
+use crate::ast::prelude::{AstArena, DataType, ExpressionKind, LiteralValue, PrimitiveType, Span};
 use crate::bytecode::Instruction;
-use crate::hir::{Binding, HirExpressionKind};
+use crate::hir::{Binding, HirExpressionKind, HirStatementKind};
 use crate::interpreter::prelude::SharedInterner;
 use crate::parser::prelude::Parser;
 use crate::shared::SharedMut;
@@ -92,6 +92,92 @@ answer = identity(42)
     assert_eq!(module.bytecode.bodies.len(), 1);
 }
 
+#[test]
+fn nested_functions_capture_variables_of_the_enclosing_function() {
+    let interner: SharedInterner = SharedMut::new(StringInterner::new());
+    let parser = Parser::new(
+        interner.clone(),
+        "capture_test",
+        PathBuf::from("capture.goida"),
+    );
+    let module = parser
+        .parse(
+            r#"
+function outer(base) {
+    step = 1
+    function inner(value) {
+        return base + step + value
+    }
+    return inner
+}
+"#,
+        )
+        .expect("module should lower");
+
+    let captures = module
+        .hir
+        .arena
+        .statements()
+        .find_map(|(_, statement)| match &statement.kind {
+            HirStatementKind::FunctionDefinition { captures, .. } => Some(captures.clone()),
+            _ => None,
+        })
+        .expect("nested function should be lowered");
+    let names = interner.read(|interner| {
+        captures
+            .iter()
+            .filter_map(|name| interner.resolve(*name).map(str::to_string))
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(names, ["base", "step"]);
+    let step = interner.read(|interner| interner.get("step")).unwrap();
+    assert!(module.hir.arena.statements().any(|(_, statement)| matches!(
+        statement.kind,
+        HirStatementKind::Assign {
+            name,
+            binding: Binding::Dynamic(_),
+            ..
+        } if name == step
+    )));
+}
+
+#[test]
+fn only_bodies_that_yield_are_compiled_as_generators() {
+    let module = Parser::new(
+        goida_model::new_interner(),
+        "generators",
+        PathBuf::from("generators.goida"),
+    )
+    .parse(
+        r#"
+function count(limit) {
+    index = 0
+    while (index < limit) {
+        yield index
+        index += 1
+    }
+}
+
+function wrapper() {
+    function inner() {
+        yield 1
+    }
+    return inner
+}
+"#,
+    )
+    .expect("module should compile");
+
+    let mut generators = module
+        .bytecode
+        .bodies
+        .values()
+        .map(|body| body.generator)
+        .collect::<Vec<_>>();
+    generators.sort();
+    assert_eq!(generators, [false, true, true]);
+}
+
 #[test]
 fn type_checker_rejects_invalid_assignments() {
     assert_type_error(
@@ -133,6 +219,37 @@ add("text", 1)
     );
 }
 
+#[test]
+fn type_checker_widens_numbers_to_floats() {
+    Parser::new(
+        goida_model::new_interner(),
+        "type_check",
+        PathBuf::from("type_check.goida"),
+    )
+    .parse(
+        r#"
+function half(value: float) -> float { return value / 2 }
+ratio: float = 1
+ratio = half(3) + 1
+mixed: float = ratio * 2
+"#,
+    )
+    .expect("numbers should be accepted where floats are expected");
+
+    assert_type_error(
+        "whole: number = 1\nwhole = 1 + 2.5\n",
+        "Несовместимый тип присваивания",
+    );
+}
+
+#[test]
+fn type_checker_infers_repeated_text_as_text() {
+    assert_type_error(
+        "line: number = \"-\" * 3\n",
+        "Несовместимый тип присваивания",
+    );
+}
+
 #[test]
 fn type_checker_records_inferred_expression_types_and_keeps_dynamic_values_dynamic() {
     let module = Parser::new(
@@ -188,6 +305,101 @@ result = answer() + 1
     assert!(module.bytecode.expressions.len() < module.arena.expressions.len());
 }
 
+#[test]
+fn class_constants_fold_into_their_uses_only_when_compiling() {
+    let source = r#"
+class Box {
+    public const SIZE: number = 3 + 4
+}
+
+result = Box.SIZE + 1
+"#;
+    let is_property = |kind: &ExpressionKind| matches!(kind, ExpressionKind::PropertyAccess { .. });
+    let parser = |name: &str| Parser::new(goida_model::new_interner(), name, PathBuf::from(name));
+
+    let module = parser("constants")
+        .parse(source)
+        .expect("module should compile");
+    assert!(!module
+        .arena
+        .expressions
+        .iter()
+        .any(|e| is_property(&e.kind)));
+    assert!(module
+        .arena
+        .expressions
+        .iter()
+        .any(|e| matches!(e.kind, ExpressionKind::Literal(LiteralValue::Number(8)))));
+
+    let syntax = parser("constants_syntax")
+        .parse_syntax(source)
+        .expect("module should parse");
+    assert!(syntax
+        .arena
+        .expressions
+        .iter()
+        .any(|e| is_property(&e.kind)));
+}
+
+#[test]
+fn constants_propagate_into_their_uses_only_with_optimizations() {
+    let source = r#"
+const WIDTH = 6
+const HEIGHT = WIDTH * 7
+counter = 1
+counter = counter + 1
+scale = 3
+scaled = scale * 5
+area = -HEIGHT
+big = HEIGHT > 40
+"#;
+    let numbers = |source: &str, optimize: bool| {
+        let module = Parser::new(
+            goida_model::new_interner(),
+            "propagate",
+            PathBuf::from("propagate.goida"),
+        )
+        .with_optimizations(optimize)
+        .parse(source)
+        .expect("module should compile");
+        let folded_comparison = module
+            .arena
+            .expressions
+            .iter()
+            .any(|e| matches!(e.kind, ExpressionKind::Literal(LiteralValue::Boolean(true))));
+        let numbers = module
+            .arena
+            .expressions
+            .iter()
+            .filter_map(|e| match e.kind {
+                ExpressionKind::Literal(LiteralValue::Number(value)) => Some(value),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        (numbers, folded_comparison)
+    };
+
+    let (optimized, folded_comparison) = numbers(source, true);
+    assert!(optimized.contains(&42));
+    assert!(optimized.contains(&-42));
+    assert!(folded_comparison);
+    // `scale` is assigned once, while `counter` is reassigned and stays a variable read.
+    assert!(optimized.contains(&15));
+    assert!(!optimized.contains(&2));
+
+    // Code run by `выполнить` may assign `scale`, so its reads are kept.
+    let (evaluated, _) = numbers(
+        "scale = 3\nвыполнить(\"scale = 4\")\nscaled = scale * 5\n",
+        true,
+    );
+    assert!(!evaluated.contains(&15));
+
+    let (plain, folded_comparison) = numbers(source, false);
+    assert!(!plain.contains(&42));
+    assert!(!plain.contains(&15));
+    assert!(!folded_comparison);
+}
+
 #[test]
 fn syntax_only_parse_does_not_build_hir_or_bytecode() {
     let interner = goida_model::new_interner();
@@ -251,6 +463,43 @@ fn parser_reports_cyclic_module_imports() {
     std::fs::remove_dir_all(root).expect("test directory should be removed");
 }
 
+#[test]
+fn imports_are_parsed_ahead_and_shared_imports_are_not_mistaken_for_cycles() {
+    let root = std::env::temp_dir().join(format!("goida-import-prefetch-{}", std::process::id()));
+    std::fs::create_dir_all(&root).expect("test directory should exist");
+    std::fs::write(root.join("base.goida"), "value = 1\n").expect("base should be written");
+    std::fs::write(
+        root.join("left.goida"),
+        "import \"base.goida\" as base\nfunction left() {\n    return base.value\n}\n",
+    )
+    .expect("left should be written");
+    std::fs::write(
+        root.join("right.goida"),
+        "import \"base.goida\" as base\nfunction right() {\n    return base.value\n}\n",
+    )
+    .expect("right should be written");
+
+    let parser = Parser::new(
+        goida_model::new_interner(),
+        "diamond",
+        root.join("main.goida"),
+    );
+    let loader = parser.module_loader.clone();
+    let module = parser
+        .parse("import \"left.goida\" as left\nimport \"right.goida\" as right\n")
+        .expect("shared import should parse");
+
+    assert_eq!(module.modules.len(), 2);
+    assert!(module
+        .modules
+        .values()
+        .all(|imported| imported.modules.len() == 1));
+    assert_eq!(loader.read(|loader| loader.sources.files.len()), 3);
+    assert!(loader.read(|loader| loader.preparsed.is_empty()));
+
+    std::fs::remove_dir_all(root).expect("test directory should be removed");
+}
+
 #[test]
 fn ast_formatter_preserves_comments_and_ignores_comment_markers_in_strings() {
     let interner = goida_model::new_interner();
@@ -279,3 +528,280 @@ fn format_language_detection_prefers_the_dominant_keyword_language() {
         FormatLanguage::English
     );
 }
+
+#[test]
+fn lexer_streams_tokens_with_lookahead_markers() {
+    use crate::parser::lexer::{lex, Token};
+
+    let mut stream = lex("f = (x) => x\nобъект.метод(1)\n@");
+    assert!(matches!(stream.next(), Some(Ok((0, Token::Ident(_), 1)))));
+
+    let tokens = stream
+        .filter_map(Result::ok)
+        .map(|(_, token, _)| token)
+        .collect::<Vec<_>>();
+    assert!(tokens.contains(&Token::LambdaLParen));
+    assert!(tokens.contains(&Token::MethodDot));
+    assert_eq!(
+        tokens.iter().filter(|token| **token == Token::Semi).count(),
+        2
+    );
+    assert_eq!(tokens.last(), Some(&Token::Eof));
+}
+
+#[test]
+fn membership_words_are_operators_only_between_operands() {
+    use crate::parser::lexer::{lex, Token};
+
+    let tokens = lex("в = 1\nв в х\nв не в х\nне в")
+        .filter_map(Result::ok)
+        .map(|(_, token, _)| token)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tokens.iter().filter(|token| **token == Token::KwIn).count(),
+        2
+    );
+    assert_eq!(
+        tokens
+            .iter()
+            .filter(|token| **token == Token::KwNot)
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn instance_word_is_an_operator_only_between_operands() {
+    use crate::parser::lexer::{lex, Token};
+
+    let tokens = lex("является(х, К)\nх является К\nх = является(у, К) или у является (К)")
+        .filter_map(Result::ok)
+        .map(|(_, token, _)| token)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tokens.iter().filter(|token| **token == Token::KwIs).count(),
+        2
+    );
+}
+
+#[test]
+fn with_word_opens_a_statement_only_before_a_binding_and_block() {
+    use crate::parser::lexer::{lex, Token};
+
+    let tokens = lex("с = 1\nф(с = 2)\nс (ф = с) { печать(с) }\nwith (x = y) {}")
+        .filter_map(Result::ok)
+        .map(|(_, token, _)| token)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tokens
+            .iter()
+            .filter(|token| **token == Token::KwWith)
+            .count(),
+        2
+    );
+}
+
+#[test]
+fn super_word_is_a_keyword_only_before_a_method_or_constructor_call() {
+    use crate::parser::lexer::{lex, Token};
+
+    let tokens =
+        lex("родитель = 1\nПуть.родитель(п)\nродитель.имя\nродитель.метод()\nsuper.constructor()")
+            .filter_map(Result::ok)
+            .map(|(_, token, _)| token)
+            .collect::<Vec<_>>();
+    assert_eq!(
+        tokens
+            .iter()
+            .filter(|token| **token == Token::KwSuper)
+            .count(),
+        2
+    );
+
+    let crate::parser::prelude::ParseError::InvalidSyntax(data) =
+        parse_error("функция ф(это) {\n    родитель.метод()\n}\n")
+    else {
+        panic!("expected syntax error");
+    };
+    assert!(data.message.contains("родитель"), "{}", data.message);
+}
+
+#[test]
+fn incomplete_input_is_detected_at_end_of_source() {
+    assert!(Parser::is_incomplete("функция f() {\n    печать(1)\n"));
+    assert!(Parser::is_incomplete("печать(1,\n"));
+    assert!(!Parser::is_incomplete("функция f() {\n    печать(1)\n}\n"));
+    assert!(!Parser::is_incomplete("печать(1))\n"));
+}
+
+#[test]
+fn ast_json_writes_symbol_names_inline() {
+    let parser = Parser::new(
+        goida_model::new_interner(),
+        "ast",
+        PathBuf::from("ast.goida"),
+    );
+    let ast = parser
+        .ast_json("значение = 1 + 2\nпечать(значение)\n")
+        .expect("source should parse");
+
+    let identifiers = ast["arena"]["expressions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|node| node["kind"]["Identifier"].as_str())
+        .collect::<Vec<_>>();
+    assert!(identifiers.contains(&"значение"), "{identifiers:?}");
+    assert!(identifiers.contains(&"печать"), "{identifiers:?}");
+    assert!(ast["arena"]["statements"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|node| node["kind"]["Assign"]["name"] == "значение"));
+    assert_eq!(ast["module"], "ast");
+    assert_eq!(ast["arena"]["expressions"][0]["span"]["file_id"], "ast");
+    assert!(ast.get("symbols").is_none());
+    assert_eq!(ast["body"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn token_dump_reports_character_columns_for_cyrillic_source() {
+    let dump = Parser::dump_tokens("имя = \"а\\\"б\"\n");
+    let lines = dump.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines[0], "1:1\t0..6\tIdent(\"имя\")");
+    assert_eq!(lines[1], "1:5\t7..8\tEq");
+    assert_eq!(lines[2], "1:7\t9..17\tString(\"а\\\"б\")");
+    assert_eq!(lines.last(), Some(&"2:1\t18..18\tEof"));
+}
+
+#[test]
+fn doc_comments_are_kept_on_definitions_and_collected_into_module_docs() {
+    let source = r#"
+/// Складывает числа.
+/// Второе слагаемое необязательно.
+функция сумма(а: число, б: число = 1) -> число {
+    вернуть а + б
+}
+
+/// Не относится к функции ниже.
+
+// обычный комментарий
+функция без_документации() {
+}
+
+/// Счётчик.
+класс Счетчик {
+    /// Создаёт счётчик.
+    публичный конструктор новый(это, старт: число) {
+    }
+
+    /// Текущее значение счётчика.
+    публичный функция значение(это) -> число {
+        вернуть 1
+    }
+
+    /// Текущее значение.
+    публичный статичный функция ноль() -> число {
+        вернуть 0
+    }
+
+    приватный функция скрытый() {
+    }
+}
+"#;
+    let docs = Parser::new(
+        goida_model::new_interner(),
+        "docs_test",
+        PathBuf::from("docs.goida"),
+    )
+    .module_docs(source)
+    .expect("source should parse");
+
+    let [sum, undocumented] = docs.functions.as_slice() else {
+        panic!("expected two functions, got {:?}", docs.functions);
+    };
+    assert_eq!(
+        sum.doc.as_deref(),
+        Some("Складывает числа.\nВторое слагаемое необязательно.")
+    );
+    assert_eq!(
+        sum.signature(),
+        "функция сумма(а: число, б: число = 1) -> число"
+    );
+    assert_eq!(undocumented.doc, None);
+
+    let [class] = docs.classes.as_slice() else {
+        panic!("expected one class, got {:?}", docs.classes);
+    };
+    assert_eq!(class.doc.as_deref(), Some("Счётчик."));
+    let constructor = class.constructor.as_ref().expect("constructor");
+    assert_eq!(constructor.signature(), "конструктор новый(старт: число)");
+    assert_eq!(constructor.doc.as_deref(), Some("Создаёт счётчик."));
+    let signatures = class
+        .methods
+        .iter()
+        .map(|method| method.signature())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        signatures,
+        [
+            "функция значение() -> число",
+            "статичный функция ноль() -> число"
+        ]
+    );
+    assert!(docs.to_markdown().contains("Текущее значение."));
+}
+
+#[test]
+fn leading_shebang_line_is_skipped_and_kept_by_formatter() {
+    let source = "#!/usr/bin/env goida\nзначение = 1\n";
+    let module = Parser::new(
+        goida_model::new_interner(),
+        "shebang",
+        PathBuf::from("shebang.goida"),
+    )
+    .parse(source)
+    .expect("shebang line should be skipped");
+    assert_eq!(module.body.len(), 1);
+
+    let formatted = Parser::new(
+        goida_model::new_interner(),
+        "shebang",
+        PathBuf::from("shebang.goida"),
+    )
+    .format_source_ast(source)
+    .expect("source should format");
+    assert_eq!(formatted, source);
+}
+
+#[test]
+fn arena_reuses_identical_types_and_reports_stats() {
+    let mut arena = AstArena::new();
+    let span = Span::default();
+    let other_span = Span { start: 4, ..span };
+
+    let first = arena.add_expression(ExpressionKind::Literal(LiteralValue::Number(1)), span);
+    let repeated = arena.add_expression(ExpressionKind::Literal(LiteralValue::Number(1)), span);
+    arena.add_expression(ExpressionKind::Literal(LiteralValue::Number(1)), other_span);
+    assert_ne!(first, repeated);
+
+    let number = arena.add_type(DataType::Primitive(PrimitiveType::Number));
+    let list = arena.add_type(DataType::List(Box::new(DataType::Any)));
+    assert_eq!(
+        number,
+        arena.add_type(DataType::Primitive(PrimitiveType::Number))
+    );
+    assert_eq!(
+        list,
+        arena.add_type(DataType::List(Box::new(DataType::Any)))
+    );
+    assert_ne!(number, list);
+
+    let stats = arena.stats();
+    assert_eq!(stats.expressions, 3);
+    assert_eq!(stats.types, 2);
+    assert_eq!(stats.reused_types, 2);
+    assert_eq!(stats.repeated_spans, 1);
+    assert!(stats.bytes > 0);
+}
@@ -1,9 +1,10 @@
 use crate::ast::prelude::ErrorData;
 use crate::interpreter::prelude::{Module, SharedInterner};
 use crate::shared::SharedMut;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::Arc;
+use string_interner::DefaultSymbol as Symbol;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// Keyword language used when rendering source code.
@@ -45,6 +46,7 @@ impl FormatLanguage {
             "as",
             "new",
             "return",
+            "yield",
             "and",
             "or",
             "true",
@@ -75,6 +77,7 @@ impl FormatLanguage {
             "как",
             "новый",
             "вернуть",
+            "выдать",
             "и",
             "или",
             "истина",
@@ -105,11 +108,39 @@ pub struct Parser {
     pub module: Module,
     pub(crate) interner: SharedInterner,
     pub(crate) module_loader: SharedMut<ModuleLoader>,
+    /// Globals defined outside the parsed source, e.g. by earlier REPL inputs.
+    pub(crate) predeclared: HashSet<Symbol>,
+    /// Global that receives the value of a trailing expression statement.
+    pub(crate) result_binding: Option<Symbol>,
+    /// Base class and object parameter of the method being built, for `родитель`.
+    pub(crate) super_context: Option<(Symbol, Symbol)>,
 }
 
 #[derive(Debug, Default)]
 pub(crate) struct ModuleLoader {
     pub(crate) modules: HashMap<PathBuf, ModuleLoadState>,
+    /// Imported files recorded while parsing, or served instead of the disk.
+    pub(crate) sources: ProgramSources,
+    /// Propagate constant values, see [`Parser::with_optimizations`].
+    pub(crate) optimize: bool,
+    /// Imported files read and parsed ahead of time, by resolved path.
+    pub(crate) preparsed: HashMap<PathBuf, PreparsedFile>,
+}
+
+#[derive(Debug)]
+pub(crate) struct PreparsedFile {
+    pub(crate) code: String,
+    pub(crate) syntax: crate::parser::syntax::Program,
+}
+
+/// Text of every imported file and how each import was resolved, enough to
+/// parse a program again without its files, e.g. inside a standalone executable.
+#[derive(Debug, Clone, Default)]
+pub struct ProgramSources {
+    /// Source text by resolved path.
+    pub files: HashMap<PathBuf, String>,
+    /// Resolved path by importing module path and import string.
+    pub imports: HashMap<(PathBuf, String), PathBuf>,
 }
 
 #[derive(Debug)]
@@ -26,6 +26,8 @@ pub(crate) struct Program {
 pub(crate) struct Comment {
     pub text: String,
     pub span: Range<usize>,
+    /// `///` comment documenting the following definition.
+    pub doc: bool,
 }
 
 pub(crate) trait Visitor {
@@ -157,6 +159,7 @@ pub(crate) struct Function {
     pub params: Vec<Param>,
     pub return_type: Option<String>,
     pub body: Vec<Item>,
+    pub doc: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -172,6 +175,7 @@ pub(crate) struct Class {
     pub name: String,
     pub base: Option<String>,
     pub items: Vec<ClassItem>,
+    pub doc: Option<String>,
 }
 
 pub(crate) type ClassItem = Spanned<ClassItemKind>;
@@ -187,6 +191,8 @@ pub(crate) enum ClassItemKind {
 pub(crate) struct ClassField {
     pub visibility: Option<Visibility>,
     pub is_static: bool,
+    /// Declared with `константа`: static and never reassigned.
+    pub is_const: bool,
     pub name: String,
     pub type_name: String,
     pub default_value: Option<Expr>,
@@ -200,6 +206,7 @@ pub(crate) struct ClassMethod {
     pub params: Vec<Param>,
     pub return_type: Option<String>,
     pub body: Vec<Item>,
+    pub doc: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -254,6 +261,10 @@ pub(crate) enum StmtKind {
         target: Expr,
         value: Expr,
     },
+    MultiAssign {
+        targets: Vec<Expr>,
+        values: Vec<Expr>,
+    },
     CompoundAssign {
         target: Expr,
         op: CompoundOp,
@@ -283,6 +294,14 @@ pub(crate) enum StmtKind {
     Thread {
         body: Vec<Item>,
     },
+    Defer {
+        body: Vec<Item>,
+    },
+    With {
+        variable: String,
+        value: Expr,
+        body: Vec<Item>,
+    },
     Try {
         body: Vec<Item>,
         handlers: Vec<Catch>,
@@ -292,6 +311,7 @@ pub(crate) enum StmtKind {
         message: Option<Expr>,
     },
     Return(Option<Expr>),
+    Yield(Expr),
     Expr(Expr),
 }
 
@@ -391,10 +411,20 @@ pub(crate) enum ExprKind {
         object: Box<Expr>,
         index: Box<Expr>,
     },
+    Slice {
+        object: Box<Expr>,
+        start: Option<Box<Expr>>,
+        end: Option<Box<Expr>>,
+    },
     ObjectCreation {
         class_name: String,
         args: Vec<CallArg>,
     },
+    /// `родитель.метод(...)`, or `родитель.конструктор(...)` when `method` is `None`.
+    SuperCall {
+        method: Option<String>,
+        args: Vec<CallArg>,
+    },
     Lambda {
         params: Vec<Param>,
         body: LambdaBody,
@@ -414,12 +444,14 @@ pub(crate) enum PostfixOp {
     MethodCall(String, Vec<CallArg>, Range<usize>),
     PropertyAccess(String, Range<usize>),
     Index(Expr, Range<usize>),
+    Slice(Option<Expr>, Option<Expr>, Range<usize>),
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct CallArg {
     pub name: Option<String>,
     pub value: Expr,
+    pub spread: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -437,6 +469,9 @@ pub(crate) enum BinaryOp {
     Ge,
     And,
     Or,
+    In,
+    NotIn,
+    Is,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -445,6 +480,41 @@ pub(crate) enum UnaryOp {
     Not,
 }
 
+/// `a < b <= c` reads as `a < b и b <= c`; the shared operand is evaluated in both comparisons.
+pub(crate) fn chain_comparisons(first: Expr, rest: Vec<(BinaryOp, Expr)>) -> Expr {
+    let mut left = first;
+    let mut chain: Option<Expr> = None;
+    for (op, right) in rest {
+        let (start, end) = (left.span.start, right.span.end);
+        let comparison = Spanned::new(
+            ExprKind::Binary {
+                op,
+                left: Box::new(left),
+                right: Box::new(right.clone()),
+            },
+            start,
+            end,
+        );
+        chain = Some(match chain {
+            None => comparison,
+            Some(previous) => {
+                let start = previous.span.start;
+                Spanned::new(
+                    ExprKind::Binary {
+                        op: BinaryOp::And,
+                        left: Box::new(previous),
+                        right: Box::new(comparison),
+                    },
+                    start,
+                    end,
+                )
+            }
+        });
+        left = right;
+    }
+    chain.expect("a comparison chain has at least one operator")
+}
+
 pub(crate) fn apply_postfix(mut expr: Expr, ops: Vec<PostfixOp>) -> Expr {
     for op in ops {
         let start = expr.span.start;
@@ -482,6 +552,15 @@ pub(crate) fn apply_postfix(mut expr: Expr, ops: Vec<PostfixOp>) -> Expr {
                 start,
                 span.end,
             ),
+            PostfixOp::Slice(from, to, span) => Spanned::new(
+                ExprKind::Slice {
+                    object: Box::new(expr),
+                    start: from.map(Box::new),
+                    end: to.map(Box::new),
+                },
+                start,
+                span.end,
+            ),
         };
     }
     expr
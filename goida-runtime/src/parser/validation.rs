@@ -11,19 +11,14 @@ impl ParserTrait {
         let mut known = self.known_global_names();
         for stmt_id in &self.module.body {
             let stmt = self.module.arena.get_statement(*stmt_id).unwrap();
-            match &stmt.kind {
-                StatementKind::Assign { name, .. } => {
-                    known.insert(*name);
+            known.extend(self.module.arena.assigned_names(*stmt_id));
+            if let StatementKind::NativeLibraryDefinition(definition) = &stmt.kind {
+                for function in &definition.functions {
+                    known.insert(function.name);
                 }
-                StatementKind::NativeLibraryDefinition(definition) => {
-                    for function in &definition.functions {
-                        known.insert(function.name);
-                    }
-                    for global in &definition.globals {
-                        known.insert(global.name);
-                    }
+                for global in &definition.globals {
+                    known.insert(global.name);
                 }
-                _ => {}
             }
         }
 
@@ -35,7 +30,7 @@ impl ParserTrait {
     }
 
     pub(crate) fn known_global_names(&self) -> HashSet<Symbol> {
-        let mut names = HashSet::new();
+        let mut names = self.predeclared.clone();
 
         for name in self.module.functions.keys() {
             names.insert(*name);
@@ -68,9 +63,8 @@ impl ParserTrait {
             let Some(stmt) = module.arena.get_statement(*stmt_id) else {
                 continue;
             };
-            if let StatementKind::Assign { name, .. } = stmt.kind {
-                names.insert(name);
-            } else if let StatementKind::NativeLibraryDefinition(definition) = &stmt.kind {
+            names.extend(module.arena.assigned_names(*stmt_id));
+            if let StatementKind::NativeLibraryDefinition(definition) = &stmt.kind {
                 for function in &definition.functions {
                     names.insert(function.name);
                 }
@@ -97,6 +91,25 @@ impl ParserTrait {
                 scopes.last_mut().unwrap().insert(*name);
                 Ok(())
             }
+            StatementKind::MultiAssign { targets, values } => {
+                for value in values {
+                    self.validate_expression_names(*value, scopes)?;
+                }
+                for target in targets {
+                    match self
+                        .module
+                        .arena
+                        .get_expression(*target)
+                        .map(|node| &node.kind)
+                    {
+                        Some(ExpressionKind::Identifier(name)) => {
+                            scopes.last_mut().unwrap().insert(*name);
+                        }
+                        _ => self.validate_expression_names(*target, scopes)?,
+                    }
+                }
+                Ok(())
+            }
             StatementKind::CompoundAssign { target, value, .. } => {
                 self.validate_expression_names(*target, scopes)?;
                 self.validate_expression_names(*value, scopes)
@@ -158,7 +171,20 @@ impl ParserTrait {
                 scopes.pop();
                 Ok(())
             }
+            StatementKind::With {
+                variable,
+                value,
+                body,
+            } => {
+                self.validate_expression_names(*value, scopes)?;
+                scopes.push(HashSet::new());
+                scopes.last_mut().unwrap().insert(*variable);
+                self.validate_statement_names(*body, scopes)?;
+                scopes.pop();
+                Ok(())
+            }
             StatementKind::Thread { body } => self.validate_thread_body_names(*body, scopes),
+            StatementKind::Defer { body } => self.validate_statement_names(*body, scopes),
             StatementKind::Try { body, handlers } => {
                 self.validate_statement_names(*body, scopes)?;
                 for handler in handlers {
@@ -193,6 +219,7 @@ impl ParserTrait {
                 }
                 Ok(())
             }
+            StatementKind::Yield(expr) => self.validate_expression_names(*expr, scopes),
             StatementKind::FunctionDefinition(def) => {
                 scopes.last_mut().unwrap().insert(def.name);
                 let mut local = HashSet::new();
@@ -303,6 +330,13 @@ impl ParserTrait {
                 self.validate_expression_names(*object, scopes)?;
                 self.validate_expression_names(*index, scopes)
             }
+            ExpressionKind::Slice { object, start, end } => {
+                self.validate_expression_names(*object, scopes)?;
+                for bound in [start, end].into_iter().flatten() {
+                    self.validate_expression_names(*bound, scopes)?;
+                }
+                Ok(())
+            }
             ExpressionKind::PropertyAccess { object, .. } => {
                 self.validate_expression_names(*object, scopes)
             }
@@ -330,6 +364,13 @@ impl ParserTrait {
                 }
                 Ok(())
             }
+            ExpressionKind::SuperCall { receiver, args, .. } => {
+                self.validate_expression_names(*receiver, scopes)?;
+                for arg in args {
+                    self.validate_expression_names(arg.value, scopes)?;
+                }
+                Ok(())
+            }
             ExpressionKind::Lambda { params, body } => {
                 let mut local = HashSet::new();
                 for param in params {
@@ -1,20 +1,120 @@
+use crate::ast::prelude::{ErrorData, Span};
+use crate::builtins::random::new_random;
 use crate::builtins::registry::BUILTINS;
+use crate::interpreter::debugger::Debugger;
 use crate::interpreter::heap::CollectionStats;
-use crate::interpreter::prelude::{Interpreter, Module, RuntimeError, SharedInterner};
-use crate::traits::prelude::CoreOperations;
+use crate::interpreter::host::HostClass;
+use crate::interpreter::options::{ExecutionLimits, InterpreterOptions};
+use crate::interpreter::prelude::{
+    Interpreter, Module, RuntimeError, RuntimeFunction, SharedInterner, Value,
+};
+use crate::interpreter::profiler::{ProfileEntry, Profiler};
+use crate::parser::prelude::{ParseError, Parser};
+use crate::runtime_error;
+use crate::traits::prelude::{CoreOperations, InterpreterFunctions};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use string_interner::DefaultSymbol as Symbol;
+
+/// Hidden global that carries the value of an incremental input.
+const INCREMENTAL_RESULT: &str = "__результат_ввода";
+
+/// Why [`Session::eval`] failed.
+#[derive(Debug)]
+pub enum EvalError {
+    Parse(ParseError),
+    Runtime(RuntimeError),
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::Parse(
+                ParseError::TypeError(err)
+                | ParseError::InvalidSyntax(err)
+                | ParseError::ImportError(err),
+            ) => write!(f, "{}", err.message),
+            EvalError::Runtime(err) => {
+                write!(f, "{}: {}", err.error_class_name(), err.error_message())
+            }
+        }
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+impl From<ParseError> for EvalError {
+    fn from(err: ParseError) -> Self {
+        EvalError::Parse(err)
+    }
+}
+
+impl From<RuntimeError> for EvalError {
+    fn from(err: RuntimeError) -> Self {
+        EvalError::Runtime(err)
+    }
+}
 
 /// Isolated language session owning its interner and runtime state.
 #[derive(Debug)]
 pub struct Session {
     runtime: Interpreter,
+    globals: HashMap<Symbol, Value>,
+    optimize: bool,
 }
 
 impl Session {
     pub fn new() -> Self {
+        Self::with_options(InterpreterOptions::default())
+    }
+
+    /// Session whose builtins are limited by `options`, e.g. a sandbox.
+    pub fn with_options(options: InterpreterOptions) -> Self {
         let interner = goida_model::new_interner();
         let mut runtime = Interpreter::new(interner);
+        runtime.random = new_random(options.seed());
+        runtime.options = options;
         BUILTINS.install(&mut runtime).unwrap();
-        Self { runtime }
+        Self {
+            runtime,
+            globals: HashMap::new(),
+            optimize: false,
+        }
+    }
+
+    /// Sets the values returned by `Система.аргументы()`.
+    pub fn set_script_args(&mut self, args: Vec<String>) {
+        self.runtime.script_args = Arc::new(args);
+    }
+
+    /// Starts counting calls and time per function for [`Session::profile`].
+    pub fn enable_profiling(&mut self) {
+        self.runtime.profiler = Some(Arc::new(Profiler::default()));
+    }
+
+    /// Collected profile, most expensive entries first; empty unless enabled.
+    pub fn profile(&self) -> Vec<ProfileEntry> {
+        self.runtime
+            .profiler
+            .as_ref()
+            .map(|profiler| profiler.report())
+            .unwrap_or_default()
+    }
+
+    /// Makes [`Session::parser`] propagate constant values, see [`Parser::with_optimizations`].
+    pub fn enable_optimizations(&mut self) {
+        self.optimize = true;
+    }
+
+    pub fn optimizations_enabled(&self) -> bool {
+        self.optimize
+    }
+
+    /// Stops before statements as `debugger` decides.
+    pub fn attach_debugger(&mut self, debugger: Arc<Debugger>) {
+        self.runtime.debugger = Some(debugger);
     }
 
     pub fn interner(&self) -> SharedInterner {
@@ -23,11 +123,236 @@ impl Session {
 
     /// Executes an already parsed and lowered module tree.
     pub fn execute(&mut self, module: Module) -> Result<(), RuntimeError> {
+        self.start_limits();
         let module_id = module.name;
         self.runtime.load_start_module(module);
         self.runtime.interpret(module_id)
     }
 
+    /// Names of the top-level functions of a loaded module, in source order.
+    pub fn module_functions(&self, module: Symbol) -> Vec<String> {
+        let Some(module) = self.runtime.modules.get(&module) else {
+            return Vec::new();
+        };
+        let mut functions = module.functions.iter().collect::<Vec<_>>();
+        functions.sort_by_key(|(_, function)| function.span.start);
+        functions
+            .into_iter()
+            .filter_map(|(name, _)| self.runtime.resolve_symbol(*name))
+            .collect()
+    }
+
+    /// Calls a top-level function of a loaded module without arguments.
+    pub fn call_module_function(
+        &mut self,
+        module: Symbol,
+        name: &str,
+    ) -> Result<Value, RuntimeError> {
+        self.start_limits();
+        let symbol = self.runtime.intern_string(name);
+        let function = self
+            .runtime
+            .modules
+            .get(&module)
+            .and_then(|module| module.functions.get(&symbol))
+            .map(|function| Arc::new(RuntimeFunction::new(function.clone())))
+            .ok_or_else(|| runtime_error!(UndefinedFunction, Span::default(), "{}", name))?;
+        self.runtime
+            .call_function(&function, Vec::new(), module, Span::default())
+    }
+
+    /// Every execution gets the full time and operation budget of the options.
+    fn start_limits(&mut self) {
+        self.runtime.limits = ExecutionLimits::start(&self.runtime.options).map(Arc::new);
+    }
+
+    /// Parser that also knows the functions and classes registered by the host.
+    pub fn parser(&self, name: &str, path: PathBuf) -> Parser {
+        Parser::new(self.interner(), name, path)
+            .with_predeclared_globals(
+                self.runtime
+                    .builtins
+                    .keys()
+                    .chain(self.runtime.std_classes.keys())
+                    .copied()
+                    .collect::<Vec<_>>(),
+            )
+            .with_optimizations(self.optimize)
+    }
+
+    /// Parser for an input that continues the globals of earlier incremental runs.
+    pub fn incremental_parser(&self, name: &str) -> Parser {
+        self.parser(name, PathBuf::from(name))
+            .with_predeclared_globals(self.globals.keys().copied().collect::<Vec<_>>())
+            .with_result_binding(self.runtime.intern_string(INCREMENTAL_RESULT))
+    }
+
+    /// See [`Interpreter::register_builtin`].
+    pub fn register_builtin(
+        &mut self,
+        name: &str,
+        function: impl Fn(&Interpreter, Vec<Value>) -> Result<Value, RuntimeError>
+            + Send
+            + Sync
+            + 'static,
+    ) {
+        self.runtime.register_builtin(name, function);
+    }
+
+    /// See [`Interpreter::register_class`].
+    pub fn register_class(&mut self, class: HostClass) {
+        self.runtime.register_class(class);
+    }
+
+    /// Executes a module on top of the globals left by earlier incremental runs.
+    ///
+    /// Returns the value of the trailing expression statement, if there was one.
+    pub fn execute_incremental(
+        &mut self,
+        mut module: Module,
+    ) -> Result<Option<Value>, RuntimeError> {
+        self.start_limits();
+        let module_id = module.name;
+        let result_symbol = self.runtime.intern_string(INCREMENTAL_RESULT);
+        for (name, value) in &self.globals {
+            module.globals.entry(*name).or_insert_with(|| value.clone());
+        }
+        self.runtime.load_incremental_module(module);
+        let result = self.runtime.interpret_incremental(module_id);
+        let value = self
+            .runtime
+            .modules
+            .get_mut(&module_id)
+            .and_then(|module| module.globals.remove(&result_symbol));
+        let runtime = &self.runtime;
+        let error_classes = BUILTINS
+            .error_classes()
+            .iter()
+            .filter_map(|error| runtime.interner.read(|i| i.get(error.name)))
+            .collect::<HashSet<_>>();
+        if let Some(module) = runtime.modules.get(&module_id) {
+            self.globals.extend(
+                module
+                    .globals
+                    .iter()
+                    .filter(|(name, _)| {
+                        !runtime.builtins.contains_key(name)
+                            && !runtime.std_classes.contains_key(name)
+                            && !error_classes.contains(*name)
+                    })
+                    .map(|(name, value)| (*name, value.clone())),
+            );
+        }
+        result.map(|()| value)
+    }
+
+    /// Parses and runs `source` like a REPL input, keeping its globals for later calls.
+    ///
+    /// Returns the value of the trailing expression, or `Value::Empty` without one.
+    pub fn eval(&mut self, source: &str) -> Result<Value, EvalError> {
+        // Каждый ввод — отдельный модуль: его функции ссылаются на его байткод.
+        let name = format!("<eval:{}>", self.runtime.modules.len());
+        self.runtime.source_manager.load_source(&name, source);
+        let module = self.incremental_parser(&name).parse(source)?;
+        Ok(self.execute_incremental(module)?.unwrap_or(Value::Empty))
+    }
+
+    /// Re-reads a module imported by earlier incremental runs, named by its alias or
+    /// path, and swaps it in place. Other globals of the session are kept; functions
+    /// and classes the inputs took from the module are replaced by the new ones.
+    pub fn reload_module(&mut self, name: &str) -> Result<(), RuntimeError> {
+        self.start_limits();
+        let alias = self.runtime.interner.read(|i| i.get(name));
+        let module_id = alias
+            .and_then(|alias| match self.globals.get(&alias) {
+                Some(Value::Module(module_id)) => Some(*module_id),
+                _ => None,
+            })
+            .or_else(|| self.runtime.loaded_module_at(Path::new("."), name))
+            .ok_or_else(|| {
+                RuntimeError::ImportError(ParseError::ImportError(ErrorData::new(
+                    Span::default(),
+                    format!("Модуль '{}' не подключён", name),
+                )))
+            })?;
+        let exported = self
+            .runtime
+            .modules
+            .get(&module_id)
+            .map(|module| {
+                module
+                    .globals
+                    .iter()
+                    .filter(|(_, value)| matches!(value, Value::Function(_) | Value::Class(_)))
+                    .map(|(name, _)| *name)
+                    .collect::<HashSet<_>>()
+            })
+            .unwrap_or_default();
+        self.runtime.reload_module(module_id, Span::default())?;
+        if let Some(module) = self.runtime.modules.get(&module_id) {
+            for (name, value) in &mut self.globals {
+                if !exported.contains(name)
+                    || !matches!(value, Value::Function(_) | Value::Class(_))
+                {
+                    continue;
+                }
+                if let Some(fresh) = module.globals.get(name) {
+                    *value = fresh.clone();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Globals defined by incremental runs, sorted by name.
+    pub fn globals(&self) -> Vec<(String, Value)> {
+        let mut globals = self
+            .globals
+            .iter()
+            .filter_map(|(name, value)| Some((self.runtime.resolve_symbol(*name)?, value.clone())))
+            .collect::<Vec<_>>();
+        globals.sort_by(|left, right| left.0.cmp(&right.0));
+        globals
+    }
+
+    /// Completion candidates for `partial`, optionally after `receiver.`.
+    pub fn completions(&self, receiver: Option<&str>, partial: &str) -> Vec<String> {
+        let mut names = BTreeSet::new();
+        match receiver {
+            Some(receiver) => {
+                let symbol = self.runtime.interner.read(|i| i.get(receiver));
+                let class = literal_value(receiver)
+                    .or_else(|| symbol.and_then(|symbol| self.globals.get(&symbol).cloned()))
+                    .and_then(|value| self.runtime.get_class_for_value(&value))
+                    .or_else(|| {
+                        symbol.and_then(|symbol| self.runtime.std_classes.get(&symbol).cloned())
+                    });
+                if let Some(class) = class {
+                    class.read(|class| {
+                        names.extend(
+                            class
+                                .methods
+                                .keys()
+                                .filter_map(|name| self.runtime.resolve_symbol(*name)),
+                        )
+                    });
+                }
+            }
+            None => {
+                let symbols = self
+                    .globals
+                    .keys()
+                    .chain(self.runtime.builtins.keys())
+                    .chain(self.runtime.std_classes.keys());
+                names.extend(symbols.filter_map(|name| self.runtime.resolve_symbol(*name)));
+            }
+        }
+        names
+            .into_iter()
+            .filter(|name| name.starts_with(partial))
+            .collect()
+    }
+
     /// Keeps a partial module available for source-aware diagnostics.
     pub fn register_diagnostic_module(&mut self, module: Module) {
         self.runtime.modules.insert(module.name, module);
@@ -50,9 +375,27 @@ impl Default for Session {
     }
 }
 
+/// Value of a literal completion receiver such as `"текст"`.
+fn literal_value(receiver: &str) -> Option<Value> {
+    receiver
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+        .map(|text| Value::Text(text.into()))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Session;
+    use super::{EvalError, Session};
+    use crate::ast::prelude::{ErrorData, Span};
+    use crate::interpreter::debugger::{DebugCommand, DebugFrontend, DebugStop, Debugger};
+    use crate::interpreter::host::HostClass;
+    use crate::interpreter::options::{Capability, InterpreterOptions};
+    use crate::interpreter::prelude::{RuntimeError, Value};
+    use crate::parser::prelude::Parser;
+    use crate::traits::prelude::CoreOperations;
+    use std::path::PathBuf;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
 
     #[test]
     fn sessions_own_independent_interners_and_runtimes() {
@@ -70,4 +413,727 @@ mod tests {
             .read(|interner| interner.get("__only_in_first_session__"))
             .is_none());
     }
+
+    #[test]
+    fn module_functions_shadow_builtins_and_other_builtins_still_resolve() {
+        let mut session = Session::new();
+        let module = Parser::new(
+            session.interner(),
+            "shadowing",
+            PathBuf::from("shadowing.goida"),
+        )
+        .parse(
+            r#"
+функция проверить(значение) {
+    вернуть значение * 2
+}
+удвоено = проверить(21)
+число_из_строки = число("41") + 1
+"#,
+        )
+        .expect("program should compile");
+        let module_id = module.name;
+        session.execute(module).expect("program should run");
+
+        let global = |session: &Session, name: &str| {
+            let name = session.runtime().intern_string(name);
+            session.runtime().modules[&module_id]
+                .globals
+                .get(&name)
+                .cloned()
+        };
+        assert_eq!(global(&session, "удвоено"), Some(Value::Number(42)));
+        assert_eq!(global(&session, "число_из_строки"), Some(Value::Number(42)));
+    }
+
+    #[test]
+    fn builtins_resolve_when_no_module_function_shadows_them() {
+        let mut session = Session::new();
+        let module = Parser::new(
+            session.interner(),
+            "builtin_lookup",
+            PathBuf::from("builtin_lookup.goida"),
+        )
+        .parse("проверить(1 == 2, \"не равно\")\n")
+        .expect("program should compile");
+
+        let error = session
+            .execute(module)
+            .expect_err("the builtin assertion should fail");
+        assert!(format!("{error:?}").contains("не равно"), "{error:?}");
+    }
+
+    #[test]
+    fn incremental_runs_share_globals_and_complete_their_names() {
+        let mut session = Session::new();
+        let mut results = Vec::new();
+        for source in ["счетчик = 41", "счетчик = счетчик + 1", "счетчик * 2"]
+        {
+            let module = session
+                .incremental_parser("repl")
+                .parse(source)
+                .expect("input should parse");
+            results.push(
+                session
+                    .execute_incremental(module)
+                    .expect("input should run"),
+            );
+        }
+        assert!(matches!(
+            results.as_slice(),
+            [None, None, Some(Value::Number(84))]
+        ));
+
+        let globals = session.globals();
+        assert_eq!(globals.len(), 1);
+        assert!(matches!(globals[0], (ref name, Value::Number(42)) if name == "счетчик"));
+        assert!(session
+            .completions(None, "сч")
+            .contains(&"счетчик".to_string()));
+        assert!(session
+            .completions(Some("Система"), "плат")
+            .contains(&"платформа".to_string()));
+        assert!(session
+            .completions(Some("\"привет\""), "дли")
+            .contains(&"длина".to_string()));
+    }
+
+    #[test]
+    fn module_functions_can_be_listed_and_called_after_execution() {
+        let mut session = Session::new();
+        let module = Parser::new(session.interner(), "tests", PathBuf::from("tests"))
+            .parse(
+                "база = 40\nфункция тест_б() -> число {\n    вернуть база + 2\n}\nфункция тест_а() {\n    проверить(ложь, \"упало\")\n}\n",
+            )
+            .expect("module should parse");
+        let module_id = module.name;
+        session.execute(module).expect("module should run");
+
+        assert_eq!(session.module_functions(module_id), ["тест_б", "тест_а"]);
+        assert!(matches!(
+            session.call_module_function(module_id, "тест_б"),
+            Ok(Value::Number(42))
+        ));
+        assert!(matches!(
+            session.call_module_function(module_id, "тест_а"),
+            Err(RuntimeError::Raised(ref error, ref class)) if class == "ОшибкаПроверки" && error.message == "упало"
+        ));
+    }
+
+    #[test]
+    fn debugger_stops_at_breakpoints_and_steps_over_calls() {
+        /// Line, depth and variable names of every stop.
+        type Stops = Arc<Mutex<Vec<(usize, usize, Vec<String>)>>>;
+
+        struct Scripted {
+            stops: Stops,
+        }
+
+        impl DebugFrontend for Scripted {
+            fn stopped(&mut self, stop: &DebugStop, debugger: &Debugger) -> DebugCommand {
+                let names = stop
+                    .variables
+                    .iter()
+                    .map(|(name, _)| name.clone())
+                    .collect();
+                let mut stops = self.stops.lock().unwrap();
+                stops.push((stop.line, stop.depth, names));
+                match stops.len() {
+                    1 => {
+                        debugger.add_breakpoint(&stop.file, 4);
+                        DebugCommand::Continue
+                    }
+                    2 => DebugCommand::Next,
+                    _ => DebugCommand::Continue,
+                }
+            }
+        }
+
+        let stops = Arc::new(Mutex::new(Vec::new()));
+        let mut session = Session::new();
+        session.attach_debugger(Arc::new(Debugger::new(Box::new(Scripted {
+            stops: stops.clone(),
+        }))));
+        let source = "x = 1\nфункция удвоить(н: число) -> число {\n    результат = н * 2\n    вернуть результат\n}\nу = удвоить(x)\nz = у + 1\n";
+        session
+            .runtime()
+            .source_manager
+            .load_source("debug", source);
+        let module = Parser::new(session.interner(), "debug", PathBuf::from("debug"))
+            .parse(source)
+            .expect("module should parse");
+        session.execute(module).expect("module should run");
+
+        let stops = stops.lock().unwrap();
+        let lines = stops
+            .iter()
+            .map(|(line, depth, _)| (*line, *depth))
+            .collect::<Vec<_>>();
+        assert_eq!(lines, [(1, 0), (4, 1), (7, 0)]);
+        assert!(["н", "результат", "x"]
+            .iter()
+            .all(|name| stops[1].2.contains(&name.to_string())));
+        assert!(stops[2].2.contains(&"у".to_string()));
+    }
+
+    #[test]
+    fn sandbox_denies_file_access_unless_allowed() {
+        let run = |options: InterpreterOptions, source: &str| {
+            let mut session = Session::with_options(options);
+            let module = Parser::new(session.interner(), "sandbox", PathBuf::from("sandbox"))
+                .parse(source)
+                .expect("module should parse");
+            session.execute(module)
+        };
+        let open_file = "файл = новый Файл(\"секрет.txt\")\n";
+
+        assert!(matches!(
+            run(InterpreterOptions::sandboxed(), open_file),
+            Err(RuntimeError::PermissionDenied(_))
+        ));
+        assert!(run(
+            InterpreterOptions::sandboxed().allow(Capability::File),
+            open_file
+        )
+        .is_ok());
+        assert!(run(
+            InterpreterOptions::sandboxed(),
+            "попробовать {\n    Система.окружение(\"HOME\")\n} перехватить (ОшибкаДоступа как сообщение) {\n    проверить(сообщение.длина() > 0)\n}\n",
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn sandbox_keeps_in_memory_databases_from_attaching_files() {
+        let target = std::env::temp_dir().join(format!("goida-attach-{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&target);
+        let path = target.display().to_string().replace('\\', "/");
+        let run = |options: InterpreterOptions, sql: &str| {
+            let mut session = Session::with_options(options);
+            let source = format!(
+                "база = БазаДанных.открыть()\nбаза.выполнить(\"{}\")\n",
+                sql.replace('"', "\\\"")
+            );
+            let module = Parser::new(session.interner(), "sandbox", PathBuf::from("sandbox"))
+                .parse(&source)
+                .expect("module should parse");
+            session.execute(module)
+        };
+
+        let attach = format!("ATTACH DATABASE '{path}' AS x; CREATE TABLE x.t(a)");
+        let vacuum = format!("VACUUM INTO '{path}'");
+        for sql in [&attach, &vacuum] {
+            let error = run(InterpreterOptions::sandboxed(), sql).expect_err("SQL should fail");
+            assert!(
+                format!("{error:?}").contains("Ошибка базы данных"),
+                "{error:?}"
+            );
+            assert!(!target.exists(), "{sql} created a file");
+        }
+
+        run(
+            InterpreterOptions::sandboxed(),
+            "CREATE TABLE t(a); INSERT INTO t VALUES (1)",
+        )
+        .expect("in-memory tables stay available in the sandbox");
+        run(
+            InterpreterOptions::sandboxed().allow(Capability::File),
+            &attach,
+        )
+        .expect("ATTACH works once files are allowed");
+        assert!(target.exists());
+        std::fs::remove_file(&target).unwrap();
+    }
+
+    #[test]
+    fn limits_stop_endless_loops_even_inside_try() {
+        let endless = "попробовать {\n    пока (истина) {\n    }\n} перехватить (Ошибка как сообщение) {\n}\n";
+        let run = |options: InterpreterOptions| {
+            let mut session = Session::with_options(options);
+            let module = Parser::new(session.interner(), "limits", PathBuf::from("limits"))
+                .parse(endless)
+                .expect("module should parse");
+            session.execute(module)
+        };
+
+        assert!(matches!(
+            run(InterpreterOptions::default().with_operation_limit(10_000)),
+            Err(RuntimeError::LimitExceeded(ref error)) if error.message.contains("превышен лимит выполнения")
+        ));
+        assert!(matches!(
+            run(InterpreterOptions::default().with_time_limit(Duration::from_millis(50))),
+            Err(RuntimeError::LimitExceeded(_))
+        ));
+    }
+
+    #[test]
+    fn eval_returns_trailing_expression_and_keeps_globals() {
+        let mut session = Session::new();
+        assert!(matches!(session.eval("икс = 40\n"), Ok(Value::Empty)));
+        assert!(matches!(session.eval("икс + 2\n"), Ok(Value::Number(42))));
+        assert!(matches!(
+            crate::eval_str("\"гой\" + \"да\"\n"),
+            Ok(Value::Text(ref text)) if &**text == "гойда"
+        ));
+
+        let error = session.eval("1 / 0\n").unwrap_err();
+        assert!(matches!(
+            error,
+            EvalError::Runtime(RuntimeError::DivisionByZero(_))
+        ));
+        assert!(error.to_string().starts_with("ОшибкаДеленияНаНоль: "));
+        assert!(matches!(session.eval("икс = \n"), Err(EvalError::Parse(_))));
+    }
+
+    #[test]
+    fn eval_calls_functions_declared_by_earlier_inputs() {
+        let mut session = Session::new();
+        session
+            .eval("функция удвоить(х) { вернуть х * 2 }\n")
+            .expect("input should run");
+        session.eval("значение = 21\n").expect("input should run");
+        assert!(matches!(
+            session.eval("удвоить(значение)\n"),
+            Ok(Value::Number(42))
+        ));
+    }
+
+    #[test]
+    fn optimized_inputs_read_globals_of_earlier_inputs_before_assigning_them() {
+        let mut session = Session::new();
+        session.enable_optimizations();
+        session.eval("шаг = 1\n").expect("input should run");
+        assert!(matches!(
+            session.eval("прежний = шаг\nшаг = 2\nпрежний * 10 + шаг\n"),
+            Ok(Value::Number(12))
+        ));
+    }
+
+    #[test]
+    fn reloaded_modules_replace_their_functions_and_keep_session_globals() {
+        let path = std::env::temp_dir().join(format!("goida_reload_{}.goida", std::process::id()));
+        std::fs::write(&path, "функция версия() { вернуть 1 }\n").unwrap();
+        let mut session = Session::new();
+        session
+            .eval(&format!(
+                "подключить \"{}\" как плагин\nсчетчик = 5\n",
+                path.display()
+            ))
+            .expect("module should import");
+        assert!(matches!(session.eval("версия()\n"), Ok(Value::Number(1))));
+
+        std::fs::write(&path, "функция версия() { вернуть 2 }\n").unwrap();
+        session
+            .reload_module("плагин")
+            .expect("module should reload");
+        assert!(matches!(
+            session.eval("плагин.версия() + версия() + счетчик\n"),
+            Ok(Value::Number(9))
+        ));
+        assert!(matches!(
+            session.reload_module("нет_такого"),
+            Err(RuntimeError::ImportError(_))
+        ));
+
+        std::fs::write(&path, "функция версия() { вернуть 3 }\n").unwrap();
+        assert!(matches!(
+            session.eval("Система.перезагрузить(\"плагин\")\nплагин.версия()\n"),
+            Ok(Value::Number(3))
+        ));
+        assert!(matches!(
+            session.eval("Система.перезагрузить(1)\n"),
+            Err(EvalError::Runtime(RuntimeError::TypeError(_)))
+        ));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn static_fields_initialize_in_order_and_report_cycles() {
+        let run = |source: &str| {
+            let mut session = Session::new();
+            let module = Parser::new(session.interner(), "statics", PathBuf::from("statics"))
+                .parse(source)
+                .expect("module should parse");
+            session.execute(module)
+        };
+
+        assert!(run(
+            "класс А {\n    публичный статичный х: число = Б.у + 1\n}\nкласс Б {\n    публичный статичный у: число = 41\n}\nпроверить(А.х == 42)\n",
+        )
+        .is_ok());
+        assert!(matches!(
+            run("класс А {\n    публичный статичный х: число = Б.у\n}\nкласс Б {\n    публичный статичный у: число = А.х\n}\n"),
+            Err(RuntimeError::InvalidOperation(ref error)) if error.message.contains("Циклическая инициализация")
+        ));
+    }
+
+    #[test]
+    fn integer_overflow_is_a_catchable_runtime_error() {
+        let mut session = Session::new();
+        session.eval("большое = 9223372036854775807\n").unwrap();
+        for source in [
+            "большое + 1\n",
+            "большое * 2\n",
+            "-большое - 2\n",
+            "-(-большое - 1)\n",
+        ] {
+            let error = session.eval(source).unwrap_err();
+            assert!(
+                matches!(error, EvalError::Runtime(RuntimeError::Overflow(_))),
+                "{}",
+                source
+            );
+            assert!(error.to_string().starts_with("ОшибкаПереполнения: "));
+        }
+        assert!(matches!(
+            session.eval("(-большое - 1) / -1\n"),
+            Err(EvalError::Runtime(RuntimeError::Overflow(_)))
+        ));
+        assert!(matches!(
+            session.eval(
+                "итог = 0\nпопробовать {\n    итог = большое + 1\n} перехватить (ОшибкаОперации как е) {\n    итог = -1\n}\nитог\n"
+            ),
+            Ok(Value::Number(-1))
+        ));
+    }
+
+    #[test]
+    fn host_functions_and_classes_are_callable_from_scripts() {
+        let mut session = Session::new();
+        session.register_builtin("удвоить", |_, arguments| {
+            match arguments.as_slice() {
+                [Value::Number(number)] => Ok(Value::Number(number * 2)),
+                _ => Err(RuntimeError::TypeError(ErrorData::new(
+                    Span::default(),
+                    "ожидалось число".to_string(),
+                ))),
+            }
+        });
+        session.register_class(
+            HostClass::new("Счётчик")
+                .constructor(|interpreter, arguments| {
+                    if let Some(Value::Object(object)) = arguments.first() {
+                        let field = interpreter.intern_string("значение");
+                        object.write(|object| object.field_values.insert(field, Value::Number(0)));
+                    }
+                    Ok(Value::Empty)
+                })
+                .method("прибавить", |interpreter, arguments| {
+                    let Some(Value::Object(object)) = arguments.first() else {
+                        return Ok(Value::Empty);
+                    };
+                    let field = interpreter.intern_string("значение");
+                    Ok(object.write(|object| {
+                        let value = match object.field_values.get(&field) {
+                            Some(Value::Number(number)) => Value::Number(number + 1),
+                            _ => Value::Number(1),
+                        };
+                        object.field_values.insert(field, value.clone());
+                        value
+                    }))
+                }),
+        );
+
+        assert!(matches!(
+            session.eval("удвоить(21)\n"),
+            Ok(Value::Number(42))
+        ));
+        assert!(matches!(
+            session.eval("с = новый Счётчик()\nс.прибавить()\nс.прибавить()\n"),
+            Ok(Value::Number(2))
+        ));
+        let error = session.eval("удвоить(\"два\")\n").unwrap_err();
+        assert!(matches!(
+            error,
+            EvalError::Runtime(RuntimeError::TypeError(ref data)) if data.location != Span::default()
+        ));
+    }
+
+    #[test]
+    fn seeded_sessions_repeat_random_sequences() {
+        let source = "с = список(1, 2, 3, 4, 5, 6, 7, 8)\nСлучай.перемешать(с)\nв_json(список(Случай.число(1, 1000), Случай.дробь(), с))\n";
+        let run = |seed: u64| {
+            Session::with_options(InterpreterOptions::default().with_seed(seed))
+                .eval(source)
+                .expect("random calls should run")
+                .to_string()
+        };
+
+        assert_eq!(run(7), run(7));
+        assert_ne!(run(7), run(8));
+        assert!(matches!(
+            Session::new().eval("Случай.выбрать(список())\n"),
+            Err(EvalError::Runtime(RuntimeError::InvalidOperation(_)))
+        ));
+    }
+
+    #[test]
+    fn http_requests_return_response_objects_and_respect_sandbox() {
+        use std::io::{BufRead, BufReader, Read, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                    length = value.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8(body).unwrap());
+            reader
+                .get_mut()
+                .write_all(b"HTTP/1.1 201 Created\r\nX-Goida: da\r\nContent-Length: 5\r\nConnection: close\r\n\r\nready")
+                .unwrap();
+            request
+        });
+
+        let mut session = Session::new();
+        let response = session
+            .eval(&format!(
+                "ответ = ХТТП.отправить(\"http://{address}/items\", словарь(\"имя\", \"гойда\"), словарь(\"X-Token\", \"секрет\"))\nв_json(список(ответ.статус, ответ.тело, ответ.заголовки[\"x-goida\"]))\n"
+            ))
+            .expect("request should succeed");
+        let request = server.join().unwrap();
+
+        assert_eq!(response.to_string(), r#"[201,"ready","da"]"#);
+        assert!(request.starts_with("POST /items HTTP/1.1"));
+        assert!(request
+            .to_lowercase()
+            .contains("content-type: application/json"));
+        assert!(request.contains(r#"{"имя":"гойда"}"#));
+        assert!(matches!(
+            Session::with_options(InterpreterOptions::sandboxed())
+                .eval(&format!("ХТТП.получить(\"http://{address}/\")\n")),
+            Err(EvalError::Runtime(RuntimeError::PermissionDenied(_)))
+        ));
+    }
+
+    #[test]
+    fn tcp_connection_exchanges_text_with_listener() {
+        let mut session = Session::new();
+        let reply = session
+            .eval(
+                "сервер = ТСП.слушать(\"127.0.0.1\", 0)\nклиент = ТСП.подключиться(\"127.0.0.1\", сервер.порт())\nгость = сервер.принять_клиента()\nклиент.отправить(\"привет\")\nпроверить(гость.принять() == \"привет\")\nгость.отправить(\"пока\")\nгость.закрыть()\nответ = клиент.принять()\nпроверить(клиент.принять() == \"\")\nответ\n",
+            )
+            .expect("tcp exchange should succeed");
+
+        assert_eq!(reply.to_string(), "пока");
+        assert!(matches!(
+            session.eval("гость.отправить(\"снова\")\n"),
+            Err(EvalError::Runtime(RuntimeError::InvalidOperation(_)))
+        ));
+        assert!(matches!(
+            Session::with_options(InterpreterOptions::sandboxed())
+                .eval("ТСП.слушать(\"127.0.0.1\", 0)\n"),
+            Err(EvalError::Runtime(RuntimeError::PermissionDenied(_)))
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn subprocesses_capture_or_stream_output() {
+        let mut session = Session::new();
+        let result = session
+            .eval("р = Система.выполнить(\"sh\", список(\"-c\", \"echo раз; echo два >&2; exit 3\"))\nв_json(р)\n")
+            .expect("command should run");
+        assert_eq!(
+            result.to_string(),
+            r#"{"вывод":"раз\n","код":3,"ошибки":"два\n"}"#
+        );
+
+        let lines = session
+            .eval("строки = список()\nкод = Система.выполнить_построчно(\"printf\", список(\"a\\nb\\n\"), (строка) => строки.добавить(строка))\nв_json(список(код, строки))\n")
+            .expect("command should stream");
+        assert_eq!(lines.to_string(), r#"[0,["a","b"]]"#);
+
+        assert!(matches!(
+            session.eval("Система.выполнить(\"несуществующая-команда\")\n"),
+            Err(EvalError::Runtime(RuntimeError::IOError(_)))
+        ));
+        assert!(matches!(
+            Session::with_options(InterpreterOptions::sandboxed().allow(Capability::Network))
+                .eval("Система.выполнить(\"true\")\n"),
+            Err(EvalError::Runtime(RuntimeError::PermissionDenied(_)))
+        ));
+    }
+
+    #[test]
+    fn memory_limit_stops_growing_lists_and_texts() {
+        let run = |source: &str| {
+            let mut session =
+                Session::with_options(InterpreterOptions::default().with_memory_limit(64 * 1024));
+            let module = Parser::new(session.interner(), "memory", PathBuf::from("memory"))
+                .parse(source)
+                .expect("module should parse");
+            session.execute(module)
+        };
+
+        assert!(matches!(
+            run("данные = список()\nпопробовать {\n    пока (истина) {\n        данные.добавить(\"строка\")\n    }\n} перехватить (Ошибка как сообщение) {\n}\n"),
+            Err(RuntimeError::LimitExceeded(ref error)) if error.message.contains("превышен лимит памяти")
+        ));
+        assert!(matches!(
+            run("текст = \"а\"\nпока (истина) {\n    текст = текст + текст\n}\n"),
+            Err(RuntimeError::LimitExceeded(_))
+        ));
+        assert!(run("данные = список()\nданные.добавить(4)\n").is_ok());
+    }
+
+    #[test]
+    fn memory_limit_is_checked_where_a_single_operation_allocates() {
+        const LIMIT: usize = 64 * 1024;
+        let run = |source: &str| {
+            let mut session =
+                Session::with_options(InterpreterOptions::default().with_memory_limit(LIMIT));
+            let module = Parser::new(session.interner(), "memory", PathBuf::from("memory"))
+                .parse(source)
+                .expect("module should parse");
+            session.execute(module)
+        };
+        // Каждое значение укладывается в лимит, а удвоенное — уже нет.
+        let items = LIMIT * 3 / 4 / std::mem::size_of::<Value>();
+        let text = LIMIT * 3 / 4;
+        let list = format!("данные = список(0) * {items}\n");
+        let line = format!("текст = \"а\" * {}\n", text / "а".len());
+
+        for tail in ["больше = данные + данные\n", "данные.расширить(данные)\n"]
+        {
+            assert!(
+                matches!(
+                    run(&(list.clone() + tail)),
+                    Err(RuntimeError::LimitExceeded(_))
+                ),
+                "{tail}"
+            );
+        }
+        for tail in [
+            "больше = текст + текст\n",
+            "больше = список(текст, текст).объединить(\"\")\n",
+        ] {
+            assert!(
+                matches!(
+                    run(&(line.clone() + tail)),
+                    Err(RuntimeError::LimitExceeded(_))
+                ),
+                "{tail}"
+            );
+        }
+        assert!(run(&list).is_ok());
+        assert!(run(&line).is_ok());
+        // Вместе значения уже не помещаются: считается вся занятая память.
+        assert!(matches!(
+            run(&(list + &line)),
+            Err(RuntimeError::LimitExceeded(_))
+        ));
+    }
+
+    #[test]
+    fn memory_limit_is_overshot_by_at_most_one_allocation() {
+        const LIMIT: usize = 1024 * 1024;
+        const CHUNK: usize = 300_000;
+        let mut session =
+            Session::with_options(InterpreterOptions::default().with_memory_limit(LIMIT));
+        let source = format!(
+            "п = список()\nдля (i = 0, i < 1000, i += 1) {{\n    п.добавить(\"x\" * {CHUNK})\n}}\n"
+        );
+        let module = Parser::new(session.interner(), "memory", PathBuf::from("memory"))
+            .parse(&source)
+            .expect("module should parse");
+
+        assert!(matches!(
+            session.execute(module),
+            Err(RuntimeError::LimitExceeded(_))
+        ));
+        let used = session.runtime().heap.approximate_size();
+        assert!(used > LIMIT - CHUNK, "{used}");
+        assert!(used <= LIMIT + CHUNK, "{used}");
+    }
+
+    #[test]
+    fn text_repeat_and_padding_respect_the_memory_limit() {
+        let run = |source: &str| {
+            let mut session =
+                Session::with_options(InterpreterOptions::default().with_memory_limit(64 * 1024));
+            let module = Parser::new(session.interner(), "memory", PathBuf::from("memory"))
+                .parse(source)
+                .expect("module should parse");
+            session.execute(module)
+        };
+
+        for source in [
+            "текст = \"ab\".повторить(100000)\n",
+            "текст = \"7\".дополнить_слева(100000, \"0\")\n",
+            "текст = \"7\".дополнить_справа(100000)\n",
+        ] {
+            assert!(
+                matches!(run(source), Err(RuntimeError::LimitExceeded(_))),
+                "{source}"
+            );
+        }
+        assert!(run("проверить(\"ab\".повторить(-2) == \"\")\nпроверить(\"7\".дополнить_слева(-5) == \"7\")\n").is_ok());
+    }
+
+    #[test]
+    fn huge_repetitions_fail_with_overflow_instead_of_aborting() {
+        let run = |source: &str| {
+            let mut session = Session::new();
+            let module = Parser::new(session.interner(), "repeat", PathBuf::from("repeat"))
+                .parse(source)
+                .expect("module should parse");
+            session.execute(module)
+        };
+
+        for source in [
+            "текст = \"ab\" * 9223372036854775807\n",
+            "текст = 4611686018427387904 * \"ab\"\n",
+            "данные = список(1, 2) * 9223372036854775807\n",
+        ] {
+            assert!(
+                matches!(run(source), Err(RuntimeError::Overflow(_))),
+                "{source}"
+            );
+        }
+        assert!(run("текст = \"\" * 9223372036854775807\nпроверить(текст == \"\")\n").is_ok());
+        assert!(run("проверить(\"ab\" * -3 == \"\")\nпроверить(\"ab\" * 2 == \"abab\")\n").is_ok());
+        assert!(run(
+            "попробовать {\n    текст = \"ab\" * 9223372036854775807\n} перехватить (ОшибкаПереполнения как сообщение) {\n    проверить(сообщение.длина() > 0)\n}\n"
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn profiling_counts_calls_and_counts_recursion_once_in_total_time() {
+        let mut session = Session::new();
+        session.enable_profiling();
+        let module = Parser::new(session.interner(), "profile", PathBuf::from("profile"))
+            .parse(
+                "функция фиб(н: число) -> число {\n    если (н < 2) {\n        вернуть н\n    }\n    вернуть фиб(н - 1) + фиб(н - 2)\n}\nрезультат = фиб(6)\nтип(результат)\n",
+            )
+            .expect("module should parse");
+        session.execute(module).expect("module should run");
+
+        let profile = session.profile();
+        let fib = profile
+            .iter()
+            .find(|entry| entry.name == "функция фиб")
+            .expect("user function should be profiled");
+        assert_eq!(fib.calls, 25);
+        assert!(fib.own <= fib.total);
+        assert!(profile
+            .iter()
+            .any(|entry| entry.name == "встроенная тип" && entry.calls == 1));
+    }
 }
@@ -25,6 +25,9 @@ impl PartialEq for RuntimeMethodType {
         match (self, other) {
             (RuntimeMethodType::User(a), RuntimeMethodType::User(b)) => a == b,
             (RuntimeMethodType::Native(a), RuntimeMethodType::Native(b)) => Arc::ptr_eq(&a.0, &b.0),
+            (RuntimeMethodType::Runtime(a), RuntimeMethodType::Runtime(b)) => {
+                std::ptr::fn_addr_eq(*a, *b)
+            }
             _ => false,
         }
     }
@@ -26,7 +26,8 @@ impl RuntimeMethodType {
     pub fn get_module(&self) -> Option<Symbol> {
         match self {
             RuntimeMethodType::User(func) => func.module,
-            RuntimeMethodType::Native(_) => None, // У нативных методов нет модуля в AST
+            // У нативных методов нет модуля в AST
+            RuntimeMethodType::Native(_) | RuntimeMethodType::Runtime(_) => None,
         }
     }
 }
@@ -1,12 +1,14 @@
 use crate::ast::prelude::{FunctionDefinition, Span};
-use crate::interpreter::prelude::{CallArgValue, RuntimeError, RuntimeMethodType, Value};
+use crate::interpreter::prelude::{
+    CallArgValue, RuntimeError, RuntimeFunction, RuntimeMethodType, Value,
+};
 use std::sync::Arc;
 use string_interner::DefaultSymbol as Symbol;
 
 pub trait InterpreterFunctions {
     fn call_function(
         &mut self,
-        function: Arc<FunctionDefinition>,
+        function: &RuntimeFunction,
         arguments: Vec<CallArgValue>,
         current_module_id: Symbol,
         span: Span,
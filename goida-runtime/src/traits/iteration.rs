@@ -0,0 +1,59 @@
+use crate::ast::prelude::Span;
+use crate::interpreter::prelude::{
+    Interpreter, RuntimeError, RuntimeGenerator, RuntimeMethodType, Value,
+};
+use crate::traits::prelude::InterpreterClasses;
+use string_interner::DefaultSymbol as Symbol;
+
+/// Method returning what `для ... из` walks instead of the object itself.
+pub const ITERATOR_METHOD: &str = "__итератор";
+/// Method producing the next value; returning `пустота` ends the iteration.
+pub const NEXT_METHOD: &str = "__следующий";
+
+/// Step-by-step iteration over a runtime value; `для ... из`, `...` and unpacking share it.
+pub trait ValueIterator {
+    /// The next value, or `None` once the sequence is exhausted.
+    fn next_value(
+        &mut self,
+        interpreter: &mut Interpreter,
+        span: Span,
+    ) -> Option<Result<Value, RuntimeError>>;
+}
+
+impl ValueIterator for std::vec::IntoIter<Value> {
+    fn next_value(&mut self, _: &mut Interpreter, _: Span) -> Option<Result<Value, RuntimeError>> {
+        self.next().map(Ok)
+    }
+}
+
+impl ValueIterator for RuntimeGenerator {
+    fn next_value(&mut self, _: &mut Interpreter, _: Span) -> Option<Result<Value, RuntimeError>> {
+        self.resume()
+    }
+}
+
+/// User object walked through its `__следующий` method.
+pub struct ObjectIterator {
+    pub object: Value,
+    pub next: RuntimeMethodType,
+    pub module: Symbol,
+}
+
+impl ValueIterator for ObjectIterator {
+    fn next_value(
+        &mut self,
+        interpreter: &mut Interpreter,
+        span: Span,
+    ) -> Option<Result<Value, RuntimeError>> {
+        match interpreter.call_method(
+            self.next.clone(),
+            Vec::new(),
+            self.object.clone(),
+            self.module,
+            span,
+        ) {
+            Ok(Value::Empty) => None,
+            result => Some(result),
+        }
+    }
+}
@@ -1,8 +1,9 @@
 pub mod builtins;
 pub mod classes;
 pub mod functions;
-pub(crate) mod json;
+pub mod iteration;
 pub mod module;
 pub mod prelude;
 pub mod runtime;
+mod serialization;
 pub mod value;
@@ -1,7 +1,7 @@
 use crate::ast::prelude::{AstArena, FunctionDefinition, StmtId};
 use crate::bytecode::{BytecodeModule, BytecodeSource};
 use crate::hir::{CallableSignature, HirModule, HirSource};
-use crate::interpreter::prelude::{CompiledModule, Module, SharedInterner, Value};
+use crate::interpreter::prelude::{CompiledModule, FastHashMap, Module, SharedInterner, Value};
 use crate::shared::SharedMut;
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -25,7 +25,7 @@ impl Module {
             },
             classes: HashMap::new(),
             modules: HashMap::new(),
-            globals: HashMap::new(),
+            globals: FastHashMap::default(),
             global_slots: Vec::new(),
         }
     }
@@ -107,7 +107,8 @@ impl HirSource for Module {
                         crate::interpreter::prelude::RuntimeMethodType::User(function) => {
                             Some(function.clone())
                         }
-                        crate::interpreter::prelude::RuntimeMethodType::Native(_) => None,
+                        crate::interpreter::prelude::RuntimeMethodType::Native(_)
+                        | crate::interpreter::prelude::RuntimeMethodType::Runtime(_) => None,
                     },
                 ));
                 if let Some(crate::interpreter::prelude::RuntimeMethodType::User(constructor)) =
@@ -1,4 +1,5 @@
 pub use super::classes::*;
 pub use super::functions::*;
+pub use super::iteration::*;
 pub use super::runtime::*;
 pub use super::value::*;
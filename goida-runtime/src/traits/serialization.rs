@@ -0,0 +1,365 @@
+use crate::interpreter::prelude::{DictKey, FastHashMap, Value};
+use crate::shared::SharedMut;
+use serde::de::{self, MapAccess, SeqAccess, Visitor};
+use serde::ser::{self, SerializeMap, SerializeSeq};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+impl Value {
+    /// Why the value has no data representation, or `None` if it has one.
+    fn unserializable(&self) -> Option<String> {
+        let what = match self {
+            Value::Float(value) if !value.is_finite() => {
+                return Some(format!("Нельзя сериализовать число '{}'", value))
+            }
+            Value::Empty
+            | Value::Boolean(_)
+            | Value::Number(_)
+            | Value::Float(_)
+            | Value::Text(_)
+            | Value::List(_)
+            | Value::Array(_)
+            | Value::Bytes(_)
+            | Value::Dict(_) => return None,
+            Value::Pointer(_) => "нативный указатель",
+            Value::Object(_) => "объект класса",
+            Value::Class(_) => "класс",
+            Value::Function(_) => "функцию",
+            Value::Builtin(_) => "встроенную функцию",
+            Value::Module(_) => "модуль",
+            Value::Thread(_) => "поток",
+            Value::Iterator(_) => "итератор",
+            Value::Generator(_) => "генератор",
+            Value::Mutex(_) => "мьютекс",
+            Value::RwLock(_) => "блокировку чтения-записи",
+            Value::NativeResource(_) => "нативный ресурс",
+            Value::NativeGlobal(_) => "нативную переменную",
+        };
+        Some(format!("Нельзя сериализовать {}", what))
+    }
+}
+
+/// Data values only: empty, booleans, numbers, texts, lists, arrays, bytes and dicts.
+/// A list or dict that contains itself fails instead of recursing forever.
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Tracked {
+            value: self,
+            path: &RefCell::new(HashSet::new()),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Value being serialized plus the containers currently open above it.
+struct Tracked<'a> {
+    value: &'a Value,
+    path: &'a RefCell<HashSet<usize>>,
+}
+
+impl Tracked<'_> {
+    fn serialize_items<S: Serializer>(
+        &self,
+        items: &[Value],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(items.len()))?;
+        for item in items {
+            seq.serialize_element(&self.nested(item))?;
+        }
+        seq.end()
+    }
+
+    fn nested<'b>(&'b self, value: &'b Value) -> Tracked<'b> {
+        Tracked {
+            value,
+            path: self.path,
+        }
+    }
+
+    /// Serializes a container unless it is already open higher up in the value.
+    fn container<S: Serializer>(
+        &self,
+        identity: usize,
+        serialize: impl FnOnce() -> Result<S::Ok, S::Error>,
+    ) -> Result<S::Ok, S::Error> {
+        if !self.path.borrow_mut().insert(identity) {
+            return Err(ser::Error::custom(
+                "Нельзя сериализовать значение, которое содержит само себя",
+            ));
+        }
+        let result = serialize();
+        self.path.borrow_mut().remove(&identity);
+        result
+    }
+}
+
+impl Serialize for Tracked<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if let Some(message) = self.value.unserializable() {
+            return Err(ser::Error::custom(message));
+        }
+        match self.value {
+            Value::Boolean(value) => serializer.serialize_bool(*value),
+            Value::Number(value) => serializer.serialize_i64(*value),
+            Value::Float(value) => serializer.serialize_f64(*value),
+            Value::Text(value) => serializer.serialize_str(value),
+            Value::List(items) => self.container::<S>(items.identity(), || {
+                items.read(|items| self.serialize_items(items, serializer))
+            }),
+            Value::Array(items) => self.serialize_items(items, serializer),
+            // Байты записываются списком чисел.
+            Value::Bytes(bytes) => serializer.collect_seq(bytes.iter()),
+            Value::Dict(items) => self.container::<S>(items.identity(), || {
+                items.read(|items| {
+                    // Ключи по порядку, чтобы вывод не зависел от хеширования.
+                    let mut entries = items.iter().collect::<Vec<_>>();
+                    entries.sort_by(|left, right| left.0.cmp(right.0));
+                    check_key_collisions(entries.iter().map(|(key, _)| *key))?;
+                    let mut map = serializer.serialize_map(Some(entries.len()))?;
+                    for (key, value) in entries {
+                        map.serialize_entry(key, &self.nested(value))?;
+                    }
+                    map.end()
+                })
+            }),
+            _ => serializer.serialize_unit(),
+        }
+    }
+}
+
+/// Fails when two keys of different types read the same as text, such as `1` and `"1"`:
+/// formats with string keys would write both under one name and lose one of the values.
+fn check_key_collisions<'a, E: ser::Error>(
+    keys: impl Iterator<Item = &'a DictKey>,
+) -> Result<(), E> {
+    let mut seen = HashMap::new();
+    for key in keys {
+        let text = match key {
+            DictKey::Number(value) => value.to_string(),
+            DictKey::Text(value) => value.to_string(),
+            DictKey::Boolean(value) => value.to_string(),
+        };
+        if let Some(previous) = seen.insert(text, key) {
+            return Err(E::custom(format!(
+                "Нельзя сериализовать словарь: ключи {} и {} совпадают как строки",
+                describe_key(previous),
+                describe_key(key)
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn describe_key(key: &DictKey) -> String {
+    match key {
+        DictKey::Text(value) => format!("\"{}\"", value),
+        other => other.to_string(),
+    }
+}
+
+/// Keys keep their type; JSON writes numbers and booleans as quoted strings.
+impl Serialize for DictKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            DictKey::Number(value) => serializer.serialize_i64(*value),
+            DictKey::Text(value) => serializer.serialize_str(value),
+            DictKey::Boolean(value) => serializer.serialize_bool(*value),
+        }
+    }
+}
+
+/// Sequences become lists and maps become dicts; integers that do not fit `i64` become floats.
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("значение Гойды")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Empty)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Empty)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+        Value::deserialize(deserializer)
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<Value, E> {
+        Ok(Value::Boolean(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Value, E> {
+        Ok(Value::Number(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Value, E> {
+        Ok(i64::try_from(value).map_or(Value::Float(value as f64), Value::Number))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Value, E> {
+        Ok(Value::Float(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Value, E> {
+        Ok(Value::Text(value.into()))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<Value, E> {
+        Ok(Value::Text(value.into()))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(Value::List(SharedMut::new(items)))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        let mut items =
+            FastHashMap::with_capacity_and_hasher(map.size_hint().unwrap_or(0), Default::default());
+        while let Some((key, value)) = map.next_entry::<DictKey, Value>()? {
+            items.insert(key, value);
+        }
+        Ok(Value::Dict(SharedMut::new(items)))
+    }
+}
+
+/// JSON keys are always strings; YAML and TOML keys may also be numbers or booleans.
+impl<'de> Deserialize<'de> for DictKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(DictKeyVisitor)
+    }
+}
+
+struct DictKeyVisitor;
+
+impl<'de> Visitor<'de> for DictKeyVisitor {
+    type Value = DictKey;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("ключ словаря: число, строку или логическое значение")
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<DictKey, E> {
+        Ok(DictKey::Boolean(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<DictKey, E> {
+        Ok(DictKey::Number(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<DictKey, E> {
+        Ok(i64::try_from(value)
+            .map_or_else(|_| DictKey::Text(value.to_string().into()), DictKey::Number))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<DictKey, E> {
+        Ok(DictKey::Text(value.into()))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<DictKey, E> {
+        Ok(DictKey::Text(value.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interpreter::prelude::{DictKey, FastHashMap, Value};
+    use crate::shared::SharedMut;
+
+    #[test]
+    fn data_values_round_trip_through_serde() {
+        let value = Value::Dict(SharedMut::new(FastHashMap::from_iter([
+            (
+                "список".into(),
+                Value::List(SharedMut::new(vec![
+                    Value::Number(1),
+                    Value::Float(2.5),
+                    Value::Empty,
+                ])),
+            ),
+            ("имя".into(), Value::Text("гойда".into())),
+            ("флаг".into(), Value::Boolean(true)),
+        ])));
+
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"имя":"гойда","список":[1,2.5,null],"флаг":true}"#);
+        let back: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&back).unwrap(), json);
+        assert!(matches!(
+            serde_json::from_str::<Value>("18446744073709551615").unwrap(),
+            Value::Float(_)
+        ));
+    }
+
+    #[test]
+    fn number_and_boolean_keys_become_json_strings() {
+        let value = Value::Dict(SharedMut::new(FastHashMap::from_iter([
+            (DictKey::Number(1), Value::Text("один".into())),
+            (DictKey::Boolean(true), Value::Text("да".into())),
+        ])));
+
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"1":"один","true":"да"}"#);
+    }
+
+    #[test]
+    fn self_containing_values_fail_to_serialize() {
+        let list = SharedMut::new(vec![Value::Number(1)]);
+        list.write(|items| items.push(Value::List(list.clone())));
+        let error = serde_json::to_string(&Value::List(list.clone())).unwrap_err();
+        assert!(error.to_string().contains("содержит само себя"), "{error}");
+
+        let dict = SharedMut::new(FastHashMap::default());
+        dict.write(|items| items.insert("я".into(), Value::Dict(dict.clone())));
+        assert!(serde_json::to_string(&Value::Dict(dict.clone())).is_err());
+
+        // Один и тот же список дважды рядом — не цикл.
+        let shared = Value::List(SharedMut::new(vec![Value::Number(2)]));
+        let twice = Value::List(SharedMut::new(vec![shared.clone(), shared]));
+        assert_eq!(serde_json::to_string(&twice).unwrap(), "[[2],[2]]");
+
+        list.write(|items| items.clear());
+        dict.write(|items| items.clear());
+    }
+
+    #[test]
+    fn keys_that_collide_as_text_fail_to_serialize() {
+        let value = Value::Dict(SharedMut::new(FastHashMap::from_iter([
+            (DictKey::Number(1), Value::Text("a".into())),
+            ("1".into(), Value::Text("b".into())),
+        ])));
+        let error = serde_json::to_string(&value).unwrap_err();
+        assert!(error.to_string().contains("1 и \"1\""), "{error}");
+
+        let value = Value::Dict(SharedMut::new(FastHashMap::from_iter([
+            (DictKey::Boolean(true), Value::Number(1)),
+            ("true".into(), Value::Number(2)),
+        ])));
+        assert!(serde_json::to_string(&value).is_err());
+    }
+
+    #[test]
+    fn non_data_values_fail_to_serialize() {
+        let error = serde_json::to_string(&Value::Pointer(0)).unwrap_err();
+        assert!(error.to_string().contains("нативный указатель"));
+        assert!(serde_json::to_string(&Value::Float(f64::NAN)).is_err());
+    }
+}
@@ -1,5 +1,7 @@
 use crate::ast::prelude::{ErrorData, Span};
-use crate::interpreter::prelude::{ClassInstance, Interpreter, RuntimeError, RuntimeFieldData};
+use crate::interpreter::prelude::{
+    ClassInstance, DictKey, Interpreter, RuntimeError, RuntimeFieldData,
+};
 use crate::interpreter::structs::Value;
 use crate::shared::SharedMut;
 use crate::traits::runtime::CoreOperations;
@@ -39,6 +41,8 @@ pub trait ValueOperations {
         right: Value,
         span: Span,
     ) -> Result<Value, RuntimeError>;
+    /// `==` of the language: identity for objects, except values compared by content.
+    fn values_equal(&self, left: &Value, right: &Value) -> bool;
 }
 
 impl Value {
@@ -56,9 +60,11 @@ impl Value {
             Value::Module(_) => true,
             Value::List(list) => !list.read(|l| l.is_empty()),
             Value::Array(array) => !array.is_empty(),
+            Value::Bytes(bytes) => !bytes.is_empty(),
             Value::Dict(dict) => !dict.read(|d| d.is_empty()),
             Value::Iterator(iterator) => !iterator.source.is_empty(),
             Value::Thread(_) => true,
+            Value::Generator(_) => true,
             Value::Mutex(_) => true,
             Value::RwLock(_) => true,
             Value::NativeResource(_) => true,
@@ -81,7 +87,7 @@ impl Value {
         }
     }
 
-    pub fn as_str(&self) -> Option<&String> {
+    pub fn as_str(&self) -> Option<&str> {
         if let Value::Text(s) = self {
             Some(s)
         } else {
@@ -138,9 +144,61 @@ impl Value {
 
         Ok(final_idx)
     }
+
+    /// Bounds of `срез(от, до)` over `len` items: negative values count from the end,
+    /// out-of-range values are clamped and `пустота` means "from the start"/"to the end".
+    pub fn slice_range(
+        len: usize,
+        start: Option<&Value>,
+        end: Option<&Value>,
+        span: Span,
+    ) -> Result<std::ops::Range<usize>, RuntimeError> {
+        let len = len as i64;
+        let bound = |value: Option<&Value>, default: i64| match value {
+            None | Some(Value::Empty) => Ok(default),
+            Some(Value::Number(n)) if *n < 0 => Ok((len + n).max(0)),
+            Some(Value::Number(n)) => Ok((*n).min(len)),
+            Some(_) => bail_runtime!(TypeError, span, "Границы среза должны быть числами"),
+        };
+        let start = bound(start, 0)? as usize;
+        let end = bound(end, len)? as usize;
+        Ok(start..end.max(start))
+    }
 }
 
 impl Interpreter {
+    /// Language-level type name of a value, as returned by `тип`.
+    pub fn type_name(&self, value: &Value) -> String {
+        let name = |symbol| {
+            self.resolve_symbol(symbol)
+                .unwrap_or_else(|| "неизвестно".into())
+        };
+        match value {
+            Value::Number(_) => "число".into(),
+            Value::Float(_) => "дробь".into(),
+            Value::Pointer(_) => "указатель".into(),
+            Value::Text(_) => "строка".into(),
+            Value::Boolean(_) => "логический".into(),
+            Value::Object(obj) => format!("объект \"{}\"", name(obj.read(|i| i.class_name))),
+            Value::Class(cls) => format!("класс \"{}\"", name(cls.read(|i| i.name))),
+            Value::Function(function) => format!("функция \"{}\"", name(function.name)),
+            Value::Builtin(_) => "встроенная функция".into(),
+            Value::Module(module) => format!("модуль \"{}\"", name(*module)),
+            Value::List(_) => "список".into(),
+            Value::Array(_) => "массив".into(),
+            Value::Bytes(_) => "байты".into(),
+            Value::Dict(_) => "словарь".into(),
+            Value::Iterator(_) => "итератор".into(),
+            Value::Generator(_) => "генератор".into(),
+            Value::Thread(_) => "Поток".into(),
+            Value::Mutex(_) => "Мьютекс".into(),
+            Value::RwLock(_) => "БлокировкаЧтенияЗаписи".into(),
+            Value::NativeResource(_) => "ресурс".into(),
+            Value::NativeGlobal(_) => "нативная переменная".into(),
+            Value::Empty => "пустота".into(),
+        }
+    }
+
     /// Formats a runtime value using names from this interpreter's interner.
     pub fn format_value(&self, value: &Value) -> String {
         self.format_value_inner(value, &mut HashSet::new())
@@ -211,7 +269,11 @@ impl Interpreter {
                             pairs
                                 .into_iter()
                                 .map(|(key, value)| {
-                                    format!("\"{}\": {}", key, self.format_value_inner(value, path))
+                                    format!(
+                                        "{}: {}",
+                                        key.container_repr(),
+                                        self.format_value_inner(value, path)
+                                    )
                                 })
                                 .collect::<Vec<_>>()
                                 .join(", ")
@@ -312,15 +374,26 @@ impl Value {
                             if i > 0 {
                                 write!(f, ", ")?;
                             }
-                            write!(f, "\"{}\": ", k)?;
+                            write!(f, "{}: ", k.container_repr())?;
                             v.fmt_inner(f, path)?;
                         }
                         write!(f, "}}")
                     })
                 })
             }
+            Value::Bytes(bytes) => {
+                write!(f, "байты[")?;
+                for (i, byte) in bytes.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", byte)?;
+                }
+                write!(f, "]")
+            }
             Value::Iterator(iterator) => write!(f, "<Итератор {}>", iterator.source.len()),
             Value::Thread(thread) => write!(f, "<Поток {:p}>", thread),
+            Value::Generator(generator) => write!(f, "<Генератор {:p}>", generator.channel),
             Value::Mutex(mutex) => write!(f, "<Мьютекс {:p}>", mutex),
             Value::RwLock(rwlock) => write!(f, "<БлокировкаЧтенияЗаписи {:p}>", rwlock),
             Value::NativeResource(resource) => write!(f, "<Ресурс {:p}>", resource),
@@ -403,9 +476,10 @@ impl TryFrom<Value> for bool {
             Value::Text(s) => Ok(!s.is_empty()),
             Value::List(list) => Ok(!list.read(|l| l.is_empty())),
             Value::Array(array) => Ok(!array.is_empty()),
+            Value::Bytes(bytes) => Ok(!bytes.is_empty()),
             Value::Dict(dict) => Ok(!dict.read(|d| d.is_empty())),
             Value::Iterator(iterator) => Ok(!iterator.source.is_empty()),
-            Value::Thread(_) | Value::Mutex(_) | Value::RwLock(_) => Ok(true),
+            Value::Thread(_) | Value::Generator(_) | Value::Mutex(_) | Value::RwLock(_) => Ok(true),
             Value::Object(_)
             | Value::Class(_)
             | Value::Function(_)
@@ -417,8 +491,17 @@ impl TryFrom<Value> for bool {
     }
 }
 
+/// Lists, arrays and dicts compare by contents; objects, functions and other handles by identity.
 impl PartialEq for Value {
     fn eq(&self, other: &Self) -> bool {
+        self.eq_inner(other, &mut HashSet::new())
+    }
+}
+
+impl Value {
+    /// Structural equality; `visiting` holds container pairs already being compared,
+    /// so cyclic collections are treated as equal instead of recursing forever.
+    fn eq_inner(&self, other: &Self, visiting: &mut HashSet<(FormatNode, FormatNode)>) -> bool {
         match (self, other) {
             (Value::Number(a), Value::Number(b)) => a == b,
             (Value::Float(a), Value::Float(b)) => a == b,
@@ -430,13 +513,56 @@ impl PartialEq for Value {
             (Value::Object(a), Value::Object(b)) => a.ptr_eq(b),
             (Value::Function(a), Value::Function(b)) => Arc::ptr_eq(a, b),
             (Value::Module(a), Value::Module(b)) => a == b,
-            (Value::List(a), Value::List(b)) => a.ptr_eq(b),
-            (Value::Array(a), Value::Array(b)) => Arc::ptr_eq(a, b),
-            (Value::Dict(a), Value::Dict(b)) => a.ptr_eq(b),
+            (Value::List(a), Value::List(b)) => {
+                a.ptr_eq(b)
+                    || eq_containers(
+                        (
+                            FormatNode::List(a.identity()),
+                            FormatNode::List(b.identity()),
+                        ),
+                        visiting,
+                        |visiting| a.read(|left| b.read(|right| eq_items(left, right, visiting))),
+                    )
+            }
+            (Value::Array(a), Value::Array(b)) => {
+                Arc::ptr_eq(a, b)
+                    || eq_containers(
+                        (
+                            FormatNode::Array(Arc::as_ptr(a) as usize),
+                            FormatNode::Array(Arc::as_ptr(b) as usize),
+                        ),
+                        visiting,
+                        |visiting| eq_items(a, b, visiting),
+                    )
+            }
+            (Value::Bytes(a), Value::Bytes(b)) => a == b,
+            (Value::Dict(a), Value::Dict(b)) => {
+                a.ptr_eq(b)
+                    || eq_containers(
+                        (
+                            FormatNode::Dict(a.identity()),
+                            FormatNode::Dict(b.identity()),
+                        ),
+                        visiting,
+                        |visiting| {
+                            a.read(|left| {
+                                b.read(|right| {
+                                    left.len() == right.len()
+                                        && left.iter().all(|(key, value)| {
+                                            right.get(key).is_some_and(|other| {
+                                                value.eq_inner(other, visiting)
+                                            })
+                                        })
+                                })
+                            })
+                        },
+                    )
+            }
             (Value::Iterator(a), Value::Iterator(b)) => {
                 Arc::ptr_eq(&a.source, &b.source) && Arc::ptr_eq(&a.steps, &b.steps)
             }
             (Value::Thread(a), Value::Thread(b)) => Arc::ptr_eq(&a.handle, &b.handle),
+            (Value::Generator(a), Value::Generator(b)) => Arc::ptr_eq(&a.channel, &b.channel),
             (Value::Mutex(a), Value::Mutex(b)) => Arc::ptr_eq(&a.value, &b.value),
             (Value::RwLock(a), Value::RwLock(b)) => Arc::ptr_eq(&a.value, &b.value),
             (Value::NativeGlobal(a), Value::NativeGlobal(b)) => Arc::ptr_eq(a, b),
@@ -446,6 +572,85 @@ impl PartialEq for Value {
     }
 }
 
+fn eq_items(
+    left: &[Value],
+    right: &[Value],
+    visiting: &mut HashSet<(FormatNode, FormatNode)>,
+) -> bool {
+    left.len() == right.len()
+        && left
+            .iter()
+            .zip(right)
+            .all(|(left, right)| left.eq_inner(right, visiting))
+}
+
+fn eq_containers(
+    pair: (FormatNode, FormatNode),
+    visiting: &mut HashSet<(FormatNode, FormatNode)>,
+    compare: impl FnOnce(&mut HashSet<(FormatNode, FormatNode)>) -> bool,
+) -> bool {
+    if !visiting.insert(pair) {
+        return true;
+    }
+    let equal = compare(visiting);
+    visiting.remove(&pair);
+    equal
+}
+
+impl DictKey {
+    /// Key for `словарь[значение]`; only numbers, strings and booleans can be keys.
+    pub fn from_value(value: &Value, span: Span) -> Result<Self, RuntimeError> {
+        match value {
+            Value::Number(n) => Ok(DictKey::Number(*n)),
+            Value::Text(text) => Ok(DictKey::Text(text.clone())),
+            Value::Boolean(b) => Ok(DictKey::Boolean(*b)),
+            _ => bail_runtime!(
+                TypeError,
+                span,
+                "Ключом словаря может быть только число, строка или логическое значение"
+            ),
+        }
+    }
+
+    pub fn to_value(&self) -> Value {
+        match self {
+            DictKey::Number(n) => Value::Number(*n),
+            DictKey::Text(text) => Value::Text(text.clone()),
+            DictKey::Boolean(b) => Value::Boolean(*b),
+        }
+    }
+
+    /// Key as it appears inside a printed dictionary: strings are quoted.
+    fn container_repr(&self) -> String {
+        match self {
+            DictKey::Text(text) => format!("\"{}\"", text),
+            key => key.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for DictKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DictKey::Number(n) => write!(f, "{}", n),
+            DictKey::Text(text) => write!(f, "{}", text),
+            DictKey::Boolean(b) => write!(f, "{}", if *b { "истина" } else { "ложь" }),
+        }
+    }
+}
+
+impl From<String> for DictKey {
+    fn from(text: String) -> Self {
+        DictKey::Text(text.into())
+    }
+}
+
+impl From<&str> for DictKey {
+    fn from(text: &str) -> Self {
+        DictKey::Text(text.into())
+    }
+}
+
 impl From<SharedMut<Value>> for RuntimeFieldData {
     fn from(lock: SharedMut<Value>) -> Self {
         RuntimeFieldData::Value(lock)
@@ -455,7 +660,7 @@ impl From<SharedMut<Value>> for RuntimeFieldData {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::interpreter::prelude::Interpreter;
+    use crate::interpreter::prelude::{FastHashMap, Interpreter};
     use crate::traits::runtime::CoreOperations;
 
     #[test]
@@ -470,8 +675,8 @@ mod tests {
 
     #[test]
     fn display_formats_mutually_referencing_dicts_without_recursing_forever() {
-        let left = Value::Dict(SharedMut::new(std::collections::HashMap::new()));
-        let right = Value::Dict(SharedMut::new(std::collections::HashMap::new()));
+        let left = Value::Dict(SharedMut::new(FastHashMap::default()));
+        let right = Value::Dict(SharedMut::new(FastHashMap::default()));
         let Value::Dict(left_dict) = &left else {
             unreachable!()
         };
@@ -483,4 +688,29 @@ mod tests {
 
         assert_eq!(left.to_string(), "{\"right\": {\"left\": <cycle>}}");
     }
+
+    #[test]
+    fn collections_compare_by_contents_even_when_cyclic() {
+        let list = |items: Vec<Value>| Value::List(SharedMut::new(items));
+        assert_eq!(
+            list(vec![Value::Number(1), list(vec![Value::Text("а".into())])]),
+            list(vec![Value::Float(1.0), list(vec![Value::Text("а".into())])])
+        );
+        assert_ne!(
+            list(vec![Value::Number(1)]),
+            list(vec![Value::Number(1), Value::Empty])
+        );
+        assert_ne!(
+            Value::Array(Arc::new(vec![Value::Number(1)])),
+            list(vec![Value::Number(1)])
+        );
+
+        let cyclic = || {
+            let items = SharedMut::new(vec![Value::Number(1)]);
+            let value = Value::List(items.clone());
+            items.write(|items| items.push(value.clone()));
+            value
+        };
+        assert_eq!(cyclic(), cyclic());
+    }
 }
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::sync::{Arc, PoisonError};
 use std::thread;
 use goida_hir::MethodResolution;
 use goida_model::SharedMut;
@@ -10,7 +10,13 @@ impl<'a> Vm<'a> {
         let mut registers = vec![Value::Empty; chunk.register_count as usize];
         let mut ip = 0usize;
         while ip < chunk.code.len() {
+            if self.interpreter.debugger.is_some() {
+                self.debug_statement(chunk, ip);
+            }
             let span = chunk.spans.get(ip).copied().unwrap_or_default();
+            if let Some(limits) = &self.interpreter.limits {
+                limits.tick(&self.interpreter.heap, span)?;
+            }
             let instruction = &chunk.code[ip];
             ip += 1;
             match instruction {
@@ -19,7 +25,7 @@ impl<'a> Vm<'a> {
                         LiteralValue::Number(value) => Value::Number(*value),
                         LiteralValue::Float(value) => Value::Float(*value),
                         LiteralValue::Text(value) => {
-                            Value::Text(self.interpreter.resolve_symbol(*value).unwrap_or_default())
+                            Value::Text(self.interpreter.resolve_symbol(*value).unwrap_or_default().into())
                         }
                         LiteralValue::Boolean(value) => Value::Boolean(*value),
                         LiteralValue::Unit => Value::Empty,
@@ -46,7 +52,20 @@ impl<'a> Vm<'a> {
                 Instruction::Unary { dst, op, operand } => {
                     let value = Self::get(&registers, *operand);
                     let value = match (op, value) {
-                        (UnaryOperator::Negative, Value::Number(value)) => Value::Number(-value),
+                        (UnaryOperator::Negative, Value::Number(value)) => {
+                            match value.checked_neg() {
+                                Some(value) => Value::Number(value),
+                                None => {
+                                    return bail_runtime!(
+                                        Overflow,
+                                        span,
+                                        "Результат -({}) не помещается в число",
+                                        value
+                                    )
+                                }
+                            }
+                        }
+                        (UnaryOperator::Negative, Value::Float(value)) => Value::Float(-value),
                         (UnaryOperator::Not, value) => Value::Boolean(!value.is_truthy()),
                         _ => {
                             return bail_runtime!(
@@ -72,12 +91,29 @@ impl<'a> Vm<'a> {
                     )?;
                     Self::set(&mut registers, *dst, value);
                 }
-                Instruction::ToBoolean { dst, source } => {
-                    let value = Value::Boolean(Self::get(&registers, *source).is_truthy());
+                Instruction::Move { dst, source } => {
+                    let value = Self::get(&registers, *source);
                     Self::set(&mut registers, *dst, value);
                 }
+                Instruction::Unpack { dsts, source } => {
+                    let values = self
+                        .interpreter
+                        .iterable_values(Self::get(&registers, *source), span)?;
+                    if values.len() != dsts.len() {
+                        return bail_runtime!(
+                            InvalidOperation,
+                            span,
+                            "Cannot unpack {} values into {} targets",
+                            values.len(),
+                            dsts.len()
+                        );
+                    }
+                    for (dst, value) in dsts.iter().zip(values) {
+                        Self::set(&mut registers, *dst, value);
+                    }
+                }
                 Instruction::CallDirect { dst, name, args } => {
-                    let args = Self::args(&registers, args);
+                    let args = self.args(&registers, args, span)?;
                     let value =
                         self.interpreter
                             .call_function_by_name(*name, args, self.module, span)?;
@@ -88,11 +124,11 @@ impl<'a> Vm<'a> {
                     callable,
                     args,
                 } => {
-                    let args = Self::args(&registers, args);
+                    let args = self.args(&registers, args, span)?;
                     let value = match Self::get(&registers, *callable) {
                         Value::Function(function) => {
                             self.interpreter
-                                .call_function(function, args, self.module, span)?
+                                .call_function(&function, args, self.module, span)?
                         }
                         Value::Builtin(function) => function(self.interpreter, args, span)?,
                         _ => return bail_runtime!(InvalidOperation, span, "Value is not callable"),
@@ -107,18 +143,34 @@ impl<'a> Vm<'a> {
                     )?;
                     Self::set(&mut registers, *dst, value);
                 }
+                Instruction::ReadSlice {
+                    dst,
+                    object,
+                    start,
+                    end,
+                } => {
+                    let value = self.read_slice(
+                        Self::get(&registers, *object),
+                        start.map(|start| Self::get(&registers, start)),
+                        end.map(|end| Self::get(&registers, end)),
+                        span,
+                    )?;
+                    Self::set(&mut registers, *dst, value);
+                }
                 Instruction::ReadProperty {
                     dst,
                     object,
                     property,
                     receiver_is_this,
                     receiver_name,
+                    cache,
                 } => {
                     let value = self.read_property(
                         Ok(Self::get(&registers, *object)),
                         *property,
                         *receiver_is_this,
                         *receiver_name,
+                        cache,
                         span,
                     )?;
                     Self::set(&mut registers, *dst, value);
@@ -129,18 +181,20 @@ impl<'a> Vm<'a> {
                     resolution,
                     args,
                     receiver_is_this,
+                    cache,
                 } => {
                     let method = match resolution {
                         MethodResolution::Static(method) | MethodResolution::Dynamic(method) => {
                             *method
                         }
                     };
-                    let args = Self::args(&registers, args);
+                    let args = self.args(&registers, args, span)?;
                     let value = self.call_method(
                         Self::get(&registers, *object),
                         method,
                         args,
                         *receiver_is_this,
+                        cache,
                         span,
                     )?;
                     Self::set(&mut registers, *dst, value);
@@ -150,7 +204,7 @@ impl<'a> Vm<'a> {
                     class_name,
                     args,
                 } => {
-                    let args = Self::args(&registers, args);
+                    let args = self.args(&registers, args, span)?;
                     let (class, module) = self.interpreter.resolve_class_for_creation(
                         *class_name,
                         self.module,
@@ -161,11 +215,33 @@ impl<'a> Vm<'a> {
                         .instantiate_class(class, module, args, span)?;
                     Self::set(&mut registers, *dst, value);
                 }
-                Instruction::MakeLambda { dst, function } => {
+                Instruction::CallSuper {
+                    dst,
+                    class,
+                    receiver,
+                    method,
+                    args,
+                } => {
+                    let args = self.args(&registers, args, span)?;
+                    let value = self.call_super(
+                        Self::get(&registers, *receiver),
+                        *class,
+                        *method,
+                        args,
+                        span,
+                    )?;
+                    Self::set(&mut registers, *dst, value);
+                }
+                Instruction::MakeLambda {
+                    dst,
+                    function,
+                    captures,
+                } => {
                     let mut function = function.clone();
-                    function.name = self.interpreter.intern_string("<lambda>");
+                    function.name = self.interpreter.symbols.lambda;
                     function.module = Some(self.module);
-                    Self::set(&mut registers, *dst, Value::Function(Arc::new(function)));
+                    let function = self.interpreter.make_function(function, captures);
+                    Self::set(&mut registers, *dst, Value::Function(function));
                 }
                 Instruction::InvalidThis { .. } => {
                     return bail_runtime!(
@@ -240,26 +316,68 @@ impl<'a> Vm<'a> {
                 }
                 Instruction::ForEach {
                     variable,
+                    binding,
                     iterable,
                     body,
                 } => {
-                    let values = self
+                    let mut values = self
                         .interpreter
-                        .iterable_values(Self::get(&registers, *iterable), span)?;
-                    let module = self.module;
-                    self.interpreter.scoped_child_environment(
-                        |_| {},
-                        |interpreter| {
-                            for value in values {
-                                interpreter
-                                    .environment
-                                    .write(|environment| environment.define(*variable, value));
-                                Vm::new(interpreter, module).run(body)?;
-                            }
-                            Ok(())
-                        },
-                    )?;
+                        .iterate(Self::get(&registers, *iterable), span)?;
+                    // Inside a function the loop shares the function's slots, so the body
+                    // sees (and updates) its locals instead of running in a fresh VM.
+                    if let Binding::LocalSlot(slot) = binding {
+                        while let Some(value) = values.next_value(self.interpreter, span) {
+                            self.set_local(*slot as usize, value?);
+                            self.run_chunk(body)?;
+                        }
+                    } else {
+                        let module = self.module;
+                        self.interpreter.scoped_child_environment(
+                            |_| {},
+                            |interpreter| {
+                                while let Some(value) = values.next_value(interpreter, span) {
+                                    let value = value?;
+                                    interpreter.environment.write(|environment| {
+                                        environment.define(*variable, value)
+                                    });
+                                    Vm::new(interpreter, module).run(body)?;
+                                }
+                                Ok(())
+                            },
+                        )?;
+                    }
+                }
+                Instruction::With {
+                    variable,
+                    binding,
+                    value,
+                    body,
+                } => {
+                    let resource = Self::get(&registers, *value);
+                    let (close, close_module) =
+                        self.interpreter.resource_closer(&resource, span)?;
+                    let result = if let Binding::LocalSlot(slot) = binding {
+                        self.set_local(*slot as usize, resource.clone());
+                        self.run_chunk(body)
+                    } else {
+                        let module = self.module;
+                        self.interpreter.scoped_child_environment(
+                            |environment| environment.define(*variable, resource.clone()),
+                            |interpreter| Vm::new(interpreter, module).run(body),
+                        )
+                    };
+                    // The resource is closed even when the body fails or returns early.
+                    let closed = self.interpreter.call_method(
+                        close,
+                        Vec::new(),
+                        resource,
+                        close_module,
+                        span,
+                    );
+                    result?;
+                    closed?;
                 }
+                Instruction::Defer(body) => self.deferred.push(body.clone()),
                 Instruction::Thread(body) => {
                     let mut interpreter = self.interpreter.fork_for_thread();
                     let module = self.module;
@@ -270,7 +388,8 @@ impl<'a> Vm<'a> {
                             result => result,
                         };
                         result?;
-                        interpreter.join_background_threads(module, span)
+                        interpreter.join_background_threads(module, span)?;
+                        Ok(Value::Empty)
                     });
                     self.interpreter
                         .background_threads
@@ -278,7 +397,9 @@ impl<'a> Vm<'a> {
                 }
                 Instruction::Try { body, handlers } => match self.run_chunk(body) {
                     Ok(()) => {}
-                    Err(error @ RuntimeError::Return(..)) => return Err(error),
+                    Err(error @ (RuntimeError::Return(..) | RuntimeError::LimitExceeded(_))) => {
+                        return Err(error)
+                    }
                     Err(error) => {
                         let error_class = error.error_class_name();
                         let error_message = error.error_message();
@@ -296,7 +417,7 @@ impl<'a> Vm<'a> {
                                     |environment| {
                                         if let Some(name) = handler.error_text {
                                             environment
-                                                .define(name, Value::Text(error_message.clone()));
+                                                .define(name, Value::Text(error_message.clone().into()));
                                         }
                                     },
                                     |interpreter| Vm::new(interpreter, module).run(&handler.body),
@@ -332,11 +453,31 @@ impl<'a> Vm<'a> {
                         .unwrap_or(Value::Empty);
                     return bail_runtime!(                         Return,                         span,                         "{}",                         self.interpreter.format_value(&value) => value                     );
                 }
-                Instruction::DefineFunction(function) => {
-                    self.interpreter.environment.write(|environment| {
-                        environment
-                            .define(function.name, Value::Function(Arc::new(function.clone())));
-                    });
+                Instruction::Yield(value) => {
+                    let Some(sink) = &self.interpreter.generator else {
+                        return bail_runtime!(
+                            InvalidOperation,
+                            span,
+                            "'yield' is only allowed inside a function"
+                        );
+                    };
+                    let sink = sink.lock().unwrap_or_else(PoisonError::into_inner);
+                    // Nobody waits for more values: the generator was dropped, so the body ends.
+                    if sink.values.send(Ok(Self::get(&registers, *value))).is_err()
+                        || sink.resume.recv().is_err()
+                    {
+                        return Err(RuntimeError::Return(
+                            ErrorData::new(span, String::new()),
+                            Value::Empty,
+                        ));
+                    }
+                }
+                Instruction::DefineFunction { function, captures } => {
+                    let value =
+                        Value::Function(self.interpreter.make_function(function.clone(), captures));
+                    self.interpreter
+                        .environment
+                        .write(|environment| environment.define(function.name, value));
                 }
                 Instruction::LoadNativeLibrary(definition) => self
                     .interpreter
@@ -1,13 +1,15 @@
 use crate::ast::prelude::{BinaryOperator, ErrorData, Span, Visibility};
-use crate::bytecode::{Chunk, Instruction, Register, RegisterArg};
+use crate::bytecode::{Chunk, InlineCache, Instruction, Register, RegisterArg};
 use crate::hir::Binding;
 use crate::interpreter::prelude::{
-    CallArgValue, Interpreter, RuntimeError, RuntimeFieldData, Value,
+    CallArgValue, DictKey, Interpreter, RuntimeError, RuntimeFieldData, RuntimeMethodType, Value,
 };
 use crate::traits::prelude::{
     CoreOperations, InterpreterClasses, InterpreterFunctions, ValueOperations,
 };
 use crate::{bail_runtime, runtime_error};
+use goida_model::WeakSharedMut;
+use std::collections::HashMap;
 use string_interner::DefaultSymbol as Symbol;
 
 #[derive(Default)]
@@ -35,12 +37,30 @@ impl DenseSlotSet {
     }
 }
 
+/// Method a `CallMethod` site found in the class of its last receiver. The
+/// class is held weakly so that caches never keep classes alive.
+struct CachedMethod {
+    class: WeakSharedMut<RuntimeClassDefinition>,
+    visibility: Visibility,
+    is_static: bool,
+    method: RuntimeMethodType,
+}
+
+/// How the class of the last object read at a `ReadProperty` site declares the
+/// property; `None` when only the instance has it.
+struct CachedField {
+    class: WeakSharedMut<RuntimeClassDefinition>,
+    declared: Option<Visibility>,
+}
+
 pub struct Vm<'a> {
     interpreter: &'a mut Interpreter,
     module: Symbol,
     locals: Vec<Option<Value>>,
     local_constants: DenseSlotSet,
     prefer_environment_globals: bool,
+    /// Blocks registered by `отложить`, run in reverse order when the frame finishes.
+    deferred: Vec<Arc<Chunk>>,
 }
 
 impl<'a> Vm<'a> {
@@ -51,11 +71,26 @@ impl<'a> Vm<'a> {
             locals: Vec::new(),
             local_constants: DenseSlotSet::default(),
             prefer_environment_globals: false,
+            deferred: Vec::new(),
         }
     }
 
     pub fn run(mut self, chunk: &Chunk) -> Result<(), RuntimeError> {
-        self.run_chunk(chunk)
+        let result = self.run_chunk(chunk);
+        self.run_deferred(result)
+    }
+
+    /// Runs the `отложить` blocks of the frame; the first failure is the one reported.
+    fn run_deferred(&mut self, mut result: Result<(), RuntimeError>) -> Result<(), RuntimeError> {
+        while let Some(body) = self.deferred.pop() {
+            let cleanup = self.run_chunk(&body);
+            if let Err(error) = cleanup {
+                if matches!(result, Ok(()) | Err(RuntimeError::Return(..))) {
+                    result = Err(error);
+                }
+            }
+        }
+        result
     }
 
     pub fn evaluate_compiled(
@@ -104,13 +139,65 @@ impl<'a> Vm<'a> {
         registers[register as usize] = value;
     }
 
-    fn args(registers: &[Value], args: &[RegisterArg]) -> Vec<CallArgValue> {
-        args.iter()
-            .map(|arg| CallArgValue {
-                name: arg.name,
-                value: Self::get(registers, arg.register),
-            })
-            .collect()
+    fn args(
+        &mut self,
+        registers: &[Value],
+        args: &[RegisterArg],
+        span: Span,
+    ) -> Result<Vec<CallArgValue>, RuntimeError> {
+        let mut values = Vec::with_capacity(args.len());
+        for arg in args {
+            let value = Self::get(registers, arg.register);
+            if arg.spread {
+                values.extend(
+                    self.interpreter
+                        .iterable_values(value, span)?
+                        .into_iter()
+                        .map(|value| CallArgValue { name: None, value }),
+                );
+            } else {
+                values.push(CallArgValue {
+                    name: arg.name,
+                    value,
+                });
+            }
+        }
+        Ok(values)
+    }
+
+    fn debug_statement(&self, chunk: &Chunk, ip: usize) {
+        let index = chunk
+            .statements
+            .partition_point(|(address, _)| *address <= ip);
+        let Some(&(address, span)) = index.checked_sub(1).map(|index| &chunk.statements[index])
+        else {
+            return;
+        };
+        if address != ip {
+            return;
+        }
+        self.interpreter.debug_statement(self.module, span, || {
+            let mut names = HashMap::new();
+            for instruction in &chunk.code {
+                if let Instruction::LoadName {
+                    name,
+                    binding: Binding::LocalSlot(slot),
+                    ..
+                }
+                | Instruction::StoreName {
+                    name,
+                    binding: Binding::LocalSlot(slot),
+                    ..
+                } = instruction
+                {
+                    names.insert(*slot as usize, *name);
+                }
+            }
+            names
+                .into_iter()
+                .filter_map(|(slot, name)| Some((name, self.locals.get(slot)?.clone()?)))
+                .collect()
+        });
     }
 
     fn set_local(&mut self, slot: usize, value: Value) {
@@ -138,17 +225,24 @@ impl<'a> Vm<'a> {
         if let Some(module) = self.interpreter.resolve_import_alias_symbol(module, name) {
             return Ok(Value::Module(module));
         }
+        if let Some((_, value)) = self.interpreter.load_imported_global(self.module, name)? {
+            return self.interpreter.resolve_runtime_value(value, span);
+        }
         let name = self.interpreter.resolve_symbol(name).unwrap_or_default();
         if let Some((module_name, member_name)) = name.split_once('.') {
             let module_symbol = self.interpreter.intern_string(module_name);
             let member_symbol = self.interpreter.intern_string(member_name);
-            if let Some(module) = self
+            let target = self
                 .interpreter
-                .resolve_import_alias_symbol(module, module_symbol)
-            {
-                if let Some((_, value)) = self
-                    .interpreter
-                    .resolve_module_member_value(module, member_symbol)
+                .modules
+                .get(&self.module)
+                .and_then(|module| {
+                    self.interpreter
+                        .resolve_import_alias_symbol(module, module_symbol)
+                });
+            if let Some(target) = target {
+                if let Some((_, value)) =
+                    self.interpreter.load_module_member(target, member_symbol)?
                 {
                     return self.interpreter.resolve_runtime_value(value, span);
                 }
@@ -208,12 +302,23 @@ impl<'a> Vm<'a> {
             BinaryOperator::Mul => self.interpreter.multiply_values(left, right, span),
             BinaryOperator::Div => self.interpreter.divide_values(left, right, span),
             BinaryOperator::Mod => self.interpreter.modulo_values(left, right, span),
-            BinaryOperator::Eq => Ok(Value::Boolean(left == right)),
-            BinaryOperator::Ne => Ok(Value::Boolean(left != right)),
+            BinaryOperator::Eq => Ok(Value::Boolean(self.interpreter.values_equal(&left, &right))),
+            BinaryOperator::Ne => Ok(Value::Boolean(
+                !self.interpreter.values_equal(&left, &right),
+            )),
             BinaryOperator::Gt => self.interpreter.compare_greater(left, right, span),
             BinaryOperator::Lt => self.interpreter.compare_less(left, right, span),
             BinaryOperator::Ge => self.interpreter.compare_greater_equal(left, right, span),
             BinaryOperator::Le => self.interpreter.compare_less_equal(left, right, span),
+            BinaryOperator::In => Ok(Value::Boolean(
+                self.interpreter.contains_value(&right, &left, span)?,
+            )),
+            BinaryOperator::NotIn => Ok(Value::Boolean(
+                !self.interpreter.contains_value(&right, &left, span)?,
+            )),
+            BinaryOperator::Is => Ok(Value::Boolean(
+                self.interpreter.is_instance_of(&left, &right),
+            )),
             BinaryOperator::And | BinaryOperator::Or => unreachable!(),
         }
     }
@@ -234,9 +339,21 @@ impl<'a> Vm<'a> {
                     .cloned()
                     .ok_or_else(|| runtime_error!(InvalidOperation, span, "Index out of bounds"))
             }
+            Value::Bytes(bytes) => {
+                let index = index.resolve_index(bytes.len(), span)?;
+                Ok(Value::Number(i64::from(bytes[index])))
+            }
+            // Строка индексируется по символам, а не по байтам UTF-8.
+            Value::Text(text) => {
+                let index = index.resolve_index(text.chars().count(), span)?;
+                text.chars()
+                    .nth(index)
+                    .map(|ch| Value::Text(ch.to_string().into()))
+                    .ok_or_else(|| runtime_error!(InvalidOperation, span, "Index out of bounds"))
+            }
             Value::Dict(values) => values.read(|values| {
                 values
-                    .get(&self.interpreter.format_value(&index))
+                    .get(&DictKey::from_value(&index, span)?)
                     .cloned()
                     .ok_or_else(|| {
                         runtime_error!(InvalidOperation, span, "Dictionary key is missing")
@@ -246,6 +363,41 @@ impl<'a> Vm<'a> {
         }
     }
 
+    fn read_slice(
+        &self,
+        object: Value,
+        start: Option<Value>,
+        end: Option<Value>,
+        span: Span,
+    ) -> Result<Value, RuntimeError> {
+        let range = |len| Value::slice_range(len, start.as_ref(), end.as_ref(), span);
+        match object {
+            Value::List(values) => values.read(|values| {
+                let range = range(values.len())?;
+                Ok(Value::List(SharedMut::new(values[range].to_vec())))
+            }),
+            Value::Array(values) => {
+                let range = range(values.len())?;
+                Ok(Value::Array(Arc::new(values[range].to_vec())))
+            }
+            Value::Bytes(bytes) => {
+                let range = range(bytes.len())?;
+                Ok(Value::Bytes(Arc::new(bytes[range].to_vec())))
+            }
+            Value::Text(text) => {
+                let range = range(text.chars().count())?;
+                Ok(Value::Text(
+                    text.chars()
+                        .skip(range.start)
+                        .take(range.len())
+                        .collect::<String>()
+                        .into(),
+                ))
+            }
+            _ => bail_runtime!(TypeError, span, "Value cannot be sliced"),
+        }
+    }
+
     fn assign_index(
         &self,
         object: Value,
@@ -262,7 +414,7 @@ impl<'a> Vm<'a> {
                 Ok(())
             }),
             Value::Dict(values) => values.write(|values| {
-                values.insert(self.interpreter.format_value(&index), value);
+                values.insert(DictKey::from_value(&index, span)?, value);
                 Ok(())
             }),
             _ => bail_runtime!(TypeError, span, "Value cannot be assigned by index"),
@@ -275,18 +427,24 @@ impl<'a> Vm<'a> {
         property: Symbol,
         receiver_is_this: bool,
         receiver_name: Option<Symbol>,
+        cache: &InlineCache,
         span: Span,
     ) -> Result<Value, RuntimeError> {
         match object {
             Ok(Value::Module(module)) => self
                 .interpreter
-                .resolve_module_member_value(module, property)
+                .load_module_member(module, property)?
                 .map(|(_, value)| value)
                 .ok_or_else(|| runtime_error!(UndefinedVariable, span, "Module member is missing")),
             Ok(Value::Object(instance)) => {
                 let external = !receiver_is_this && self.interpreter.method_depth == 0;
                 let field = instance.read(|instance| {
-                    if !instance.is_field_accessible(&property, external) {
+                    let cached = Self::declared_field(cache, &instance.class_ref, property);
+                    if !instance.is_declared_field_accessible(
+                        &property,
+                        cached.declared.as_ref(),
+                        external,
+                    ) {
                         return None;
                     }
                     if let Some(value) = instance.field_values.get(&property) {
@@ -297,8 +455,14 @@ impl<'a> Vm<'a> {
                 });
                 match field {
                     Some(Ok(value)) => Ok(value),
+                    // Значение по умолчанию вычисляется один раз и сохраняется в объекте,
+                    // иначе `объект.поле[0] = х` менял бы временную копию.
                     Some(Err(Some(expr))) => {
-                        Vm::evaluate_compiled(self.interpreter, self.module, expr)
+                        let value = Vm::evaluate_compiled(self.interpreter, self.module, expr)?;
+                        instance.write(|instance| {
+                            instance.set_field_value(property, value.clone());
+                        });
+                        Ok(value)
                     }
                     Some(Err(None)) => Ok(Value::Empty),
                     None => bail_runtime!(InvalidOperation, span, "Property is not accessible"),
@@ -313,9 +477,9 @@ impl<'a> Vm<'a> {
                     Some((_, false, _)) => {
                         bail_runtime!(InvalidOperation, span, "Property is not static")
                     }
-                    Some((_, true, RuntimeFieldData::Value(value))) => Ok(value.read(Clone::clone)),
-                    Some((_, true, RuntimeFieldData::Expression(_))) => {
-                        bail_runtime!(InvalidOperation, span, "Static property is not initialized")
+                    Some((_, true, _)) => {
+                        self.interpreter
+                            .static_field_value(&class, property, self.module, span)
                     }
                     None => bail_runtime!(UndefinedVariable, span, "Property is missing"),
                 }
@@ -325,6 +489,33 @@ impl<'a> Vm<'a> {
         }
     }
 
+    /// How `class` declares `property`, served from `cache` while the site
+    /// keeps reading objects of the same class.
+    fn declared_field(
+        cache: &InlineCache,
+        class: &SharedMut<RuntimeClassDefinition>,
+        property: Symbol,
+    ) -> Arc<CachedField> {
+        if let Some(cached) = cache
+            .get::<CachedField>()
+            .filter(|cached| cached.class.identity() == class.identity())
+        {
+            return cached;
+        }
+        let declared = class.read(|class| {
+            class
+                .fields
+                .get(&property)
+                .map(|(visibility, _, _)| visibility.clone())
+        });
+        let cached = Arc::new(CachedField {
+            class: class.downgrade(),
+            declared,
+        });
+        cache.store(cached.clone());
+        cached
+    }
+
     fn assign_property(
         &self,
         object: Value,
@@ -335,6 +526,30 @@ impl<'a> Vm<'a> {
     ) -> Result<(), RuntimeError> {
         self.interpreter.adopt_value(&object);
         self.interpreter.adopt_value(&value);
+        if let Value::Class(class) = object {
+            return class.write(|class| {
+                if class.constants.contains(&property) {
+                    return bail_runtime!(InvalidOperation, span, "Нельзя изменить константу");
+                }
+                match class.fields.get_mut(&property) {
+                    Some((Visibility::Private, _, _)) if !receiver_is_this => {
+                        bail_runtime!(InvalidOperation, span, "Property is private")
+                    }
+                    Some((_, false, _)) => {
+                        bail_runtime!(InvalidOperation, span, "Property is not static")
+                    }
+                    Some((_, true, RuntimeFieldData::Value(current))) => {
+                        current.write(|current| *current = value);
+                        Ok(())
+                    }
+                    Some((_, true, data)) => {
+                        *data = RuntimeFieldData::Value(SharedMut::new(value));
+                        Ok(())
+                    }
+                    None => bail_runtime!(UndefinedVariable, span, "Property is missing"),
+                }
+            });
+        }
         let Value::Object(instance) = object else {
             return bail_runtime!(TypeMismatch, span, "Expected object");
         };
@@ -354,40 +569,44 @@ impl<'a> Vm<'a> {
         method: Symbol,
         args: Vec<CallArgValue>,
         receiver_is_this: bool,
+        cache: &InlineCache,
         span: Span,
     ) -> Result<Value, RuntimeError> {
         if let Some(class) = self.interpreter.get_class_for_value(&target) {
-            if let Some((visibility, is_static, method_type)) = class.read(|class| {
-                class
-                    .methods
-                    .get(&method)
-                    .map(|(v, s, m)| (v.clone(), *s, m.clone()))
-            }) {
-                if matches!(target, Value::Class(_)) && !is_static {
+            if let Some(cached) = Self::class_method(cache, &class, method) {
+                if matches!(target, Value::Class(_)) && !cached.is_static {
                     return bail_runtime!(
                         InvalidOperation,
                         span,
                         "Instance method needs an object"
                     );
                 }
-                if !receiver_is_this && matches!(visibility, Visibility::Private) {
+                if !receiver_is_this && matches!(cached.visibility, Visibility::Private) {
                     return bail_runtime!(InvalidOperation, span, "Method is private");
                 }
-                let receiver = if is_static { Value::Empty } else { target };
-                let module = method_type.get_module().unwrap_or(self.module);
-                return self
-                    .interpreter
-                    .call_method(method_type, args, receiver, module, span);
+                let receiver = if cached.is_static {
+                    Value::Empty
+                } else {
+                    target
+                };
+                let module = cached.method.get_module().unwrap_or(self.module);
+                return self.interpreter.call_method(
+                    cached.method.clone(),
+                    args,
+                    receiver,
+                    module,
+                    span,
+                );
             }
         }
         if let Value::Module(module) = target {
             if let Some((definition_module, value)) =
-                self.interpreter.resolve_module_member_value(module, method)
+                self.interpreter.load_module_member(module, method)?
             {
                 return match value {
                     Value::Function(function) => {
                         self.interpreter
-                            .call_function(function, args, definition_module, span)
+                            .call_function(&function, args, definition_module, span)
                     }
                     Value::Builtin(function) => function(self.interpreter, args, span),
                     Value::Class(class) => {
@@ -400,6 +619,78 @@ impl<'a> Vm<'a> {
         }
         bail_runtime!(UndefinedMethod, span, "Method is missing")
     }
+
+    /// Method `method` of `class`, served from `cache` while the site keeps
+    /// calling it on receivers of the same class. Misses are not cached, so a
+    /// missing method is looked up again every time.
+    fn class_method(
+        cache: &InlineCache,
+        class: &SharedMut<RuntimeClassDefinition>,
+        method: Symbol,
+    ) -> Option<Arc<CachedMethod>> {
+        if let Some(cached) = cache
+            .get::<CachedMethod>()
+            .filter(|cached| cached.class.identity() == class.identity())
+        {
+            return Some(cached);
+        }
+        let (visibility, is_static, method) = class.read(|class| {
+            class
+                .methods
+                .get(&method)
+                .map(|(v, s, m)| (v.clone(), *s, m.clone()))
+        })?;
+        let cached = Arc::new(CachedMethod {
+            class: class.downgrade(),
+            visibility,
+            is_static,
+            method,
+        });
+        cache.store(cached.clone());
+        Some(cached)
+    }
+
+    /// Вызывает метод или конструктор базового класса, минуя переопределения в наследниках.
+    fn call_super(
+        &mut self,
+        receiver: Value,
+        class: Symbol,
+        method: Option<Symbol>,
+        args: Vec<CallArgValue>,
+        span: Span,
+    ) -> Result<Value, RuntimeError> {
+        let (class, module) =
+            self.interpreter
+                .resolve_class_for_creation(class, self.module, span)?;
+        let Some(method) = method else {
+            let Some(constructor) = class.read(|class| class.constructor.clone()) else {
+                if args.is_empty() {
+                    return Ok(Value::Empty);
+                }
+                return bail_runtime!(
+                    InvalidOperation,
+                    span,
+                    "Базовый класс не объявляет конструктор"
+                );
+            };
+            let module = constructor.get_module().unwrap_or(module);
+            self.interpreter
+                .call_method(constructor, args, receiver, module, span)?;
+            return Ok(Value::Empty);
+        };
+        let Some((is_static, method_type)) = class.read(|class| {
+            class
+                .methods
+                .get(&method)
+                .map(|(_, is_static, method)| (*is_static, method.clone()))
+        }) else {
+            return bail_runtime!(UndefinedMethod, span, "Method is missing");
+        };
+        let receiver = if is_static { Value::Empty } else { receiver };
+        let module = method_type.get_module().unwrap_or(module);
+        self.interpreter
+            .call_method(method_type, args, receiver, module, span)
+    }
 }
 
 #[cfg(test)]
@@ -143,6 +143,39 @@ result = sum(5)
     );
 }
 
+#[test]
+fn local_for_each_updates_function_locals() {
+    let mut session = Session::new();
+    let module = Parser::new(
+        session.interner(),
+        "for_each_slots",
+        PathBuf::from("for_each_slots.goida"),
+    )
+    .parse(
+        r#"
+function count(text) {
+    total = 0
+    for ch from text {
+        total += 1
+    }
+    return total
+}
+result = count("гойда")
+"#,
+    )
+    .expect("program should compile");
+    let module_id = module.name;
+
+    session
+        .execute(module)
+        .expect("for-each over function locals should execute");
+    let result = session.runtime().intern_string("result");
+    assert_eq!(
+        session.runtime().modules[&module_id].globals.get(&result),
+        Some(&Value::Number(5))
+    );
+}
+
 #[test]
 fn known_globals_use_dense_module_slots() {
     let mut session = Session::new();
@@ -230,3 +263,70 @@ fn dense_slot_set_handles_sparse_word_boundaries() {
     assert!(!slots.contains(boundary - 1));
     assert!(!slots.contains(boundary * 2));
 }
+
+#[test]
+fn property_and_method_caches_follow_the_receiver_class() {
+    let mut session = Session::new();
+    let module = Parser::new(
+        session.interner(),
+        "inline_caches",
+        PathBuf::from("inline_caches.goida"),
+    )
+    .parse(
+        r#"
+class Cat {
+    public name: string = "cat"
+    public function voice(this) {
+        return "meow"
+    }
+}
+
+class Dog {
+    private name: string = "dog"
+    public function voice(this) {
+        return "woof"
+    }
+}
+
+function speak(animal) {
+    return animal.voice()
+}
+
+function name_of(animal) {
+    return animal.name
+}
+
+voices = ""
+for (i = 0, i < 4, i += 1) {
+    if (i % 2 == 0) {
+        voices += speak(new Cat())
+    } else {
+        voices += speak(new Dog())
+    }
+}
+cat_name = name_of(new Cat())
+dog_name = name_of(new Dog())
+"#,
+    )
+    .expect("program should compile");
+    let module_id = module.name;
+
+    let error = session
+        .execute(module)
+        .expect_err("the private field must stay private after a cached public read");
+    assert!(matches!(error, RuntimeError::InvalidOperation(_)));
+
+    let global = |name: &str| {
+        let name = session.runtime().intern_string(name);
+        session.runtime().modules[&module_id]
+            .globals
+            .get(&name)
+            .cloned()
+    };
+    assert_eq!(
+        global("voices"),
+        Some(Value::Text("meowwoofmeowwoof".into()))
+    );
+    assert_eq!(global("cat_name"), Some(Value::Text("cat".into())));
+    assert_eq!(global("dog_name"), None);
+}
@@ -1,14 +1,17 @@
 use crate::ast::prelude::{ExprId, Parameter, Span, StmtId, TypeId};
+use crate::ast::symbols;
+use serde::Serialize;
 use string_interner::DefaultSymbol as Symbol;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum Visibility {
     Private,
     Public,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ClassField {
+    #[serde(serialize_with = "symbols::symbol")]
     pub name: Symbol,
     pub field_type: Option<TypeId>,
     pub visibility: Visibility,
@@ -17,8 +20,9 @@ pub struct ClassField {
     pub span: Span,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ClassMethod {
+    #[serde(serialize_with = "symbols::symbol")]
     pub name: Symbol,
     pub params: Vec<Parameter>,
     pub return_type: Option<TypeId>,
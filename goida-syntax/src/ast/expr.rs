@@ -1,25 +1,30 @@
 use crate::ast::prelude::{BinaryOperator, Parameter, Span, StmtId, TypeId, UnaryOperator};
+use crate::ast::symbols;
+use serde::Serialize;
 use string_interner::DefaultSymbol as Symbol;
 
 /// Stable index of an expression inside [`AstArena`](crate::ast::arena::AstArena).
 pub type ExprId = u32;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 /// Function or method call argument.
 pub struct CallArg {
     /// Argument name for `имя=значение`; `None` for positional arguments.
+    #[serde(serialize_with = "symbols::optional_symbol")]
     pub name: Option<Symbol>,
     /// Expression that produces the argument value.
     pub value: ExprId,
+    /// `...значение`: the items of a collection become positional arguments.
+    pub spread: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 /// All expression forms supported by the language AST.
 pub enum ExpressionKind {
     /// Compile-time literal.
     Literal(LiteralValue),
     /// Local/global/module identifier.
-    Identifier(Symbol),
+    Identifier(#[serde(serialize_with = "symbols::symbol")] Symbol),
     /// Left-associative binary operation.
     Binary {
         op: BinaryOperator,
@@ -38,17 +43,26 @@ pub enum ExpressionKind {
         object: ExprId,
         index: ExprId,
     },
+    /// `объект[начало:конец]`, either bound may be omitted.
+    Slice {
+        object: ExprId,
+        start: Option<ExprId>,
+        end: Option<ExprId>,
+    },
 
     PropertyAccess {
         object: ExprId,
+        #[serde(serialize_with = "symbols::symbol")]
         property: Symbol,
     },
     MethodCall {
         object: ExprId,
+        #[serde(serialize_with = "symbols::symbol")]
         method: Symbol,
         args: Vec<CallArg>,
     },
     ObjectCreation {
+        #[serde(serialize_with = "symbols::symbol")]
         class_name: Symbol,
         args: Vec<CallArg>,
     },
@@ -56,20 +70,30 @@ pub enum ExpressionKind {
         params: Vec<Parameter>,
         body: StmtId,
     },
+    /// `родитель.метод(...)` inside a method: `receiver` is the method's object
+    /// parameter, `method` is `None` for `родитель.конструктор(...)`.
+    SuperCall {
+        #[serde(serialize_with = "symbols::symbol")]
+        class: Symbol,
+        receiver: ExprId,
+        #[serde(serialize_with = "symbols::optional_symbol")]
+        method: Option<Symbol>,
+        args: Vec<CallArg>,
+    },
     This,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 /// Literal value stored directly in the AST.
 pub enum LiteralValue {
     Number(i64),
     Float(f64),
-    Text(Symbol),
+    Text(#[serde(serialize_with = "symbols::symbol")] Symbol),
     Boolean(bool),
     Unit,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 /// Expression plus source span and optional inferred/declared type hint.
 pub struct ExpressionNode {
     pub kind: ExpressionKind,
@@ -6,4 +6,5 @@ pub mod program;
 pub mod source;
 pub mod span;
 pub mod stmt;
+pub mod symbols;
 pub mod types;
@@ -1,4 +1,4 @@
-pub use super::arena::{AstArena, BuiltinTypeSpec};
+pub use super::arena::{ArenaStats, AstArena, BuiltinTypeSpec};
 pub use super::class::{ClassField, ClassMethod, Visibility};
 pub use super::expr::{CallArg, ExprId, ExpressionKind, ExpressionNode, LiteralValue};
 pub use super::program::{
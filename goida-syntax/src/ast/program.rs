@@ -1,88 +1,109 @@
 use crate::ast::class::Visibility;
 use crate::ast::prelude::{ExprId, Span, StmtId, TypeId};
-use std::collections::HashMap;
+use crate::ast::symbols;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use string_interner::DefaultSymbol as Symbol;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 /// User-defined function with parsed parameters, return type and body.
 pub struct FunctionDefinition {
+    #[serde(serialize_with = "symbols::symbol")]
     pub name: Symbol,
     pub params: Vec<Parameter>,
     pub return_type: Option<TypeId>,
     pub body: StmtId,
     pub span: Span,
+    #[serde(serialize_with = "symbols::optional_symbol")]
     pub module: Option<Symbol>,
+    /// Text of the `///` comments above the definition.
+    pub doc: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 /// Function, method, or constructor parameter.
 pub struct Parameter {
+    #[serde(serialize_with = "symbols::symbol")]
     pub name: Symbol,
     pub param_type: TypeId,
     pub default_value: Option<ExprId>,
     pub span: Span,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 /// Resolved import target and alias.
 pub struct ImportItem {
+    #[serde(serialize_with = "symbols::symbol")]
     pub path: Symbol,
+    #[serde(serialize_with = "symbols::symbol")]
     pub alias: Symbol,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 /// Source-level import declaration.
 pub struct Import {
     pub item: ImportItem,
     pub span: Span,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 /// Native library function signature declared by `библиотека`.
 pub struct NativeFunctionDefinition {
+    #[serde(serialize_with = "symbols::symbol")]
     pub name: Symbol,
     pub params: Vec<Parameter>,
     pub return_type: Option<TypeId>,
     pub span: Span,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 /// Native library global variable signature.
 pub struct NativeGlobalDefinition {
+    #[serde(serialize_with = "symbols::symbol")]
     pub name: Symbol,
     pub value_type: TypeId,
     pub span: Span,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 /// Native dynamic library declaration and its exported symbols.
 pub struct NativeLibraryDefinition {
+    #[serde(serialize_with = "symbols::symbol")]
     pub path: Symbol,
     pub functions: Vec<NativeFunctionDefinition>,
     pub globals: Vec<NativeGlobalDefinition>,
     pub span: Span,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 /// Callable class member implementation.
 pub enum MethodType<NativeMethod = ()> {
     User(Arc<FunctionDefinition>),
     Native(Arc<NativeMethod>),
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
+#[serde(bound(serialize = "NativeMethod: Serialize, RuntimeValue: Serialize"))]
 /// Runtime class metadata, including fields, methods, constructor and base class.
 pub struct ClassDefinition<NativeMethod = (), RuntimeValue = ()> {
+    #[serde(serialize_with = "symbols::symbol")]
     pub name: Symbol,
+    #[serde(serialize_with = "symbols::optional_symbol")]
     pub base_class: Option<Symbol>,
+    #[serde(serialize_with = "symbols::symbol_map")]
     pub fields: HashMap<Symbol, (Visibility, bool, FieldData<RuntimeValue>)>,
+    /// Static fields declared with `константа`; they cannot be reassigned.
+    #[serde(serialize_with = "symbols::symbol_set")]
+    pub constants: HashSet<Symbol>,
+    #[serde(serialize_with = "symbols::symbol_map")]
     pub methods: HashMap<Symbol, (Visibility, bool, MethodType<NativeMethod>)>,
     pub constructor: Option<MethodType<NativeMethod>>,
     pub span: Span,
+    pub doc: Option<String>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 /// Stored field initializer or already computed static/native value.
 pub enum FieldData<RuntimeValue = ()> {
     Expression(Option<ExprId>),
@@ -27,9 +27,24 @@ impl SourceManager {
         }
     }
 
-    /// Reads the current file content from disk.
+    /// Caches source that has no file on disk, such as stdin or `--eval` input.
+    pub fn load_source(&self, path: &str, content: &str) {
+        let mut files = self.files.write().unwrap();
+        if let Entry::Vacant(entry) = files.entry(path.to_string()) {
+            entry.insert(Box::new(Source::from(content.to_string())));
+        }
+    }
+
+    /// Reads the current file content from disk, falling back to cached in-memory source.
     pub fn get_file_content(&self, path: &str) -> String {
-        std::fs::read_to_string(path).unwrap_or_default()
+        std::fs::read_to_string(path).unwrap_or_else(|_| {
+            self.files
+                .read()
+                .unwrap()
+                .get(path)
+                .map(|source| source.text().to_string())
+                .unwrap_or_default()
+        })
     }
 
     /// Converts a character offset into zero-based line and column.
@@ -138,4 +153,15 @@ mod tests {
         let current = cache.fetch(&&path).unwrap() as *const Source<String>;
         assert_eq!(original, current);
     }
+
+    #[test]
+    fn in_memory_source_is_served_without_a_file() {
+        let manager = SourceManager::new();
+        let path = String::from("<stdin>");
+        manager.load_source(&path, "печать(1)\n");
+
+        assert_eq!(manager.get_file_content(&path), "печать(1)\n");
+        let mut cache = &manager;
+        assert_eq!(cache.fetch(&&path).unwrap().text(), "печать(1)\n");
+    }
 }
@@ -1,11 +1,14 @@
+use crate::ast::symbols;
+use serde::Serialize;
 use std::ops::Range;
 use string_interner::{DefaultSymbol, Symbol};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 /// Source range in byte offsets plus the interned file/module id.
 pub struct Span {
     pub start: u32,
     pub end: u32,
+    #[serde(serialize_with = "symbols::symbol")]
     pub file_id: DefaultSymbol,
 }
 
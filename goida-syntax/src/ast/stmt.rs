@@ -2,30 +2,39 @@ use crate::ast::prelude::{
     BinaryOperator, ClassDefinition, ExprId, FunctionDefinition, ImportItem,
     NativeLibraryDefinition, Span, TypeId,
 };
+use crate::ast::symbols;
+use serde::Serialize;
 use string_interner::DefaultSymbol as Symbol;
 
 /// Stable index of a statement inside [`AstArena`](crate::ast::arena::AstArena).
 pub type StmtId = u32;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 /// Statement plus its source span.
 pub struct StatementNode {
     pub kind: StatementKind,
     pub span: Span,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 /// All executable and declarative statement forms.
 pub enum StatementKind {
     Expression(ExprId),
     /// Source import retained for tooling; bytecode compilation intentionally ignores it.
     Import(ImportItem),
     Assign {
+        #[serde(serialize_with = "symbols::symbol")]
         name: Symbol,
         is_const: bool,
         type_hint: Option<TypeId>,
         value: ExprId,
     },
+    /// Assignment of several targets at once, `а, б = б, а` or `х, у = f()`.
+    /// All values are evaluated before any target is written; a single value is unpacked.
+    MultiAssign {
+        targets: Vec<ExprId>,
+        values: Vec<ExprId>,
+    },
     /// In-place arithmetic assignment such as `x += 1` or `obj.field *= 2`.
     CompoundAssign {
         target: ExprId,
@@ -47,6 +56,7 @@ pub enum StatementKind {
         body: StmtId,
     },
     For {
+        #[serde(serialize_with = "symbols::symbol")]
         variable: Symbol,
         init: ExprId,
         condition: ExprId,
@@ -54,6 +64,7 @@ pub enum StatementKind {
         body: StmtId,
     },
     ForEach {
+        #[serde(serialize_with = "symbols::symbol")]
         variable: Symbol,
         iterable: ExprId,
         body: StmtId,
@@ -61,33 +72,50 @@ pub enum StatementKind {
     Thread {
         body: StmtId,
     },
+    /// `с (имя = значение) { ... }`: the value is closed after the body, even on error.
+    With {
+        #[serde(serialize_with = "symbols::symbol")]
+        variable: Symbol,
+        value: ExprId,
+        body: StmtId,
+    },
+    /// `отложить { ... }`: the body runs when the enclosing function exits, even on error.
+    Defer {
+        body: StmtId,
+    },
     Try {
         body: StmtId,
         handlers: Vec<TryHandler>,
     },
     Raise {
+        #[serde(serialize_with = "symbols::symbol")]
         error_type: Symbol,
         message: Option<ExprId>,
     },
     Block(Vec<StmtId>),
     Return(Option<ExprId>),
+    /// `выдать` inside a function body: the function becomes a generator.
+    Yield(ExprId),
     FunctionDefinition(FunctionDefinition),
     NativeLibraryDefinition(NativeLibraryDefinition),
     ClassDefinition(ClassDefinition),
     PropertyAssign {
         object: ExprId,
+        #[serde(serialize_with = "symbols::symbol")]
         property: Symbol,
         value: ExprId,
     },
     Empty,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 /// Single `перехватить` branch of a `попробовать` statement.
 pub struct TryHandler {
     /// Error class accepted by this handler; `None` means catch any runtime error.
+    #[serde(serialize_with = "symbols::optional_symbol")]
     pub error_type: Option<Symbol>,
     /// Optional binding for the error message text.
+    #[serde(serialize_with = "symbols::optional_symbol")]
     pub error_text: Option<Symbol>,
     /// Handler body statement id.
     pub body: StmtId,
@@ -0,0 +1,85 @@
+//! Serialization of interned names inside AST nodes.
+//!
+//! Symbols serialize as their numeric ids, unless [`resolve_symbols`] has
+//! installed an interner for the current thread; then they serialize as the
+//! names they stand for.
+
+use goida_model::SharedInterner;
+use serde::ser::{SerializeMap, SerializeSeq};
+use serde::{Serialize, Serializer};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use string_interner::DefaultSymbol as Symbol;
+
+thread_local! {
+    static NAMES: RefCell<Option<SharedInterner>> = const { RefCell::new(None) };
+}
+
+/// Restores the previously installed interner, also when `f` panics.
+struct Restore(Option<SharedInterner>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        NAMES.with(|names| *names.borrow_mut() = self.0.take());
+    }
+}
+
+/// Runs `f` with symbols serialized as names resolved through `interner`.
+pub fn resolve_symbols<R>(interner: &SharedInterner, f: impl FnOnce() -> R) -> R {
+    let _restore = Restore(NAMES.with(|names| names.replace(Some(interner.clone()))));
+    f()
+}
+
+/// Name of `symbol` in the installed interner, if there is one.
+fn name(symbol: Symbol) -> Option<String> {
+    NAMES.with(|names| {
+        names
+            .borrow()
+            .as_ref()
+            .and_then(|interner| interner.read(|i| i.resolve(symbol).map(str::to_owned)))
+    })
+}
+
+struct Name(Symbol);
+
+impl Serialize for Name {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match name(self.0) {
+            Some(name) => serializer.serialize_str(&name),
+            None => self.0.serialize(serializer),
+        }
+    }
+}
+
+pub(crate) fn symbol<S: Serializer>(symbol: &Symbol, serializer: S) -> Result<S::Ok, S::Error> {
+    Name(*symbol).serialize(serializer)
+}
+
+pub(crate) fn optional_symbol<S: Serializer>(
+    symbol: &Option<Symbol>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    symbol.map(Name).serialize(serializer)
+}
+
+pub(crate) fn symbol_map<V: Serialize, S: Serializer>(
+    map: &HashMap<Symbol, V>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut output = serializer.serialize_map(Some(map.len()))?;
+    for (key, value) in map {
+        output.serialize_entry(&Name(*key), value)?;
+    }
+    output.end()
+}
+
+pub(crate) fn symbol_set<S: Serializer>(
+    set: &HashSet<Symbol>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut output = serializer.serialize_seq(Some(set.len()))?;
+    for symbol in set {
+        output.serialize_element(&Name(*symbol))?;
+    }
+    output.end()
+}
@@ -1,9 +1,11 @@
+use crate::ast::symbols;
+use serde::Serialize;
 use std::fmt;
 use string_interner::DefaultSymbol as Symbol;
 
 pub type TypeId = u32;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 pub enum DataType {
     Primitive(PrimitiveType),
     List(Box<DataType>),
@@ -16,20 +18,20 @@ pub enum DataType {
         params: Vec<DataType>,
         return_type: Box<DataType>,
     },
-    Object(Symbol),
+    Object(#[serde(serialize_with = "symbols::symbol")] Symbol),
     Runtime(RuntimeType),
     Any,
     Unit,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub enum RuntimeType {
     Class,
     Module,
     Resource,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub enum PrimitiveType {
     Number,
     Float,
@@ -50,7 +52,7 @@ impl fmt::Display for PrimitiveType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
 pub enum BinaryOperator {
     Add,
     Sub,
@@ -65,9 +67,15 @@ pub enum BinaryOperator {
     Ge,
     And,
     Or,
+    /// `элемент в коллекция`.
+    In,
+    /// `элемент не в коллекция`.
+    NotIn,
+    /// `объект является Класс`.
+    Is,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
 pub enum UnaryOperator {
     Negative,
     Not,
@@ -1,7 +1,6 @@
 use goida_model::SharedInterner;
 use goida_runtime::builtins::registry::BUILTINS;
 use goida_runtime::interpreter::prelude::Module;
-use goida_syntax::ast::prelude::StatementKind;
 use tower_lsp::lsp_types::{CompletionItem, CompletionItemKind};
 
 pub(crate) fn completion_items(
@@ -89,10 +88,7 @@ fn add_module_items(items: &mut Vec<CompletionItem>, module: &Module, interner:
         });
     }
     for stmt_id in &module.body {
-        let Some(stmt) = module.arena.get_statement(*stmt_id) else {
-            continue;
-        };
-        if let StatementKind::Assign { name, .. } = stmt.kind {
+        for name in module.arena.assigned_names(*stmt_id) {
             if let Some(name) = module.arena.resolve_symbol(interner, name) {
                 items.push(item(name, CompletionItemKind::VARIABLE, "variable"));
             }
@@ -139,6 +139,25 @@ fn collect_declarations(
                 }
                 collect_expression_declarations(module, interner, *value, out);
             }
+            StatementKind::MultiAssign { targets, values } => {
+                for target in targets {
+                    let Some(node) = module.arena.get_expression(*target) else {
+                        continue;
+                    };
+                    if let ExpressionKind::Identifier(name) = node.kind {
+                        if let Some(name) = module.arena.resolve_symbol(interner, name) {
+                            out.push(Declaration {
+                                name,
+                                span: node.span,
+                                kind: "Переменная",
+                            });
+                        }
+                    }
+                }
+                for value in values {
+                    collect_expression_declarations(module, interner, *value, out);
+                }
+            }
             StatementKind::For {
                 variable,
                 update,
@@ -165,6 +184,16 @@ fn collect_declarations(
                 }
                 collect_declarations(module, interner, &[*body], out);
             }
+            StatementKind::With { variable, body, .. } => {
+                if let Some(name) = module.arena.resolve_symbol(interner, *variable) {
+                    out.push(Declaration {
+                        name,
+                        span: statement.span,
+                        kind: "Переменная",
+                    });
+                }
+                collect_declarations(module, interner, &[*body], out);
+            }
             StatementKind::Block(items) => collect_declarations(module, interner, items, out),
             StatementKind::If {
                 then_body,
@@ -176,7 +205,9 @@ fn collect_declarations(
                     collect_declarations(module, interner, &[*else_body], out);
                 }
             }
-            StatementKind::While { body, .. } | StatementKind::Thread { body } => {
+            StatementKind::While { body, .. }
+            | StatementKind::Thread { body }
+            | StatementKind::Defer { body } => {
                 collect_declarations(module, interner, &[*body], out);
             }
             StatementKind::Try { body, handlers } => {
@@ -287,6 +318,16 @@ fn collect_usages(
             StatementKind::Assign { value, .. } => {
                 collect_expression_usages(module, interner, *value, out)
             }
+            StatementKind::MultiAssign { targets, values } => {
+                for expr in values.iter().chain(targets.iter().filter(|target| {
+                    !matches!(
+                        module.arena.get_expression(**target).map(|node| &node.kind),
+                        Some(ExpressionKind::Identifier(_))
+                    )
+                })) {
+                    collect_expression_usages(module, interner, *expr, out);
+                }
+            }
             StatementKind::CompoundAssign { target, value, .. } => {
                 collect_expression_usages(module, interner, *target, out);
                 collect_expression_usages(module, interner, *value, out);
@@ -335,7 +376,11 @@ fn collect_usages(
                 collect_expression_usages(module, interner, *iterable, out);
                 collect_usages(module, interner, &[*body], out);
             }
-            StatementKind::Thread { body } => {
+            StatementKind::With { value, body, .. } => {
+                collect_expression_usages(module, interner, *value, out);
+                collect_usages(module, interner, &[*body], out);
+            }
+            StatementKind::Thread { body } | StatementKind::Defer { body } => {
                 collect_usages(module, interner, &[*body], out);
             }
             StatementKind::Block(body) => {
@@ -363,6 +408,7 @@ fn collect_usages(
                     collect_expression_usages(module, interner, *expr, out);
                 }
             }
+            StatementKind::Yield(expr) => collect_expression_usages(module, interner, *expr, out),
             StatementKind::FunctionDefinition(function) => {
                 collect_usages(module, interner, &[function.body], out);
             }
@@ -423,6 +469,12 @@ fn collect_expression_usages(
                 collect_expression_usages(module, interner, arg.value, out);
             }
         }
+        ExpressionKind::SuperCall { receiver, args, .. } => {
+            collect_expression_usages(module, interner, *receiver, out);
+            for arg in args {
+                collect_expression_usages(module, interner, arg.value, out);
+            }
+        }
         ExpressionKind::Binary { left, right, .. } => {
             collect_expression_usages(module, interner, *left, out);
             collect_expression_usages(module, interner, *right, out);
@@ -434,6 +486,12 @@ fn collect_expression_usages(
             collect_expression_usages(module, interner, *object, out);
             collect_expression_usages(module, interner, *index, out);
         }
+        ExpressionKind::Slice { object, start, end } => {
+            collect_expression_usages(module, interner, *object, out);
+            for bound in [start, end].into_iter().flatten() {
+                collect_expression_usages(module, interner, *bound, out);
+            }
+        }
         ExpressionKind::PropertyAccess { object, .. } => {
             collect_expression_usages(module, interner, *object, out);
         }
@@ -121,6 +121,31 @@ fn collect_statement_tokens(
                 }
                 collect_expression_tokens(module, interner, *value, text, line_starts, out);
             }
+            StatementKind::MultiAssign { targets, values } => {
+                for target in targets {
+                    let Some(node) = module.arena.get_expression(*target) else {
+                        continue;
+                    };
+                    match node.kind {
+                        ExpressionKind::Identifier(name) => {
+                            if let Some(name) = module.arena.resolve_symbol(interner, name) {
+                                push_name_token(out, text, line_starts, node.span, &name, 1, true);
+                            }
+                        }
+                        _ => collect_expression_tokens(
+                            module,
+                            interner,
+                            *target,
+                            text,
+                            line_starts,
+                            out,
+                        ),
+                    }
+                }
+                for value in values {
+                    collect_expression_tokens(module, interner, *value, text, line_starts, out);
+                }
+            }
             StatementKind::CompoundAssign { target, value, .. } => {
                 collect_expression_tokens(module, interner, *target, text, line_starts, out);
                 collect_expression_tokens(module, interner, *value, text, line_starts, out);
@@ -196,7 +221,18 @@ fn collect_statement_tokens(
                 collect_expression_tokens(module, interner, *iterable, text, line_starts, out);
                 collect_statement_tokens(module, interner, &[*body], text, line_starts, out);
             }
-            StatementKind::Thread { body } => {
+            StatementKind::With {
+                variable,
+                value,
+                body,
+            } => {
+                if let Some(name) = module.arena.resolve_symbol(interner, *variable) {
+                    push_name_token(out, text, line_starts, statement.span, &name, 1, true);
+                }
+                collect_expression_tokens(module, interner, *value, text, line_starts, out);
+                collect_statement_tokens(module, interner, &[*body], text, line_starts, out);
+            }
+            StatementKind::Thread { body } | StatementKind::Defer { body } => {
                 collect_statement_tokens(module, interner, &[*body], text, line_starts, out);
             }
             StatementKind::Try { body, handlers } => {
@@ -230,6 +266,9 @@ fn collect_statement_tokens(
                     collect_expression_tokens(module, interner, *expr_id, text, line_starts, out);
                 }
             }
+            StatementKind::Yield(expr_id) => {
+                collect_expression_tokens(module, interner, *expr_id, text, line_starts, out)
+            }
             StatementKind::FunctionDefinition(function) => {
                 if let Some(name) = module.arena.resolve_symbol(interner, function.name) {
                     push_name_token(out, text, line_starts, function.span, &name, 0, true);
@@ -377,6 +416,16 @@ fn collect_expression_tokens(
                 collect_expression_tokens(module, interner, arg.value, text, line_starts, out);
             }
         }
+        ExpressionKind::SuperCall { method, args, .. } => {
+            if let Some(name) =
+                method.and_then(|method| module.arena.resolve_symbol(interner, method))
+            {
+                push_name_token(out, text, line_starts, expr.span, &name, 4, false);
+            }
+            for arg in args {
+                collect_expression_tokens(module, interner, arg.value, text, line_starts, out);
+            }
+        }
         ExpressionKind::Binary { left, right, .. } => {
             collect_expression_tokens(module, interner, *left, text, line_starts, out);
             collect_expression_tokens(module, interner, *right, text, line_starts, out);
@@ -388,6 +437,12 @@ fn collect_expression_tokens(
             collect_expression_tokens(module, interner, *object, text, line_starts, out);
             collect_expression_tokens(module, interner, *index, text, line_starts, out);
         }
+        ExpressionKind::Slice { object, start, end } => {
+            collect_expression_tokens(module, interner, *object, text, line_starts, out);
+            for bound in [start, end].into_iter().flatten() {
+                collect_expression_tokens(module, interner, *bound, text, line_starts, out);
+            }
+        }
         ExpressionKind::Lambda { params, body } => {
             for param in params {
                 if let Some(name) = module.arena.resolve_symbol(interner, param.name) {
@@ -46,7 +46,7 @@ pub(crate) fn find_top_level_symbol(
         let Some(statement) = module.arena.get_statement(*stmt_id) else {
             continue;
         };
-        if let StatementKind::Assign { name: symbol, .. } = statement.kind {
+        for symbol in module.arena.assigned_names(*stmt_id) {
             if let Some(assign_name) = module.arena.resolve_symbol(interner, symbol) {
                 if assign_name == name {
                     return Some(ResolvedSymbol {
@@ -55,7 +55,8 @@ pub(crate) fn find_top_level_symbol(
                     });
                 }
             }
-        } else if let StatementKind::NativeLibraryDefinition(definition) = &statement.kind {
+        }
+        if let StatementKind::NativeLibraryDefinition(definition) = &statement.kind {
             for function in &definition.functions {
                 if let Some(function_name) = module.arena.resolve_symbol(interner, function.name) {
                     if function_name == name {
@@ -103,6 +104,27 @@ pub(crate) fn collect_declarations(
                 }
                 collect_expression_declarations(module, interner, *value, out);
             }
+            StatementKind::MultiAssign { targets, values } => {
+                for target in targets {
+                    let Some(node) = module.arena.get_expression(*target) else {
+                        continue;
+                    };
+                    match node.kind {
+                        ExpressionKind::Identifier(name) => {
+                            if let Some(name) = module.arena.resolve_symbol(interner, name) {
+                                out.push(ResolvedSymbol {
+                                    name,
+                                    span: node.span,
+                                });
+                            }
+                        }
+                        _ => collect_expression_declarations(module, interner, *target, out),
+                    }
+                }
+                for value in values {
+                    collect_expression_declarations(module, interner, *value, out);
+                }
+            }
             StatementKind::CompoundAssign { target, value, .. } => {
                 collect_expression_declarations(module, interner, *target, out);
                 collect_expression_declarations(module, interner, *value, out);
@@ -155,6 +177,20 @@ pub(crate) fn collect_declarations(
                 collect_expression_declarations(module, interner, *iterable, out);
                 collect_declarations(module, interner, &[*body], out);
             }
+            StatementKind::With {
+                variable,
+                value,
+                body,
+            } => {
+                if let Some(name) = module.arena.resolve_symbol(interner, *variable) {
+                    out.push(ResolvedSymbol {
+                        name,
+                        span: statement.span,
+                    });
+                }
+                collect_expression_declarations(module, interner, *value, out);
+                collect_declarations(module, interner, &[*body], out);
+            }
             StatementKind::If {
                 condition,
                 then_body,
@@ -175,7 +211,9 @@ pub(crate) fn collect_declarations(
                     collect_expression_declarations(module, interner, *message, out);
                 }
             }
-            StatementKind::Thread { body } => collect_declarations(module, interner, &[*body], out),
+            StatementKind::Thread { body } | StatementKind::Defer { body } => {
+                collect_declarations(module, interner, &[*body], out)
+            }
             StatementKind::Try { body, handlers } => {
                 collect_declarations(module, interner, &[*body], out);
                 for handler in handlers {
@@ -196,6 +234,9 @@ pub(crate) fn collect_declarations(
                     collect_expression_declarations(module, interner, *expr, out);
                 }
             }
+            StatementKind::Yield(expr) => {
+                collect_expression_declarations(module, interner, *expr, out)
+            }
             StatementKind::FunctionDefinition(function) => {
                 if let Some(name) = module.arena.resolve_symbol(interner, function.name) {
                     out.push(ResolvedSymbol {
@@ -295,7 +336,7 @@ fn collect_expression_declarations(
                 collect_expression_declarations(module, interner, arg.value, out);
             }
         }
-        ExpressionKind::ObjectCreation { args, .. } => {
+        ExpressionKind::ObjectCreation { args, .. } | ExpressionKind::SuperCall { args, .. } => {
             for arg in args {
                 collect_expression_declarations(module, interner, arg.value, out);
             }
@@ -311,6 +352,12 @@ fn collect_expression_declarations(
             collect_expression_declarations(module, interner, *object, out);
             collect_expression_declarations(module, interner, *index, out);
         }
+        ExpressionKind::Slice { object, start, end } => {
+            collect_expression_declarations(module, interner, *object, out);
+            for bound in [start, end].into_iter().flatten() {
+                collect_expression_declarations(module, interner, *bound, out);
+            }
+        }
         ExpressionKind::PropertyAccess { object, .. } => {
             collect_expression_declarations(module, interner, *object, out);
         }
@@ -0,0 +1,337 @@
+mod common;
+
+use std::path::{Path, PathBuf};
+
+fn write_source(test: &str, name: &str, source: &str) -> PathBuf {
+    let dir = Path::new("target").join(test);
+    std::fs::create_dir_all(&dir).expect("Не удалось создать временную папку теста");
+    let file = dir.join(name);
+    std::fs::write(&file, source).expect("Не удалось записать временный файл");
+    file
+}
+
+#[test]
+fn test_check_validates_without_running() {
+    let valid = write_source(
+        "cli_check_test",
+        "valid.goida",
+        "функция f() -> число {\n    вернуть 1\n}\nпечать(f())\n",
+    );
+    let output = common::goida_command()
+        .args(["check", valid.to_str().unwrap()])
+        .output()
+        .expect("Не удалось запустить goida check");
+
+    assert!(
+        output.status.success(),
+        "goida check завершился с ошибкой\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("1\n"));
+
+    let invalid = write_source(
+        "cli_check_test",
+        "invalid.goida",
+        "печать(\"до\")\nпечать(неизвестное)\n",
+    );
+    let output = common::goida_command()
+        .args(["check", invalid.to_str().unwrap()])
+        .output()
+        .expect("Не удалось запустить goida check");
+
+    assert!(!output.status.success());
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("до"));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("неизвестное"));
+}
+
+#[test]
+fn test_runner_reports_failures_with_locations() {
+    write_source(
+        "cli_test_runner_test",
+        "арифметика_тест.goida",
+        "функция тест_сложение() {\n    проверить(2 + 2 == 4)\n}\n\nфункция тест_провал() {\n    проверить(2 + 2 == 5, \"неверная сумма\")\n}\n",
+    );
+    let other = write_source("cli_test_runner_test", "обычный.goida", "проверить(ложь)\n");
+    let output = common::goida_command()
+        .args(["test", other.parent().unwrap().to_str().unwrap()])
+        .output()
+        .expect("Не удалось запустить goida test");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(!output.status.success());
+    assert!(stdout.contains("ок      "), "STDOUT: {stdout}");
+    assert!(stdout.contains("арифметика_тест.goida:6:5: ОшибкаПроверки: неверная сумма"));
+    assert!(stdout.contains("Итог: 1 пройдено, 1 провалено"));
+}
+
+#[test]
+fn test_run_forwards_script_arguments() {
+    let script = write_source(
+        "cli_script_args_test",
+        "args.goida",
+        "аргументы = Система.аргументы()\nпечать(аргументы.длина())\nпечать(аргументы)\n",
+    );
+    let output = common::goida_command()
+        .args([
+            "--",
+            "run",
+            script.to_str().unwrap(),
+            "--",
+            "арг1",
+            "--флаг",
+            "два слова",
+        ])
+        .output()
+        .expect("Не удалось запустить goida run");
+
+    assert!(
+        output.status.success(),
+        "STDERR: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "3\n[арг1, --флаг, два слова]\n"
+    );
+}
+
+#[test]
+fn test_code_runs_from_stdin_and_eval_flag() {
+    let output = common::goida_command()
+        .args(["run", "-"])
+        .output_with_stdin("x = 20\nпечать(x + 1)\n")
+        .expect("Не удалось запустить goida run -");
+    assert!(
+        output.status.success(),
+        "STDERR: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "21\n");
+
+    let output = common::goida_command()
+        .args(["-e", "печать(\"привет\")"])
+        .output()
+        .expect("Не удалось запустить goida -e");
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "привет\n");
+
+    let output = common::goida_command()
+        .args(["--eval", "печать(1 / 0)"])
+        .output()
+        .expect("Не удалось запустить goida --eval");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("<eval>:1:8"));
+}
+
+#[test]
+fn test_build_embeds_script_and_imports_into_executable() {
+    write_source(
+        "cli_build_test",
+        "helpers.goida",
+        "функция удвоить(x: число) -> число {\n    вернуть x * 2\n}\n",
+    );
+    let script = write_source(
+        "cli_build_test",
+        "main.goida",
+        "подключить \"helpers\" как helpers\nпечать(helpers.удвоить(21))\nпечать(Система.аргументы())\n",
+    );
+    let executable = script.with_file_name(format!("программа{}", std::env::consts::EXE_SUFFIX));
+    let output = common::goida_command()
+        .args([
+            "build",
+            script.to_str().unwrap(),
+            "-o",
+            executable.to_str().unwrap(),
+        ])
+        .output()
+        .expect("Не удалось запустить goida build");
+    assert!(
+        output.status.success(),
+        "STDERR: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // Собранной программе исходники больше не нужны.
+    std::fs::remove_file(&script).unwrap();
+    std::fs::remove_file(script.with_file_name("helpers.goida")).unwrap();
+    let output = std::process::Command::new(&executable)
+        .args(["один", "два"])
+        .output()
+        .expect("Не удалось запустить собранную программу");
+    assert!(
+        output.status.success(),
+        "STDERR: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "42\n[один, два]\n");
+}
+
+#[test]
+fn test_lsp_subcommand_answers_initialize() {
+    let request = r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{"capabilities":{}}}"#;
+    let message = format!("Content-Length: {}\r\n\r\n{request}", request.len());
+    let output = common::goida_command()
+        .args(["lsp"])
+        .output_with_stdin(&message)
+        .expect("Не удалось запустить goida lsp");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("\"id\":1"), "STDOUT: {stdout}");
+    assert!(stdout.contains("definitionProvider"), "STDOUT: {stdout}");
+    assert!(stdout.contains("completionProvider"), "STDOUT: {stdout}");
+}
+
+#[test]
+fn test_debug_stops_at_breakpoints_and_prints_variables() {
+    let script = write_source(
+        "cli_debug_test",
+        "debug.goida",
+        "x = 1\nфункция удвоить(н: число) -> число {\n    результат = н * 2\n    вернуть результат\n}\nу = удвоить(x)\nпечать(у)\n",
+    );
+    let output = common::goida_command()
+        .args(["debug", script.to_str().unwrap()])
+        .output_with_stdin("b 4\nc\np\nn\np\n")
+        .expect("Не удалось запустить goida debug");
+
+    assert!(
+        output.status.success(),
+        "STDERR: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("debug.goida:1  x = 1"), "STDOUT: {stdout}");
+    assert!(
+        stdout.contains("debug.goida:4  вернуть результат"),
+        "STDOUT: {stdout}"
+    );
+    assert!(stdout.contains("  н = 1\n"), "STDOUT: {stdout}");
+    assert!(stdout.contains("  результат = 2\n"), "STDOUT: {stdout}");
+    assert!(
+        stdout.contains("debug.goida:7  печать(у)"),
+        "STDOUT: {stdout}"
+    );
+    assert!(stdout.contains("  у = 2\n"), "STDOUT: {stdout}");
+    // Когда ввод кончился, программа просто доработала до конца.
+    assert!(stdout.ends_with("2\n"), "STDOUT: {stdout}");
+}
+
+#[test]
+fn test_bare_script_path_runs_script_with_shebang() {
+    let script = write_source(
+        "cli_shebang_test",
+        "script.goida",
+        "#!/usr/bin/env goida\nпечать(Система.аргументы())\n",
+    );
+    let output = common::goida_command()
+        .args([script.to_str().unwrap(), "арг"])
+        .output()
+        .expect("Не удалось запустить goida с путём к файлу");
+
+    assert!(
+        output.status.success(),
+        "STDERR: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "[арг]\n");
+}
+
+#[test]
+fn test_bench_reports_every_iteration_and_summary() {
+    let script = write_source(
+        "cli_bench_test",
+        "script.goida",
+        "сумма = 0\nдля (н = 0, н < 100, н += 1) {\n    сумма += н\n}\n",
+    );
+    let output = common::goida_command()
+        .args(["bench", script.to_str().unwrap(), "-n", "3"])
+        .output()
+        .expect("Не удалось запустить goida bench");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(
+        output.status.success(),
+        "STDERR: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(stdout.matches("итерация").count(), 3);
+    assert!(stdout.contains("медиана"));
+    assert!(stdout.lines().any(|line| line.starts_with("выполнение")));
+
+    let output = common::goida_command()
+        .args(["bench", script.to_str().unwrap(), "-n", "0"])
+        .output()
+        .expect("Не удалось запустить goida bench");
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_imports_run_on_first_access_unless_eager() {
+    write_source(
+        "cli_lazy_imports_test",
+        "утилиты.goida",
+        "печать(\"утилиты загружены\")\nшаг = 10\nфункция удвоить(х) {\n    вернуть х * 2\n}\n",
+    );
+    write_source(
+        "cli_lazy_imports_test",
+        "лишний.goida",
+        "печать(\"лишний загружен\")\n",
+    );
+    let main = write_source(
+        "cli_lazy_imports_test",
+        "главный.goida",
+        "подключить \"утилиты.goida\" как утилиты\nподключить \"лишний.goida\" как лишний\nпечать(\"старт\")\nпечать(утилиты.удвоить(шаг))\n",
+    );
+    let run = |extra: &[&str]| {
+        let mut args = vec!["run"];
+        args.extend_from_slice(extra);
+        args.push(main.to_str().unwrap());
+        let output = common::goida_command()
+            .args(args)
+            .output()
+            .expect("Не удалось запустить goida run");
+        assert!(
+            output.status.success(),
+            "goida run завершился с ошибкой\nSTDOUT: {}",
+            String::from_utf8_lossy(&output.stdout)
+        );
+        String::from_utf8_lossy(&output.stdout).replace("\r\n", "\n")
+    };
+
+    assert_eq!(run(&[]), "старт\nутилиты загружены\n20\n");
+    assert_eq!(
+        run(&["--eager-imports"]),
+        "утилиты загружены\nлишний загружен\nстарт\n20\n"
+    );
+}
+
+#[test]
+fn test_import_first_loaded_in_a_thread_runs_once() {
+    write_source(
+        "cli_thread_imports_test",
+        "мод.goida",
+        "печать(\"мод загружен\")\nвызовы = список()\nфункция отметить(кто) {\n    вызовы.добавить(кто)\n    вернуть вызовы.длина()\n}\n",
+    );
+    let main = write_source(
+        "cli_thread_imports_test",
+        "главный.goida",
+        "подключить \"мод.goida\" как мод\nфункция работа() {\n    печать(мод.отметить(\"поток\"))\n}\nп = Поток.запустить(работа)\nп.ждать()\nпечать(мод.отметить(\"главный\"))\n",
+    );
+    for extra in [None, Some("--eager-imports")] {
+        let mut args = vec!["run"];
+        args.extend(extra);
+        args.push(main.to_str().unwrap());
+        let output = common::goida_command()
+            .args(args)
+            .output()
+            .expect("Не удалось запустить goida run");
+        assert!(
+            output.status.success(),
+            "goida run завершился с ошибкой\nSTDOUT: {}",
+            String::from_utf8_lossy(&output.stdout)
+        );
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout).replace("\r\n", "\n"),
+            "мод загружен\n1\n2\n"
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use std::ffi::OsStr;
+use std::io::Write;
 use std::path::{Path, PathBuf};
-use std::process::{Command, Output};
+use std::process::{Command, Output, Stdio};
 use std::sync::OnceLock;
 
 static RELEASE_BINARY: OnceLock<PathBuf> = OnceLock::new();
@@ -78,4 +79,19 @@ impl GoidaCommand {
     pub fn output(&mut self) -> std::io::Result<Output> {
         self.command.output()
     }
+
+    pub fn output_with_stdin(&mut self, input: &str) -> std::io::Result<Output> {
+        let mut child = self
+            .command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        child
+            .stdin
+            .take()
+            .expect("stdin is piped")
+            .write_all(input.as_bytes())?;
+        child.wait_with_output()
+    }
 }
@@ -314,6 +314,49 @@ fn test_regex_class_uses_russian_name() {
     assert_eq!("истина\nтест\n", String::from_utf8_lossy(&output.stdout));
 }
 
+#[test]
+fn test_regex_short_name_split_and_named_groups() {
+    let dir = std::path::Path::new("target/regex_named_groups_test");
+    std::fs::create_dir_all(dir).expect("Не удалось создать временную папку теста");
+
+    let main_file = dir.join("main.goida");
+    std::fs::write(
+        &main_file,
+        r#"
+дата = новый Регулярка("(?P<год>[0-9]{4})-(?P<месяц>[0-9]{2})(-(?P<день>[0-9]{2}))?")
+части = дата.именованные_группы("срок 2024-05")
+печать(части["год"], части["месяц"], части["день"])
+печать(дата.именованные_группы("нет даты"))
+печать(новый Регулярка("[,;] *").разбить("а, б;в"))
+"#,
+    )
+    .expect("Не удалось записать временный файл");
+
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            main_file.to_str().unwrap(),
+        ])
+        .output()
+        .expect("Не удалось запустить cargo run");
+
+    assert!(
+        output.status.success(),
+        "Регулярка завершилась с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        "2024 05 пустота\nпустота\n[а, б, в]\n",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
 #[test]
 fn test_lambda_expression_and_block_forms() {
     let dir = std::path::Path::new("target/lambda_expression_test");
@@ -0,0 +1,17 @@
+mod common;
+
+#[test]
+fn test_repl_inputs_see_functions_and_variables_of_earlier_inputs() {
+    let output = common::goida_command()
+        .args(["repl"])
+        .output_with_stdin(
+            "функция удвоить(х) {\n    вернуть х * 2\n}\nзначение = удвоить(21)\nпечать(значение)\nвыход\n",
+        )
+        .expect("Не удалось запустить goida repl");
+    let stdout = String::from_utf8_lossy(&output.stdout).replace("\r\n", "\n");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success(), "STDERR: {stderr}");
+    assert!(!stderr.contains("Ошибка"), "STDERR: {stderr}");
+    assert!(stdout.contains("42\n"), "STDOUT: {stdout}");
+}
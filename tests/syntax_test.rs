@@ -275,6 +275,1989 @@ fn test_json_roundtrip_example() {
     assert_eq!("1\nx\n", String::from_utf8_lossy(&output.stdout));
 }
 
+#[test]
+fn test_json_class_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/json_class_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить json_class_test.goida");
+
+    assert!(
+        output.status.success(),
+        "json_class_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        "Гойда\n8081\n[\"json\",null]\n{\n  \"а\": 1\n}\nошибка разбора\n",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_stopwatch_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/stopwatch_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить stopwatch_test.goida");
+
+    assert!(
+        output.status.success(),
+        "stopwatch_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        "0\nистина\nистина\nистина\n",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_bytes_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/bytes_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить bytes_test.goida");
+
+    assert!(
+        output.status.success(),
+        "bytes_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        "10\n208\nГо\n[104, 0, 105, 0]\nhi\nбайты[0, 255, 16]\nистина\n",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_file_lines_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/file_lines_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить file_lines_test.goida");
+
+    assert!(
+        output.status.success(),
+        "file_lines_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        "первая\n[вторая, третья]\nпустота\n> первая\n> вторая\n> третья\n3\n",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_file_metadata_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/file_metadata_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить file_metadata_test.goida");
+
+    assert!(
+        output.status.success(),
+        "file_metadata_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        "12\nложь\nистина\nистина\n9\nистина\n",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_directory_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/directory_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить directory_test.goida");
+
+    assert!(
+        output.status.success(),
+        "directory_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        "истина\n[а.txt, вложенная]\n[а.txt, вложенная/б.txt]\n[а.txt, вложенная/б.txt]\nложь\nложь\n",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_path_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/path_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить path_test.goida");
+
+    assert!(
+        output.status.success(),
+        "path_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        "истина\nотчёт.final.txt\ntxt\n2024\nистина\n..\n\nистина\n",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_config_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/config_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить config_test.goida");
+
+    assert!(
+        output.status.success(),
+        "config_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        "гойда\n8080\n[а, б]\n9090\nключ:\n- 1\n- 2\n\n3\nошибка разбора\n",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_database_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/database_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить database_test.goida");
+
+    assert!(
+        output.status.success(),
+        "database_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        "Война и мир 1869\nМастер и Маргарита 1967\nоткат: передумали\n2 1968\n",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_crypto_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/crypto_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить crypto_test.goida");
+
+    assert!(
+        output.status.success(),
+        "crypto_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "a3886d9f917965ca77a6d79f3e226c3908f47b6d737064dda0930ab63680b69c\n",
+            "a9993e364706816aba3e25717850c26c9cd0d89d\n",
+            "900150983cd24fb0d6963f7d28e17f72\n",
+            "ffd502332c126c0ce770304a67e56027530b13e5f02f1d3e87677865eb25eb7f\n",
+            "80070713463e7749b90c2dc24911e275\n",
+            "0J/RgNC40LLQtdGCLCDQvNC40YAh\n",
+            "Привет, мир!\n",
+            "000fff\n",
+            "[0, 255]\n",
+            "%D0%B0%20%D0%B1%26%D0%B2\n",
+            "гойда lang\n",
+            "неверный hex\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_url_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/url_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить url_test.goida");
+
+    assert!(
+        output.status.success(),
+        "url_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "https example.com 8443\n",
+            "/поиск\n",
+            "гойда 2\n",
+            "итоги\n",
+            "80\n",
+            "q=%D0%B0%20%D0%B1&tag=x&tag=y\n",
+            "https://api.example.com/v1/search?limit=10&q=%D0%B3%D0%BE%D0%B9%D0%B4%D0%B0%20lang\n",
+            "неверный адрес\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_datetime_arithmetic_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/datetime_arithmetic_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить datetime_arithmetic_test.goida");
+
+    assert!(
+        output.status.success(),
+        "datetime_arithmetic_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        "2 51 3090\n-51\n1 90\nистина ложь истина\nистина истина\nистина\nвстреча не раньше восьми часов от начала\n",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_timezone_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/timezone_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить timezone_test.goida");
+
+    assert!(
+        output.status.success(),
+        "timezone_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "UTC 1970-01-01 00:00 UTC\n",
+            "Europe/Moscow 3 01.01.1970 03:00 MSK (+03:00)\n",
+            "31 19 19:00 -0500\n",
+            "истина 0\n",
+            "пустота\n",
+            "UTC\n",
+            "неизвестный пояс\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_terminal_cursor_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/terminal_cursor_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить terminal_cursor_test.goida");
+
+    assert!(
+        output.status.success(),
+        "terminal_cursor_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "\x1b[2J\x1b[1;1H\x1b[?25l",
+            "\x1b[3;10Hгойда\n",
+            "\x1b[5;1Hсчёт: 1\n",
+            "\x1b[2K\r\x1b[?25h",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_terminal_key_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/terminal_key_test.goida",
+        ])
+        .output_with_stdin("ddaж \nq")
+        .expect("Не удалось запустить terminal_key_test.goida");
+
+    assert!(
+        output.status.success(),
+        "terminal_key_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "d -> 1\n",
+            "d -> 2\n",
+            "a -> 1\n",
+            "ж -> 1\n",
+            "пробел -> 1\n",
+            "ввод -> 1\n",
+            "конец: 1\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_terminal_table_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/terminal_table_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить terminal_table_test.goida");
+
+    assert!(
+        output.status.success(),
+        "terminal_table_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "\r[░░░░░░░░░░]   0% 0/4",
+            "\r[█████░░░░░]  50% 2/4",
+            "\r[██████████] 100% 4/4\n",
+            "готово\n",
+            "возраст | город  | имя\n",
+            "--------+--------+------\n",
+            "     30 | Москва | Анна\n",
+            "      7 |        | Борис\n",
+            "    112 | Тверь  | Вера\n",
+            "имя   | город\n",
+            "------+-------\n",
+            "Анна  | Москва\n",
+            "Борис |\n",
+            "Вера  | Тверь\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_terminal_colors_example() {
+    let run = |force_color: &str| {
+        let output = common::goida_command()
+            .args([
+                "run",
+                "-q",
+                "-p",
+                "goida-cli",
+                "--",
+                "run",
+                "examples/terminal_colors_test.goida",
+            ])
+            .env("NO_COLOR", "")
+            .env("FORCE_COLOR", force_color)
+            .output()
+            .expect("Не удалось запустить terminal_colors_test.goida");
+
+        assert!(
+            output.status.success(),
+            "terminal_colors_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+
+    // Вывод в канал: оформление отключено.
+    assert_eq!(
+        concat!(
+            "ложь\n",
+            "внимание\n",
+            "мягко\n",
+            "оранжевый\n",
+            "42\n",
+            "неверная составляющая\n",
+            "неизвестный стиль\n",
+        ),
+        run("")
+    );
+    assert_eq!(
+        concat!(
+            "истина\n",
+            "\x1b[1m\x1b[31mвнимание\x1b[0m\n",
+            "\x1b[38;2;255;136;0m\x1b[48;2;32;32;32mмягко\x1b[0m\n",
+            "\x1b[38;5;208mоранжевый\x1b[0m\n",
+            "\x1b[3m\x1b[4m42\x1b[0m\n",
+            "неверная составляющая\n",
+            "неизвестный стиль\n",
+        ),
+        run("1")
+    );
+}
+
+#[test]
+fn test_text_methods_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/text_methods_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить text_methods_test.goida");
+
+    assert!(
+        output.status.success(),
+        "text_methods_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "0 7 -1\n",
+            "истина истина\n",
+            "ура, ура!\n",
+            "2\n",
+            "[пробелы]\n",
+            "-----\n",
+            "[007] [ок  ]\n",
+            "длинная\n",
+            "[]\n",
+            "слишком длинное повторение\n",
+            "слишком длинное дополнение\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_unicode_text_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/unicode_text_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить unicode_text_test.goida");
+
+    assert!(
+        output.status.success(),
+        "unicode_text_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        "12 21\nП м !\n1 ж\n5\nвне строки\n",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_text_chars_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/text_chars_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить text_chars_test.goida");
+
+    assert!(
+        output.status.success(),
+        "text_chars_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "жсмзг, плу\n",
+            "гойда, мир\n",
+            "[ё, ж] 1046 ё\n",
+            "ПРИВЕТ ёлка Москва\n",
+            "1074\n",
+            "нет такого символа\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_list_sort_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/list_sort_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить list_sort_test.goida");
+
+    assert!(
+        output.status.success(),
+        "list_sort_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "[1, 3.5, 5, 7, 9]\n",
+            "[9, 7, 5, 3.5, 1]\n",
+            "[ананас, банан, груша, яблоко]\n",
+            "[банан, груша, ананас, яблоко]\n",
+            "Анна 25\n",
+            "Борис 25\n",
+            "Вера 31\n",
+            "разные типы\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_list_functional_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/list_functional_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить list_functional_test.goida");
+
+    assert!(
+        output.status.success(),
+        "list_functional_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "[1, 4, 9, 16, 25, 36]\n",
+            "[2, 4, 6]\n",
+            "21\n",
+            "720\n",
+            "[#4, #5, #6]\n",
+            ">аб\n",
+            "пустой список\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_list_operations_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/list_operations_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить list_operations_test.goida");
+
+    assert!(
+        output.status.success(),
+        "list_operations_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "[20, 30] [40, 50] []\n",
+            "2 -1\n",
+            "истина ложь\n",
+            "[50, 40, 30, 20, 10]\n",
+            "[5, 50, 40, 30, 20, 15, 10, 0]\n",
+            "[5, 50, 40, 30, 20, 15, 10, 0, 1, 2, 3] 11\n",
+            "индекс вне списка\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_slice_syntax_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/slice_syntax_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить slice_syntax_test.goida");
+
+    assert!(
+        output.status.success(),
+        "slice_syntax_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "[20, 30, 40] [10, 20] [40, 50] [10, 20, 30, 40, 50]\n",
+            "[40, 50] [10, 20] []\n",
+            "мир Привет ми\n",
+            "10 99\n",
+            "[20, 30] [2, 3]\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_negative_index_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/negative_index_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить negative_index_test.goida");
+
+    assert!(
+        output.status.success(),
+        "negative_index_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "30 10 2 8\n",
+            "т П\n",
+            "[10, 20, 99]\n",
+            "индекс вне списка\n",
+            "индекс вне строки\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_index_assign_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/index_assign_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить index_assign_test.goida");
+
+    assert!(
+        output.status.success(),
+        "index_assign_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "[10, 2, 8]\n",
+            "{\"имя\": Гойда, \"числа\": [1, 20]}\n",
+            "[[1, 20], [30, 4]]\n",
+            "[молоко, сыр]\n",
+            "индекс вне списка\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_dict_entries_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/dict_entries_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить dict_entries_test.goida");
+
+    assert!(
+        output.status.success(),
+        "dict_entries_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "молоко 90\n",
+            "хлеб 40\n",
+            "молоко\n",
+            "хлеб\n",
+            "80 3 90 2\n",
+            "[[молоко, 80], [сыр, 300], [хлеб, 40]]\n",
+            "3\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_dict_keys_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/dict_keys_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить dict_keys_test.goida");
+
+    assert!(
+        output.status.success(),
+        "dict_keys_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "{1: число, \"1\": строка, истина: да} 3\n",
+            "число строка да ложь\n",
+            "1 1 число\n",
+            "2 4 число\n",
+            "3 9 число\n",
+            "список не может быть ключом\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_set_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/set_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить set_test.goida");
+
+    assert!(
+        output.status.success(),
+        "set_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "[1, 2, 3, 5] 4 истина ложь\n",
+            "истина ложь\n",
+            "[2, 3, 5, 7]\n",
+            "[2, 5]\n",
+            "[3]\n",
+            "[3]\n",
+            "[а, б, д, к, р]\n",
+            "список не может быть элементом\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_queue_stack_classes_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/queue_stack_classes_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить queue_stack_classes_test.goida");
+
+    assert!(
+        output.status.success(),
+        "queue_stack_classes_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "первый 2 [второй, третий]\n",
+            "3 3 2 1 ложь\n",
+            "[1, 2, 3, 4]\n",
+            "стек пуст\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_bigint_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/bigint_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить bigint_test.goida");
+
+    assert!(
+        output.status.success(),
+        "bigint_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "15511210043330985984000000\n",
+            "870 790627\n",
+            "0 -123456789012345678901234567891\n",
+            "1267650600228229401496703205376\n",
+            "-1 43\n",
+            "не помещается\n",
+            "деление на ноль\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_mixed_numbers_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/mixed_numbers_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить mixed_numbers_test.goida");
+
+    assert!(
+        output.status.success(),
+        "mixed_numbers_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "3.5 2.5 1.5 6 1.5\n",
+            "-0.5 -3 истина ложь истина\n",
+            "2 0.25\n",
+            "1.25\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_division_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/division_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить division_test.goida");
+
+    assert!(
+        output.status.success(),
+        "division_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "3.5 4 -3.5 1\n",
+            "3 -3 3 1 1.5\n",
+            "в\n",
+            "деление на ноль\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_text_compare_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/text_compare_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить text_compare_test.goida");
+
+    assert!(
+        output.status.success(),
+        "text_compare_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "истина истина истина истина истина\n",
+            "[апельсин, арбуз, банан, груша]\n",
+            "груша\n",
+            "строку с числом не сравнить\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_deep_equality_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/deep_equality_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить deep_equality_test.goida");
+
+    assert!(
+        output.status.success(),
+        "deep_equality_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "истина ложь ложь\n",
+            "истина истина\n",
+            "истина 1\n",
+            "ложь\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_membership_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/membership_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить membership_test.goida");
+
+    assert!(
+        output.status.success(),
+        "membership_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "истина ложь истина\n",
+            "истина истина ложь ложь\n",
+            "истина истина истина\n",
+            "истина истина\n",
+            "истина\n",
+            "в = 5\n",
+            "в числе искать нельзя\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_chained_comparison_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/chained_comparison_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить chained_comparison_test.goida");
+
+    assert!(
+        output.status.success(),
+        "chained_comparison_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "истина ложь\n",
+            "истина истина истина\n",
+            "истина\n",
+            "истина ложь\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_short_circuit_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/short_circuit_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить short_circuit_test.goida");
+
+    assert!(
+        output.status.success(),
+        "short_circuit_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "ключа б нет\n",
+            "ложь\n",
+            "истина\n",
+            "гость\n",
+            "Аня\n",
+            "0\n",
+            "непусто\n",
+            "последний\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_repetition_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/repetition_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить repetition_test.goida");
+
+    assert!(
+        output.status.success(),
+        "repetition_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "--------------------\n",
+            "хахаха\n",
+            "[1, 0, 0, 0, 0] 5\n",
+            "[1, 2, 1, 2] [а, а, а]\n",
+            "[] []\n",
+            "[[7], [7]]\n",
+            "повторить можно только целое число раз\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_multiple_assignment_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/multiple_assignment_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить multiple_assignment_test.goida");
+
+    assert!(
+        output.status.success(),
+        "multiple_assignment_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "2 1\n",
+            "3 2\n",
+            "[право, лево]\n",
+            "[3, 2, 1]\n",
+            "3 4\n",
+            "не хватает значений\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_spread_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/spread_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить spread_test.goida");
+
+    assert!(
+        output.status.success(),
+        "spread_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "6\n",
+            "60\n",
+            "вызов с 3 аргументами\n",
+            "15\n",
+            "[1, 2, 3, 4, 5]\n",
+            "[3, 4, 3, 4]\n",
+            "а-б\n",
+            "число нельзя разложить\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_call_chain_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/call_chain_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить call_chain_test.goida");
+
+    assert!(
+        output.status.success(),
+        "call_chain_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "10\n",
+            "[20, 30]\n",
+            "3\n",
+            "42\n",
+            "10\n",
+            "101\n",
+            "12 8\n",
+            "9\n",
+            "2\n",
+            "42\n",
+            "[1, 2]\n",
+            "дай\n",
+            "аргумент\n",
+            "6\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_literal_methods_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/literal_methods_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить literal_methods_test.goida");
+
+    assert!(
+        output.status.success(),
+        "literal_methods_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "6\n",
+            "ПРИВЕТ\n",
+            "2\n",
+            "3\n",
+            "2\n",
+            "АБ\n",
+            "П\n",
+            "Мир\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_nested_functions_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/nested_functions_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить nested_functions_test.goida");
+
+    assert!(
+        output.status.success(),
+        "nested_functions_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "111\n",
+            "112\n",
+            "15\n",
+            "120\n",
+            "2\n",
+            "внешняя\n",
+            "[1, 2]\n",
+            "нажата ок\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_function_refs_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/function_refs_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить function_refs_test.goida");
+
+    assert!(
+        output.status.success(),
+        "function_refs_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "8\n",
+            "10\n",
+            "12\n",
+            "14\n",
+            "[2, 4, 6]\n",
+            "10\n",
+            "123\n",
+            "123\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_generators_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/generators_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить generators_test.goida");
+
+    assert!(
+        output.status.success(),
+        "generators_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "выдаю 0\n",
+            "получил 0\n",
+            "выдаю 1\n",
+            "получил 1\n",
+            "выдаю 2\n",
+            "получил 2\n",
+            "1\n",
+            "2\n",
+            "3\n",
+            "выдаю 0\n",
+            "выдаю 1\n",
+            "выдаю 2\n",
+            "выдаю 3\n",
+            "[0, 1, 4, 9]\n",
+            "АЗБУКА\n",
+            "БУКВАРЬ\n",
+            "1\n",
+            "поймал: генератор сломался\n",
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_iterator_protocol_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/iterator_protocol_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить iterator_protocol_test.goida");
+
+    assert!(
+        output.status.success(),
+        "iterator_protocol_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!("1\n", "2\n", "3\n", "[1, 2, 3]\n", "6\n", "б\n", "а\n"),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_with_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/with_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить with_test.goida");
+
+    assert!(
+        output.status.success(),
+        "with_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "файл уже закрыт\n",
+            "[первая строка, вторая строка]\n",
+            "работаем с база\n",
+            "закрыто: база\n",
+            "перехвачено: сбой запроса\n",
+            "первая строка\n",
+            "закрыто: вручную\n"
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_reflection_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/reflection_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить reflection_test.goida");
+
+    assert!(
+        output.status.success(),
+        "reflection_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "[x, y]\n",
+            "[все_поля, длина]\n",
+            "истина\n",
+            "истина\n",
+            "ложь\n",
+            "ложь\n",
+            "6\n",
+            "нет\n",
+            "{x=6, y=4}\n",
+            "[x, y, метка]\n",
+            "метка недоступна\n"
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_dynamic_eval_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/dynamic_eval_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить dynamic_eval_test.goida");
+
+    assert!(
+        output.status.success(),
+        "dynamic_eval_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "21\n",
+            "30\n",
+            "15\n",
+            "42\n",
+            "49\n",
+            "6\n",
+            "истина\n",
+            "ошибка разбора\n"
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_module_reload_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/module_reload_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить module_reload_test.goida");
+
+    assert!(
+        output.status.success(),
+        "module_reload_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "1\n",
+            "привет, мир\n",
+            "привет, мир\n",
+            "2\n",
+            "здравствуй, мир\n",
+            "Модуль 'нет_такого_модуля' не подключён\n"
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_static_fields_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/static_fields_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить static_fields_test.goida");
+
+    assert!(
+        output.status.success(),
+        "static_fields_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "10\n",
+            "20\n",
+            "101\n",
+            "100\n",
+            "пустота\n",
+            "[первый, второй]\n",
+            "3\n"
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_class_constants_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/class_constants_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить class_constants_test.goida");
+
+    assert!(
+        output.status.success(),
+        "class_constants_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "3.14159\n",
+            "5\n",
+            "геометрия\n",
+            "12.56636\n",
+            "геометрия\n",
+            "5\n",
+            "Нельзя изменить константу\n",
+            "3.14159\n"
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_super_call_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/super_call_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить super_call_test.goida");
+
+    assert!(
+        output.status.success(),
+        "super_call_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "фигура круг радиуса 2\n",
+            "красный фигура круг радиуса 3\n",
+            "базовый\n"
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_instance_check_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/instance_check_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить instance_check_test.goida");
+
+    assert!(
+        output.status.success(),
+        "instance_check_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "истина\n",
+            "истина\n",
+            "ложь\n",
+            "ложь\n",
+            "истина\n",
+            "машина — не животное\n",
+            "истина\n",
+            "истина\n",
+            "истина\n",
+            "ложь\n"
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_defer_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/defer_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить defer_test.goida");
+
+    assert!(
+        output.status.success(),
+        "defer_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!(
+            "открыт отчёт\n",
+            "сохранён отчёт\n",
+            "закрыт отчёт\n",
+            "готово отчёт\n",
+            "уборка после ошибки\n",
+            "перехвачено: сбой\n",
+            "итог 3\n",
+            "имя\n"
+        ),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_string_builder_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/string_builder_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить string_builder_test.goida");
+
+    assert!(
+        output.status.success(),
+        "string_builder_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        concat!("числа: 1 2 3 4 5\n", "16\n", "абв\n", "истина\n"),
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
 #[test]
 fn test_thread_example() {
     let output = common::goida_command()
@@ -297,7 +2280,88 @@ fn test_thread_example() {
         String::from_utf8_lossy(&output.stderr)
     );
 
-    assert_eq!("2\n3\n", String::from_utf8_lossy(&output.stdout));
+    assert_eq!(
+        "2\n3\n5\n[1, 4, 9, 16]\n",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn test_channel_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/channel_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить channel_test.goida");
+
+    assert!(
+        output.status.success(),
+        "channel_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!("60\nпустота\n", String::from_utf8_lossy(&output.stdout));
+}
+
+#[test]
+fn test_lock_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/lock_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить lock_test.goida");
+
+    assert!(
+        output.status.success(),
+        "lock_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!("400\n800\n", String::from_utf8_lossy(&output.stdout));
+}
+
+#[test]
+fn test_async_example() {
+    let output = common::goida_command()
+        .args([
+            "run",
+            "-q",
+            "-p",
+            "goida-cli",
+            "--",
+            "run",
+            "examples/async_test.goida",
+        ])
+        .output()
+        .expect("Не удалось запустить async_test.goida");
+
+    assert!(
+        output.status.success(),
+        "async_test.goida завершился с ошибкой\nSTDOUT: {}\nSTDERR: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        "[файл 0, файл 1, файл 2]\nпривет\nистина\nфайла нет\n",
+        String::from_utf8_lossy(&output.stdout)
+    );
 }
 
 #[test]